@@ -609,6 +609,9 @@ export declare enum BuiltinPluginName {
   RslibPlugin = 'RslibPlugin',
   CircularDependencyRspackPlugin = 'CircularDependencyRspackPlugin',
   URLPlugin = 'URLPlugin',
+  DotenvPlugin = 'DotenvPlugin',
+  TypeCheckPlugin = 'TypeCheckPlugin',
+  DuplicatePackageRspackPlugin = 'DuplicatePackageRspackPlugin',
   JsLoaderRspackPlugin = 'JsLoaderRspackPlugin',
   LazyCompilationPlugin = 'LazyCompilationPlugin',
   ModuleInfoHeaderPlugin = 'ModuleInfoHeaderPlugin',
@@ -879,6 +882,9 @@ export interface JsHtmlPluginAssets {
   js: Array<string>
   css: Array<string>
   favicon?: string
+  importMap?: string
+  modulePreload: Array<string>
+  speculationRules?: string
   jsIntegrity?: Array<string | undefined | null>
   cssIntegrity?: Array<string | undefined | null>
 }
@@ -1057,9 +1063,15 @@ export interface JsRscClientPluginOptions {
   coordinator: JsCoordinator
 }
 
+export interface JsRscReferenceManifestOptions {
+  clientFilename: string
+  serverFilename: string
+}
+
 export interface JsRscServerPluginOptions {
   coordinator: JsCoordinator
   onServerComponentChanges?: (() => void) | undefined | null
+  referenceManifest?: JsRscReferenceManifestOptions
 }
 
 export interface JsRsdoctorAsset {
@@ -1768,6 +1780,7 @@ export interface NodeFsStats {
   birthtimeMs: number
   size: number
   mode: number
+  ino?: number
 }
 
 export interface PathWithInfo {
@@ -1805,6 +1818,10 @@ export interface RawAssetInlineGeneratorOptions {
   binary?: boolean
 }
 
+export interface RawAssetInlineParserOptions {
+  image?: RawAssetParserImageOptions
+}
+
 export interface RawAssetParserDataUrl {
   type: "options"
   options?: RawAssetParserDataUrlOptions
@@ -1814,8 +1831,14 @@ export interface RawAssetParserDataUrlOptions {
   maxSize?: number
 }
 
+export interface RawAssetParserImageOptions {
+  optimize?: boolean
+  quality?: number
+}
+
 export interface RawAssetParserOptions {
   dataUrlCondition?: RawAssetParserDataUrl
+  image?: RawAssetParserImageOptions
 }
 
 export interface RawAssetResourceGeneratorOptions {
@@ -1827,6 +1850,10 @@ export interface RawAssetResourceGeneratorOptions {
   binary?: boolean
 }
 
+export interface RawAssetResourceParserOptions {
+  image?: RawAssetParserImageOptions
+}
+
 export interface RawBannerPluginOptions {
   banner: string | ((args: { hash: string, chunk: Chunk, filename: string }) => string)
   entryOnly?: boolean
@@ -1882,6 +1909,13 @@ export interface RawCacheOptionsPersistent {
   readonly?: boolean
 }
 
+export interface RawChunkLoadRetry {
+  maxRetries: number
+  retryDelay: number
+  cacheBust: boolean
+  fallbackPublicPaths: Array<string>
+}
+
 export interface RawCircularDependencyRspackPluginOptions {
   failOnError?: boolean
   exclude?: RegExp
@@ -2138,6 +2172,13 @@ export interface RawDllManifestContentItem {
   buildMeta?: JsBuildMeta
   exports?: string[] | true
   id?: number | string
+  package?: RawDllManifestPackage
+  hash?: string
+}
+
+export interface RawDllManifestPackage {
+  name: string
+  version: string
 }
 
 export interface RawDllReferenceAgencyPluginOptions {
@@ -2151,10 +2192,23 @@ export interface RawDllReferenceAgencyPluginOptions {
   manifest?: RawDllManifest
 }
 
+export interface RawDotenvPluginOptions {
+  dir: string
+  mode?: string
+  prefixes?: Array<string>
+  processEnv?: boolean
+  importMetaEnv?: boolean
+}
+
 export interface RawDraft {
   customMedia: boolean
 }
 
+export interface RawDuplicatePackageRspackPluginOptions {
+  failOnError?: boolean
+  allowlist?: Array<string | RegExp>
+}
+
 export interface RawDynamicEntryPluginOptions {
   context: string
   entry: () => Promise<RawEntryDynamicResult[]>
@@ -2199,6 +2253,7 @@ export interface RawExperiments {
   useInputFileSystem?: false | Array<RegExp>
   css?: boolean
   deferImport: boolean
+  strictRegex?: boolean
 }
 
 export interface RawExposeOptions {
@@ -2301,9 +2356,16 @@ export interface RawHtmlRspackPluginOptions {
   meta?: Record<string, Record<string, string>>
   hash?: boolean
   base?: RawHtmlRspackPluginBaseOptions
+  modulePreload?: boolean
+  speculationRules?: RawHtmlSpeculationRules
   uid?: number
 }
 
+export interface RawHtmlSpeculationRules {
+  urls: Record<string, Array<string>>
+  eagerness?: "immediate" | "eager" | "moderate" | "conservative"
+}
+
 export interface RawHttpExternalsRspackPluginOptions {
   css: boolean
   webAsync: boolean
@@ -2500,11 +2562,25 @@ export interface RawLimitChunkCountPluginOptions {
   maxChunks: number
 }
 
+export interface RawManglePropertiesOptions {
+  regex: RegExp
+  reserved?: Array<string>
+}
+
 export interface RawManifestExposeOption {
   path: string
   name: string
 }
 
+export interface RawManifestRemoteTypesOption {
+  alias: string
+  archive: string
+}
+
+export interface RawManifestSharedCheckOption {
+  remoteManifests?: Array<string>
+}
+
 export interface RawManifestSharedOption {
   name: string
   version?: string
@@ -2512,6 +2588,10 @@ export interface RawManifestSharedOption {
   singleton?: boolean
 }
 
+export interface RawManifestTypesOption {
+  zipName: string
+}
+
 export interface RawModuleFederationManifestPluginOptions {
   name?: string
   globalName?: string
@@ -2524,6 +2604,10 @@ export interface RawModuleFederationManifestPluginOptions {
   exposes?: Array<RawManifestExposeOption>
   shared?: Array<RawManifestSharedOption>
   buildInfo?: RawStatsBuildInfo
+  types?: RawManifestTypesOption
+  remoteTypes?: Array<RawManifestRemoteTypesOption>
+  typesFolder?: string
+  sharedCheck?: RawManifestSharedCheckOption
 }
 
 export interface RawModuleFederationRuntimeExperimentsOptions {
@@ -2673,6 +2757,10 @@ __references: Record<string, any>
 __virtual_files?: Array<JsVirtualFile>
 }
 
+export interface RawOutputImportMap {
+  filename: string
+}
+
 export interface RawOutputOptions {
   path: string
   pathinfo: boolean | "verbose"
@@ -2700,8 +2788,10 @@ export interface RawOutputOptions {
   importMetaName: string
   iife: boolean
   module: boolean
+  importMap?: RawOutputImportMap
   chunkLoading: string | false
   chunkLoadTimeout: number
+  chunkLoadRetry?: RawChunkLoadRetry
   enabledChunkLoadingTypes?: Array<string>
   trustedTypes?: RawTrustedTypes
   sourceMapFilename: string
@@ -2719,8 +2809,10 @@ export interface RawOutputOptions {
 }
 
 export interface RawParserOptions {
-  type: "asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json"
+  type: "asset" | "asset/inline" | "asset/resource" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json"
   asset?: RawAssetParserOptions
+  assetInline?: RawAssetInlineParserOptions
+  assetResource?: RawAssetResourceParserOptions
   css?: RawCssParserOptions
   cssAuto?: RawCssAutoParserOptions
   cssModule?: RawCssModuleParserOptions
@@ -2777,6 +2869,11 @@ export interface RawRemoteOptions {
   shareScope: string
 }
 
+export interface RawRemoteStorageOptions {
+  url: string
+  mode: "read-only" | "write-through"
+}
+
 export interface RawResolveOptions {
   preferRelative?: boolean
   preferAbsolute?: boolean
@@ -2801,6 +2898,7 @@ export interface RawResolveOptions {
   roots?: Array<string>
   pnp?: boolean
   pnpManifest?: string | false
+  importMap?: string
 }
 
 export interface RawResolveOptionsWithDependencyType {
@@ -2829,6 +2927,7 @@ export interface RawResolveOptionsWithDependencyType {
   resolveToContext?: boolean
   pnp?: boolean
   pnpManifest?: string | false
+  importMap?: string
 }
 
 export interface RawResolveTsconfigOptions {
@@ -2911,17 +3010,35 @@ export interface RawSharedUsedExportsOptimizerPluginOptions {
   statsFileName?: string
 }
 
+export interface RawSizeBaseline {
+  path: string
+  maxGrowth?: number
+  update?: boolean
+}
+
+export interface RawSizeBudget {
+  name?: string
+  entry?: string
+  asset?: string
+  chunkType?: "initial" | "async" | "css"
+  compression?: "gzip" | "brotli"
+  maxSize?: number
+}
+
 export interface RawSizeLimitsPluginOptions {
   assetFilter?: (assetFilename: string) => boolean
   hints?: "error" | "warning"
   maxAssetSize?: number
   maxEntrypointSize?: number
+  budgets?: Array<RawSizeBudget>
+  baseline?: RawSizeBaseline
 }
 
 export interface RawSnapshotOptions {
   immutablePaths: Array<string|RegExp>
   unmanagedPaths: Array<string|RegExp>
   managedPaths: Array<string|RegExp>
+  mode?: "timestamp" | "content"
 }
 
 export interface RawSplitChunkSizes {
@@ -2965,6 +3082,7 @@ export interface RawStatsOptions {
 export interface RawStorageOptions {
   type: "filesystem"
   directory: string
+  remote?: RawRemoteStorageOptions
 }
 
 export interface RawSubresourceIntegrityPluginOptions {
@@ -2987,6 +3105,8 @@ export interface RawSwcJsMinimizerRspackPluginOptions {
   include?: string | RegExp | (string | RegExp)[]
   exclude?: string | RegExp | (string | RegExp)[]
   extractComments?: RawExtractComments
+  mangleProperties?: RawManglePropertiesOptions
+  nameCache?: string
   minimizerOptions: RawSwcJsMinimizerOptions
 }
 
@@ -3011,6 +3131,15 @@ export interface RawTrustedTypes {
   onPolicyCreationFailure?: string
 }
 
+export interface RawTypeCheckPluginOptions {
+  command: string
+  tsconfig?: string
+  buildInfoFile?: string
+  args?: Array<string>
+  blocking: boolean
+  onChecked?: () => void
+}
+
 export interface RealDependencyLocation {
   start: SourcePosition
   end?: SourcePosition
@@ -38,19 +38,20 @@ use externals::ExternalsPresets;
 use indexmap::IndexMap;
 use rspack_core::{
   AssetParserDataUrl, AssetParserDataUrlOptions, AssetParserOptions, BoxPlugin, ByDependency,
-  CacheOptions, ChunkLoading, ChunkLoadingType, CleanOptions, Compiler, CompilerOptions,
-  CompilerPlatform, Context, CrossOriginLoading, CssAutoGeneratorOptions, CssAutoParserOptions,
-  CssExportsConvention, CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions,
-  CssParserImport, CssParserOptions, DynamicImportMode, EntryDescription, EntryOptions,
-  EntryRuntime, Environment, Experiments, ExternalItem, ExternalType, Filename, GeneratorOptions,
-  GeneratorOptionsMap, JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions,
-  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions,
-  JsonParserOptions, LibraryName, LibraryNonUmdObject, LibraryOptions, LibraryType,
-  MangleExportsOption, Mode, ModuleNoParseRules, ModuleOptions, ModuleRule, ModuleRuleEffect,
-  ModuleType, NodeDirnameOption, NodeFilenameOption, NodeGlobalOption, NodeOption, Optimization,
-  OutputOptions, ParseOption, ParserOptions, ParserOptionsMap, PathInfo, PublicPath, Resolve,
-  RuleSetCondition, RuleSetLogicalConditions, SideEffectOption, StatsOptions, TrustedTypes,
-  UsedExportsOption, WasmLoading, WasmLoadingType, incremental::IncrementalOptions,
+  CacheOptions, ChunkLoadRetry, ChunkLoading, ChunkLoadingType, CleanOptions, Compiler,
+  CompilerOptions, CompilerPlatform, Context, CrossOriginLoading, CssAutoGeneratorOptions,
+  CssAutoParserOptions, CssExportsConvention, CssGeneratorOptions, CssModuleGeneratorOptions,
+  CssModuleParserOptions, CssParserImport, CssParserOptions, DynamicImportMode, EntryDescription,
+  EntryOptions, EntryRuntime, Environment, Experiments, ExternalItem, ExternalType, Filename,
+  GeneratorOptions, GeneratorOptionsMap, JavascriptParserCommonjsExportsOption,
+  JavascriptParserCommonjsOptions, JavascriptParserOptions, JavascriptParserOrder,
+  JavascriptParserUrl, JsonGeneratorOptions, JsonParserOptions, LibraryName, LibraryNonUmdObject,
+  LibraryOptions, LibraryType, MangleExportsOption, Mode, ModuleNoParseRules, ModuleOptions,
+  ModuleRule, ModuleRuleEffect, ModuleType, NodeDirnameOption, NodeFilenameOption,
+  NodeGlobalOption, NodeOption, Optimization, OutputImportMap, OutputOptions, ParseOption,
+  ParserOptions, ParserOptionsMap, PathInfo, PublicPath, Resolve, RuleSetCondition,
+  RuleSetLogicalConditions, SideEffectOption, StatsOptions, TrustedTypes, UsedExportsOption,
+  WasmLoading, WasmLoadingType, incremental::IncrementalOptions,
 };
 use rspack_error::{Error, Result};
 use rspack_fs::{IntermediateFileSystem, ReadableFileSystem, WritableFileSystem};
@@ -1245,7 +1246,7 @@ impl CompilerOptionsBuilder {
 
     let amd = self.amd.take();
 
-    Ok(CompilerOptions {
+    let mut options = CompilerOptions {
       name,
       context,
       output,
@@ -1262,7 +1263,9 @@ impl CompilerOptionsBuilder {
       amd,
       bail,
       __references: Default::default(),
-    })
+    };
+    options.apply_experiments()?;
+    Ok(options)
   }
 }
 
@@ -1684,6 +1687,7 @@ impl ModuleOptionsBuilder {
           data_url_condition: Some(AssetParserDataUrl::Options(AssetParserDataUrlOptions {
             max_size: Some(8096.0),
           })),
+          image: None,
         }),
       );
     }
@@ -2144,6 +2148,8 @@ pub struct OutputOptionsBuilder {
   chunk_loading_global: Option<String>,
   /// Set the chunk load timeout.
   chunk_load_timeout: Option<u32>,
+  /// Set the chunk load retry.
+  chunk_load_retry: Option<ChunkLoadRetry>,
   /// Set the chunk format.
   chunk_format: Option<String>,
   /// Set the filename.
@@ -2182,6 +2188,8 @@ pub struct OutputOptionsBuilder {
   iife: Option<bool>,
   /// Set the module.
   module: Option<bool>,
+  /// Set the import map.
+  import_map: Option<OutputImportMap>,
   /// Set the trusted types.
   trusted_types: Option<TrustedTypes>,
   /// Set the source map filename.
@@ -2230,6 +2238,7 @@ impl From<OutputOptions> for OutputOptionsBuilder {
       chunk_loading: Some(value.chunk_loading),
       chunk_loading_global: Some(value.chunk_loading_global),
       chunk_load_timeout: Some(value.chunk_load_timeout),
+      chunk_load_retry: value.chunk_load_retry,
       chunk_format: None,
       filename: Some(value.filename),
       chunk_filename: Some(value.chunk_filename),
@@ -2247,6 +2256,7 @@ impl From<OutputOptions> for OutputOptionsBuilder {
       import_meta_name: Some(value.import_meta_name),
       iife: Some(value.iife),
       module: Some(value.module),
+      import_map: value.import_map,
       trusted_types: value.trusted_types,
       source_map_filename: Some(value.source_map_filename),
       hash_function: Some(value.hash_function),
@@ -2283,6 +2293,7 @@ impl From<&mut OutputOptionsBuilder> for OutputOptionsBuilder {
       chunk_loading: value.chunk_loading.take(),
       chunk_loading_global: value.chunk_loading_global.take(),
       chunk_load_timeout: value.chunk_load_timeout.take(),
+      chunk_load_retry: value.chunk_load_retry.take(),
       chunk_format: value.chunk_format.take(),
       filename: value.filename.take(),
       chunk_filename: value.chunk_filename.take(),
@@ -2302,6 +2313,7 @@ impl From<&mut OutputOptionsBuilder> for OutputOptionsBuilder {
       import_meta_name: value.import_meta_name.take(),
       iife: value.iife.take(),
       module: value.module.take(),
+      import_map: value.import_map.take(),
       trusted_types: value.trusted_types.take(),
       source_map_filename: value.source_map_filename.take(),
       hash_function: value.hash_function.take(),
@@ -2412,6 +2424,14 @@ impl OutputOptionsBuilder {
     self
   }
 
+  /// Set how failed chunk loads are retried at runtime.
+  ///
+  /// Default set to `None`, which rejects on the first failure.
+  pub fn chunk_load_retry(&mut self, retry: ChunkLoadRetry) -> &mut Self {
+    self.chunk_load_retry = Some(retry);
+    self
+  }
+
   /// The format of chunks (formats included by default are 'array-push' (web/webworker), 'commonjs' (node.js), 'module' (ESM).
   pub fn chunk_format(&mut self, chunk_format: String) -> &mut Self {
     self.chunk_format = Some(chunk_format);
@@ -2532,6 +2552,14 @@ impl OutputOptionsBuilder {
     self
   }
 
+  /// Set whether to import chunks through bare specifiers resolved by an emitted import map.
+  ///
+  /// Only takes effect with the `module` chunk format. Default set to `None`.
+  pub fn import_map(&mut self, import_map: OutputImportMap) -> &mut Self {
+    self.import_map = Some(import_map);
+    self
+  }
+
   /// Set controls [Trusted Types](https://web.dev/articles/trusted-types) compatibility.
   pub fn trusted_types(&mut self, trusted_types: TrustedTypes) -> &mut Self {
     self.trusted_types = Some(trusted_types);
@@ -3138,6 +3166,7 @@ impl OutputOptionsBuilder {
       chunk_loading,
       chunk_loading_global,
       chunk_load_timeout,
+      chunk_load_retry: self.chunk_load_retry.take(),
       filename,
       chunk_filename,
       cross_origin_loading,
@@ -3154,6 +3183,7 @@ impl OutputOptionsBuilder {
       import_meta_name,
       iife,
       module,
+      import_map: self.import_map.take(),
       trusted_types: self.trusted_types.take(),
       source_map_filename,
       hash_function,
@@ -3623,6 +3653,8 @@ impl OptimizationOptionsBuilder {
                 include: None,
                 exclude: None,
                 extract_comments: None,
+                mangle_properties: None,
+                name_cache: None,
                 minimizer_options: Default::default(),
               },
             ),
@@ -3679,6 +3711,8 @@ pub struct ExperimentsBuilder {
   css: Option<bool>,
   /// Whether to enable async web assembly.
   async_web_assembly: Option<bool>,
+  /// Whether to only allow regexes in `module.rules` that can be matched in linear time.
+  strict_regex: Option<bool>,
   // TODO: lazy compilation
 }
 
@@ -3688,6 +3722,7 @@ impl From<Experiments> for ExperimentsBuilder {
       future_defaults: None,
       css: Some(value.css),
       async_web_assembly: None,
+      strict_regex: Some(value.strict_regex),
     }
   }
 }
@@ -3698,6 +3733,7 @@ impl From<&mut ExperimentsBuilder> for ExperimentsBuilder {
       future_defaults: value.future_defaults.take(),
       css: value.css.take(),
       async_web_assembly: value.async_web_assembly.take(),
+      strict_regex: value.strict_regex.take(),
     }
   }
 }
@@ -3721,6 +3757,12 @@ impl ExperimentsBuilder {
     self
   }
 
+  /// Set whether to only allow regexes in `module.rules` that can be matched in linear time.
+  pub fn strict_regex(&mut self, strict_regex: bool) -> &mut Self {
+    self.strict_regex = Some(strict_regex);
+    self
+  }
+
   /// Build [`Experiments`] from options.
   ///
   /// [`Experiments`]: rspack_core::options::Experiments
@@ -3738,6 +3780,7 @@ impl ExperimentsBuilder {
     Ok(Experiments {
       css: d!(self.css, false),
       defer_import: false,
+      strict_regex: d!(self.strict_regex, false),
     })
   }
 }
@@ -61,3 +61,7 @@
 //!
 //! To track the current stats for API, please refer to [this](https://github.com/web-infra-dev/rspack/issues/9378) GitHub issue.
 pub mod builder;
+
+/// Serve the output of a compiler and push the compilation results to the `webpack-dev-server` client.
+#[cfg(feature = "dev_server")]
+pub use rspack_dev_server as dev_server;
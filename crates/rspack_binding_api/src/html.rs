@@ -84,6 +84,9 @@ pub struct JsHtmlPluginAssets {
   pub js: Vec<String>,
   pub css: Vec<String>,
   pub favicon: Option<String>,
+  pub import_map: Option<String>,
+  pub module_preload: Vec<String>,
+  pub speculation_rules: Option<String>,
   // manifest: Option<String>,
   pub js_integrity: Option<Vec<Option<String>>>,
   pub css_integrity: Option<Vec<Option<String>>>,
@@ -96,6 +99,9 @@ impl From<HtmlPluginAssets> for JsHtmlPluginAssets {
       js: value.js,
       css: value.css,
       favicon: value.favicon,
+      import_map: value.import_map,
+      module_preload: value.module_preload,
+      speculation_rules: value.speculation_rules,
       js_integrity: value.js_integrity,
       css_integrity: value.css_integrity,
     }
@@ -109,6 +115,9 @@ impl From<JsHtmlPluginAssets> for HtmlPluginAssets {
       js: value.js,
       css: value.css,
       favicon: value.favicon,
+      import_map: value.import_map,
+      module_preload: value.module_preload,
+      speculation_rules: value.speculation_rules,
       js_integrity: value.js_integrity,
       css_integrity: value.css_integrity,
     }
@@ -1,7 +1,7 @@
 use napi::Either;
 use napi_derive::napi;
 use rspack_core::{
-  Alias, AliasMap, ByDependency, DependencyCategory, PnpManifest, Resolve,
+  Alias, AliasMap, ByDependency, DependencyCategory, ImportMapOptions, PnpManifest, Resolve,
   ResolveOptionsWithDependencyType, Restriction, TsconfigOptions, TsconfigReferences,
 };
 use rspack_error::error;
@@ -58,6 +58,7 @@ pub struct RawResolveOptions {
   pub pnp: Option<bool>,
   #[napi(ts_type = "string | false")]
   pub pnp_manifest: Option<Either<String, bool>>,
+  pub import_map: Option<String>,
 }
 
 fn normalize_alias(
@@ -156,6 +157,9 @@ impl TryFrom<RawResolveOptions> for Resolve {
     let imports_fields = value
       .imports_fields
       .map(|v| v.into_iter().map(|s| vec![s]).collect());
+    let import_map = value
+      .import_map
+      .map(|path| ImportMapOptions { path: path.into() });
 
     Ok(Resolve {
       modules,
@@ -181,6 +185,7 @@ impl TryFrom<RawResolveOptions> for Resolve {
       imports_fields,
       pnp,
       pnp_manifest,
+      import_map,
       builtin_modules: false,
     })
   }
@@ -246,6 +251,7 @@ pub struct RawResolveOptionsWithDependencyType {
   pub pnp: Option<bool>,
   #[napi(ts_type = "string | false")]
   pub pnp_manifest: Option<Either<String, ()>>,
+  pub import_map: Option<String>,
 }
 
 pub fn normalize_raw_resolve_options_with_dependency_type(
@@ -321,6 +327,9 @@ pub fn normalize_raw_resolve_options_with_dependency_type(
         by_dependency,
         description_files: raw.description_files,
         enforce_extension: raw.enforce_extension,
+        import_map: raw
+          .import_map
+          .map(|path| ImportMapOptions { path: path.into() }),
         builtin_modules: false,
       };
       Ok(ResolveOptionsWithDependencyType {
@@ -12,7 +12,9 @@ use napi::{
 use once_cell::unsync::OnceCell;
 use rspack_core::{Compiler, CompilerId};
 use rspack_error::ToStringResultToRspackResultExt;
-use rspack_plugin_rsc::{Coordinator, RscClientPluginOptions, RscServerPluginOptions};
+use rspack_plugin_rsc::{
+  Coordinator, ReferenceManifestOptions, RscClientPluginOptions, RscServerPluginOptions,
+};
 
 use crate::JsCompiler;
 
@@ -78,6 +80,22 @@ impl From<&JsRscClientPluginOptions<'_>> for RscClientPluginOptions {
 pub struct JsRscServerPluginOptions<'a> {
   pub coordinator: ClassInstance<'a, JsCoordinator>,
   pub on_server_component_changes: Option<Either3<Function<'static, (), ()>, Undefined, Null>>,
+  pub reference_manifest: Option<JsRscReferenceManifestOptions>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct JsRscReferenceManifestOptions {
+  pub client_filename: String,
+  pub server_filename: String,
+}
+
+impl From<&JsRscReferenceManifestOptions> for ReferenceManifestOptions {
+  fn from(value: &JsRscReferenceManifestOptions) -> Self {
+    Self {
+      client_filename: value.client_filename.clone(),
+      server_filename: value.server_filename.clone(),
+    }
+  }
 }
 
 impl TryFrom<&JsRscServerPluginOptions<'_>> for RscServerPluginOptions {
@@ -109,6 +127,7 @@ impl TryFrom<&JsRscServerPluginOptions<'_>> for RscServerPluginOptions {
     Ok(Self {
       coordinator: value.coordinator.i.clone(),
       on_server_component_changes,
+      reference_manifest: value.reference_manifest.as_ref().map(Into::into),
     })
   }
 }
@@ -81,7 +81,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
     let resolve_loader = value.resolve_loader.try_into()?;
     let mode = value.mode.unwrap_or_default().into();
     let module: ModuleOptions = value.module.try_into()?;
-    let cache = normalize_raw_cache(value.cache);
+    let cache = normalize_raw_cache(value.cache)?;
     let experiments: Experiments = value.experiments.into();
     let mut incremental: IncrementalOptions = match value.incremental {
       Some(value) => match value {
@@ -130,7 +130,7 @@ impl TryFrom<RawOptions> for CompilerOptions {
       })
       .transpose()?;
 
-    Ok(CompilerOptions {
+    let mut options = CompilerOptions {
       name: value.name,
       context,
       mode,
@@ -147,7 +147,9 @@ impl TryFrom<RawOptions> for CompilerOptions {
       amd: value.amd,
       bail: value.bail,
       __references: value.__references,
-    })
+    };
+    options.apply_experiments()?;
+    Ok(options)
   }
 }
 
@@ -6,6 +6,8 @@ mod raw_copy;
 mod raw_css_chunking;
 mod raw_css_extract;
 mod raw_dll;
+mod raw_dotenv;
+mod raw_duplicate_packages;
 mod raw_esm_lib;
 mod raw_html;
 mod raw_http_uri;
@@ -21,6 +23,7 @@ mod raw_runtime_chunk;
 mod raw_size_limits;
 mod raw_sri;
 mod raw_swc_js_minimizer;
+mod raw_type_check;
 
 use std::cell::RefCell;
 
@@ -58,6 +61,8 @@ use rspack_plugin_devtool::{
 use rspack_plugin_dll::{
   DllEntryPlugin, DllReferenceAgencyPlugin, FlagAllModulesAsUsedPlugin, LibManifestPlugin,
 };
+use rspack_plugin_dotenv::DotenvPlugin;
+use rspack_plugin_duplicate_packages::DuplicatePackageRspackPlugin;
 use rspack_plugin_dynamic_entry::DynamicEntryPlugin;
 use rspack_plugin_ensure_chunk_conditions::EnsureChunkConditionsPlugin;
 use rspack_plugin_entry::EntryPlugin;
@@ -101,6 +106,7 @@ use rspack_plugin_schemes::{DataUriPlugin, FileUriPlugin};
 use rspack_plugin_size_limits::SizeLimitsPlugin;
 use rspack_plugin_sri::{SubresourceIntegrityPlugin, SubresourceIntegrityPluginOptions};
 use rspack_plugin_swc_js_minimizer::SwcJsMinimizerRspackPlugin;
+use rspack_plugin_type_check::TypeCheckPlugin;
 use rspack_plugin_wasm::{
   AsyncWasmPlugin, FetchCompileAsyncWasmPlugin, enable_wasm_loading_plugin,
 };
@@ -117,6 +123,8 @@ use self::{
   raw_css_chunking::RawCssChunkingPluginOptions,
   raw_css_extract::RawCssExtractPluginOption,
   raw_dll::{RawDllEntryPluginOptions, RawLibManifestPluginOptions},
+  raw_dotenv::RawDotenvPluginOptions,
+  raw_duplicate_packages::RawDuplicatePackageRspackPluginOptions,
   raw_html::RawHtmlRspackPluginOptions,
   raw_ignore::RawIgnorePluginOptions,
   raw_lazy_compilation::{JsBackend, RawLazyCompilationOption},
@@ -129,6 +137,7 @@ use self::{
   raw_runtime_chunk::RawRuntimeChunkOptions,
   raw_size_limits::RawSizeLimitsPluginOptions,
   raw_swc_js_minimizer::RawSwcJsMinimizerRspackPluginOptions,
+  raw_type_check::RawTypeCheckPluginOptions,
 };
 use crate::{
   options::entry::JsEntryPluginOptions,
@@ -240,6 +249,9 @@ pub enum BuiltinPluginName {
   RslibPlugin,
   CircularDependencyRspackPlugin,
   URLPlugin,
+  DotenvPlugin,
+  TypeCheckPlugin,
+  DuplicatePackageRspackPlugin,
 
   // rspack js adapter plugins
   // naming format follow XxxRspackPlugin
@@ -327,6 +339,24 @@ impl<'a> BuiltinPlugin<'a> {
       BuiltinPluginName::URLPlugin => {
         plugins.push(URLPlugin::default().boxed());
       }
+      BuiltinPluginName::DotenvPlugin => {
+        let plugin = DotenvPlugin::new(
+          downcast_into::<RawDotenvPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
+      BuiltinPluginName::TypeCheckPlugin => {
+        let plugin = TypeCheckPlugin::new(
+          downcast_into::<RawTypeCheckPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .into(),
+        )
+        .boxed();
+        plugins.push(plugin);
+      }
       BuiltinPluginName::BannerPlugin => {
         let plugin = BannerPlugin::new(
           downcast_into::<RawBannerPluginOptions>(self.options)
@@ -683,7 +713,8 @@ impl<'a> BuiltinPlugin<'a> {
         let plugin = SizeLimitsPlugin::new(
           downcast_into::<RawSizeLimitsPluginOptions>(self.options)
             .map_err(|report| napi::Error::from_reason(report.to_string()))?
-            .into(),
+            .try_into()
+            .map_err(|report: rspack_error::Error| napi::Error::from_reason(report.to_string()))?,
         )
         .boxed();
         plugins.push(plugin)
@@ -764,6 +795,14 @@ impl<'a> BuiltinPlugin<'a> {
         )
         .boxed(),
       ),
+      BuiltinPluginName::DuplicatePackageRspackPlugin => plugins.push(
+        DuplicatePackageRspackPlugin::new(
+          downcast_into::<RawDuplicatePackageRspackPluginOptions>(self.options)
+            .map_err(|report| napi::Error::from_reason(report.to_string()))?
+            .into(),
+        )
+        .boxed(),
+      ),
       BuiltinPluginName::JsLoaderRspackPlugin => {
         // Set the compiler._runLoader property on the JsObject to ensure that the runLoader
         // is not garbage collected by JS while the stats Object holds a reference to JsLoaderPlugin.
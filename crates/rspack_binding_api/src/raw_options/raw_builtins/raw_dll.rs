@@ -3,7 +3,8 @@ use napi_derive::napi;
 use rspack_core::ModuleId;
 use rspack_plugin_dll::{
   DllEntryPluginOptions, DllManifest, DllManifestContent, DllManifestContentItem,
-  DllManifestContentItemExports, DllReferenceAgencyPluginOptions, LibManifestPluginOptions,
+  DllManifestContentItemExports, DllManifestPackage, DllReferenceAgencyPluginOptions,
+  LibManifestPluginOptions,
 };
 use rustc_hash::FxHashMap as HashMap;
 use swc_core::atoms::Atom;
@@ -85,6 +86,23 @@ pub struct RawDllManifestContentItem {
   #[napi(ts_type = "string[] | true")]
   pub exports: Option<Either<Vec<String>, bool>>,
   pub id: Option<Either<u32, String>>,
+  pub package: Option<RawDllManifestPackage>,
+  pub hash: Option<String>,
+}
+
+#[napi(object, object_to_js = false)]
+pub struct RawDllManifestPackage {
+  pub name: String,
+  pub version: String,
+}
+
+impl From<RawDllManifestPackage> for DllManifestPackage {
+  fn from(value: RawDllManifestPackage) -> Self {
+    Self {
+      name: value.name,
+      version: value.version,
+    }
+  }
 }
 
 impl From<RawDllManifestContentItem> for DllManifestContentItem {
@@ -111,6 +129,8 @@ impl From<RawDllManifestContentItem> for DllManifestContentItem {
         Either::A(n) => ModuleId::from(n),
         Either::B(s) => ModuleId::from(s),
       }),
+      package: value.package.map(|package| package.into()),
+      hash: value.hash,
     }
   }
 }
@@ -0,0 +1,26 @@
+use napi_derive::napi;
+use rspack_plugin_dotenv::DotenvPluginOptions;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawDotenvPluginOptions {
+  pub dir: String,
+  pub mode: Option<String>,
+  pub prefixes: Option<Vec<String>>,
+  pub process_env: Option<bool>,
+  pub import_meta_env: Option<bool>,
+}
+
+impl From<RawDotenvPluginOptions> for DotenvPluginOptions {
+  fn from(value: RawDotenvPluginOptions) -> Self {
+    Self {
+      dir: value.dir.into(),
+      mode: value.mode,
+      prefixes: value
+        .prefixes
+        .unwrap_or_else(|| vec!["PUBLIC_".to_string()]),
+      process_env: value.process_env.unwrap_or(true),
+      import_meta_env: value.import_meta_env.unwrap_or(true),
+    }
+  }
+}
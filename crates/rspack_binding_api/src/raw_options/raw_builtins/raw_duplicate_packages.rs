@@ -0,0 +1,31 @@
+use napi::Either;
+use napi_derive::napi;
+use rspack_plugin_duplicate_packages::{
+  DuplicatePackageAllowlistEntry, DuplicatePackageRspackPluginOptions,
+};
+use rspack_regex::RspackRegex;
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawDuplicatePackageRspackPluginOptions {
+  pub fail_on_error: Option<bool>,
+  #[napi(ts_type = "Array<string | RegExp>")]
+  pub allowlist: Option<Vec<Either<String, RspackRegex>>>,
+}
+
+impl From<RawDuplicatePackageRspackPluginOptions> for DuplicatePackageRspackPluginOptions {
+  fn from(value: RawDuplicatePackageRspackPluginOptions) -> Self {
+    Self {
+      fail_on_error: value.fail_on_error.unwrap_or(false),
+      allowlist: value
+        .allowlist
+        .unwrap_or_default()
+        .into_iter()
+        .map(|entry| match entry {
+          Either::A(string) => DuplicatePackageAllowlistEntry::String(string),
+          Either::B(pattern) => DuplicatePackageAllowlistEntry::Pattern(pattern),
+        })
+        .collect(),
+    }
+  }
+}
@@ -5,7 +5,8 @@ use napi_derive::napi;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
 use rspack_plugin_html::config::{
   HtmlChunkSortMode, HtmlInject, HtmlRspackPluginBaseOptions, HtmlRspackPluginOptions,
-  HtmlScriptLoading, TemplateParameterFn, TemplateParameters, TemplateRenderFn,
+  HtmlScriptLoading, HtmlSpeculationRules, TemplateParameterFn, TemplateParameters,
+  TemplateRenderFn,
 };
 use rustc_hash::FxHashMap as HashMap;
 
@@ -53,6 +54,8 @@ pub struct RawHtmlRspackPluginOptions {
   pub meta: Option<HashMap<String, HashMap<String, String>>>,
   pub hash: Option<bool>,
   pub base: Option<RawHtmlRspackPluginBaseOptions>,
+  pub module_preload: Option<bool>,
+  pub speculation_rules: Option<RawHtmlSpeculationRules>,
   pub uid: Option<u32>,
 }
 
@@ -109,6 +112,8 @@ impl From<RawHtmlRspackPluginOptions> for HtmlRspackPluginOptions {
       meta: value.meta,
       hash: value.hash,
       base: value.base.map(|v| v.into()),
+      module_preload: value.module_preload,
+      speculation_rules: value.speculation_rules.map(|v| v.into()),
       uid: value.uid,
     }
   }
@@ -130,3 +135,20 @@ impl From<RawHtmlRspackPluginBaseOptions> for HtmlRspackPluginBaseOptions {
     }
   }
 }
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawHtmlSpeculationRules {
+  pub urls: HashMap<String, Vec<String>>,
+  #[napi(ts_type = "\"immediate\" | \"eager\" | \"moderate\" | \"conservative\"")]
+  pub eagerness: Option<String>,
+}
+
+impl From<RawHtmlSpeculationRules> for HtmlSpeculationRules {
+  fn from(value: RawHtmlSpeculationRules) -> Self {
+    HtmlSpeculationRules {
+      urls: value.urls,
+      eagerness: value.eagerness,
+    }
+  }
+}
@@ -5,10 +5,11 @@ use napi_derive::napi;
 use rspack_plugin_mf::{
   CollectSharedEntryPluginOptions, ConsumeOptions, ConsumeSharedPluginOptions, ConsumeVersion,
   ContainerPluginOptions, ContainerReferencePluginOptions, ExposeOptions, ManifestExposeOption,
-  ManifestSharedOption, ModuleFederationManifestPluginOptions,
-  ModuleFederationRuntimeExperimentsOptions, ModuleFederationRuntimePluginOptions,
-  OptimizeSharedConfig, ProvideOptions, ProvideVersion, RemoteAliasTarget, RemoteOptions,
-  SharedContainerPluginOptions, SharedUsedExportsOptimizerPluginOptions, StatsBuildInfo,
+  ManifestRemoteTypesOption, ManifestSharedCheckOption, ManifestSharedOption, ManifestTypesOption,
+  ModuleFederationManifestPluginOptions, ModuleFederationRuntimeExperimentsOptions,
+  ModuleFederationRuntimePluginOptions, OptimizeSharedConfig, ProvideOptions, ProvideVersion,
+  RemoteAliasTarget, RemoteOptions, SharedContainerPluginOptions,
+  SharedUsedExportsOptimizerPluginOptions, StatsBuildInfo,
 };
 
 use crate::options::{
@@ -375,6 +376,25 @@ pub struct RawStatsBuildInfo {
   pub plugins: Option<Vec<String>>,
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManifestTypesOption {
+  pub zip_name: String,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManifestRemoteTypesOption {
+  pub alias: String,
+  pub archive: String,
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManifestSharedCheckOption {
+  pub remote_manifests: Option<Vec<String>>,
+}
+
 #[derive(Debug)]
 #[napi(object)]
 pub struct RawModuleFederationManifestPluginOptions {
@@ -389,6 +409,10 @@ pub struct RawModuleFederationManifestPluginOptions {
   pub exposes: Option<Vec<RawManifestExposeOption>>,
   pub shared: Option<Vec<RawManifestSharedOption>>,
   pub build_info: Option<RawStatsBuildInfo>,
+  pub types: Option<RawManifestTypesOption>,
+  pub remote_types: Option<Vec<RawManifestRemoteTypesOption>>,
+  pub types_folder: Option<String>,
+  pub shared_check: Option<RawManifestSharedCheckOption>,
 }
 
 impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifestPluginOptions {
@@ -439,6 +463,26 @@ impl From<RawModuleFederationManifestPluginOptions> for ModuleFederationManifest
         target: info.target,
         plugins: info.plugins,
       }),
+      types: value.types.map(|types| ManifestTypesOption {
+        zip_name: types.zip_name,
+      }),
+      remote_types: value
+        .remote_types
+        .unwrap_or_default()
+        .into_iter()
+        .map(|remote| ManifestRemoteTypesOption {
+          alias: remote.alias,
+          archive: remote.archive,
+        })
+        .collect(),
+      types_folder: value
+        .types_folder
+        .unwrap_or_else(|| "@mf-types".to_string()),
+      shared_check: value
+        .shared_check
+        .map(|shared_check| ManifestSharedCheckOption {
+          remote_manifests: shared_check.remote_manifests.unwrap_or_default(),
+        }),
     }
   }
 }
@@ -1,7 +1,53 @@
 use derive_more::Debug;
 use napi_derive::napi;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
-use rspack_plugin_size_limits::{AssetFilterFn, SizeLimitsPluginOptions};
+use rspack_plugin_size_limits::{AssetFilterFn, SizeBaseline, SizeBudget, SizeLimitsPluginOptions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSizeBudget {
+  pub name: Option<String>,
+  pub entry: Option<String>,
+  pub asset: Option<String>,
+  #[napi(ts_type = "\"initial\" | \"async\" | \"css\"")]
+  pub chunk_type: Option<String>,
+  #[napi(ts_type = "\"gzip\" | \"brotli\"")]
+  pub compression: Option<String>,
+  pub max_size: Option<f64>,
+}
+
+impl TryFrom<RawSizeBudget> for SizeBudget {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSizeBudget) -> Result<Self, rspack_error::Error> {
+    Ok(SizeBudget {
+      name: value.name,
+      entry: value.entry,
+      asset: value.asset,
+      chunk_type: value.chunk_type.map(TryInto::try_into).transpose()?,
+      compression: value.compression.map(TryInto::try_into).transpose()?,
+      max_size: value.max_size,
+    })
+  }
+}
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawSizeBaseline {
+  pub path: String,
+  pub max_growth: Option<f64>,
+  pub update: Option<bool>,
+}
+
+impl From<RawSizeBaseline> for SizeBaseline {
+  fn from(value: RawSizeBaseline) -> Self {
+    SizeBaseline {
+      path: value.path,
+      max_growth: value.max_growth.unwrap_or_default(),
+      update: value.update.unwrap_or_default(),
+    }
+  }
+}
 
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
@@ -13,11 +59,15 @@ pub struct RawSizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  pub budgets: Option<Vec<RawSizeBudget>>,
+  pub baseline: Option<RawSizeBaseline>,
 }
 
-impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
-  fn from(value: RawSizeLimitsPluginOptions) -> Self {
-    SizeLimitsPluginOptions {
+impl TryFrom<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawSizeLimitsPluginOptions) -> Result<Self, rspack_error::Error> {
+    Ok(SizeLimitsPluginOptions {
       asset_filter: value.asset_filter.map(|asset_filter| {
         let asset_filter_fn: AssetFilterFn = Box::new(move |name| {
           let f = asset_filter.clone();
@@ -29,6 +79,13 @@ impl From<RawSizeLimitsPluginOptions> for SizeLimitsPluginOptions {
       hints: value.hints,
       max_asset_size: value.max_asset_size,
       max_entrypoint_size: value.max_entrypoint_size,
-    }
+      budgets: value
+        .budgets
+        .unwrap_or_default()
+        .into_iter()
+        .map(SizeBudget::try_from)
+        .collect::<Result<Vec<_>, rspack_error::Error>>()?,
+      baseline: value.baseline.map(Into::into),
+    })
   }
 }
@@ -2,8 +2,9 @@ use napi::Either;
 use napi_derive::napi;
 use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_plugin_swc_js_minimizer::{
-  ExtractComments, MinimizerOptions, OptionWrapper, PluginOptions,
+  ExtractComments, ManglePropertiesOptions, MinimizerOptions, OptionWrapper, PluginOptions,
 };
+use rspack_regex::RspackRegex;
 use serde::de::DeserializeOwned;
 use swc_core::base::BoolOrDataConfig;
 
@@ -16,6 +17,14 @@ pub struct RawExtractComments {
   pub condition: Option<String>,
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawManglePropertiesOptions {
+  #[napi(ts_type = "RegExp")]
+  pub regex: RspackRegex,
+  pub reserved: Option<Vec<String>>,
+}
+
 #[derive(Debug)]
 #[napi(object, object_to_js = false)]
 pub struct RawSwcJsMinimizerRspackPluginOptions {
@@ -26,6 +35,8 @@ pub struct RawSwcJsMinimizerRspackPluginOptions {
   #[napi(ts_type = "string | RegExp | (string | RegExp)[]")]
   pub exclude: Option<RawAssetConditions>,
   pub extract_comments: Option<RawExtractComments>,
+  pub mangle_properties: Option<RawManglePropertiesOptions>,
+  pub name_cache: Option<String>,
   pub minimizer_options: RawSwcJsMinimizerOptions,
 }
 
@@ -86,6 +97,13 @@ impl TryFrom<RawSwcJsMinimizerRspackPluginOptions> for PluginOptions {
 
     Ok(Self {
       extract_comments: into_extract_comments(value.extract_comments),
+      mangle_properties: value
+        .mangle_properties
+        .map(|options| ManglePropertiesOptions {
+          regex: options.regex,
+          reserved: options.reserved.unwrap_or_default(),
+        }),
+      name_cache: value.name_cache,
       test: value.test.map(into_asset_conditions),
       include: value.include.map(into_asset_conditions),
       exclude: value.exclude.map(into_asset_conditions),
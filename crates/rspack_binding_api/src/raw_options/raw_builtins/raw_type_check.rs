@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use derive_more::Debug;
+use napi_derive::napi;
+use rspack_napi::threadsafe_function::ThreadsafeFunction;
+use rspack_plugin_type_check::{CheckedFn, TypeCheckPluginOptions};
+
+#[derive(Debug)]
+#[napi(object, object_to_js = false)]
+pub struct RawTypeCheckPluginOptions {
+  pub command: String,
+  pub tsconfig: Option<String>,
+  pub build_info_file: Option<String>,
+  pub args: Option<Vec<String>>,
+  pub blocking: bool,
+  #[debug(skip)]
+  #[napi(ts_type = "() => void")]
+  pub on_checked: Option<ThreadsafeFunction<(), ()>>,
+}
+
+impl From<RawTypeCheckPluginOptions> for TypeCheckPluginOptions {
+  fn from(value: RawTypeCheckPluginOptions) -> Self {
+    Self {
+      command: value.command,
+      tsconfig: value.tsconfig.map(Into::into),
+      build_info_file: value.build_info_file.map(Into::into),
+      args: value.args.unwrap_or_default(),
+      blocking: value.blocking,
+      on_checked: value.on_checked.map(|callback| -> CheckedFn {
+        Arc::new(move || {
+          let callback = callback.clone();
+          Box::pin(async move { callback.call_with_sync(()).await })
+        })
+      }),
+    }
+  }
+}
@@ -10,7 +10,10 @@ use napi::{
 use napi_derive::napi;
 use raw_snapshot::RawSnapshotOptions;
 use raw_storage::RawStorageOptions;
-use rspack_core::{CacheOptions, cache::persistent::PersistentCacheOptions};
+use rspack_core::{
+  CacheOptions,
+  cache::persistent::{PersistentCacheOptions, storage::StorageOptions},
+};
 
 #[derive(Debug)]
 #[napi(object)]
@@ -23,9 +26,18 @@ pub struct RawCacheOptionsPersistent {
   pub readonly: Option<bool>,
 }
 
-impl From<RawCacheOptionsPersistent> for PersistentCacheOptions {
-  fn from(value: RawCacheOptionsPersistent) -> Self {
-    Self {
+impl TryFrom<RawCacheOptionsPersistent> for PersistentCacheOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawCacheOptionsPersistent) -> Result<Self, Self::Error> {
+    let storage: StorageOptions = value.storage.unwrap_or_default().try_into()?;
+    let portable = value.portable.unwrap_or_default();
+    if storage.has_remote() && !portable {
+      return Err(rspack_error::error!(
+        "cache.storage.remote requires cache.portable to be true, the remote cache is shared between different directories"
+      ));
+    }
+    Ok(Self {
       build_dependencies: value
         .build_dependencies
         .unwrap_or_default()
@@ -34,10 +46,10 @@ impl From<RawCacheOptionsPersistent> for PersistentCacheOptions {
         .collect(),
       version: value.version.unwrap_or_default(),
       snapshot: value.snapshot.unwrap_or_default().into(),
-      storage: value.storage.unwrap_or_default().into(),
-      portable: value.portable.unwrap_or_default(),
+      storage,
+      portable,
       readonly: value.readonly.unwrap_or_default(),
-    }
+    })
   }
 }
 
@@ -50,7 +62,7 @@ pub struct RawCacheOptionsMemory {
 #[derive(Debug)]
 pub enum InnerCacheOptions {
   Memory(RawCacheOptionsMemory),
-  Persistent(RawCacheOptionsPersistent),
+  Persistent(Box<RawCacheOptionsPersistent>),
 }
 
 impl TypeName for InnerCacheOptions {
@@ -77,7 +89,7 @@ impl FromNapiValue for InnerCacheOptions {
       let v = match &*t {
         "persistent" => {
           let o = RawCacheOptionsPersistent::from_napi_value(env, napi_val)?;
-          Self::Persistent(o)
+          Self::Persistent(Box::new(o))
         }
         "memory" => {
           let o = RawCacheOptionsMemory::from_napi_value(env, napi_val)?;
@@ -93,8 +105,8 @@ impl FromNapiValue for InnerCacheOptions {
 
 pub type RawCacheOptions = Either<bool, InnerCacheOptions>;
 
-pub fn normalize_raw_cache(options: RawCacheOptions) -> CacheOptions {
-  match options {
+pub fn normalize_raw_cache(options: RawCacheOptions) -> rspack_error::Result<CacheOptions> {
+  Ok(match options {
     Either::A(options) => {
       if options {
         CacheOptions::Memory { max_generations: 1 }
@@ -103,10 +115,10 @@ pub fn normalize_raw_cache(options: RawCacheOptions) -> CacheOptions {
       }
     }
     Either::B(options) => match options {
-      InnerCacheOptions::Persistent(options) => CacheOptions::Persistent(options.into()),
+      InnerCacheOptions::Persistent(options) => CacheOptions::Persistent((*options).try_into()?),
       InnerCacheOptions::Memory(options) => CacheOptions::Memory {
         max_generations: options.max_generations.unwrap_or(1),
       },
     },
-  }
+  })
 }
@@ -1,6 +1,6 @@
 use napi::Either;
 use napi_derive::napi;
-use rspack_core::cache::persistent::snapshot::{PathMatcher, SnapshotOptions};
+use rspack_core::cache::persistent::snapshot::{PathMatcher, SnapshotMode, SnapshotOptions};
 use rspack_regex::RspackRegex;
 
 type RawPathMatcher = Either<String, RspackRegex>;
@@ -21,6 +21,8 @@ pub struct RawSnapshotOptions {
   pub unmanaged_paths: Vec<RawPathMatcher>,
   #[napi(ts_type = r#"Array<string|RegExp>"#)]
   pub managed_paths: Vec<RawPathMatcher>,
+  #[napi(ts_type = r#""timestamp" | "content""#)]
+  pub mode: Option<String>,
 }
 
 impl From<RawSnapshotOptions> for SnapshotOptions {
@@ -42,5 +44,9 @@ impl From<RawSnapshotOptions> for SnapshotOptions {
         .map(normalize_raw_path_matcher)
         .collect(),
     )
+    .with_mode(match value.mode.as_deref() {
+      Some("content") => SnapshotMode::Content,
+      _ => SnapshotMode::Timestamp,
+    })
   }
 }
@@ -1,5 +1,6 @@
 use napi_derive::napi;
-use rspack_core::cache::persistent::storage::StorageOptions;
+use rspack_core::cache::persistent::storage::{RemoteMode, RemoteOptions, StorageOptions};
+use rspack_error::error;
 
 #[derive(Debug, Default)]
 #[napi(object)]
@@ -7,15 +8,40 @@ pub struct RawStorageOptions {
   #[napi(ts_type = r#""filesystem""#)]
   pub r#type: String,
   pub directory: String,
+  pub remote: Option<RawRemoteStorageOptions>,
 }
 
-impl From<RawStorageOptions> for StorageOptions {
-  fn from(value: RawStorageOptions) -> Self {
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawRemoteStorageOptions {
+  pub url: String,
+  #[napi(ts_type = r#""read-only" | "write-through""#)]
+  pub mode: String,
+}
+
+impl TryFrom<RawRemoteStorageOptions> for RemoteOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawRemoteStorageOptions) -> Result<Self, Self::Error> {
+    let mode = match value.mode.as_str() {
+      "read-only" => RemoteMode::ReadOnly,
+      "write-through" => RemoteMode::WriteThrough,
+      s => return Err(error!("unsupported remote cache mode `{s}`")),
+    };
+    RemoteOptions::new(value.url, mode)
+  }
+}
+
+impl TryFrom<RawStorageOptions> for StorageOptions {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: RawStorageOptions) -> Result<Self, Self::Error> {
     match value.r#type.as_str() {
-      "filesystem" => StorageOptions::FileSystem {
+      "filesystem" => Ok(StorageOptions::FileSystem {
         directory: value.directory.into(),
-      },
-      s => panic!("unsupported storage type {s}"),
+        remote: value.remote.map(TryInto::try_into).transpose()?,
+      }),
+      s => Err(error!("unsupported storage type `{s}`")),
     }
   }
 }
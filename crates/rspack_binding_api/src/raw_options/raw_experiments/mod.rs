@@ -14,6 +14,7 @@ pub struct RawExperiments {
   pub use_input_file_system: Option<WithFalse<Vec<RspackRegex>>>,
   pub css: Option<bool>,
   pub defer_import: bool,
+  pub strict_regex: Option<bool>,
 }
 
 impl From<RawExperiments> for Experiments {
@@ -21,6 +22,7 @@ impl From<RawExperiments> for Experiments {
     Self {
       css: value.css.unwrap_or(false),
       defer_import: value.defer_import,
+      strict_regex: value.strict_regex.unwrap_or(false),
     }
   }
 }
@@ -14,17 +14,17 @@ use napi_derive::napi;
 use regex::Regex;
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnCtx, AssetGeneratorDataUrlOptions,
-  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetParserDataUrl,
-  AssetParserDataUrlOptions, AssetParserOptions, AssetResourceGeneratorOptions,
-  CssAutoGeneratorOptions, CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions,
-  CssModuleParserOptions, CssParserImport, CssParserImportContext, CssParserOptions,
-  DescriptionData, DynamicImportFetchPriority, DynamicImportMode, ExportPresenceMode, FuncUseCtx,
-  GeneratorOptions, GeneratorOptionsMap, JavascriptParserCommonjsExportsOption,
-  JavascriptParserCommonjsOptions, JavascriptParserOptions, JavascriptParserOrder,
-  JavascriptParserUrl, JsonGeneratorOptions, JsonParserOptions, ModuleNoParseRule,
-  ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions, ModuleRule, ModuleRuleEffect,
-  ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader, OverrideStrict, ParseOption,
-  ParserOptions, ParserOptionsMap, TypeReexportPresenceMode,
+  AssetGeneratorOptions, AssetInlineGeneratorOptions, AssetInlineParserOptions, AssetParserDataUrl,
+  AssetParserDataUrlOptions, AssetParserImageOptions, AssetParserOptions,
+  AssetResourceGeneratorOptions, AssetResourceParserOptions, CssAutoGeneratorOptions,
+  CssAutoParserOptions, CssGeneratorOptions, CssModuleGeneratorOptions, CssModuleParserOptions,
+  CssParserImport, CssParserImportContext, CssParserOptions, DescriptionData,
+  DynamicImportFetchPriority, DynamicImportMode, ExportPresenceMode, FuncUseCtx, GeneratorOptions,
+  GeneratorOptionsMap, JavascriptParserCommonjsExportsOption, JavascriptParserCommonjsOptions,
+  JavascriptParserOptions, JavascriptParserOrder, JavascriptParserUrl, JsonGeneratorOptions,
+  JsonParserOptions, ModuleNoParseRule, ModuleNoParseRules, ModuleNoParseTestFn, ModuleOptions,
+  ModuleRule, ModuleRuleEffect, ModuleRuleEnforce, ModuleRuleUse, ModuleRuleUseLoader,
+  OverrideStrict, ParseOption, ParserOptions, ParserOptionsMap, TypeReexportPresenceMode,
 };
 use rspack_error::error;
 use rspack_napi::threadsafe_function::ThreadsafeFunction;
@@ -191,10 +191,12 @@ pub struct RawModuleRule {
 #[napi(object, object_to_js = false)]
 pub struct RawParserOptions {
   #[napi(
-    ts_type = r#""asset" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json""#
+    ts_type = r#""asset" | "asset/inline" | "asset/resource" | "css" | "css/auto" | "css/module" | "javascript" | "javascript/auto" | "javascript/dynamic" | "javascript/esm" | "json""#
   )]
   pub r#type: String,
   pub asset: Option<RawAssetParserOptions>,
+  pub asset_inline: Option<RawAssetInlineParserOptions>,
+  pub asset_resource: Option<RawAssetResourceParserOptions>,
   pub css: Option<RawCssParserOptions>,
   pub css_auto: Option<RawCssAutoParserOptions>,
   pub css_module: Option<RawCssModuleParserOptions>,
@@ -211,6 +213,20 @@ impl From<RawParserOptions> for ParserOptions {
           .expect("should have an \"asset\" when RawParserOptions.type is \"asset\"")
           .into(),
       ),
+      "asset/inline" => Self::AssetInline(
+        value
+          .asset_inline
+          .expect("should have an \"asset_inline\" when RawParserOptions.type is \"asset/inline\"")
+          .into(),
+      ),
+      "asset/resource" => Self::AssetResource(
+        value
+          .asset_resource
+          .expect(
+            "should have an \"asset_resource\" when RawParserOptions.type is \"asset/resource\"",
+          )
+          .into(),
+      ),
       "javascript" => Self::Javascript(
         value
           .javascript
@@ -417,12 +433,58 @@ impl From<RawJsonGeneratorOptions> for JsonGeneratorOptions {
 #[napi(object)]
 pub struct RawAssetParserOptions {
   pub data_url_condition: Option<RawAssetParserDataUrl>,
+  pub image: Option<RawAssetParserImageOptions>,
 }
 
 impl From<RawAssetParserOptions> for AssetParserOptions {
   fn from(value: RawAssetParserOptions) -> Self {
     Self {
       data_url_condition: value.data_url_condition.map(|i| i.into()),
+      image: value.image.map(|i| i.into()),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawAssetInlineParserOptions {
+  pub image: Option<RawAssetParserImageOptions>,
+}
+
+impl From<RawAssetInlineParserOptions> for AssetInlineParserOptions {
+  fn from(value: RawAssetInlineParserOptions) -> Self {
+    Self {
+      image: value.image.map(|i| i.into()),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawAssetResourceParserOptions {
+  pub image: Option<RawAssetParserImageOptions>,
+}
+
+impl From<RawAssetResourceParserOptions> for AssetResourceParserOptions {
+  fn from(value: RawAssetResourceParserOptions) -> Self {
+    Self {
+      image: value.image.map(|i| i.into()),
+    }
+  }
+}
+
+#[derive(Debug, Default)]
+#[napi(object)]
+pub struct RawAssetParserImageOptions {
+  pub optimize: Option<bool>,
+  pub quality: Option<u32>,
+}
+
+impl From<RawAssetParserImageOptions> for AssetParserImageOptions {
+  fn from(value: RawAssetParserImageOptions) -> Self {
+    Self {
+      optimize: value.optimize,
+      quality: value.quality.map(|quality| quality.clamp(1, 100) as u8),
     }
   }
 }
@@ -1,8 +1,8 @@
 use napi::Either;
 use napi_derive::napi;
 use rspack_core::{
-  ChunkLoading, CleanOptions, CrossOriginLoading, Environment, OnPolicyCreationFailure,
-  OutputOptions, PathInfo, TrustedTypes, WasmLoading,
+  ChunkLoadRetry, ChunkLoading, CleanOptions, CrossOriginLoading, Environment,
+  OnPolicyCreationFailure, OutputImportMap, OutputOptions, PathInfo, TrustedTypes, WasmLoading,
 };
 
 use crate::{
@@ -29,6 +29,40 @@ impl From<RawTrustedTypes> for TrustedTypes {
   }
 }
 
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawChunkLoadRetry {
+  pub max_retries: u32,
+  pub retry_delay: u32,
+  pub cache_bust: bool,
+  pub fallback_public_paths: Vec<String>,
+}
+
+impl From<RawChunkLoadRetry> for ChunkLoadRetry {
+  fn from(value: RawChunkLoadRetry) -> Self {
+    Self {
+      max_retries: value.max_retries,
+      retry_delay: value.retry_delay,
+      cache_bust: value.cache_bust,
+      fallback_public_paths: value.fallback_public_paths,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[napi(object)]
+pub struct RawOutputImportMap {
+  pub filename: String,
+}
+
+impl From<RawOutputImportMap> for OutputImportMap {
+  fn from(value: RawOutputImportMap) -> Self {
+    Self {
+      filename: value.filename,
+    }
+  }
+}
+
 type RawCrossOriginLoading = WithFalse<String>;
 
 impl From<RawCrossOriginLoading> for CrossOriginLoading {
@@ -117,9 +151,11 @@ pub struct RawOutputOptions {
   pub import_meta_name: String,
   pub iife: bool,
   pub module: bool,
+  pub import_map: Option<RawOutputImportMap>,
   #[napi(ts_type = "string | false")]
   pub chunk_loading: RawChunkLoading,
   pub chunk_load_timeout: u32,
+  pub chunk_load_retry: Option<RawChunkLoadRetry>,
   pub enabled_chunk_loading_types: Option<Vec<String>>,
   pub trusted_types: Option<RawTrustedTypes>,
   pub source_map_filename: String,
@@ -201,6 +237,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       import_meta_name: value.import_meta_name,
       iife: value.iife,
       module: value.module,
+      import_map: value.import_map.map(Into::into),
       trusted_types: value.trusted_types.map(Into::into),
       source_map_filename: value.source_map_filename.into(),
       hash_function: value.hash_function.as_str().into(),
@@ -217,6 +254,7 @@ impl TryFrom<RawOutputOptions> for OutputOptions {
       },
       environment: value.environment.into(),
       chunk_load_timeout: value.chunk_load_timeout,
+      chunk_load_retry: value.chunk_load_retry.map(Into::into),
       compare_before_emit: value.compare_before_emit,
     })
   }
@@ -78,7 +78,7 @@ impl JsResolver {
       async move {
         let mut resolve_context = ResolveContext::default();
         match resolver
-          .resolve_with_context(Path::new(&path), &request, &mut resolve_context)
+          .resolve_with_context(Path::new(&path), None, &request, &mut resolve_context)
           .await
         {
           Ok(rspack_core::ResolveResult::Resource(resource)) => {
@@ -42,6 +42,7 @@ impl TrieNode {
         mtime_ms: file_node.mtime,
         ctime_ms: file_node.ctime,
         size: file_node.content.len() as u64,
+        ino: 0,
       },
       TrieNode::Directory(directory_node) => FileMetadata {
         is_file: false,
@@ -51,6 +52,7 @@ impl TrieNode {
         mtime_ms: directory_node.mtime,
         ctime_ms: directory_node.ctime,
         size: 0,
+        ino: 0,
       },
     }
   }
@@ -15,6 +15,7 @@ mod exports_info_artifact;
 mod imported_by_defer_modules_artifact;
 mod module_graph_cache_artifact;
 mod module_ids_artifact;
+mod process_assets_cache_artifact;
 mod process_runtime_requirements_cache_artifact;
 mod side_effects_do_optimize_artifact;
 
@@ -98,5 +99,6 @@ pub use exports_info_artifact::ExportsInfoArtifact;
 pub use imported_by_defer_modules_artifact::ImportedByDeferModulesArtifact;
 pub use module_graph_cache_artifact::*;
 pub use module_ids_artifact::ModuleIdsArtifact;
+pub use process_assets_cache_artifact::ProcessAssetsCacheArtifact;
 pub use process_runtime_requirements_cache_artifact::ProcessRuntimeRequirementsCacheArtifact;
 pub use side_effects_do_optimize_artifact::*;
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use dashmap::{DashMap, DashSet};
+use rspack_cacheable::{
+  __private::rkyv::{Archive, Deserialize, Serialize, bytecheck::CheckBytes},
+  Deserializer, Serializer, Validator,
+};
+use rustc_hash::FxHashMap as HashMap;
+
+use crate::cache::persistent::codec::CacheCodec;
+
+/// Results of process assets plugins stored in the persistent cache, e.g. minified assets.
+///
+/// The key should be computed from everything that affects the result, such as
+/// the input source and the plugin options. Entries not used by a build are removed
+/// when the cache is saved.
+///
+/// This artifact is disabled unless the persistent cache is enabled.
+#[derive(Debug, Default)]
+pub struct ProcessAssetsCacheArtifact {
+  codec: Option<Arc<CacheCodec>>,
+  /// Entries recovered from the persistent cache.
+  #[allow(clippy::rc_buffer)]
+  recovered: HashMap<Vec<u8>, Arc<Vec<u8>>>,
+  /// Keys of the recovered entries used by this compilation.
+  used: DashSet<Vec<u8>>,
+  /// Entries added by this compilation.
+  added: DashMap<Vec<u8>, Vec<u8>>,
+}
+
+impl ProcessAssetsCacheArtifact {
+  #[allow(clippy::rc_buffer)]
+  pub(crate) fn new(codec: Arc<CacheCodec>, recovered: HashMap<Vec<u8>, Arc<Vec<u8>>>) -> Self {
+    Self {
+      codec: Some(codec),
+      recovered,
+      used: Default::default(),
+      added: Default::default(),
+    }
+  }
+
+  pub fn is_enabled(&self) -> bool {
+    self.codec.is_some()
+  }
+
+  /// Get the cached result of `plugin` with `key`.
+  pub fn get<T>(&self, plugin: &str, key: &str) -> Option<T>
+  where
+    T: Archive,
+    T::Archived: for<'a> CheckBytes<Validator<'a>> + Deserialize<T, Deserializer>,
+  {
+    let codec = self.codec.as_ref()?;
+    let key = cache_key(plugin, key);
+    let bytes = self.recovered.get(&key)?;
+    // a broken entry is treated as missing, it will be replaced by the new result
+    let value = codec.decode(bytes).ok()?;
+    self.used.insert(key);
+    Some(value)
+  }
+
+  /// Save the result of `plugin` with `key`.
+  pub fn set<T>(&self, plugin: &str, key: &str, value: &T)
+  where
+    T: for<'a> Serialize<Serializer<'a>>,
+  {
+    let Some(codec) = &self.codec else {
+      return;
+    };
+    if let Ok(bytes) = codec.encode(value) {
+      self.added.insert(cache_key(plugin, key), bytes);
+    }
+  }
+
+  /// Entries added by this compilation.
+  pub(crate) fn added_entries(&self) -> Vec<(Vec<u8>, Vec<u8>)> {
+    self
+      .added
+      .iter()
+      .map(|entry| (entry.key().clone(), entry.value().clone()))
+      .collect()
+  }
+
+  /// Keys of the recovered entries which are neither used nor replaced by this compilation.
+  pub(crate) fn unused_keys(&self) -> Vec<&[u8]> {
+    self
+      .recovered
+      .keys()
+      .filter(|key| !self.used.contains(*key) && !self.added.contains_key(*key))
+      .map(|key| key.as_slice())
+      .collect()
+  }
+}
+
+fn cache_key(plugin: &str, key: &str) -> Vec<u8> {
+  format!("{plugin}|{key}").into_bytes()
+}
@@ -21,7 +21,7 @@ use rspack_workspace::rspack_pkg_version;
 use self::{
   build_dependencies::{BuildDeps, BuildDepsOptions},
   codec::CacheCodec,
-  occasion::{MakeOccasion, MetaOccasion},
+  occasion::{MakeOccasion, MetaOccasion, ProcessAssetsOccasion},
   snapshot::{Snapshot, SnapshotOptions, SnapshotScope},
   storage::{Storage, StorageOptions, create_storage},
 };
@@ -51,6 +51,7 @@ pub struct PersistentCache {
   snapshot: Arc<Snapshot>,
   make_occasion: MakeOccasion,
   meta_occasion: MetaOccasion,
+  process_assets_occasion: ProcessAssetsOccasion,
   async_mode: bool,
   storage: Arc<dyn Storage>,
   // TODO replace to logger and output warnings directly.
@@ -105,7 +106,8 @@ impl PersistentCache {
       ),
       snapshot,
       make_occasion: MakeOccasion::new(storage.clone(), codec.clone()),
-      meta_occasion: MetaOccasion::new(storage.clone(), codec),
+      meta_occasion: MetaOccasion::new(storage.clone(), codec.clone()),
+      process_assets_occasion: ProcessAssetsOccasion::new(storage.clone(), codec),
       warnings: Default::default(),
       async_mode,
       storage,
@@ -288,4 +290,27 @@ impl Cache for PersistentCache {
         .save(&compilation.build_module_graph_artifact);
     }
   }
+
+  async fn before_process_assets(&mut self, compilation: &mut Compilation) {
+    let artifact = if self.valid {
+      match self.process_assets_occasion.recovery().await {
+        Ok(artifact) => artifact,
+        Err(err) => {
+          self.warnings.push(err.to_string());
+          self.process_assets_occasion.empty_artifact()
+        }
+      }
+    } else {
+      self.process_assets_occasion.empty_artifact()
+    };
+    compilation.process_assets_cache_artifact = Arc::new(artifact);
+  }
+
+  async fn after_process_assets(&self, compilation: &Compilation) {
+    if !self.readonly {
+      self
+        .process_assets_occasion
+        .save(&compilation.process_assets_cache_artifact);
+    }
+  }
 }
@@ -1,5 +1,7 @@
 pub mod make;
 pub mod meta;
+pub mod process_assets;
 
 pub use make::MakeOccasion;
 pub use meta::MetaOccasion;
+pub use process_assets::ProcessAssetsOccasion;
@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use rspack_error::Result;
+
+use super::super::{Storage, codec::CacheCodec};
+use crate::ProcessAssetsCacheArtifact;
+
+pub const SCOPE: &str = "process_assets";
+
+/// Process Assets Occasion is used to save the results of process assets plugins.
+#[derive(Debug)]
+pub struct ProcessAssetsOccasion {
+  storage: Arc<dyn Storage>,
+  codec: Arc<CacheCodec>,
+}
+
+impl ProcessAssetsOccasion {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self { storage, codec }
+  }
+
+  /// Create an empty artifact which collects the new results.
+  pub fn empty_artifact(&self) -> ProcessAssetsCacheArtifact {
+    ProcessAssetsCacheArtifact::new(self.codec.clone(), Default::default())
+  }
+
+  #[tracing::instrument("Cache::Occasion::ProcessAssets::save", skip_all)]
+  pub fn save(&self, artifact: &ProcessAssetsCacheArtifact) {
+    for key in artifact.unused_keys() {
+      self.storage.remove(SCOPE, key);
+    }
+    for (key, value) in artifact.added_entries() {
+      self.storage.set(SCOPE, key, value);
+    }
+  }
+
+  #[tracing::instrument("Cache::Occasion::ProcessAssets::recovery", skip_all)]
+  pub async fn recovery(&self) -> Result<ProcessAssetsCacheArtifact> {
+    let recovered = self
+      .storage
+      .load(SCOPE)
+      .await?
+      .into_iter()
+      .map(|(key, value)| (key.as_ref().clone(), value))
+      .collect();
+    Ok(ProcessAssetsCacheArtifact::new(
+      self.codec.clone(),
+      recovered,
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use super::ProcessAssetsOccasion;
+  use crate::cache::persistent::{codec::CacheCodec, storage::MemoryStorage};
+
+  #[tokio::test]
+  async fn should_process_assets_occasion_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let occasion = ProcessAssetsOccasion::new(storage, Arc::new(CacheCodec::new(None)));
+
+    let artifact = occasion.recovery().await.unwrap();
+    assert!(artifact.is_enabled());
+    assert_eq!(artifact.get::<String>("plugin", "a"), None);
+    artifact.set("plugin", "a", &String::from("value_a"));
+    artifact.set("plugin", "b", &String::from("value_b"));
+    occasion.save(&artifact);
+
+    let artifact = occasion.recovery().await.unwrap();
+    assert_eq!(
+      artifact.get::<String>("plugin", "a"),
+      Some(String::from("value_a"))
+    );
+    assert_eq!(artifact.get::<String>("other_plugin", "a"), None);
+    occasion.save(&artifact);
+
+    // the entries unused by the last build are removed
+    let artifact = occasion.recovery().await.unwrap();
+    assert!(artifact.get::<String>("plugin", "a").is_some());
+    assert!(artifact.get::<String>("plugin", "b").is_none());
+  }
+}
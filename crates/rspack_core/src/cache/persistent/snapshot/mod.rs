@@ -8,9 +8,9 @@ use rspack_error::Result;
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, ArcPathSet};
 
-use self::strategy::{StrategyHelper, ValidateResult};
+use self::strategy::{HashCache, StrategyHelper, ValidateResult};
 pub use self::{
-  option::{PathMatcher, SnapshotOptions},
+  option::{PathMatcher, SnapshotMode, SnapshotOptions},
   scope::SnapshotScope,
   strategy::Strategy,
 };
@@ -27,6 +27,8 @@ pub struct Snapshot {
   fs: Arc<dyn ReadableFileSystem>,
   storage: Arc<dyn Storage>,
   codec: Arc<CacheCodec>,
+  /// file content hashes cache, only used by [SnapshotMode::Content]
+  hash_cache: Option<Arc<HashCache>>,
 }
 
 impl Snapshot {
@@ -36,14 +38,25 @@ impl Snapshot {
     storage: Arc<dyn Storage>,
     codec: Arc<CacheCodec>,
   ) -> Self {
+    let hash_cache = (options.mode() == SnapshotMode::Content)
+      .then(|| Arc::new(HashCache::new(storage.clone(), codec.clone())));
     Self {
       options: Arc::new(options),
       fs,
       storage,
       codec,
+      hash_cache,
     }
   }
 
+  fn new_helper(&self) -> Arc<StrategyHelper> {
+    Arc::new(StrategyHelper::new(
+      self.fs.clone(),
+      self.options.clone(),
+      self.hash_cache.clone(),
+    ))
+  }
+
   async fn calc_strategy(
     options: &Arc<SnapshotOptions>,
     helper: &Arc<StrategyHelper>,
@@ -68,7 +81,7 @@ impl Snapshot {
 
   #[tracing::instrument("Cache::Snapshot::add", skip_all)]
   pub async fn add(&self, scope: SnapshotScope, paths: impl Iterator<Item = ArcPath>) {
-    let helper = Arc::new(StrategyHelper::new(self.fs.clone(), self.options.clone()));
+    let helper = self.new_helper();
     let codec = self.codec.clone();
     // TODO merge package version file
     paths
@@ -96,7 +109,12 @@ impl Snapshot {
     for item in paths {
       self
         .storage
-        .remove(scope.name(), item.as_os_str().as_encoded_bytes())
+        .remove(scope.name(), item.as_os_str().as_encoded_bytes());
+      if let Some(hash_cache) = &self.hash_cache
+        && matches!(scope, SnapshotScope::FILE)
+      {
+        hash_cache.remove(&item);
+      }
     }
   }
 
@@ -109,7 +127,7 @@ impl Snapshot {
     let mut modified_path = ArcPathSet::default();
     let mut deleted_path = ArcPathSet::default();
     let mut no_change_path = ArcPathSet::default();
-    let helper = Arc::new(StrategyHelper::new(self.fs.clone(), self.options.clone()));
+    let helper = self.new_helper();
     let codec = self.codec.clone();
 
     let data = self.storage.load(scope.name()).await?;
@@ -18,6 +18,22 @@ impl PathMatcher {
   }
 }
 
+/// How the files outside of managed paths are validated
+#[cacheable]
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum SnapshotMode {
+  /// Files with an unchanged modified time are considered unchanged,
+  /// the others are compared by content hash.
+  #[default]
+  Timestamp,
+  /// Files are always compared by size and content hash, the modified time is ignored,
+  /// so a fresh checkout that touches all modified times can still reuse the cache.
+  ///
+  /// Content hashes are cached on disk by inode, size and modified time,
+  /// so files are only read again when one of them changes.
+  Content,
+}
+
 /// Snapshot options
 #[cacheable]
 #[derive(Debug, Default, Clone, Hash)]
@@ -29,6 +45,8 @@ pub struct SnapshotOptions {
   unmanaged_paths: Vec<PathMatcher>,
   /// managed_paths, snapshot will use lib version strategy
   managed_paths: Vec<PathMatcher>,
+  /// how unmanaged files are validated
+  mode: SnapshotMode,
 }
 
 impl SnapshotOptions {
@@ -41,9 +59,19 @@ impl SnapshotOptions {
       immutable_paths,
       unmanaged_paths,
       managed_paths,
+      mode: SnapshotMode::default(),
     }
   }
 
+  pub fn with_mode(mut self, mode: SnapshotMode) -> Self {
+    self.mode = mode;
+    self
+  }
+
+  pub fn mode(&self) -> SnapshotMode {
+    self.mode
+  }
+
   pub fn is_immutable_path(&self, path_str: &str) -> bool {
     for item in &self.immutable_paths {
       if item.try_match(path_str) {
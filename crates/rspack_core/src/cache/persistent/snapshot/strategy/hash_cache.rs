@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use rspack_cacheable::cacheable;
+use rspack_fs::FileMetadata;
+use rspack_paths::{ArcPath, ArcPathDashMap};
+use tokio::sync::OnceCell;
+
+use super::super::super::{codec::CacheCodec, storage::Storage};
+
+const SCOPE: &str = "snapshot_hash_cache";
+
+/// The identity of a file, files with the same identity are considered to have the same content.
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileIdentity {
+  pub ino: u64,
+  pub size: u64,
+  pub mtime: u64,
+}
+
+impl FileIdentity {
+  pub fn new(metadata: &FileMetadata, mtime: u64) -> Self {
+    Self {
+      ino: metadata.ino,
+      size: metadata.size,
+      mtime,
+    }
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+struct HashCacheEntry {
+  identity: FileIdentity,
+  hash: u64,
+}
+
+/// An on-disk cache of file content hashes keyed by [FileIdentity].
+///
+/// The entries are loaded from storage lazily at the first access,
+/// and the new entries are written back with the other snapshot data.
+#[derive(Debug)]
+pub struct HashCache {
+  storage: Arc<dyn Storage>,
+  codec: Arc<CacheCodec>,
+  entries: OnceCell<ArcPathDashMap<HashCacheEntry>>,
+}
+
+impl HashCache {
+  pub fn new(storage: Arc<dyn Storage>, codec: Arc<CacheCodec>) -> Self {
+    Self {
+      storage,
+      codec,
+      entries: Default::default(),
+    }
+  }
+
+  async fn entries(&self) -> &ArcPathDashMap<HashCacheEntry> {
+    self
+      .entries
+      .get_or_init(|| async {
+        let entries = ArcPathDashMap::default();
+        // a broken hash cache only makes the files hashed again
+        let Ok(data) = self.storage.load(SCOPE).await else {
+          return entries;
+        };
+        for (key, value) in data {
+          if let Ok(path) = self.codec.decode::<ArcPath>(&key)
+            && let Ok(entry) = self.codec.decode::<HashCacheEntry>(&value)
+          {
+            entries.insert(path, entry);
+          }
+        }
+        entries
+      })
+      .await
+  }
+
+  /// Get the cached content hash if the file identity is unchanged.
+  pub async fn get(&self, path: &ArcPath, identity: &FileIdentity) -> Option<u64> {
+    let entry = self.entries().await.get(path)?;
+    (&entry.identity == identity).then_some(entry.hash)
+  }
+
+  pub async fn set(&self, path: &ArcPath, identity: FileIdentity, hash: u64) {
+    let entry = HashCacheEntry { identity, hash };
+    self.storage.set(
+      SCOPE,
+      self.codec.encode(path).expect("should encode success"),
+      self.codec.encode(&entry).expect("should encode success"),
+    );
+    self.entries().await.insert(path.clone(), entry);
+  }
+
+  pub fn remove(&self, path: &ArcPath) {
+    self.storage.remove(
+      SCOPE,
+      &self.codec.encode(path).expect("should encode success"),
+    );
+    if let Some(entries) = self.entries.get() {
+      entries.remove(path);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::Arc;
+
+  use rspack_paths::ArcPath;
+
+  use super::{
+    super::super::super::{codec::CacheCodec, storage::MemoryStorage},
+    FileIdentity, HashCache,
+  };
+
+  #[tokio::test]
+  async fn should_hash_cache_work() {
+    let storage = Arc::new(MemoryStorage::default());
+    let codec = Arc::new(CacheCodec::new(None));
+    let path = ArcPath::from("/file.js");
+    let identity = FileIdentity {
+      ino: 1,
+      size: 3,
+      mtime: 100,
+    };
+
+    let cache = HashCache::new(storage.clone(), codec.clone());
+    assert_eq!(cache.get(&path, &identity).await, None);
+    cache.set(&path, identity.clone(), 42).await;
+    assert_eq!(cache.get(&path, &identity).await, Some(42));
+
+    // load from storage
+    let cache = HashCache::new(storage, codec);
+    assert_eq!(cache.get(&path, &identity).await, Some(42));
+    let touched = FileIdentity {
+      mtime: 200,
+      ..identity
+    };
+    assert_eq!(cache.get(&path, &touched).await, None);
+  }
+}
@@ -7,13 +7,17 @@ use rspack_fs::{FileMetadata, ReadableFileSystem};
 use rspack_paths::{ArcPath, ArcPathDashMap, AssertUtf8};
 use rustc_hash::FxHasher;
 
-use super::{PackageHelper, SnapshotOptions};
+use super::{
+  PackageHelper, SnapshotOptions,
+  hash_cache::{FileIdentity, HashCache},
+};
 
-/// Content hash with modification time.
+/// Content hash with modification time and size.
 #[derive(Debug, Clone, Default)]
 pub struct ContentHash {
   pub hash: u64,
   pub mtime: u64,
+  pub size: u64,
 }
 
 /// A helper for computing content hashes of files and directories.
@@ -22,6 +26,7 @@ pub struct HashHelper {
   fs: Arc<dyn ReadableFileSystem>,
   snapshot_options: Arc<SnapshotOptions>,
   package_helper: Arc<PackageHelper>,
+  hash_cache: Option<Arc<HashCache>>,
   file_cache: ArcPathDashMap<Option<ContentHash>>,
   dir_cache: ArcPathDashMap<Option<ContentHash>>,
 }
@@ -32,11 +37,13 @@ impl HashHelper {
     fs: Arc<dyn ReadableFileSystem>,
     snapshot_options: Arc<SnapshotOptions>,
     package_helper: Arc<PackageHelper>,
+    hash_cache: Option<Arc<HashCache>>,
   ) -> Self {
     Self {
       fs,
       snapshot_options,
       package_helper,
+      hash_cache,
       file_cache: Default::default(),
       dir_cache: Default::default(),
     }
@@ -70,24 +77,47 @@ impl HashHelper {
     } else {
       metadata.mtime_ms
     };
-    let mut hasher = FxHasher::default();
-    if metadata.is_symlink {
+    let hash = if metadata.is_symlink {
+      let mut hasher = FxHasher::default();
       if let Ok(target) = self.fs.canonicalize(utf8_path).await {
         target.hash(&mut hasher)
       }
-    } else if metadata.is_file
-      && let Ok(content) = self.fs.read(utf8_path).await
-    {
-      content.hash(&mut hasher);
+      hasher.finish()
+    } else if metadata.is_file {
+      self.content_hash(path, &metadata, mtime).await
+    } else {
+      FxHasher::default().finish()
     };
     let hash = Some(ContentHash {
-      hash: hasher.finish(),
+      hash,
       mtime,
+      size: metadata.size,
     });
     self.file_cache.insert(path.into(), hash.clone());
     hash
   }
 
+  /// Hash the file content, the hash in hash cache is reused if the file identity is unchanged.
+  async fn content_hash(&self, path: &ArcPath, metadata: &FileMetadata, mtime: u64) -> u64 {
+    let identity = FileIdentity::new(metadata, mtime);
+    if let Some(hash_cache) = &self.hash_cache
+      && let Some(hash) = hash_cache.get(path, &identity).await
+    {
+      return hash;
+    }
+
+    let mut hasher = FxHasher::default();
+    let Ok(content) = self.fs.read(path.assert_utf8()).await else {
+      return hasher.finish();
+    };
+    content.hash(&mut hasher);
+    let hash = hasher.finish();
+    if let Some(hash_cache) = &self.hash_cache {
+      hash_cache.set(path, identity, hash).await;
+    }
+    hash
+  }
+
   /// Get file content hash.
   pub async fn file_hash(&self, path: &ArcPath) -> Option<ContentHash> {
     self.inner_file_hash(path, None).await
@@ -129,8 +159,9 @@ impl HashHelper {
         }
         Some(ContentHash {
           hash: hasher.finish(),
-          // The mtime value is always set to 0 for directories.
+          // The mtime and size values are always set to 0 for directories.
           mtime: 0,
+          size: 0,
         })
       } else {
         None
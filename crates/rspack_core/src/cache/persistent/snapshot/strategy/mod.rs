@@ -1,3 +1,4 @@
+mod hash_cache;
 mod hash_helper;
 mod package_helper;
 
@@ -7,11 +8,12 @@ use rspack_cacheable::cacheable;
 use rspack_fs::ReadableFileSystem;
 use rspack_paths::{ArcPath, AssertUtf8};
 
+pub use self::hash_cache::HashCache;
 use self::{
   hash_helper::{ContentHash, HashHelper},
   package_helper::PackageHelper,
 };
-use super::SnapshotOptions;
+use super::{SnapshotMode, SnapshotOptions};
 
 /// Snapshot check strategy
 #[cacheable]
@@ -29,6 +31,12 @@ pub enum Strategy {
   /// and then compare the file hash.
   FileHash { mtime: u64, hash: u64 },
 
+  /// Check by file size and hash
+  ///
+  /// This strategy is used by [SnapshotMode::Content], it will first compare
+  /// the file size, and then compare the file hash. The modified time is ignored.
+  ContentHash { size: u64, hash: u64 },
+
   /// Check by dir hash
   ///
   /// This strategy will compare the content hash of all files within the directory.
@@ -52,6 +60,7 @@ impl PartialEq for Strategy {
     match (self, other) {
       (Self::PackageVersion(v1), Self::PackageVersion(v2)) => v1 == v2,
       (Self::FileHash { hash: h1, .. }, Self::FileHash { hash: h2, .. }) => h1 == h2,
+      (Self::ContentHash { hash: h1, .. }, Self::ContentHash { hash: h2, .. }) => h1 == h2,
       (Self::DirHash { hash: h1, .. }, Self::DirHash { hash: h2, .. }) => h1 == h2,
       (Self::Missing, Self::Missing) => true,
       (Self::Failed, Self::Failed) => true,
@@ -73,16 +82,22 @@ pub enum ValidateResult {
 
 pub struct StrategyHelper {
   fs: Arc<dyn ReadableFileSystem>,
+  mode: SnapshotMode,
   package_helper: Arc<PackageHelper>,
   hash_helper: HashHelper,
 }
 
 impl StrategyHelper {
-  pub fn new(fs: Arc<dyn ReadableFileSystem>, snapshot_options: Arc<SnapshotOptions>) -> Self {
+  pub fn new(
+    fs: Arc<dyn ReadableFileSystem>,
+    snapshot_options: Arc<SnapshotOptions>,
+    hash_cache: Option<Arc<HashCache>>,
+  ) -> Self {
     let package_helper = Arc::new(PackageHelper::new(fs.clone()));
     Self {
       fs: fs.clone(),
-      hash_helper: HashHelper::new(fs, snapshot_options, package_helper.clone()),
+      mode: snapshot_options.mode(),
+      hash_helper: HashHelper::new(fs, snapshot_options, package_helper.clone(), hash_cache),
       package_helper,
     }
   }
@@ -112,10 +127,12 @@ impl StrategyHelper {
 
   /// get path file hash strategy
   pub async fn file_hash(&self, path: &ArcPath) -> Strategy {
-    if let Some(ContentHash { hash, mtime }) = self.hash_helper.file_hash(path).await {
-      Strategy::FileHash { mtime, hash }
-    } else {
-      Strategy::Missing
+    match self.hash_helper.file_hash(path).await {
+      Some(ContentHash { hash, size, .. }) if self.mode == SnapshotMode::Content => {
+        Strategy::ContentHash { size, hash }
+      }
+      Some(ContentHash { hash, mtime, .. }) => Strategy::FileHash { mtime, hash },
+      None => Strategy::Missing,
     }
   }
 
@@ -158,6 +175,24 @@ impl StrategyHelper {
           ValidateResult::Modified
         }
       }
+      Strategy::ContentHash { size, hash } => {
+        let Ok(metadata) = self.fs.metadata(path.assert_utf8()).await else {
+          return ValidateResult::Deleted;
+        };
+        // a different size means different content, no need to read the file
+        if metadata.is_file && &metadata.size != size {
+          return ValidateResult::Modified;
+        }
+        let Some(ContentHash { hash: cur_hash, .. }) = self.hash_helper.file_hash(path).await
+        else {
+          return ValidateResult::Deleted;
+        };
+        if &cur_hash == hash {
+          ValidateResult::NoChanged
+        } else {
+          ValidateResult::Modified
+        }
+      }
       Strategy::DirHash { hash } => {
         let Some(ContentHash { hash: cur_hash, .. }) = self.hash_helper.dir_hash(path).await else {
           return ValidateResult::Deleted;
@@ -184,10 +219,14 @@ impl StrategyHelper {
 mod tests {
   use std::sync::Arc;
 
-  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_fs::{MemoryFileSystem, ReadableFileSystem, WritableFileSystem};
   use rspack_paths::ArcPath;
 
-  use super::{Strategy, StrategyHelper, ValidateResult};
+  use super::{
+    super::super::{codec::CacheCodec, storage::MemoryStorage},
+    HashCache, SnapshotMode, SnapshotOptions, Strategy, StrategyHelper, ValidateResult,
+    hash_cache::FileIdentity,
+  };
 
   #[tokio::test]
   async fn validate_package_version() {
@@ -204,7 +243,7 @@ mod tests {
       .unwrap();
 
     let strategy = Strategy::PackageVersion("1.0.0".into());
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     assert!(matches!(
       helper
         .validate(&ArcPath::from("/packages/lib/file.js"), &strategy)
@@ -212,7 +251,7 @@ mod tests {
       ValidateResult::NoChanged
     ));
 
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     fs.write(
       "/packages/lib/package.json".into(),
       r#"{"version": "1.2.0"}"#.as_bytes(),
@@ -226,7 +265,7 @@ mod tests {
       ValidateResult::Modified
     ));
 
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     fs.remove_file("/packages/lib/package.json".into())
       .await
       .unwrap();
@@ -247,7 +286,7 @@ mod tests {
       .unwrap();
 
     std::thread::sleep(std::time::Duration::from_millis(100));
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     let strategy = helper.file_hash(&ArcPath::from("/file1.js")).await;
     assert!(matches!(
       helper
@@ -257,7 +296,7 @@ mod tests {
     ));
 
     std::thread::sleep(std::time::Duration::from_millis(100));
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     fs.write("/file1.js".into(), "abc".as_bytes())
       .await
       .unwrap();
@@ -269,7 +308,7 @@ mod tests {
     ));
 
     std::thread::sleep(std::time::Duration::from_millis(100));
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     fs.write("/file1.js".into(), "abcd".as_bytes())
       .await
       .unwrap();
@@ -281,7 +320,81 @@ mod tests {
     ));
 
     std::thread::sleep(std::time::Duration::from_millis(100));
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
+    fs.remove_file("/file1.js".into()).await.unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Deleted
+    ));
+  }
+
+  #[tokio::test]
+  async fn validate_content_hash() {
+    let fs = Arc::new(MemoryFileSystem::default());
+    fs.create_dir_all("/".into()).await.unwrap();
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    let options = Arc::new(SnapshotOptions::default().with_mode(SnapshotMode::Content));
+    let hash_cache = Arc::new(HashCache::new(
+      Arc::new(MemoryStorage::default()),
+      Arc::new(CacheCodec::new(None)),
+    ));
+
+    let helper = StrategyHelper::new(fs.clone(), options.clone(), Some(hash_cache.clone()));
+    let strategy = helper.file_hash(&ArcPath::from("/file1.js")).await;
+    let Strategy::ContentHash { size: 3, hash } = strategy else {
+      panic!("should be content hash strategy");
+    };
+
+    // the content hash is cached by the file identity
+    let metadata = fs.metadata("/file1.js".into()).await.unwrap();
+    let identity = FileIdentity::new(&metadata, metadata.mtime_ms.max(metadata.ctime_ms));
+    assert_eq!(
+      hash_cache.get(&ArcPath::from("/file1.js"), &identity).await,
+      Some(hash)
+    );
+
+    // the modified time is ignored, the content is the same
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let helper = StrategyHelper::new(fs.clone(), options.clone(), Some(hash_cache.clone()));
+    fs.write("/file1.js".into(), "abc".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::NoChanged
+    ));
+
+    // same size, different content
+    let helper = StrategyHelper::new(fs.clone(), options.clone(), Some(hash_cache.clone()));
+    fs.write("/file1.js".into(), "abd".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Modified
+    ));
+
+    // different size
+    let helper = StrategyHelper::new(fs.clone(), options.clone(), Some(hash_cache.clone()));
+    fs.write("/file1.js".into(), "abcd".as_bytes())
+      .await
+      .unwrap();
+    assert!(matches!(
+      helper
+        .validate(&ArcPath::from("/file1.js"), &strategy)
+        .await,
+      ValidateResult::Modified
+    ));
+
+    let helper = StrategyHelper::new(fs.clone(), options, Some(hash_cache));
     fs.remove_file("/file1.js".into()).await.unwrap();
     assert!(matches!(
       helper
@@ -299,7 +412,7 @@ mod tests {
       .await
       .unwrap();
 
-    let helper = StrategyHelper::new(fs.clone(), Default::default());
+    let helper = StrategyHelper::new(fs.clone(), Default::default(), None);
     let strategy = Strategy::Missing;
     assert!(matches!(
       helper
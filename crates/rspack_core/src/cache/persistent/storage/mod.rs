@@ -4,10 +4,18 @@ mod memory;
 use std::{path::PathBuf, sync::Arc};
 
 pub use memory::MemoryStorage;
-use rspack_cacheable::{cacheable, utils::PortablePath, with::As};
+use rspack_cacheable::{
+  cacheable,
+  utils::PortablePath,
+  with::{As, Skip},
+};
+use rspack_error::Result;
 use rspack_fs::IntermediateFileSystem;
-pub use rspack_storage::Storage;
-use rspack_storage::{BridgeFileSystem, PackStorage, PackStorageOptions};
+use rspack_storage::{
+  BridgeFileSystem, DirRemoteCache, HttpRemoteCache, PackStorage, PackStorageOptions, RemoteCache,
+  RemoteStorage, RemoteStorageOptions,
+};
+pub use rspack_storage::{RemoteMode, Storage};
 
 /// Storage Options
 ///
@@ -19,16 +27,48 @@ pub enum StorageOptions {
   FileSystem {
     #[cacheable(with=As<PortablePath>)]
     directory: PathBuf,
+    /// The remote cache is not a part of the cache version,
+    /// so that builds with different remote settings can share the cache.
+    #[cacheable(with=Skip)]
+    remote: Option<RemoteOptions>,
   },
 }
 
+impl StorageOptions {
+  /// Whether the storage shares the cache through a remote cache.
+  pub fn has_remote(&self) -> bool {
+    match self {
+      Self::FileSystem { remote, .. } => remote.is_some(),
+    }
+  }
+}
+
+/// Options of the remote cache shared between machines, e.g. CI runners.
+#[derive(Debug, Clone, Hash)]
+pub struct RemoteOptions {
+  /// An `http://` url of a content-addressed cache server, or a directory shared between machines.
+  url: String,
+  mode: RemoteMode,
+}
+
+impl RemoteOptions {
+  /// Create the options, fail if the url is neither an `http://` url nor a directory.
+  pub fn new(url: String, mode: RemoteMode) -> Result<Self> {
+    if url.contains("://") {
+      HttpRemoteCache::new(&url)?;
+    }
+    Ok(Self { url, mode })
+  }
+}
+
 pub fn create_storage(
   options: StorageOptions,
   version: String,
   fs: Arc<dyn IntermediateFileSystem>,
 ) -> Arc<dyn Storage> {
   match options {
-    StorageOptions::FileSystem { directory } => {
+    StorageOptions::FileSystem { directory, remote } => {
+      let fs = Arc::new(BridgeFileSystem(fs));
       let option = PackStorageOptions {
         temp_root: directory.join(".temp"),
         root: directory,
@@ -36,12 +76,33 @@ pub fn create_storage(
         bucket_size: 20,
         pack_size: 500 * 1024,
         expire: 7 * 24 * 60 * 60 * 1000,
-        fs: Arc::new(BridgeFileSystem(fs)),
+        fs: fs.clone(),
         fresh_generation: Some(1),
         release_generation: Some(2),
-        version,
+        version: version.clone(),
       };
-      Arc::new(PackStorage::new(option))
+      let local: Arc<dyn Storage> = Arc::new(PackStorage::new(option));
+      let Some(remote) = remote else {
+        return local;
+      };
+      let cache: Arc<dyn RemoteCache> = if remote.url.contains("://") {
+        match HttpRemoteCache::new(&remote.url) {
+          Ok(cache) => Arc::new(cache),
+          Err(err) => {
+            // the url is validated by `RemoteOptions::new`, keep the local cache working anyway
+            tracing::warn!("remote cache is disabled, {err}");
+            return local;
+          }
+        }
+      } else {
+        Arc::new(DirRemoteCache::new(remote.url.into(), fs))
+      };
+      Arc::new(RemoteStorage::new(RemoteStorageOptions {
+        local,
+        remote: cache,
+        mode: remote.mode,
+        version,
+      }))
     }
   }
 }
@@ -13,9 +13,10 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet, FxHasher};
 
 use crate::{
-  ChunkGraph, ChunkGroupByUkey, ChunkGroupOrderKey, ChunkGroupUkey, ChunkHashesArtifact, ChunkUkey,
-  Compilation, EntryOptions, Filename, RenderManifestEntry, RuntimeSpec, SourceType,
-  chunk_graph_chunk::ChunkId, compare_chunk_group, sort_group_by_index,
+  ChunkGraph, ChunkGroupByUkey, ChunkGroupKind, ChunkGroupOrderKey, ChunkGroupUkey,
+  ChunkHashesArtifact, ChunkLoading, ChunkUkey, Compilation, EntryOptions, Filename,
+  RenderManifestEntry, RuntimeSpec, SourceType, chunk_graph_chunk::ChunkId, compare_chunk_group,
+  sort_group_by_index,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,6 +107,10 @@ impl Chunk {
     self.css_filename_template.as_ref()
   }
 
+  pub fn set_css_filename_template(&mut self, css_filename_template: Option<Filename>) {
+    self.css_filename_template = css_filename_template;
+  }
+
   pub fn id(&self) -> Option<&ChunkId> {
     self.id.as_ref()
   }
@@ -349,6 +354,22 @@ impl Chunk {
       .all(|group| group.is_initial())
   }
 
+  /// Whether the chunk is in an async entrypoint which can't load chunks, e.g. a worklet, so its
+  /// modules can't be split into other chunks.
+  pub fn is_in_non_loading_async_entrypoint(&self, chunk_group_by_ukey: &ChunkGroupByUkey) -> bool {
+    self
+      .groups
+      .iter()
+      .filter_map(|ukey| chunk_group_by_ukey.get(ukey))
+      .any(|group| {
+        matches!(
+          &group.kind,
+          ChunkGroupKind::Entrypoint { initial: false, options }
+            if matches!(options.chunk_loading, Some(ChunkLoading::Disable))
+        )
+      })
+  }
+
   pub fn has_entry_module(&self, chunk_graph: &ChunkGraph) -> bool {
     chunk_graph.get_number_of_entry_modules(&self.ukey) > 0
   }
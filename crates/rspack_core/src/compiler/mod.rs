@@ -99,6 +99,9 @@ pub struct Compiler {
   pub emitted_asset_versions: HashMap<String, String>,
   pub platform: Arc<CompilerPlatform>,
   compiler_context: Arc<CompilerContext>,
+  /// The engine chosen for each regex in `module.rules`, whether it's linear and the message
+  /// reported in each compilation.
+  rule_regex_engines: Vec<(bool, String)>,
 }
 
 impl Compiler {
@@ -146,6 +149,20 @@ impl Compiler {
       ))
     });
 
+    let rule_regex_engines = options
+      .module
+      .rule_regexes()
+      .into_iter()
+      .map(|(path, regex)| {
+        let message = format!(
+          "{path}: `{}` is matched by the {} engine, {}",
+          regex.to_source_string(),
+          regex.engine(),
+          regex.engine_reason()
+        );
+        (regex.engine().is_linear(), message)
+      })
+      .collect();
     let options = Arc::new(options);
     let plugin_driver = PluginDriver::new(options.clone(), plugins, resolver_factory.clone());
     let buildtime_plugin_driver =
@@ -195,6 +212,7 @@ impl Compiler {
       input_filesystem,
       platform,
       compiler_context,
+      rule_regex_engines,
     }
   }
 
@@ -293,6 +311,8 @@ impl Compiler {
       .call(&mut self.compilation, &mut compilation_params)
       .await?;
 
+    self.log_rule_regex_engines();
+
     let logger = self.compilation.get_logger("rspack.Compiler");
     let start = logger.time("seal compilation");
     self
@@ -310,6 +330,20 @@ impl Compiler {
     Ok(())
   }
 
+  /// Report the engine chosen for each regex in `module.rules`,
+  /// regexes matched by the backtracking engine may stall rule matching on pathological inputs,
+  /// so they are reported at the info level.
+  fn log_rule_regex_engines(&self) {
+    let logger = self.compilation.get_logger("rspack.RegexEngine");
+    for (linear, message) in &self.rule_regex_engines {
+      if *linear {
+        logger.debug(message.clone());
+      } else {
+        logger.info(message.clone());
+      }
+    }
+  }
+
   #[instrument("Compile:done", skip_all)]
   async fn compile_done(&mut self) -> Result<()> {
     let logger = self.compilation.get_logger("rspack.Compiler");
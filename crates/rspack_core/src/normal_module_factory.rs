@@ -783,6 +783,12 @@ module.exports = "data:,";
       parser.as_ref(),
       |global, local| match (global, local) {
         (ParserOptions::Asset(a), ParserOptions::Asset(b)) => ParserOptions::Asset(a.merge_from(b)),
+        (ParserOptions::AssetInline(a), ParserOptions::AssetInline(b)) => {
+          ParserOptions::AssetInline(a.merge_from(b))
+        }
+        (ParserOptions::AssetResource(a), ParserOptions::AssetResource(b)) => {
+          ParserOptions::AssetResource(a.merge_from(b))
+        }
         (ParserOptions::Css(a), ParserOptions::Css(b)) => ParserOptions::Css(a.merge_from(b)),
         (ParserOptions::CssAuto(a), ParserOptions::CssAuto(b)) => {
           ParserOptions::CssAuto(a.merge_from(b))
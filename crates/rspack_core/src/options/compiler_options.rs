@@ -1,3 +1,5 @@
+use rspack_error::Result;
+
 use crate::{
   CacheOptions, Context, Experiments, Mode, ModuleOptions, NodeOption, Optimization, OutputOptions,
   Resolve, StatsOptions, incremental::IncrementalOptions,
@@ -24,3 +26,13 @@ pub struct CompilerOptions {
 }
 
 pub type References = serde_json::Map<String, serde_json::Value>;
+
+impl CompilerOptions {
+  /// Apply the experiments that rewrite other options, once all options are built.
+  pub fn apply_experiments(&mut self) -> Result<()> {
+    if self.experiments.strict_regex {
+      self.module.use_linear_regexes()?;
+    }
+    Ok(())
+  }
+}
@@ -5,4 +5,6 @@
 pub struct Experiments {
   pub css: bool,
   pub defer_import: bool,
+  /// Only allow regexes in `module.rules` that can be matched in linear time.
+  pub strict_regex: bool,
 }
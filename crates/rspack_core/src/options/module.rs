@@ -9,7 +9,7 @@ use bitflags::bitflags;
 use derive_more::Debug;
 use futures::future::BoxFuture;
 use rspack_cacheable::{cacheable, with::Unsupported};
-use rspack_error::Result;
+use rspack_error::{Result, error};
 use rspack_macros::MergeFrom;
 use rspack_regex::RspackRegex;
 use rspack_util::{MergeFrom, try_all, try_any};
@@ -51,6 +51,8 @@ impl ParserOptionsMap {
 #[derive(Debug, Clone, MergeFrom)]
 pub enum ParserOptions {
   Asset(AssetParserOptions),
+  AssetInline(AssetInlineParserOptions),
+  AssetResource(AssetResourceParserOptions),
   Css(CssParserOptions),
   CssAuto(CssAutoParserOptions),
   CssModule(CssModuleParserOptions),
@@ -75,6 +77,12 @@ macro_rules! get_variant {
 
 impl ParserOptions {
   get_variant!(get_asset, Asset, AssetParserOptions);
+  get_variant!(get_asset_inline, AssetInline, AssetInlineParserOptions);
+  get_variant!(
+    get_asset_resource,
+    AssetResource,
+    AssetResourceParserOptions
+  );
   get_variant!(get_css, Css, CssParserOptions);
   get_variant!(get_css_auto, CssAuto, CssAutoParserOptions);
   get_variant!(get_css_module, CssModule, CssModuleParserOptions);
@@ -315,6 +323,29 @@ pub struct JavascriptParserOptions {
 #[derive(Debug, Clone, MergeFrom)]
 pub struct AssetParserOptions {
   pub data_url_condition: Option<AssetParserDataUrl>,
+  pub image: Option<AssetParserImageOptions>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub struct AssetInlineParserOptions {
+  pub image: Option<AssetParserImageOptions>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub struct AssetResourceParserOptions {
+  pub image: Option<AssetParserImageOptions>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone, MergeFrom)]
+pub struct AssetParserImageOptions {
+  /// Losslessly recompresses PNG images, and JPEG images at `quality` when it's set,
+  /// defaults to `true`.
+  pub optimize: Option<bool>,
+  /// The quality of lossy encodings from 1 to 100.
+  pub quality: Option<u8>,
 }
 
 #[cacheable]
@@ -1171,3 +1202,127 @@ pub struct ModuleOptions {
   pub generator: Option<GeneratorOptionsMap>,
   pub no_parse: Option<ModuleNoParseRules>,
 }
+
+impl ModuleOptions {
+  /// All regexes in `module.rules`, along with their paths in the config, e.g. `module.rules[0].oneOf[1].test`.
+  pub fn rule_regexes(&self) -> Vec<(String, &RspackRegex)> {
+    let mut regexes = vec![];
+    for (index, rule) in self.rules.iter().enumerate() {
+      rule.collect_regexes(format!("module.rules[{index}]"), &mut regexes);
+    }
+    regexes
+  }
+
+  /// Recompile all regexes in `module.rules` with engines that guarantee linear-time matching,
+  /// fail with the path of the rule if a regex needs backtracking.
+  pub fn use_linear_regexes(&mut self) -> Result<()> {
+    let mut regexes = vec![];
+    for (index, rule) in self.rules.iter_mut().enumerate() {
+      rule.collect_regexes_mut(format!("module.rules[{index}]"), &mut regexes);
+    }
+    for (path, regex) in regexes {
+      *regex = regex
+        .to_linear()
+        .map_err(|err| error!("`{path}` is rejected by `experiments.strictRegex`. {err}"))?;
+    }
+    Ok(())
+  }
+}
+
+macro_rules! impl_collect_regexes {
+  ($collect:ident $(, $mutability:tt)?) => {
+    impl RuleSetCondition {
+      fn $collect<'a>(
+        &'a $($mutability)? self,
+        path: String,
+        regexes: &mut Vec<(String, &'a $($mutability)? RspackRegex)>,
+      ) {
+        match self {
+          Self::String(_) | Self::Func(_) => {}
+          Self::Regexp(regex) => regexes.push((path, regex)),
+          Self::Logical(logical) => {
+            let RuleSetLogicalConditions { and, or, not } = &$($mutability)? **logical;
+            for (key, conditions) in [("and", and), ("or", or)] {
+              for (index, condition) in IntoIterator::into_iter(conditions).flatten().enumerate() {
+                condition.$collect(format!("{path}.{key}[{index}]"), regexes);
+              }
+            }
+            if let Some(not) = not {
+              not.$collect(format!("{path}.not"), regexes);
+            }
+          }
+          Self::Array(conditions) => {
+            for (index, condition) in IntoIterator::into_iter(conditions).enumerate() {
+              condition.$collect(format!("{path}[{index}]"), regexes);
+            }
+          }
+        }
+      }
+    }
+
+    impl ModuleRule {
+      fn $collect<'a>(
+        &'a $($mutability)? self,
+        path: String,
+        regexes: &mut Vec<(String, &'a $($mutability)? RspackRegex)>,
+      ) {
+        let ModuleRule {
+          rspack_resource,
+          test,
+          include,
+          exclude,
+          resource,
+          resource_query,
+          resource_fragment,
+          dependency,
+          issuer,
+          issuer_layer,
+          scheme,
+          mimetype,
+          description_data,
+          with,
+          one_of,
+          rules,
+          ..
+        } = self;
+        for (key, condition) in [
+          ("rspackResource", rspack_resource),
+          ("test", test),
+          ("include", include),
+          ("exclude", exclude),
+          ("resource", resource),
+          ("dependency", dependency),
+        ] {
+          if let Some(condition) = condition {
+            condition.$collect(format!("{path}.{key}"), regexes);
+          }
+        }
+        for (key, condition) in [
+          ("resourceQuery", resource_query),
+          ("resourceFragment", resource_fragment),
+          ("issuer", issuer),
+          ("issuerLayer", issuer_layer),
+          ("scheme", scheme),
+          ("mimetype", mimetype),
+        ] {
+          if let Some(condition) = condition {
+            condition.condition.$collect(format!("{path}.{key}"), regexes);
+          }
+        }
+        for (key, conditions) in [("descriptionData", description_data), ("with", with)] {
+          for (name, condition) in IntoIterator::into_iter(conditions).flatten() {
+            condition.condition.$collect(format!("{path}.{key}.{name}"), regexes);
+          }
+        }
+        for (key, rules) in [("oneOf", one_of), ("rules", rules)] {
+          for (index, rule) in IntoIterator::into_iter(rules).flatten().enumerate() {
+            rule.$collect(format!("{path}.{key}[{index}]"), regexes);
+          }
+        }
+      }
+    }
+  };
+}
+
+impl_collect_regexes!(collect_regexes);
+impl_collect_regexes!(collect_regexes_mut, mut);
@@ -41,6 +41,7 @@ pub struct OutputOptions {
   pub chunk_loading: ChunkLoading,
   pub chunk_loading_global: String,
   pub chunk_load_timeout: u32,
+  pub chunk_load_retry: Option<ChunkLoadRetry>,
   pub filename: Filename,
   pub chunk_filename: Filename,
   pub cross_origin_loading: CrossOriginLoading,
@@ -57,6 +58,7 @@ pub struct OutputOptions {
   pub import_meta_name: String,
   pub iife: bool,
   pub module: bool,
+  pub import_map: Option<OutputImportMap>,
   pub trusted_types: Option<TrustedTypes>,
   pub source_map_filename: Filename,
   pub hash_function: HashFunction,
@@ -100,6 +102,36 @@ pub struct TrustedTypes {
   pub on_policy_creation_failure: OnPolicyCreationFailure,
 }
 
+#[derive(Debug, Clone)]
+pub struct OutputImportMap {
+  /// The filename of the emitted import map, relative to `output.path`.
+  pub filename: String,
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct ChunkLoadRetry {
+  /// How many times a failed chunk load is retried before rejecting with the `ChunkLoadError`.
+  pub max_retries: u32,
+  /// The delay in milliseconds before the first retry, doubled for every following retry.
+  pub retry_delay: u32,
+  /// Whether to append the attempt number as a query parameter to the retried url.
+  pub cache_bust: bool,
+  /// Public paths which replace `output.publicPath` in turn for the retried requests.
+  pub fallback_public_paths: Vec<String>,
+}
+
+impl Default for ChunkLoadRetry {
+  fn default() -> Self {
+    Self {
+      max_retries: 3,
+      retry_delay: 1000,
+      cache_bust: true,
+      fallback_public_paths: Vec::new(),
+    }
+  }
+}
+
 #[cacheable]
 #[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ChunkLoading {
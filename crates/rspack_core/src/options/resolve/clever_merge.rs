@@ -2,7 +2,7 @@ use hashlink::LinkedHashMap;
 
 use super::{
   Alias, AliasFields, ByDependency, ConditionNames, DependencyCategoryStr, DescriptionFiles,
-  EnforceExtension, ExportsFields, ExtensionAlias, Extensions, Fallback, FullySpecified,
+  EnforceExtension, ExportsFields, ExtensionAlias, Extensions, Fallback, FullySpecified, ImportMap,
   ImportsFields, MainFields, MainFiles, Modules, PnpManifest, PreferAbsolute, PreferRelative,
   Resolve, Restrictions, Roots, Symlink, TsconfigOptions,
   value_type::{GetValueType, ValueType},
@@ -41,6 +41,7 @@ fn is_empty(resolve: &Resolve) -> bool {
     && is_none!(tsconfig)
     && is_none!(by_dependency)
     && is_none!(pnp_manifest)
+    && is_none!(import_map)
 }
 
 #[derive(Default, Debug)]
@@ -73,6 +74,7 @@ struct ResolveWithEntry {
   roots: Entry<Roots>,
   pnp: Entry<bool>,
   pnp_manifest: Entry<PnpManifest>,
+  import_map: Entry<ImportMap>,
 }
 
 fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
@@ -107,6 +109,7 @@ fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
     roots: entry!(roots),
     pnp: entry!(pnp),
     pnp_manifest: entry!(pnp_manifest),
+    import_map: entry!(import_map),
   };
   let Some(by_dependency) = resolve.by_dependency else {
     return res;
@@ -166,6 +169,7 @@ fn parse_resolve(resolve: Resolve) -> ResolveWithEntry {
   update_by_value!(roots, |i: Option<&_>| i.is_some());
   update_by_value!(tsconfig, |i: Option<&_>| i.is_some());
   update_by_value!(pnp_manifest, |i: Option<&_>| i.is_some());
+  update_by_value!(import_map, |i: Option<&_>| i.is_some());
 
   res
 }
@@ -371,6 +375,7 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
     alias_fields: merge!(alias_fields, ValueType::Other, |_| false, |_, b| b),
     restrictions: merge!(restrictions, ValueType::Other, |_| false, |_, b| b),
     roots: merge!(roots, ValueType::Other, |_| false, |_, b| b),
+    import_map: merge!(import_map, ValueType::Other, |_| false, |_, b| b),
   };
 
   let mut by_dependency: LinkedHashMap<DependencyCategoryStr, Resolve> = LinkedHashMap::new();
@@ -408,6 +413,7 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
   setup_by_values!(restrictions);
   setup_by_values!(roots);
   setup_by_values!(pnp_manifest);
+  setup_by_values!(import_map);
 
   macro_rules! to_resolve {
     ($ident: ident) => {
@@ -442,6 +448,7 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
   to_resolve!(restrictions);
   to_resolve!(roots);
   to_resolve!(pnp_manifest);
+  to_resolve!(import_map);
 
   let by_dependency = if by_dependency.iter().all(|(_, by_value)| is_empty(by_value)) {
     None
@@ -473,6 +480,7 @@ fn _merge_resolve(first: Resolve, second: Resolve) -> Resolve {
     roots: result_entry.roots.base,
     pnp: result_entry.pnp.base,
     pnp_manifest: result_entry.pnp_manifest.base,
+    import_map: result_entry.import_map.base,
     builtin_modules: false,
   }
 }
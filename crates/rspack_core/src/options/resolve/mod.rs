@@ -72,6 +72,18 @@ impl value_type::GetValueType for PnpManifest {
   }
 }
 
+/// Import map options
+///
+/// See [import maps](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps)
+#[cacheable]
+#[derive(Debug, Clone, Hash, PartialEq, Eq, Default)]
+pub struct ImportMapOptions {
+  /// The absolute path of the import map file, which contains `imports` and `scopes`.
+  /// Addresses and scope prefixes are resolved relative to this file.
+  #[cacheable(with=AsPreset)]
+  pub path: Utf8PathBuf,
+}
+
 pub(super) type Extensions = Vec<String>;
 pub(super) type PreferRelative = bool;
 pub(super) type PreferAbsolute = bool;
@@ -90,6 +102,7 @@ pub(super) type ExtensionAlias = Vec<(String, Vec<String>)>;
 pub(super) type Modules = Vec<String>;
 pub(super) type Roots = Vec<String>;
 pub(super) type Restrictions = Vec<Restriction>;
+pub(super) type ImportMap = ImportMapOptions;
 
 #[cacheable]
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
@@ -154,6 +167,8 @@ pub struct Resolve {
   pub pnp: Option<bool>,
   /// Path to PnP manifest file
   pub pnp_manifest: Option<PnpManifest>,
+  /// Import map applied with the scoped resolution algorithm before the normal resolution.
+  pub import_map: Option<ImportMap>,
   /// Whether to parse [module.builtinModules](https://nodejs.org/api/module.html#modulebuiltinmodules) or not.
   pub builtin_modules: bool,
 }
@@ -0,0 +1,365 @@
+//! An implementation of the [import maps](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps)
+//! resolution algorithm, applied before the normal resolution.
+
+use std::{path::Path, sync::Arc};
+
+use dashmap::DashMap;
+use rspack_fs::ReadableFileSystem;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rustc_hash::FxBuildHasher;
+use serde_json::{Map, Value};
+use url::Url;
+
+/// A specifier map with keys sorted in descending code unit order,
+/// `None` means the entry is blocked by a null (or invalid) address.
+type SpecifierMap = Vec<(String, Option<Url>)>;
+
+/// A parsed import map.
+#[derive(Debug)]
+pub struct ImportMap {
+  imports: SpecifierMap,
+  scopes: Vec<(String, SpecifierMap)>,
+}
+
+impl ImportMap {
+  /// Parse an import map string, addresses and scopes are resolved against `base_url`.
+  ///
+  /// See: <https://html.spec.whatwg.org/multipage/webappapis.html#parse-an-import-map-string>
+  pub fn parse(input: &str, base_url: &Url) -> Result<Self, String> {
+    let parsed: Value =
+      serde_json::from_str(input).map_err(|e| format!("Invalid JSON in import map: {e}"))?;
+    let Value::Object(parsed) = parsed else {
+      return Err("Import map must be a JSON object".to_string());
+    };
+
+    let imports = match parsed.get("imports") {
+      Some(Value::Object(imports)) => sort_and_normalize_specifier_map(imports, base_url),
+      Some(_) => return Err("The \"imports\" top-level key must be a JSON object".to_string()),
+      None => vec![],
+    };
+
+    let scopes = match parsed.get("scopes") {
+      Some(Value::Object(scopes)) => sort_and_normalize_scopes(scopes, base_url)?,
+      Some(_) => return Err("The \"scopes\" top-level key must be a JSON object".to_string()),
+      None => vec![],
+    };
+
+    Ok(Self { imports, scopes })
+  }
+
+  /// Resolve `specifier` imported from `referrer` with the import map.
+  ///
+  /// Returns `Ok(None)` if no entry matches, so the specifier should go through
+  /// the normal resolution untouched.
+  ///
+  /// See: <https://html.spec.whatwg.org/multipage/webappapis.html#resolve-a-module-specifier>
+  pub fn resolve(&self, specifier: &str, referrer: &Url) -> Result<Option<Url>, String> {
+    let as_url = parse_url_like_import_specifier(specifier, referrer);
+    let normalized_specifier = as_url
+      .as_ref()
+      .map_or(specifier, |url| url.as_str())
+      .to_string();
+
+    let referrer = referrer.as_str();
+    for (scope_prefix, scope_imports) in &self.scopes {
+      if (scope_prefix == referrer
+        || (scope_prefix.ends_with('/') && referrer.starts_with(scope_prefix)))
+        && let Some(resolved) =
+          resolve_imports_match(&normalized_specifier, as_url.as_ref(), scope_imports)?
+      {
+        return Ok(Some(resolved));
+      }
+    }
+
+    resolve_imports_match(&normalized_specifier, as_url.as_ref(), &self.imports)
+  }
+}
+
+/// See: <https://html.spec.whatwg.org/multipage/webappapis.html#resolving-an-imports-match>
+fn resolve_imports_match(
+  normalized_specifier: &str,
+  as_url: Option<&Url>,
+  specifier_map: &SpecifierMap,
+) -> Result<Option<Url>, String> {
+  for (specifier_key, resolution_result) in specifier_map {
+    if specifier_key == normalized_specifier {
+      let Some(resolution_result) = resolution_result else {
+        return Err(format!(
+          "Resolution of '{normalized_specifier}' was blocked by a null entry in the import map"
+        ));
+      };
+      return Ok(Some(resolution_result.clone()));
+    }
+
+    if specifier_key.ends_with('/')
+      && let Some(after_prefix) = normalized_specifier.strip_prefix(specifier_key.as_str())
+      && as_url.is_none_or(is_special)
+    {
+      let Some(resolution_result) = resolution_result else {
+        return Err(format!(
+          "Resolution of '{normalized_specifier}' was blocked by a null entry in the import map"
+        ));
+      };
+      let url = resolution_result.join(after_prefix).map_err(|_| {
+        format!("Resolution of '{normalized_specifier}' was blocked since the '{after_prefix}' could not be parsed relative to '{resolution_result}'")
+      })?;
+      if !url.as_str().starts_with(resolution_result.as_str()) {
+        return Err(format!(
+          "Resolution of '{normalized_specifier}' was blocked due to it backtracking above its prefix '{specifier_key}'"
+        ));
+      }
+      return Ok(Some(url));
+    }
+  }
+  Ok(None)
+}
+
+/// See: <https://html.spec.whatwg.org/multipage/webappapis.html#sorting-and-normalizing-a-module-specifier-map>
+fn sort_and_normalize_specifier_map(
+  original_map: &Map<String, Value>,
+  base_url: &Url,
+) -> SpecifierMap {
+  let mut normalized: SpecifierMap = Vec::with_capacity(original_map.len());
+  for (specifier_key, value) in original_map {
+    let Some(normalized_specifier_key) = normalize_specifier_key(specifier_key, base_url) else {
+      continue;
+    };
+    let address_url = value
+      .as_str()
+      .and_then(|value| parse_url_like_import_specifier(value, base_url))
+      .filter(|address_url| !specifier_key.ends_with('/') || address_url.as_str().ends_with('/'));
+    normalized.retain(|(key, _)| key != &normalized_specifier_key);
+    normalized.push((normalized_specifier_key, address_url));
+  }
+  normalized.sort_by(|(a, _), (b, _)| b.cmp(a));
+  normalized
+}
+
+/// See: <https://html.spec.whatwg.org/multipage/webappapis.html#sorting-and-normalizing-scopes>
+fn sort_and_normalize_scopes(
+  original_map: &Map<String, Value>,
+  base_url: &Url,
+) -> Result<Vec<(String, SpecifierMap)>, String> {
+  let mut normalized = Vec::with_capacity(original_map.len());
+  for (scope_prefix, potential_specifier_map) in original_map {
+    let Value::Object(potential_specifier_map) = potential_specifier_map else {
+      return Err(format!(
+        "The value of the scope with prefix '{scope_prefix}' must be a JSON object"
+      ));
+    };
+    let Ok(scope_prefix_url) = base_url.join(scope_prefix) else {
+      continue;
+    };
+    normalized.push((
+      scope_prefix_url.to_string(),
+      sort_and_normalize_specifier_map(potential_specifier_map, base_url),
+    ));
+  }
+  normalized.sort_by(|(a, _), (b, _)| b.cmp(a));
+  Ok(normalized)
+}
+
+/// See: <https://html.spec.whatwg.org/multipage/webappapis.html#normalizing-a-specifier-key>
+fn normalize_specifier_key(specifier_key: &str, base_url: &Url) -> Option<String> {
+  if specifier_key.is_empty() {
+    return None;
+  }
+  Some(
+    parse_url_like_import_specifier(specifier_key, base_url)
+      .map_or_else(|| specifier_key.to_string(), |url| url.to_string()),
+  )
+}
+
+/// See: <https://html.spec.whatwg.org/multipage/webappapis.html#resolving-a-url-like-module-specifier>
+fn parse_url_like_import_specifier(specifier: &str, base_url: &Url) -> Option<Url> {
+  if specifier.starts_with('/') || specifier.starts_with("./") || specifier.starts_with("../") {
+    return base_url.join(specifier).ok();
+  }
+  Url::parse(specifier).ok()
+}
+
+fn is_special(url: &Url) -> bool {
+  matches!(
+    url.scheme(),
+    "ftp" | "file" | "http" | "https" | "ws" | "wss"
+  )
+}
+
+/// Convert the resolved url back into a request for the resolver.
+///
+/// `file:` urls become absolute paths, other urls are kept as is so that
+/// scheme plugins can handle them.
+pub(super) fn url_to_request(url: &Url) -> String {
+  if url.scheme() == "file"
+    && let Ok(path) = url.to_file_path()
+  {
+    let mut request = path.to_string_lossy().into_owned();
+    if let Some(query) = url.query() {
+      request.push('?');
+      request.push_str(query);
+    }
+    if let Some(fragment) = url.fragment() {
+      request.push('#');
+      request.push_str(fragment);
+    }
+    return request;
+  }
+  url.to_string()
+}
+
+/// The referrer url of a request issued by the `issuer` file, or from the `context`
+/// directory when the issuer is unknown.
+pub(super) fn referrer_url(context: &Path, issuer: Option<&Path>) -> Option<Url> {
+  match issuer {
+    Some(issuer) if issuer.is_absolute() => Url::from_file_path(issuer).ok(),
+    _ => Url::from_directory_path(context).ok(),
+  }
+}
+
+/// Import maps loaded by resolvers, shared between all resolvers created by the same factory.
+#[derive(Debug, Default)]
+pub struct ImportMapCache {
+  maps: DashMap<Utf8PathBuf, Arc<ImportMap>, FxBuildHasher>,
+}
+
+impl ImportMapCache {
+  pub fn clear(&self) {
+    self.maps.clear();
+  }
+
+  pub async fn get(
+    &self,
+    fs: &dyn ReadableFileSystem,
+    path: &Utf8Path,
+  ) -> Result<Arc<ImportMap>, String> {
+    if let Some(map) = self.maps.get(path) {
+      return Ok(map.clone());
+    }
+    let content = fs
+      .read_to_string(path)
+      .await
+      .map_err(|e| format!("Failed to read import map '{path}': {e}"))?;
+    let base_url = Url::from_file_path(path)
+      .map_err(|_| format!("Import map path '{path}' should be an absolute path"))?;
+    let map =
+      Arc::new(ImportMap::parse(&content, &base_url).map_err(|e| format!("{e} in '{path}'"))?);
+    self.maps.insert(path.to_path_buf(), map.clone());
+    Ok(map)
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn base_url() -> Url {
+    Url::parse("file:///project/importmap.json").expect("should parse")
+  }
+
+  fn resolve(map: &ImportMap, specifier: &str, referrer: &str) -> Option<String> {
+    let referrer = Url::parse(referrer).expect("should parse");
+    map
+      .resolve(specifier, &referrer)
+      .expect("should resolve")
+      .map(|url| url.to_string())
+  }
+
+  #[test]
+  fn should_resolve_bare_specifiers_and_prefixes() {
+    let map = ImportMap::parse(
+      r#"{
+        "imports": {
+          "react": "./vendor/react.js",
+          "lodash/": "/node_modules/lodash-es/",
+          "lodash/fp/": "./vendor/fp/",
+          "https://cdn.example.com/": "./cdn/"
+        }
+      }"#,
+      &base_url(),
+    )
+    .expect("should parse");
+
+    let referrer = "file:///project/src/";
+    assert_eq!(
+      resolve(&map, "react", referrer).as_deref(),
+      Some("file:///project/vendor/react.js")
+    );
+    assert_eq!(
+      resolve(&map, "lodash/map.js", referrer).as_deref(),
+      Some("file:///node_modules/lodash-es/map.js")
+    );
+    assert_eq!(
+      resolve(&map, "lodash/fp/map.js", referrer).as_deref(),
+      Some("file:///project/vendor/fp/map.js")
+    );
+    assert_eq!(
+      resolve(&map, "https://cdn.example.com/a.js", referrer).as_deref(),
+      Some("file:///project/cdn/a.js")
+    );
+    assert_eq!(resolve(&map, "vue", referrer), None);
+    assert_eq!(resolve(&map, "./react", referrer), None);
+  }
+
+  #[test]
+  fn should_prefer_the_most_specific_scope() {
+    let map = ImportMap::parse(
+      r#"{
+        "imports": { "a": "./a.js" },
+        "scopes": {
+          "./src/": { "a": "./src-a.js" },
+          "./src/legacy/": { "a": "./legacy-a.js" }
+        }
+      }"#,
+      &base_url(),
+    )
+    .expect("should parse");
+
+    assert_eq!(
+      resolve(&map, "a", "file:///project/").as_deref(),
+      Some("file:///project/a.js")
+    );
+    assert_eq!(
+      resolve(&map, "a", "file:///project/src/components/").as_deref(),
+      Some("file:///project/src-a.js")
+    );
+    assert_eq!(
+      resolve(&map, "a", "file:///project/src/legacy/").as_deref(),
+      Some("file:///project/legacy-a.js")
+    );
+  }
+
+  #[test]
+  fn should_block_null_entries_and_backtracking() {
+    let map = ImportMap::parse(
+      r#"{
+        "imports": {
+          "blocked": null,
+          "invalid/": "./no-trailing-slash",
+          "pkg/": "./pkg/"
+        }
+      }"#,
+      &base_url(),
+    )
+    .expect("should parse");
+
+    let referrer = Url::parse("file:///project/").expect("should parse");
+    assert!(map.resolve("blocked", &referrer).is_err());
+    assert!(map.resolve("invalid/a.js", &referrer).is_err());
+    assert!(map.resolve("pkg/../../escape.js", &referrer).is_err());
+  }
+
+  #[test]
+  fn should_reject_invalid_import_maps() {
+    assert!(ImportMap::parse("[]", &base_url()).is_err());
+    assert!(ImportMap::parse(r#"{ "imports": [] }"#, &base_url()).is_err());
+    assert!(ImportMap::parse(r#"{ "scopes": { "./": 1 } }"#, &base_url()).is_err());
+  }
+
+  #[test]
+  fn should_convert_file_urls_to_requests() {
+    let url = Url::parse("file:///project/a.js?raw#hash").expect("should parse");
+    assert_eq!(url_to_request(&url), "/project/a.js?raw#hash");
+    let url = Url::parse("https://cdn.example.com/a.js").expect("should parse");
+    assert_eq!(url_to_request(&url), "https://cdn.example.com/a.js");
+  }
+}
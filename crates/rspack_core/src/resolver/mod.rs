@@ -1,5 +1,6 @@
 mod boxfs;
 mod factory;
+mod import_map;
 mod resolver_impl;
 use std::{
   borrow::Borrow,
@@ -11,7 +12,7 @@ use std::{
 use regex::Regex;
 use rspack_error::Error;
 use rspack_fs::ReadableFileSystem;
-use rspack_loader_runner::{DescriptionData, ResourceData};
+use rspack_loader_runner::{DescriptionData, ResourceData, parse_resource};
 use rspack_paths::{AssertUtf8, Utf8PathBuf};
 use rspack_util::identifier::insert_zero_width_space_for_fragment;
 use rustc_hash::FxHashSet;
@@ -323,8 +324,18 @@ pub async fn resolve(
 
   let mut context = Default::default();
   let resolver = plugin_driver.resolver_factory.get(dep);
+  // the issuer is a resource, which may carry a query or a fragment
+  let issuer = args
+    .issuer
+    .and_then(parse_resource)
+    .map(|issuer| issuer.path);
   let mut result = resolver
-    .resolve_with_context(args.context.as_ref(), args.specifier, &mut context)
+    .resolve_with_context(
+      args.context.as_ref(),
+      issuer.as_deref().map(|issuer| issuer.as_std_path()),
+      args.specifier,
+      &mut context,
+    )
     .await
     .map_err(|error| error.into_resolve_error(&args));
 
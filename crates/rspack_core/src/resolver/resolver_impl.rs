@@ -1,4 +1,5 @@
 use std::{
+  borrow::Cow,
   fmt,
   path::{Path, PathBuf},
   sync::Arc,
@@ -7,11 +8,15 @@ use std::{
 use rspack_error::{Error, Severity, cyan, yellow};
 use rspack_fs::ReadableFileSystem;
 use rspack_loader_runner::DescriptionData;
-use rspack_paths::AssertUtf8;
+use rspack_paths::{AssertUtf8, Utf8PathBuf};
 use rspack_util::location::byte_line_column_to_offset;
 use rustc_hash::FxHashSet as HashSet;
 
-use super::{ResolveResult, Resource, boxfs::BoxFS};
+use super::{
+  ResolveResult, Resource,
+  boxfs::BoxFS,
+  import_map::{ImportMapCache, referrer_url, url_to_request},
+};
 use crate::{
   Alias, AliasMap, DependencyCategory, PnpManifest, Resolve, ResolveArgs,
   ResolveOptionsWithDependencyType,
@@ -29,12 +34,14 @@ pub struct ResolveContext {
 #[derive(Debug)]
 pub enum ResolveInnerError {
   RspackResolver(rspack_resolver::ResolveError),
+  ImportMap(String),
 }
 
 impl fmt::Display for ResolveInnerError {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     match self {
       Self::RspackResolver(error) => write!(f, "{error:?}"),
+      Self::ImportMap(error) => write!(f, "{error}"),
     }
   }
 }
@@ -90,6 +97,10 @@ impl ResolveInnerOptions<'_> {
 pub struct Resolver {
   inner_fs: Arc<dyn ReadableFileSystem>,
   resolver: rspack_resolver::ResolverGeneric<BoxFS>,
+  /// Path of the import map applied before the normal resolution.
+  import_map: Option<Utf8PathBuf>,
+  /// Loaded import maps, shared between all resolvers like the internal caches.
+  import_maps: Arc<ImportMapCache>,
 }
 
 impl Resolver {
@@ -98,18 +109,23 @@ impl Resolver {
   }
 
   fn new_rspack_resolver(options: Resolve, fs: Arc<dyn ReadableFileSystem>) -> Self {
-    let options = to_rspack_resolver_options(options, false, DependencyCategory::Unknown);
+    let options = options.merge_by_dependency(DependencyCategory::Unknown);
+    let import_map = options.import_map.clone().map(|o| o.path);
+    let options = to_rspack_resolver_options(options, false);
     let boxfs = BoxFS::new(fs.clone());
     let resolver = rspack_resolver::ResolverGeneric::new_with_file_system(boxfs, options);
     Self {
       inner_fs: fs,
       resolver,
+      import_map,
+      import_maps: Default::default(),
     }
   }
 
   /// Clear cache for all resolver instances
   pub fn clear_cache(&self) {
     self.resolver.clear_cache();
+    self.import_maps.clear();
   }
 
   /// Create a new resolver by cloning its internal cache.
@@ -119,16 +135,54 @@ impl Resolver {
     options_with_dependency_type: &ResolveOptionsWithDependencyType,
   ) -> Self {
     let resolver = &self.resolver;
-    let options = to_rspack_resolver_options(
-      options,
-      options_with_dependency_type.resolve_to_context,
-      options_with_dependency_type.dependency_category,
-    );
+    let options = options.merge_by_dependency(options_with_dependency_type.dependency_category);
+    let import_map = options.import_map.clone().map(|o| o.path);
+    let options =
+      to_rspack_resolver_options(options, options_with_dependency_type.resolve_to_context);
 
     let resolver = resolver.clone_with_options(options);
     Self {
       inner_fs: self.inner_fs.clone(),
       resolver,
+      import_map,
+      import_maps: self.import_maps.clone(),
+    }
+  }
+
+  /// Apply the import map to the request, if any.
+  ///
+  /// Returns the request unchanged when there is no import map or no entry matches.
+  async fn apply_import_map<'r>(
+    &self,
+    path: &Path,
+    issuer: Option<&Path>,
+    request: &'r str,
+    resolve_context: Option<&mut ResolveContext>,
+  ) -> Result<Cow<'r, str>, ResolveInnerError> {
+    let Some(import_map_path) = &self.import_map else {
+      return Ok(Cow::Borrowed(request));
+    };
+    if let Some(resolve_context) = resolve_context {
+      // the resolution result depends on the import map, track it so watch and
+      // persistent cache are invalidated when the import map changes.
+      resolve_context
+        .file_dependencies
+        .insert(import_map_path.clone().into_std_path_buf());
+    }
+    let import_map = self
+      .import_maps
+      .get(self.inner_fs.as_ref(), import_map_path)
+      .await
+      .map_err(ResolveInnerError::ImportMap)?;
+    let Some(referrer) = referrer_url(path, issuer) else {
+      return Ok(Cow::Borrowed(request));
+    };
+    match import_map
+      .resolve(request, &referrer)
+      .map_err(ResolveInnerError::ImportMap)?
+    {
+      Some(url) => Ok(Cow::Owned(url_to_request(&url))),
+      None => Ok(Cow::Borrowed(request)),
     }
   }
 
@@ -143,7 +197,8 @@ impl Resolver {
     path: &Path,
     request: &str,
   ) -> Result<ResolveResult, ResolveInnerError> {
-    match self.resolver.resolve(path, request).await {
+    let request = self.apply_import_map(path, None, request, None).await?;
+    match self.resolver.resolve(path, &request).await {
       Ok(r) => Ok(ResolveResult::Resource(Resource {
         path: r.path().to_path_buf().assert_utf8(),
         query: r.query().unwrap_or_default().to_string(),
@@ -158,16 +213,23 @@ impl Resolver {
   }
 
   /// Resolve a specifier to a given path.
+  ///
+  /// `issuer` is the resource of the module issuing the request, it is used as the
+  /// referrer of import map scopes and falls back to `path` when not provided.
   pub async fn resolve_with_context(
     &self,
     path: &Path,
+    issuer: Option<&Path>,
     request: &str,
     resolve_context: &mut ResolveContext,
   ) -> Result<ResolveResult, ResolveInnerError> {
+    let request = self
+      .apply_import_map(path, issuer, request, Some(&mut *resolve_context))
+      .await?;
     let resolver = &self.resolver;
     let mut context = Default::default();
     let result = resolver
-      .resolve_with_context(path, request, &mut context)
+      .resolve_with_context(path, &request, &mut context)
       .await;
     resolve_context
       .file_dependencies
@@ -198,16 +260,16 @@ impl ResolveInnerError {
   pub fn into_resolve_error(self, args: &ResolveArgs<'_>) -> Error {
     match self {
       Self::RspackResolver(error) => map_rspack_resolver_error(error, args),
+      Self::ImportMap(error) => rspack_error::error!(error),
     }
   }
 }
 
+/// Converts options already merged by dependency category.
 fn to_rspack_resolver_options(
   options: Resolve,
   resolve_to_context: bool,
-  dependency_type: DependencyCategory,
 ) -> rspack_resolver::ResolveOptions {
-  let options = options.merge_by_dependency(dependency_type);
   let tsconfig = options.tsconfig.map(|c| c.into());
   let enforce_extension =
     options
@@ -300,6 +300,10 @@ define_runtime_globals! {
 
   // react server component
   const RSC_MANIFEST;
+
+  // rspack only, output.chunkLoadRetry
+  const CHUNK_LOAD_RETRY;
+  const CHUNK_LOAD_RETRY_URL;
 }
 
 impl Default for RuntimeGlobals {
@@ -373,6 +377,8 @@ pub static REQUIRE_SCOPE_GLOBALS: LazyLock<RuntimeGlobals> = LazyLock::new(|| {
     | RuntimeGlobals::RSPACK_UNIQUE_ID
     | RuntimeGlobals::ASYNC_STARTUP
     | RuntimeGlobals::RSC_MANIFEST
+    | RuntimeGlobals::CHUNK_LOAD_RETRY
+    | RuntimeGlobals::CHUNK_LOAD_RETRY_URL
     | RuntimeGlobals::TO_BINARY
     | RuntimeGlobals::DEFERRED_MODULES_ASYNC_TRANSITIVE_DEPENDENCIES
     | RuntimeGlobals::DEFERRED_MODULES_ASYNC_TRANSITIVE_DEPENDENCIES_SYMBOL
@@ -473,6 +479,8 @@ pub fn runtime_globals_to_string(
     RuntimeGlobals::HAS_FETCH_PRIORITY => "has fetch priority",
 
     RuntimeGlobals::RSC_MANIFEST => "rscM",
+    RuntimeGlobals::CHUNK_LOAD_RETRY => "rl",
+    RuntimeGlobals::CHUNK_LOAD_RETRY_URL => "ru",
     RuntimeGlobals::TO_BINARY => "tb",
     _ => unreachable!(),
   };
@@ -0,0 +1,108 @@
+use std::net::IpAddr;
+
+/// The hosts which are allowed to access the server, in the `Host` header of the requests
+/// and the `Origin` header of the WebSocket connections.
+///
+/// Checking them prevents other sites from reading the output through DNS rebinding,
+/// and from connecting to the WebSocket endpoint of the server.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AllowedHosts {
+  /// Allows `localhost`, its subdomains, IP addresses and the host the server listens on.
+  #[default]
+  Auto,
+  /// Allows every host, which disables the checks.
+  All,
+  /// Allows the hosts of [AllowedHosts::Auto] and the listed hosts. A host starting with `.`
+  /// also allows its subdomains, e.g. `.example.com` allows `example.com` and `app.example.com`.
+  List(Vec<String>),
+}
+
+impl AllowedHosts {
+  /// Checks the value of a `Host` or `Origin` header, `server_host` is the host the server
+  /// listens on.
+  pub(crate) fn allows(&self, server_host: &str, header: &str) -> bool {
+    if matches!(self, AllowedHosts::All) {
+      return true;
+    }
+
+    let Some(hostname) = parse_hostname(header) else {
+      return false;
+    };
+    if hostname.parse::<IpAddr>().is_ok()
+      || hostname == "localhost"
+      || hostname.ends_with(".localhost")
+      || hostname.eq_ignore_ascii_case(server_host)
+    {
+      return true;
+    }
+
+    match self {
+      AllowedHosts::List(hosts) => hosts.iter().any(|host| match host.strip_prefix('.') {
+        Some(domain) => {
+          hostname.eq_ignore_ascii_case(domain)
+            || hostname
+              .len()
+              .checked_sub(host.len())
+              .and_then(|start| hostname.get(start..))
+              .is_some_and(|suffix| suffix.eq_ignore_ascii_case(host))
+        }
+        None => hostname.eq_ignore_ascii_case(host),
+      }),
+      _ => false,
+    }
+  }
+}
+
+/// Gets the hostname from a `Host` header like `example.com:8080`, or from an `Origin` header
+/// like `http://example.com:8080`, IPv6 addresses are returned without brackets.
+fn parse_hostname(header: &str) -> Option<&str> {
+  let authority = match header.split_once("://") {
+    Some((_, authority)) => authority,
+    None => header,
+  };
+  let hostname = if let Some(ipv6) = authority.strip_prefix('[') {
+    ipv6.split_once(']')?.0
+  } else {
+    authority.split(':').next()?
+  };
+  (!hostname.is_empty()).then_some(hostname)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_hostname() {
+    assert_eq!(parse_hostname("localhost:8080"), Some("localhost"));
+    assert_eq!(
+      parse_hostname("http://example.com:8080"),
+      Some("example.com")
+    );
+    assert_eq!(parse_hostname("[::1]:8080"), Some("::1"));
+    assert_eq!(parse_hostname("null"), Some("null"));
+    assert_eq!(parse_hostname(""), None);
+  }
+
+  #[test]
+  fn test_allows() {
+    let auto = AllowedHosts::Auto;
+    assert!(auto.allows("127.0.0.1", "localhost:8080"));
+    assert!(auto.allows("127.0.0.1", "app.localhost"));
+    assert!(auto.allows("127.0.0.1", "192.168.1.2:8080"));
+    assert!(auto.allows("127.0.0.1", "[::1]:8080"));
+    assert!(auto.allows("dev.internal", "http://dev.internal:8080"));
+    assert!(!auto.allows("127.0.0.1", "evil.com"));
+    assert!(!auto.allows("127.0.0.1", "http://evil.com"));
+    assert!(!auto.allows("127.0.0.1", "null"));
+
+    let list = AllowedHosts::List(vec!["app.test".to_string(), ".example.com".to_string()]);
+    assert!(list.allows("127.0.0.1", "app.test"));
+    assert!(list.allows("127.0.0.1", "example.com"));
+    assert!(list.allows("127.0.0.1", "https://www.example.com"));
+    assert!(!list.allows("127.0.0.1", "www.app.test"));
+    assert!(!list.allows("127.0.0.1", "evilexample.com"));
+
+    assert!(AllowedHosts::All.allows("127.0.0.1", "evil.com"));
+  }
+}
@@ -0,0 +1,18 @@
+//! A development server for Rust embedders of Rspack.
+//!
+//! It serves the output of a compiler from its output filesystem, which is usually a
+//! [MemoryFileSystem](rspack_fs::MemoryFileSystem), and pushes the hash and the result of
+//! each compilation to the clients over WebSocket, speaking the protocol of the
+//! `webpack-dev-server` client. Requests from hosts which are not in
+//! [AllowedHosts] are rejected.
+
+mod allowed_hosts;
+mod message;
+mod plugin;
+mod server;
+mod websocket;
+
+pub use allowed_hosts::AllowedHosts;
+pub use message::ServerMessage;
+pub use plugin::DevServerPlugin;
+pub use server::{DevServer, DevServerOptions};
@@ -0,0 +1,73 @@
+use serde_json::{Value, json};
+
+/// A message pushed to the clients over WebSocket, in the format of the
+/// `webpack-dev-server` client protocol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServerMessage {
+  /// Hot module replacement is enabled.
+  Hot,
+  /// Live reloading is enabled.
+  LiveReload,
+  /// A rebuild has started.
+  Invalid,
+  /// The hash of the latest compilation.
+  Hash(String),
+  /// The latest compilation succeeded without errors or warnings.
+  Ok,
+  /// The latest compilation succeeded with warnings.
+  Warnings(Vec<String>),
+  /// The latest compilation failed.
+  Errors(Vec<String>),
+}
+
+impl ServerMessage {
+  pub fn to_json(&self) -> String {
+    let message = match self {
+      ServerMessage::Hot => json!({ "type": "hot" }),
+      ServerMessage::LiveReload => json!({ "type": "liveReload" }),
+      ServerMessage::Invalid => json!({ "type": "invalid" }),
+      ServerMessage::Hash(hash) => json!({ "type": "hash", "data": hash }),
+      ServerMessage::Ok => json!({ "type": "ok" }),
+      ServerMessage::Warnings(warnings) => json!({
+        "type": "warnings",
+        "data": to_problems(warnings),
+      }),
+      ServerMessage::Errors(errors) => json!({
+        "type": "errors",
+        "data": to_problems(errors),
+      }),
+    };
+    message.to_string()
+  }
+}
+
+fn to_problems(messages: &[String]) -> Value {
+  Value::Array(
+    messages
+      .iter()
+      .map(|message| json!({ "message": message }))
+      .collect(),
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn parse(message: ServerMessage) -> Value {
+    serde_json::from_str(&message.to_json()).expect("should be valid json")
+  }
+
+  #[test]
+  fn test_to_json() {
+    assert_eq!(parse(ServerMessage::Hot), json!({ "type": "hot" }));
+    assert_eq!(
+      parse(ServerMessage::Hash("abc".to_string())),
+      json!({ "type": "hash", "data": "abc" })
+    );
+    assert_eq!(
+      parse(ServerMessage::Errors(vec!["Module not found".to_string()])),
+      json!({ "type": "errors", "data": [{ "message": "Module not found" }] })
+    );
+  }
+}
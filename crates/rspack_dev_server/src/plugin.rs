@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  ApplyContext, Compilation, CompilationParams, CompilerDone, CompilerThisCompilation, Plugin,
+};
+use rspack_error::Result;
+use rspack_hook::{plugin, plugin_hook};
+
+use crate::server::DevServerState;
+
+/// Notifies the clients of a [DevServer](crate::DevServer) when a compilation starts and ends.
+#[plugin]
+#[derive(Debug)]
+pub struct DevServerPlugin {
+  state: Arc<DevServerState>,
+}
+
+impl DevServerPlugin {
+  pub(crate) fn new(state: Arc<DevServerState>) -> Self {
+    Self::new_inner(state)
+  }
+}
+
+#[plugin_hook(CompilerThisCompilation for DevServerPlugin)]
+async fn this_compilation(
+  &self,
+  _compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  self.state.invalidate();
+  Ok(())
+}
+
+#[plugin_hook(CompilerDone for DevServerPlugin)]
+async fn done(&self, compilation: &Compilation) -> Result<()> {
+  self.state.update(compilation)
+}
+
+impl Plugin for DevServerPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DevServerPlugin"
+  }
+
+  fn apply(&self, ctx: &mut ApplyContext) -> Result<()> {
+    ctx
+      .compiler_hooks
+      .this_compilation
+      .tap(this_compilation::new(self));
+    ctx.compiler_hooks.done.tap(done::new(self));
+    Ok(())
+  }
+}
@@ -0,0 +1,256 @@
+use std::{
+  convert::Infallible,
+  net::SocketAddr,
+  sync::{Arc, RwLock},
+};
+
+use bytes::Bytes;
+use http_body_util::Full;
+use hyper::{
+  Method, Request, Response, StatusCode,
+  body::Incoming,
+  header::{CACHE_CONTROL, CONTENT_TYPE, HOST, HeaderValue},
+  server::conn::http1,
+  service::service_fn,
+};
+use hyper_util::rt::TokioIo;
+use rspack_core::Compilation;
+use rspack_error::{Result, ToStringResultToRspackResultExt};
+use rspack_fs::ReadableFileSystem;
+use rspack_paths::Utf8PathBuf;
+use tokio::{net::TcpListener, sync::broadcast};
+
+use crate::{AllowedHosts, DevServerPlugin, message::ServerMessage, websocket};
+
+#[derive(Debug, Clone)]
+pub struct DevServerOptions {
+  /// The host to listen on.
+  pub host: String,
+  /// The port to listen on, `0` picks a free port.
+  pub port: u16,
+  /// The path of the WebSocket endpoint the clients connect to.
+  pub web_socket_path: String,
+  /// Whether the clients should apply hot updates.
+  pub hot: bool,
+  /// Whether the clients should reload the page when hot updates can not be applied.
+  pub live_reload: bool,
+  /// The hosts allowed to access the server and to connect to its WebSocket endpoint.
+  pub allowed_hosts: AllowedHosts,
+}
+
+impl DevServerOptions {
+  /// Whether a `Host` or `Origin` header is allowed by [DevServerOptions::allowed_hosts].
+  pub(crate) fn allows(&self, header: Option<&HeaderValue>) -> bool {
+    header
+      .and_then(|header| header.to_str().ok())
+      .is_some_and(|header| self.allowed_hosts.allows(&self.host, header))
+  }
+}
+
+impl Default for DevServerOptions {
+  fn default() -> Self {
+    Self {
+      host: "127.0.0.1".to_string(),
+      port: 8080,
+      web_socket_path: "/ws".to_string(),
+      hot: true,
+      live_reload: true,
+      allowed_hosts: AllowedHosts::Auto,
+    }
+  }
+}
+
+/// The state shared between the [DevServerPlugin] and the connections of the server.
+#[derive(Debug)]
+pub(crate) struct DevServerState {
+  output_filesystem: Arc<dyn ReadableFileSystem>,
+  output_path: RwLock<Option<Utf8PathBuf>>,
+  /// The messages describing the latest compilation, which are replayed to new clients.
+  stats_messages: RwLock<Vec<ServerMessage>>,
+  sender: broadcast::Sender<ServerMessage>,
+}
+
+impl DevServerState {
+  pub(crate) fn invalidate(&self) {
+    // sending fails only when no client is connected
+    let _ = self.sender.send(ServerMessage::Invalid);
+  }
+
+  /// Subscribes to the messages of the following compilations, and returns the messages
+  /// describing the latest compilation.
+  pub(crate) fn subscribe(&self) -> (broadcast::Receiver<ServerMessage>, Vec<ServerMessage>) {
+    // subscribe before reading the latest stats, so no update is missed in between
+    let receiver = self.sender.subscribe();
+    let messages = self.stats_messages.read().expect("should get lock").clone();
+    (receiver, messages)
+  }
+
+  pub(crate) fn update(&self, compilation: &Compilation) -> Result<()> {
+    let errors = compilation
+      .get_errors_sorted()
+      .map(|diagnostic| diagnostic.render_report(false))
+      .collect::<Result<Vec<_>>>()?;
+    let warnings = compilation
+      .get_warnings_sorted()
+      .map(|diagnostic| diagnostic.render_report(false))
+      .collect::<Result<Vec<_>>>()?;
+
+    let mut messages = vec![];
+    if let Some(hash) = compilation.get_hash() {
+      messages.push(ServerMessage::Hash(hash.to_string()));
+    }
+    messages.push(if !errors.is_empty() {
+      ServerMessage::Errors(errors)
+    } else if !warnings.is_empty() {
+      ServerMessage::Warnings(warnings)
+    } else {
+      ServerMessage::Ok
+    });
+
+    *self.output_path.write().expect("should get lock") =
+      Some(compilation.options.output.path.clone());
+    *self.stats_messages.write().expect("should get lock") = messages.clone();
+    for message in messages {
+      let _ = self.sender.send(message);
+    }
+    Ok(())
+  }
+}
+
+/// Serves the output of a compiler from its output filesystem, and pushes the compilation
+/// results to the clients over WebSocket with the `webpack-dev-server` client protocol.
+///
+/// The [DevServerPlugin] returned by [DevServer::plugin] has to be applied to the compiler,
+/// whose output filesystem should be the one passed to [DevServer::new].
+#[derive(Debug)]
+pub struct DevServer {
+  options: Arc<DevServerOptions>,
+  state: Arc<DevServerState>,
+}
+
+impl DevServer {
+  pub fn new(options: DevServerOptions, output_filesystem: Arc<dyn ReadableFileSystem>) -> Self {
+    let (sender, _) = broadcast::channel(64);
+    Self {
+      options: Arc::new(options),
+      state: Arc::new(DevServerState {
+        output_filesystem,
+        output_path: Default::default(),
+        stats_messages: Default::default(),
+        sender,
+      }),
+    }
+  }
+
+  pub fn plugin(&self) -> DevServerPlugin {
+    DevServerPlugin::new(self.state.clone())
+  }
+
+  /// Starts accepting connections in the background and returns the bound address.
+  pub async fn listen(&self) -> Result<SocketAddr> {
+    let listener = TcpListener::bind((self.options.host.as_str(), self.options.port))
+      .await
+      .to_rspack_result_with_message(|e| {
+        format!(
+          "DevServer: failed to listen on {}:{}: {e}",
+          self.options.host, self.options.port
+        )
+      })?;
+    let addr = listener.local_addr().to_rspack_result()?;
+
+    let options = self.options.clone();
+    let state = self.state.clone();
+    tokio::spawn(async move {
+      loop {
+        match listener.accept().await {
+          Ok((stream, _)) => {
+            let options = options.clone();
+            let state = state.clone();
+            tokio::spawn(async move {
+              let service =
+                service_fn(move |request| handle_request(request, options.clone(), state.clone()));
+              if let Err(e) = http1::Builder::new()
+                .serve_connection(TokioIo::new(stream), service)
+                .with_upgrades()
+                .await
+              {
+                tracing::debug!("DevServer: connection closed: {e}");
+              }
+            });
+          }
+          Err(e) => tracing::warn!("DevServer: failed to accept connection: {e}"),
+        }
+      }
+    });
+
+    Ok(addr)
+  }
+}
+
+async fn handle_request(
+  request: Request<Incoming>,
+  options: Arc<DevServerOptions>,
+  state: Arc<DevServerState>,
+) -> std::result::Result<Response<Full<Bytes>>, Infallible> {
+  if !options.allows(request.headers().get(HOST)) {
+    return Ok(response(StatusCode::FORBIDDEN, "Invalid Host header"));
+  }
+
+  if request.uri().path() == options.web_socket_path && websocket::is_upgrade_request(&request) {
+    return Ok(websocket::upgrade(request, options, state));
+  }
+
+  Ok(serve_file(request.method(), request.uri().path(), &state).await)
+}
+
+async fn serve_file(
+  method: &Method,
+  pathname: &str,
+  state: &DevServerState,
+) -> Response<Full<Bytes>> {
+  if method != Method::GET && method != Method::HEAD {
+    return response(StatusCode::METHOD_NOT_ALLOWED, "");
+  }
+
+  let Ok(pathname) = urlencoding::decode(pathname) else {
+    return response(StatusCode::BAD_REQUEST, "");
+  };
+  if pathname.split(['/', '\\']).any(|segment| segment == "..") {
+    return response(StatusCode::FORBIDDEN, "");
+  }
+  let mut filename = pathname.trim_start_matches('/').to_string();
+  if filename.is_empty() || filename.ends_with('/') {
+    filename.push_str("index.html");
+  }
+
+  let output_path = state.output_path.read().expect("should get lock").clone();
+  let Some(output_path) = output_path else {
+    return response(StatusCode::SERVICE_UNAVAILABLE, "Compiling...");
+  };
+
+  match state
+    .output_filesystem
+    .read(&output_path.join(&filename))
+    .await
+  {
+    Ok(content) => {
+      let content_type = mime_guess::from_path(&filename).first_or_octet_stream();
+      let mut response = response(StatusCode::OK, content);
+      if let Ok(content_type) = HeaderValue::from_str(content_type.essence_str()) {
+        response.headers_mut().insert(CONTENT_TYPE, content_type);
+      }
+      response
+    }
+    Err(_) => response(StatusCode::NOT_FOUND, ""),
+  }
+}
+
+/// Creates an uncached response, the body of `HEAD` requests is dropped by hyper.
+pub(crate) fn response(status: StatusCode, body: impl Into<Bytes>) -> Response<Full<Bytes>> {
+  let mut response = Response::new(Full::new(body.into()));
+  *response.status_mut() = status;
+  let headers = response.headers_mut();
+  headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+  headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+  response
+}
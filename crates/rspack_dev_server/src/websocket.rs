@@ -0,0 +1,140 @@
+//! The WebSocket endpoint of the dev server, which pushes the compilation results to the clients.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures::{SinkExt, StreamExt};
+use http_body_util::Full;
+use hyper::{
+  Request, Response, StatusCode,
+  body::Incoming,
+  header::{
+    CONNECTION, HeaderValue, ORIGIN, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY,
+    SEC_WEBSOCKET_VERSION, UPGRADE,
+  },
+};
+use hyper_util::rt::TokioIo;
+use tokio::{
+  io::{AsyncRead, AsyncWrite},
+  sync::broadcast,
+};
+use tokio_tungstenite::{
+  WebSocketStream,
+  tungstenite::{
+    self, Message,
+    handshake::derive_accept_key,
+    protocol::{Role, WebSocketConfig},
+  },
+};
+
+use crate::{
+  DevServerOptions,
+  message::ServerMessage,
+  server::{DevServerState, response},
+};
+
+/// Clients are not expected to send large messages, so bigger ones close the connection
+/// instead of being buffered.
+const MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+pub(crate) fn is_upgrade_request(request: &Request<Incoming>) -> bool {
+  request
+    .headers()
+    .get(UPGRADE)
+    .is_some_and(|upgrade| upgrade.as_bytes().eq_ignore_ascii_case(b"websocket"))
+}
+
+/// Answers the handshake of a WebSocket connection, and serves the connection in the
+/// background once hyper hands it over.
+pub(crate) fn upgrade(
+  mut request: Request<Incoming>,
+  options: Arc<DevServerOptions>,
+  state: Arc<DevServerState>,
+) -> Response<Full<Bytes>> {
+  // browsers always send the origin of WebSocket connections, which is checked to prevent
+  // other sites from connecting, other clients don't send it
+  if let Some(origin) = request.headers().get(ORIGIN)
+    && !options.allows(Some(origin))
+  {
+    return response(StatusCode::FORBIDDEN, "Invalid Origin header");
+  }
+  if request
+    .headers()
+    .get(SEC_WEBSOCKET_VERSION)
+    .is_none_or(|version| version.as_bytes() != b"13")
+  {
+    return response(StatusCode::BAD_REQUEST, "Unsupported WebSocket version");
+  }
+  let Some(key) = request.headers().get(SEC_WEBSOCKET_KEY) else {
+    return response(StatusCode::BAD_REQUEST, "Missing Sec-WebSocket-Key header");
+  };
+  let Ok(accept) = HeaderValue::from_str(&derive_accept_key(key.as_bytes())) else {
+    return response(StatusCode::BAD_REQUEST, "");
+  };
+
+  let on_upgrade = hyper::upgrade::on(&mut request);
+  tokio::spawn(async move {
+    let upgraded = match on_upgrade.await {
+      Ok(upgraded) => upgraded,
+      Err(e) => {
+        tracing::debug!("DevServer: failed to upgrade connection: {e}");
+        return;
+      }
+    };
+    let config = WebSocketConfig::default()
+      .max_message_size(Some(MAX_MESSAGE_SIZE))
+      .max_frame_size(Some(MAX_MESSAGE_SIZE));
+    let stream =
+      WebSocketStream::from_raw_socket(TokioIo::new(upgraded), Role::Server, Some(config)).await;
+    if let Err(e) = serve(stream, &options, &state).await {
+      tracing::debug!("DevServer: WebSocket connection closed: {e}");
+    }
+  });
+
+  let mut response = response(StatusCode::SWITCHING_PROTOCOLS, "");
+  let headers = response.headers_mut();
+  headers.insert(CONNECTION, HeaderValue::from_static("Upgrade"));
+  headers.insert(UPGRADE, HeaderValue::from_static("websocket"));
+  headers.insert(SEC_WEBSOCKET_ACCEPT, accept);
+  response
+}
+
+async fn serve<S: AsyncRead + AsyncWrite + Unpin>(
+  stream: WebSocketStream<S>,
+  options: &DevServerOptions,
+  state: &DevServerState,
+) -> tungstenite::Result<()> {
+  let (mut sink, mut stream) = stream.split();
+
+  let (mut receiver, stats_messages) = state.subscribe();
+  let mut messages = vec![];
+  if options.hot {
+    messages.push(ServerMessage::Hot);
+  }
+  if options.live_reload {
+    messages.push(ServerMessage::LiveReload);
+  }
+  messages.extend(stats_messages);
+  for message in messages {
+    sink.send(Message::text(message.to_json())).await?;
+  }
+
+  loop {
+    tokio::select! {
+      message = receiver.recv() => match message {
+        Ok(message) => sink.send(Message::text(message.to_json())).await?,
+        Err(broadcast::error::RecvError::Lagged(_)) => {}
+        Err(broadcast::error::RecvError::Closed) => break,
+      },
+      // the messages of the clients are ignored, fragmented messages are assembled, and
+      // pings and closes are answered by tungstenite while reading
+      message = stream.next() => match message {
+        Some(Ok(_)) => {}
+        Some(Err(e)) => return Err(e),
+        None => break,
+      },
+    }
+  }
+
+  Ok(())
+}
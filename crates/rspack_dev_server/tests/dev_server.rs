@@ -0,0 +1,149 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use futures::{SinkExt, StreamExt};
+use rspack::builder::Builder as _;
+use rspack_core::Compiler;
+use rspack_dev_server::{DevServer, DevServerOptions};
+use rspack_fs::MemoryFileSystem;
+use rspack_paths::Utf8Path;
+use rspack_tasks::within_compiler_context_for_testing;
+use tokio::{
+  io::{AsyncReadExt, AsyncWriteExt},
+  net::TcpStream,
+};
+use tokio_tungstenite::{
+  WebSocketStream, client_async,
+  tungstenite::{
+    self, Message,
+    client::IntoClientRequest,
+    protocol::frame::{
+      Frame,
+      coding::{Data, OpCode},
+    },
+  },
+};
+
+/// Sends a request on a new connection and reads the whole response.
+async fn request(addr: SocketAddr, head: &str) -> String {
+  let mut stream = TcpStream::connect(addr).await.unwrap();
+  stream
+    .write_all(format!("{head}Connection: close\r\n\r\n").as_bytes())
+    .await
+    .unwrap();
+  let mut response = String::new();
+  stream.read_to_string(&mut response).await.unwrap();
+  response
+}
+
+async fn connect(
+  addr: SocketAddr,
+  origin: &str,
+) -> tungstenite::Result<WebSocketStream<TcpStream>> {
+  let mut request = format!("ws://localhost:{}/ws", addr.port())
+    .into_client_request()
+    .unwrap();
+  request
+    .headers_mut()
+    .insert("Origin", origin.parse().unwrap());
+  let stream = TcpStream::connect(addr).await.unwrap();
+  client_async(request, stream)
+    .await
+    .map(|(stream, _)| stream)
+}
+
+async fn read_text(stream: &mut WebSocketStream<TcpStream>) -> String {
+  match stream.next().await {
+    Some(Ok(Message::Text(text))) => text.to_string(),
+    message => panic!("should receive a text message, got {message:?}"),
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn serves_output_and_pushes_stats() {
+  within_compiler_context_for_testing(async move {
+    let output_filesystem = Arc::new(MemoryFileSystem::default());
+    let server = DevServer::new(
+      DevServerOptions {
+        port: 0,
+        ..Default::default()
+      },
+      output_filesystem.clone(),
+    );
+    let addr = server.listen().await.unwrap();
+
+    let mut compiler = Compiler::builder()
+      .context(Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/basic"))
+      .entry("main", "./src/index.js")
+      .output_filesystem(output_filesystem)
+      .plugin(Box::new(server.plugin()))
+      .build()
+      .unwrap();
+    compiler.build().await.unwrap();
+    let hash = compiler.compilation.get_hash().unwrap().to_string();
+
+    let response = request(addr, "GET /main.js HTTP/1.1\r\nHost: localhost\r\n").await;
+    assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(response.contains("javascript\r\n"));
+    assert!(response.ends_with("console.log(123);"));
+    assert!(
+      request(addr, "GET /missing.js HTTP/1.1\r\nHost: localhost\r\n")
+        .await
+        .starts_with("HTTP/1.1 404 Not Found\r\n")
+    );
+    // a rebound domain of another site
+    assert!(
+      request(addr, "GET /main.js HTTP/1.1\r\nHost: evil.com\r\n")
+        .await
+        .starts_with("HTTP/1.1 403 Forbidden\r\n")
+    );
+
+    // a page of another site connecting to the WebSocket endpoint
+    let Err(tungstenite::Error::Http(response)) = connect(addr, "http://evil.com").await else {
+      panic!("should reject the WebSocket connection of another site");
+    };
+    assert_eq!(response.status(), 403);
+
+    let mut stream = connect(addr, &format!("http://localhost:{}", addr.port()))
+      .await
+      .expect("should connect");
+    assert_eq!(read_text(&mut stream).await, r#"{"type":"hot"}"#);
+    assert_eq!(read_text(&mut stream).await, r#"{"type":"liveReload"}"#);
+    assert_eq!(
+      serde_json::from_str::<serde_json::Value>(&read_text(&mut stream).await).unwrap(),
+      serde_json::json!({ "type": "hash", "data": hash })
+    );
+    assert_eq!(read_text(&mut stream).await, r#"{"type":"ok"}"#);
+
+    // a fragmented message and a ping of the client
+    stream
+      .send(Message::Frame(Frame::message(
+        "Hel",
+        OpCode::Data(Data::Text),
+        false,
+      )))
+      .await
+      .unwrap();
+    stream
+      .send(Message::Frame(Frame::message(
+        "lo",
+        OpCode::Data(Data::Continue),
+        true,
+      )))
+      .await
+      .unwrap();
+    stream.send(Message::Ping("ping".into())).await.unwrap();
+    assert_eq!(
+      stream.next().await.unwrap().unwrap(),
+      Message::Pong("ping".into())
+    );
+
+    compiler
+      .rebuild(Default::default(), Default::default())
+      .await
+      .unwrap();
+    assert_eq!(read_text(&mut stream).await, r#"{"type":"invalid"}"#);
+    assert!(read_text(&mut stream).await.contains(r#""type":"hash""#));
+    assert_eq!(read_text(&mut stream).await, r#"{"type":"ok"}"#);
+  })
+  .await;
+}
@@ -13,6 +13,8 @@ pub struct FileMetadata {
   pub mtime_ms: u64,
   pub ctime_ms: u64,
   pub size: u64,
+  /// The inode number, 0 if the file system doesn't provide it.
+  pub ino: u64,
 }
 
 impl FileMetadata {
@@ -29,6 +31,16 @@ impl FileMetadata {
     #[allow(unreachable_code)]
     0u64
   }
+
+  #[allow(unused_variables)]
+  fn get_ino(metadata: &Metadata) -> u64 {
+    #[cfg(unix)]
+    {
+      return std::os::unix::fs::MetadataExt::ino(metadata);
+    }
+    #[allow(unreachable_code)]
+    0u64
+  }
 }
 
 impl TryFrom<Metadata> for FileMetadata {
@@ -54,6 +66,7 @@ impl TryFrom<Metadata> for FileMetadata {
       is_file: metadata.is_file(),
       is_symlink: metadata.is_symlink(),
       size: metadata.len(),
+      ino: Self::get_ino(&metadata),
       mtime_ms,
       ctime_ms,
       atime_ms,
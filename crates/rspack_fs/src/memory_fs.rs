@@ -37,6 +37,7 @@ impl FileType {
       mtime_ms: now,
       ctime_ms: now,
       size: 0,
+      ino: 0,
     })
   }
 
@@ -51,6 +52,7 @@ impl FileType {
         mtime_ms: now,
         ctime_ms: now,
         size: content.len() as u64,
+        ino: 0,
       },
       content,
     }
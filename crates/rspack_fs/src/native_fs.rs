@@ -144,6 +144,7 @@ impl From<FileType> for FileMetadata {
       mtime_ms: 0,
       ctime_ms: 0,
       size: 0,
+      ino: 0,
     }
   }
 }
@@ -0,0 +1,227 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_core::{
+  atoms::Atom,
+  ecma::{
+    ast::{
+      AssignPat, AssignPatProp, BinExpr, BinaryOp, CallExpr, Callee, ComputedPropName, Expr,
+      IdentName, KeyValuePatProp, KeyValueProp, Lit, MemberExpr, MemberProp, ObjectPatProp, Pat,
+      Prop, PropName, Str, SuperProp,
+    },
+    visit::{Visit, VisitMut, VisitMutWith, VisitWith, noop_visit_mut_type, noop_visit_type},
+  },
+};
+
+/// Renames properties by a name map computed ahead of minification, so the same property
+/// gets the same mangled name in every file, like `mangle.properties` of terser.
+///
+/// It covers the same places as the property mangler of swc: property accesses, keys of
+/// object literals, patterns and classes, string literals of computed keys, the left side of
+/// `in` and the property argument of `Object.defineProperty`.
+pub(crate) struct PropertyMangler<'a> {
+  pub names: &'a FxHashMap<Atom, Atom>,
+}
+
+impl PropertyMangler<'_> {
+  fn rename_ident(&self, ident: &mut IdentName) {
+    if let Some(name) = self.names.get(&ident.sym) {
+      ident.sym = name.clone();
+    }
+  }
+
+  fn rename_str(&self, str: &mut Str) {
+    if let Some(name) = str.value.as_atom().and_then(|value| self.names.get(value)) {
+      *str = Str::from(name.clone());
+    }
+  }
+
+  fn rename_str_expr(&self, expr: &mut Expr) {
+    if let Expr::Lit(Lit::Str(str)) = expr {
+      self.rename_str(str);
+    }
+  }
+}
+
+impl VisitMut for PropertyMangler<'_> {
+  noop_visit_mut_type!();
+
+  fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
+    n.visit_mut_children_with(self);
+    match &mut n.prop {
+      MemberProp::Ident(ident) => self.rename_ident(ident),
+      MemberProp::Computed(ComputedPropName { expr, .. }) => self.rename_str_expr(expr),
+      MemberProp::PrivateName(_) => {}
+    }
+  }
+
+  fn visit_mut_super_prop(&mut self, n: &mut SuperProp) {
+    n.visit_mut_children_with(self);
+    match n {
+      SuperProp::Ident(ident) => self.rename_ident(ident),
+      SuperProp::Computed(ComputedPropName { expr, .. }) => self.rename_str_expr(expr),
+    }
+  }
+
+  fn visit_mut_prop_name(&mut self, n: &mut PropName) {
+    n.visit_mut_children_with(self);
+    match n {
+      PropName::Ident(ident) => self.rename_ident(ident),
+      PropName::Str(str) => self.rename_str(str),
+      PropName::Computed(ComputedPropName { expr, .. }) => self.rename_str_expr(expr),
+      PropName::Num(_) | PropName::BigInt(_) => {}
+    }
+  }
+
+  fn visit_mut_prop(&mut self, n: &mut Prop) {
+    n.visit_mut_children_with(self);
+    // `{ foo }` needs to be expanded to `{ a: foo }`
+    if let Prop::Shorthand(ident) = n
+      && let Some(name) = self.names.get(&ident.sym)
+    {
+      *n = Prop::KeyValue(KeyValueProp {
+        key: PropName::Ident(IdentName::new(name.clone(), ident.span)),
+        value: Box::new(Expr::Ident(ident.clone())),
+      });
+    }
+  }
+
+  fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
+    n.visit_mut_children_with(self);
+    // `{ foo = 1 } = obj` needs to be expanded to `{ a: foo = 1 } = obj`
+    if let ObjectPatProp::Assign(AssignPatProp { span, key, value }) = n
+      && let Some(name) = self.names.get(&key.id.sym)
+    {
+      let pat = match value.take() {
+        Some(right) => Pat::Assign(AssignPat {
+          span: *span,
+          left: Box::new(Pat::Ident(key.clone())),
+          right,
+        }),
+        None => Pat::Ident(key.clone()),
+      };
+      *n = ObjectPatProp::KeyValue(KeyValuePatProp {
+        key: PropName::Ident(IdentName::new(name.clone(), key.id.span)),
+        value: Box::new(pat),
+      });
+    }
+  }
+
+  fn visit_mut_bin_expr(&mut self, n: &mut BinExpr) {
+    n.visit_mut_children_with(self);
+    if n.op == BinaryOp::In {
+      self.rename_str_expr(&mut n.left);
+    }
+  }
+
+  fn visit_mut_call_expr(&mut self, n: &mut CallExpr) {
+    n.visit_mut_children_with(self);
+    // `Object.defineProperty(obj, "foo", descriptor)`
+    if let Callee::Expr(callee) = &n.callee
+      && let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+      }) = &**callee
+      && matches!(&**obj, Expr::Ident(obj) if obj.sym == "Object")
+      && prop.sym == "defineProperty"
+      && let Some(arg) = n.args.get_mut(1)
+      && arg.spread.is_none()
+    {
+      self.rename_str_expr(&mut arg.expr);
+    }
+  }
+}
+
+/// Collects property names at the same places renamed by [PropertyMangler].
+#[derive(Default)]
+pub(crate) struct PropertyCollector {
+  pub names: FxHashSet<Atom>,
+}
+
+impl PropertyCollector {
+  fn add_str_expr(&mut self, expr: &Expr) {
+    if let Expr::Lit(Lit::Str(str)) = expr
+      && let Some(value) = str.value.as_atom()
+    {
+      self.names.insert(value.clone());
+    }
+  }
+}
+
+impl Visit for PropertyCollector {
+  noop_visit_type!();
+
+  fn visit_member_expr(&mut self, n: &MemberExpr) {
+    n.visit_children_with(self);
+    match &n.prop {
+      MemberProp::Ident(ident) => {
+        self.names.insert(ident.sym.clone());
+      }
+      MemberProp::Computed(ComputedPropName { expr, .. }) => self.add_str_expr(expr),
+      MemberProp::PrivateName(_) => {}
+    }
+  }
+
+  fn visit_super_prop(&mut self, n: &SuperProp) {
+    n.visit_children_with(self);
+    match n {
+      SuperProp::Ident(ident) => {
+        self.names.insert(ident.sym.clone());
+      }
+      SuperProp::Computed(ComputedPropName { expr, .. }) => self.add_str_expr(expr),
+    }
+  }
+
+  fn visit_prop_name(&mut self, n: &PropName) {
+    n.visit_children_with(self);
+    match n {
+      PropName::Ident(ident) => {
+        self.names.insert(ident.sym.clone());
+      }
+      PropName::Str(str) => {
+        if let Some(value) = str.value.as_atom() {
+          self.names.insert(value.clone());
+        }
+      }
+      PropName::Computed(ComputedPropName { expr, .. }) => self.add_str_expr(expr),
+      PropName::Num(_) | PropName::BigInt(_) => {}
+    }
+  }
+
+  fn visit_prop(&mut self, n: &Prop) {
+    n.visit_children_with(self);
+    if let Prop::Shorthand(ident) = n {
+      self.names.insert(ident.sym.clone());
+    }
+  }
+
+  fn visit_object_pat_prop(&mut self, n: &ObjectPatProp) {
+    n.visit_children_with(self);
+    if let ObjectPatProp::Assign(AssignPatProp { key, .. }) = n {
+      self.names.insert(key.id.sym.clone());
+    }
+  }
+
+  fn visit_bin_expr(&mut self, n: &BinExpr) {
+    n.visit_children_with(self);
+    if n.op == BinaryOp::In {
+      self.add_str_expr(&n.left);
+    }
+  }
+
+  fn visit_call_expr(&mut self, n: &CallExpr) {
+    n.visit_children_with(self);
+    if let Callee::Expr(callee) = &n.callee
+      && let Expr::Member(MemberExpr {
+        obj,
+        prop: MemberProp::Ident(prop),
+        ..
+      }) = &**callee
+      && matches!(&**obj, Expr::Ident(obj) if obj.sym == "Object")
+      && prop.sym == "defineProperty"
+      && let Some(arg) = n.args.get(1)
+      && arg.spread.is_none()
+    {
+      self.add_str_expr(&arg.expr);
+    }
+  }
+}
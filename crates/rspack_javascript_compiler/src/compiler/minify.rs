@@ -2,7 +2,7 @@ use std::sync::Arc;
 
 use rspack_error::BatchErrors;
 use rspack_util::swc::minify_file_comments;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use serde::{Deserialize, Serialize};
 pub use swc_core::base::BoolOrDataConfig;
 use swc_core::{
@@ -17,14 +17,14 @@ use swc_core::{
     errors::HANDLER,
   },
   ecma::{
-    ast::Ident,
+    ast::{EsVersion, Ident},
     parser::{EsSyntax, Syntax},
     transforms::base::{
       fixer::{fixer, paren_remover},
       hygiene::hygiene,
       resolver,
     },
-    visit::{Visit, VisitMutWith, noop_visit_type},
+    visit::{Visit, VisitMutWith, VisitWith, noop_visit_type},
   },
 };
 pub use swc_ecma_minifier::option::{
@@ -34,11 +34,38 @@ pub use swc_ecma_minifier::option::{
 
 use super::{
   JavaScriptCompiler, TransformOutput,
+  mangle_props::{PropertyCollector, PropertyMangler},
   stringify::{PrintOptions, SourceMapConfig},
 };
 use crate::error::with_rspack_error_handler;
 
 impl JavaScriptCompiler {
+  /// Collects the property names in the given JavaScript source code, at the places renamed by
+  /// `mangle_props` of [JsMinifyOptions].
+  pub fn property_names<S: Into<String>>(
+    &self,
+    filename: FileName,
+    source: S,
+  ) -> Result<FxHashSet<Atom>, BatchErrors> {
+    let fm = self.cm.new_source_file(Arc::new(filename), source.into());
+    let program = self.parse_js(
+      fm,
+      EsVersion::latest(),
+      Syntax::Es(EsSyntax {
+        jsx: true,
+        decorators: true,
+        decorators_before_export: true,
+        import_attributes: true,
+        ..Default::default()
+      }),
+      IsModule::Unknown,
+      None,
+    )?;
+    let mut collector = PropertyCollector::default();
+    program.visit_with(&mut collector);
+    Ok(collector.names)
+  }
+
   /// Minifies the given JavaScript source code.
   ///
   /// This method takes a filename, the source code to minify, minification options, and an optional function to operate on comments.
@@ -155,6 +182,10 @@ impl JavaScriptCompiler {
             },
           );
 
+          if let Some(names) = &opts.mangle_props {
+            program.visit_mut_with(&mut PropertyMangler { names })
+          }
+
           if !is_mangler_enabled {
             program.visit_mut_with(&mut hygiene())
           }
@@ -255,6 +286,10 @@ pub struct JsMinifyOptions {
   #[serde(default = "true_as_default")]
   /// Indicates whether to inline the source content in the source map.
   pub inline_sources_content: bool,
+
+  #[serde(skip)]
+  /// Properties to rename and their mangled names, shared by all files of a build.
+  pub mangle_props: Option<Arc<FxHashMap<Atom, Atom>>>,
 }
 
 const fn true_as_default() -> bool {
@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+mod mangle_props;
 pub mod minify;
 pub mod parse;
 pub mod stringify;
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use swc_core::{
+  common::{FileName, Mark, Span, comments::SingleThreadedComments},
+  ecma::{
+    ast::{ExportSpecifier, ModuleDecl, ModuleExportName, ModuleItem, Program},
+    codegen::to_code_with_comments,
+  },
+};
+use swc_typescript::fast_dts::{FastDts, FastDtsOptions};
+
+/// A declaration that can't be generated without type inference.
+#[derive(Debug)]
+pub struct DeclarationIssue {
+  pub span: Span,
+  pub message: String,
+}
+
+#[derive(Debug)]
+pub struct IsolatedDeclaration {
+  pub code: String,
+  pub has_default_export: bool,
+  pub issues: Vec<DeclarationIssue>,
+}
+
+/// Generates the declaration of a TypeScript module like `tsc --isolatedDeclarations`, the
+/// program is transformed to the declaration in place.
+///
+/// The program should be resolved with `unresolved_mark`.
+pub fn generate_isolated_declaration(
+  program: &mut Program,
+  filename: Arc<FileName>,
+  unresolved_mark: Mark,
+  comments: &SingleThreadedComments,
+) -> IsolatedDeclaration {
+  let issues = FastDts::new(filename, unresolved_mark, FastDtsOptions::default())
+    .transform(program)
+    .into_iter()
+    .map(|issue| DeclarationIssue {
+      span: issue.range.span,
+      message: issue.message.into_owned(),
+    })
+    .collect();
+  IsolatedDeclaration {
+    code: to_code_with_comments(Some(comments), program),
+    has_default_export: has_default_export(program),
+    issues,
+  }
+}
+
+/// Whether the module has a default export, including `export { a as default }`.
+pub fn has_default_export(program: &Program) -> bool {
+  let Some(module) = program.as_module() else {
+    return false;
+  };
+  module.body.iter().any(|item| match item {
+    ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_)) => {
+      true
+    }
+    ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(named)) => {
+      named.specifiers.iter().any(|specifier| match specifier {
+        ExportSpecifier::Named(named) => matches!(
+          named.exported.as_ref().unwrap_or(&named.orig),
+          ModuleExportName::Ident(ident) if ident.sym == "default"
+        ),
+        ExportSpecifier::Default(_) => true,
+        ExportSpecifier::Namespace(_) => false,
+      })
+    }
+    _ => false,
+  })
+}
+
+pub fn is_declaration_file(path: &str) -> bool {
+  [".d.ts", ".d.mts", ".d.cts"]
+    .iter()
+    .any(|ext| path.ends_with(ext))
+}
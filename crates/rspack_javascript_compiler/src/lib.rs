@@ -1,5 +1,6 @@
 pub mod ast;
 mod compiler;
 mod error;
+pub mod isolated_declarations;
 
 pub use compiler::{JavaScriptCompiler, TransformOutput, minify, parse, transform};
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+
+use rspack_core::{
+  AssetInfo, CompilationAsset,
+  rspack_sources::{RawStringSource, SourceExt},
+};
+use rspack_error::{Diagnostic, Error};
+use rspack_javascript_compiler::isolated_declarations;
+use rspack_paths::Utf8Path;
+use rspack_util::span::SpanExt;
+use sugar_path::SugarPath;
+use swc_core::{
+  common::{FileName, Mark, comments::SingleThreadedComments},
+  ecma::ast::Program,
+};
+
+use crate::options::IsolatedDeclarationsOptions;
+
+/// The key in [AssetInfo::extras] marks the declarations to be re-exported by `[name].d.ts` of
+/// entries, the value is whether the declaration has a default export.
+pub const ISOLATED_DECLARATION_ASSET_INFO_KEY: &str = "isolatedDeclaration";
+
+pub(crate) struct IsolatedDeclaration {
+  pub(crate) code: String,
+  pub(crate) has_default_export: bool,
+  pub(crate) diagnostics: Vec<Diagnostic>,
+}
+
+/// Generates the declaration of a TypeScript module like `tsc --isolatedDeclarations`,
+/// declarations that can't be generated without type inference are reported as errors.
+pub(crate) fn generate_isolated_declaration(
+  program: &Program,
+  filename: Arc<FileName>,
+  unresolved_mark: Mark,
+  comments: &SingleThreadedComments,
+  source: &str,
+) -> IsolatedDeclaration {
+  let mut program = program.clone();
+  let declaration = isolated_declarations::generate_isolated_declaration(
+    &mut program,
+    filename,
+    unresolved_mark,
+    comments,
+  );
+  let diagnostics = declaration
+    .issues
+    .into_iter()
+    .map(|issue| {
+      Error::from_string(
+        Some(source.to_string()),
+        issue.span.real_lo() as usize,
+        issue.span.real_hi() as usize,
+        "Isolated declarations error".to_string(),
+        issue.message,
+      )
+      .into()
+    })
+    .collect();
+  IsolatedDeclaration {
+    code: declaration.code,
+    has_default_export: declaration.has_default_export,
+    diagnostics,
+  }
+}
+
+/// Returns the filename of the declaration, which mirrors the layout of the source
+/// relative to `root_dir` in `out_dir`.
+pub(crate) fn declaration_filename(
+  resource_path: &Utf8Path,
+  context: &Utf8Path,
+  options: &IsolatedDeclarationsOptions,
+) -> String {
+  let root_dir = match &options.root_dir {
+    Some(root_dir) => context.join(root_dir),
+    None => context.to_path_buf(),
+  };
+  let relative = resource_path.as_std_path().relative(root_dir.as_std_path());
+  let relative = relative.to_slash_lossy();
+  // sources out of the root dir are emitted to the root of `out_dir`
+  let relative = if relative.starts_with("..") {
+    resource_path.file_name().unwrap_or_default().into()
+  } else {
+    relative
+  };
+  let (stem, ext) = match relative.rsplit_once('.') {
+    Some((stem, "mts")) => (stem, "d.mts"),
+    Some((stem, "cts")) => (stem, "d.cts"),
+    Some((stem, _)) => (stem, "d.ts"),
+    None => (relative.as_ref(), "d.ts"),
+  };
+  let filename = format!("{stem}.{ext}");
+  let out_dir = options.out_dir.trim_matches('/');
+  if out_dir.is_empty() {
+    filename
+  } else {
+    format!("{out_dir}/{filename}")
+  }
+}
+
+pub(crate) fn declaration_asset(
+  declaration: &IsolatedDeclaration,
+  source_filename: &str,
+  options: &IsolatedDeclarationsOptions,
+) -> CompilationAsset {
+  let mut info = AssetInfo {
+    source_filename: Some(source_filename.to_string()),
+    ..Default::default()
+  };
+  if options.entry {
+    info.extras.insert(
+      ISOLATED_DECLARATION_ASSET_INFO_KEY.to_string(),
+      declaration.has_default_export.into(),
+    );
+  }
+  CompilationAsset::new(
+    Some(RawStringSource::from(declaration.code.clone()).boxed()),
+    info,
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_paths::Utf8Path;
+
+  use super::declaration_filename;
+  use crate::options::IsolatedDeclarationsOptions;
+
+  #[test]
+  fn should_mirror_source_layout() {
+    let context = Utf8Path::new("/project");
+    let options = IsolatedDeclarationsOptions {
+      out_dir: "types/".to_string(),
+      root_dir: Some("src".to_string()),
+      entry: false,
+    };
+    let filename = |path: &str| declaration_filename(Utf8Path::new(path), context, &options);
+    assert_eq!(filename("/project/src/index.ts"), "types/index.d.ts");
+    assert_eq!(filename("/project/src/a/b.tsx"), "types/a/b.d.ts");
+    assert_eq!(filename("/project/src/c.mts"), "types/c.d.mts");
+    assert_eq!(filename("/project/lib/d.cts"), "types/d.d.cts");
+  }
+}
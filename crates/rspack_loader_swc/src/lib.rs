@@ -1,6 +1,8 @@
 #![feature(box_patterns)]
 
 mod collect_ts_info;
+mod isolated_declarations;
+mod optimize_package_imports;
 mod options;
 mod plugin;
 mod rsc_transforms;
@@ -8,17 +10,21 @@ mod transformer;
 
 use std::{cell::RefCell, default::Default, path::Path, rc::Rc, sync::Arc};
 
+pub use isolated_declarations::ISOLATED_DECLARATION_ASSET_INFO_KEY;
 use options::SwcCompilerOptionsWithAdditional;
 pub use options::SwcLoaderJsOptions;
 pub use plugin::SwcLoaderPlugin;
-use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_cacheable::{cacheable, cacheable_dyn, with::Skip};
 use rspack_core::{COLLECTED_TYPESCRIPT_INFO_PARSE_META_KEY, Mode, Module, RscMeta, RunnerContext};
 use rspack_error::{Diagnostic, Error, Result};
-use rspack_javascript_compiler::{JavaScriptCompiler, TransformOutput};
+use rspack_javascript_compiler::{
+  JavaScriptCompiler, TransformOutput, isolated_declarations::is_declaration_file,
+};
 use rspack_loader_runner::{Identifier, Loader, LoaderContext};
 #[cfg(allocative)]
 use rspack_util::allocative;
 pub use rspack_workspace::rspack_swc_core_version;
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 use swc_config::{merge::Merge, types::MergingOption};
 use swc_core::{
@@ -29,6 +35,12 @@ use swc_core::{
 
 use crate::{
   collect_ts_info::collect_typescript_info,
+  isolated_declarations::{
+    IsolatedDeclaration, declaration_asset, declaration_filename, generate_isolated_declaration,
+  },
+  optimize_package_imports::{
+    BarrelCache, BarrelImports, analyze_barrels, optimize_package_imports,
+  },
   rsc_transforms::{rsc_pass, to_module_ref},
 };
 
@@ -39,6 +51,9 @@ pub struct SwcLoader {
   identifier: Identifier,
   #[cfg_attr(allocative, allocative(skip))]
   options_with_additional: SwcCompilerOptionsWithAdditional,
+  #[cacheable(with=Skip)]
+  #[cfg_attr(allocative, allocative(skip))]
+  barrel_cache: BarrelCache,
 }
 
 impl SwcLoader {
@@ -46,6 +61,7 @@ impl SwcLoader {
     Ok(Self {
       identifier: SWC_LOADER_IDENTIFIER.into(),
       options_with_additional: raw_options.try_into()?,
+      barrel_cache: Default::default(),
     })
   }
 
@@ -57,7 +73,11 @@ impl SwcLoader {
     self
   }
 
-  fn loader_impl(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+  fn loader_impl(
+    &self,
+    loader_context: &mut LoaderContext<RunnerContext>,
+    barrels: &FxHashMap<String, BarrelImports>,
+  ) -> Result<()> {
     let resource_path = loader_context
       .resource_path()
       .map(|p| p.to_path_buf())
@@ -119,6 +139,14 @@ impl SwcLoader {
     let is_typescript =
       matches!(swc_options.config.jsc.syntax, Some(syntax) if syntax.typescript());
     let mut collected_ts_info = None;
+    let mut isolated_declaration: Option<IsolatedDeclaration> = None;
+    let dts_comments = comments.clone();
+    let dts_filename = filename.clone();
+    let dts_source = self
+      .options_with_additional
+      .isolated_declarations
+      .is_some()
+      .then(|| source.clone());
     let rsc_meta: RefCell<Option<RscMeta>> = Default::default();
 
     let TransformOutput {
@@ -135,6 +163,17 @@ impl SwcLoader {
         if !is_typescript {
           return;
         }
+        if let Some(source) = &dts_source
+          && !is_declaration_file(resource_path.as_str())
+        {
+          isolated_declaration = Some(generate_isolated_declaration(
+            program,
+            dts_filename,
+            unresolved_mark,
+            &dts_comments,
+            source,
+          ));
+        }
         let Some(options) = &self.options_with_additional.collect_typescript_info else {
           return;
         };
@@ -162,6 +201,7 @@ impl SwcLoader {
             swc_core::common::pass::Either::Right(noop_pass())
           },
           transformer::transform(&self.options_with_additional.rspack_experiments),
+          optimize_package_imports(barrels),
         )
       },
     )?;
@@ -170,6 +210,27 @@ impl SwcLoader {
       loader_context.emit_diagnostic(Error::warning(diagnostic).into());
     }
 
+    if let (Some(declaration), Some(options)) = (
+      isolated_declaration,
+      &self.options_with_additional.isolated_declarations,
+    ) {
+      let filename = declaration_filename(
+        &resource_path,
+        loader_context.context.options.context.as_path(),
+        options,
+      );
+      let asset = declaration_asset(&declaration, resource_path.as_str(), options);
+      for diagnostic in declaration.diagnostics {
+        loader_context.emit_diagnostic(diagnostic);
+      }
+      loader_context
+        .context
+        .module
+        .build_info_mut()
+        .assets
+        .insert(filename, asset);
+    }
+
     if let Some(rsc) = rsc_meta.borrow_mut().take() {
       let module = &mut loader_context.context.module;
       module.build_info_mut().rsc = Some(rsc);
@@ -231,8 +292,16 @@ impl Loader<RunnerContext> for SwcLoader {
     resource =loader_context.resource(),
   ))]
   async fn run(&self, loader_context: &mut LoaderContext<RunnerContext>) -> Result<()> {
+    let barrels = match &self
+      .options_with_additional
+      .rspack_experiments
+      .optimize_package_imports
+    {
+      Some(packages) => analyze_barrels(loader_context, packages, &self.barrel_cache).await,
+      None => Default::default(),
+    };
     #[allow(unused_mut)]
-    let mut inner = || self.loader_impl(loader_context);
+    let mut inner = || self.loader_impl(loader_context, &barrels);
     #[cfg(all(debug_assertions, not(target_family = "wasm")))]
     {
       // Adjust stack to avoid stack overflow.
@@ -0,0 +1,706 @@
+//! Rewrites named imports of barrel packages, e.g. `import { Button } from "ui"`, into imports of
+//! the modules which declare the exports, so that the barrel file and its other re-exports are
+//! never built.
+//!
+//! The declaring modules are imported with package-relative requests, e.g. `ui/button/index.js`,
+//! which go through the resolver like any other import. A request is only used if it resolves to
+//! the declaring module from the importer, so nested dependencies of the package and files hidden
+//! by the `exports` field stay imported from the barrel.
+
+use std::{
+  hash::{Hash, Hasher},
+  mem,
+  sync::{Arc, Mutex},
+};
+
+use futures::future::BoxFuture;
+use rspack_core::{
+  CompilationId, DependencyCategory, LoaderContext, Module, ResolveOptionsWithDependencyType,
+  ResolveResult, Resolver, Resource, RunnerContext,
+};
+use rspack_fs::ReadableFileSystem;
+use rspack_javascript_compiler::JavaScriptCompiler;
+use rspack_paths::{AssertUtf8, Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap, FxHasher};
+use sugar_path::SugarPath;
+use swc_core::{
+  atoms::{Atom, Wtf8Atom},
+  base::config::IsModule,
+  common::{DUMMY_SP, FileName},
+  ecma::{
+    ast::{
+      Decl, EsVersion, ExportSpecifier, Ident, ImportDecl, ImportDefaultSpecifier,
+      ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, Module as SwcModule,
+      ModuleDecl, ModuleExportName, ModuleItem, Pass, Str,
+    },
+    parser::{Syntax, TsSyntax},
+    utils::find_pat_ids,
+    visit::{VisitMut, visit_mut_pass},
+  },
+};
+
+const MAX_STAR_EXPORT_DEPTH: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ExportTarget {
+  Named(Atom),
+  Namespace,
+}
+
+/// The module which declares an export of a barrel file, and the name it is exported with there.
+#[derive(Debug, Clone)]
+struct ExportSource {
+  path: Utf8PathBuf,
+  /// The package-relative request of the module, `None` if the module is the barrel itself or
+  /// out of its package. It's verified from each importer before used.
+  request: Option<String>,
+  target: ExportTarget,
+}
+
+#[derive(Debug, Default)]
+struct BarrelExports {
+  path: Utf8PathBuf,
+  exports: FxHashMap<Atom, ExportSource>,
+  /// The files read to analyze the barrel with the hashes of their content, including the
+  /// `package.json` of the packages, which invalidate the analysis and the modules importing it.
+  files: FxHashMap<Utf8PathBuf, Option<u64>>,
+}
+
+/// The analyzed barrel files shared by the modules which use the same loader options, so a barrel
+/// is analyzed once instead of once per importer.
+///
+/// It is kept across compilations, an entry is checked once in each compilation and reused until
+/// one of the files read to analyze it changes. The transformed code of the importers is stored
+/// by the persistent cache together with [`BarrelExports::files`] as their file dependencies, so
+/// the analysis only runs again for the importers rebuilt by a changed barrel.
+#[derive(Debug, Default)]
+pub(crate) struct BarrelCache {
+  inner: Mutex<FxHashMap<Utf8PathBuf, CachedBarrel>>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedBarrel {
+  barrel: Arc<BarrelExports>,
+  /// The last compilation the files of the barrel are checked in.
+  checked: CompilationId,
+}
+
+impl BarrelCache {
+  #[allow(clippy::unwrap_used)]
+  async fn get(
+    &self,
+    compilation_id: CompilationId,
+    path: &Utf8Path,
+    fs: &dyn ReadableFileSystem,
+  ) -> Option<Arc<BarrelExports>> {
+    let cached = self.inner.lock().unwrap().get(path).cloned()?;
+    if cached.checked == compilation_id {
+      return Some(cached.barrel);
+    }
+    for (file, hash) in &cached.barrel.files {
+      if hash_file(fs, file).await != *hash {
+        self.inner.lock().unwrap().remove(path);
+        return None;
+      }
+    }
+    self.insert(compilation_id, cached.barrel.clone());
+    Some(cached.barrel)
+  }
+
+  #[allow(clippy::unwrap_used)]
+  fn insert(&self, compilation_id: CompilationId, barrel: Arc<BarrelExports>) {
+    self.inner.lock().unwrap().insert(
+      barrel.path.clone(),
+      CachedBarrel {
+        barrel,
+        checked: compilation_id,
+      },
+    );
+  }
+}
+
+/// The hash of the content of `path`, `None` if it can't be read.
+async fn hash_file(fs: &dyn ReadableFileSystem, path: &Utf8Path) -> Option<u64> {
+  let content = fs.read(path).await.ok()?;
+  let mut hasher = FxHasher::default();
+  content.hash(&mut hasher);
+  Some(hasher.finish())
+}
+
+/// The exports and imports of a module, with unresolved requests.
+#[derive(Debug, Default)]
+struct ModuleExports {
+  local: Vec<Atom>,
+  reexports: Vec<(Atom, String, ExportTarget)>,
+  star_reexports: Vec<String>,
+}
+
+fn module_export_name(name: &ModuleExportName) -> Atom {
+  match name {
+    ModuleExportName::Ident(ident) => ident.sym.clone(),
+    ModuleExportName::Str(str) => Atom::from(str.value.to_string_lossy().as_ref()),
+  }
+}
+
+fn collect_module_exports(module: &SwcModule) -> ModuleExports {
+  let mut exports = ModuleExports::default();
+  let mut imports: FxHashMap<Atom, (String, ExportTarget)> = FxHashMap::default();
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(decl) = item else {
+      continue;
+    };
+    let ModuleDecl::Import(import) = decl else {
+      continue;
+    };
+    if import.type_only {
+      continue;
+    }
+    let request = import.src.value.to_string_lossy().into_owned();
+    for specifier in &import.specifiers {
+      let (local, target) = match specifier {
+        ImportSpecifier::Named(named) if !named.is_type_only => (
+          named.local.sym.clone(),
+          ExportTarget::Named(
+            named
+              .imported
+              .as_ref()
+              .map_or_else(|| named.local.sym.clone(), module_export_name),
+          ),
+        ),
+        ImportSpecifier::Default(default) => (
+          default.local.sym.clone(),
+          ExportTarget::Named("default".into()),
+        ),
+        ImportSpecifier::Namespace(namespace) => {
+          (namespace.local.sym.clone(), ExportTarget::Namespace)
+        }
+        ImportSpecifier::Named(_) => continue,
+      };
+      imports.insert(local, (request.clone(), target));
+    }
+  }
+
+  for item in &module.body {
+    let ModuleItem::ModuleDecl(decl) = item else {
+      continue;
+    };
+    match decl {
+      ModuleDecl::ExportNamed(named) if !named.type_only => {
+        let request = named
+          .src
+          .as_ref()
+          .map(|src| src.value.to_string_lossy().into_owned());
+        for specifier in &named.specifiers {
+          match (specifier, &request) {
+            (ExportSpecifier::Named(specifier), _) if specifier.is_type_only => {}
+            (ExportSpecifier::Named(specifier), Some(request)) => {
+              let orig = module_export_name(&specifier.orig);
+              let exported = specifier
+                .exported
+                .as_ref()
+                .map_or_else(|| orig.clone(), module_export_name);
+              exports
+                .reexports
+                .push((exported, request.clone(), ExportTarget::Named(orig)));
+            }
+            (ExportSpecifier::Named(specifier), None) => {
+              let orig = module_export_name(&specifier.orig);
+              let exported = specifier
+                .exported
+                .as_ref()
+                .map_or_else(|| orig.clone(), module_export_name);
+              match imports.get(&orig) {
+                Some((request, target)) => {
+                  exports
+                    .reexports
+                    .push((exported, request.clone(), target.clone()))
+                }
+                None => exports.local.push(exported),
+              }
+            }
+            (ExportSpecifier::Namespace(specifier), Some(request)) => exports.reexports.push((
+              module_export_name(&specifier.name),
+              request.clone(),
+              ExportTarget::Namespace,
+            )),
+            (ExportSpecifier::Default(specifier), Some(request)) => exports.reexports.push((
+              specifier.exported.sym.clone(),
+              request.clone(),
+              ExportTarget::Named("default".into()),
+            )),
+            _ => {}
+          }
+        }
+      }
+      ModuleDecl::ExportAll(all) if !all.type_only => {
+        exports
+          .star_reexports
+          .push(all.src.value.to_string_lossy().into_owned());
+      }
+      ModuleDecl::ExportDecl(export) => match &export.decl {
+        Decl::Class(class) => exports.local.push(class.ident.sym.clone()),
+        Decl::Fn(function) => exports.local.push(function.ident.sym.clone()),
+        Decl::Var(var) => {
+          let ids: Vec<Ident> = find_pat_ids(&var.decls);
+          exports.local.extend(ids.into_iter().map(|ident| ident.sym));
+        }
+        Decl::TsEnum(ts_enum) => exports.local.push(ts_enum.id.sym.clone()),
+        _ => {}
+      },
+      ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+        exports.local.push("default".into());
+      }
+      _ => {}
+    }
+  }
+  exports
+}
+
+fn parse_module_exports(path: &Utf8Path, source: String) -> Option<ModuleExports> {
+  let syntax = match path.extension()? {
+    "ts" | "mts" | "cts" => Syntax::Typescript(Default::default()),
+    "tsx" => Syntax::Typescript(TsSyntax {
+      tsx: true,
+      ..Default::default()
+    }),
+    "js" | "mjs" | "cjs" | "jsx" => Syntax::Es(Default::default()),
+    _ => return None,
+  };
+  let ast = JavaScriptCompiler::new()
+    .parse(
+      FileName::Real(path.as_std_path().to_path_buf()),
+      source,
+      EsVersion::EsNext,
+      syntax,
+      IsModule::Bool(true),
+      None,
+    )
+    .ok()?;
+  ast.visit(|program, _| {
+    program
+      .get_inner_program()
+      .as_module()
+      .map(collect_module_exports)
+  })
+}
+
+struct Analyzer<'a> {
+  compilation_id: CompilationId,
+  resolver: &'a Resolver,
+  fs: Arc<dyn ReadableFileSystem>,
+  cache: &'a BarrelCache,
+}
+
+impl Analyzer<'_> {
+  async fn resolve(&self, context: &Utf8Path, request: &str) -> Option<Resource> {
+    match self.resolver.resolve(context.as_std_path(), request).await {
+      Ok(ResolveResult::Resource(resource)) if resource.query.is_empty() => Some(resource),
+      _ => None,
+    }
+  }
+
+  /// The files which decide how `resource` is resolved and imported.
+  fn resource_files(resource: &Resource) -> impl Iterator<Item = Utf8PathBuf> + '_ {
+    std::iter::once(resource.path.clone()).chain(
+      resource
+        .description_data
+        .as_ref()
+        .map(|description| description.path().join("package.json").assert_utf8()),
+    )
+  }
+
+  async fn add_files(&self, barrel: &mut BarrelExports, resource: &Resource) {
+    for file in Self::resource_files(resource) {
+      if !barrel.files.contains_key(&file) {
+        let hash = hash_file(self.fs.as_ref(), &file).await;
+        barrel.files.insert(file, hash);
+      }
+    }
+  }
+
+  fn analyze<'b>(&'b self, resource: Resource, depth: usize) -> BoxFuture<'b, Arc<BarrelExports>> {
+    Box::pin(async move {
+      let path = resource.path.clone();
+      if let Some(barrel) = self
+        .cache
+        .get(self.compilation_id, &path, self.fs.as_ref())
+        .await
+      {
+        return barrel;
+      }
+      let mut barrel = BarrelExports {
+        path: path.clone(),
+        ..Default::default()
+      };
+      self.add_files(&mut barrel, &resource).await;
+      let Some(exports) = self
+        .fs
+        .read_to_string(&path)
+        .await
+        .ok()
+        .and_then(|source| parse_module_exports(&path, source))
+      else {
+        return Arc::new(barrel);
+      };
+
+      let context = path.parent().unwrap_or(&path).to_path_buf();
+      for name in exports.local {
+        barrel.exports.insert(
+          name.clone(),
+          ExportSource {
+            path: path.clone(),
+            request: None,
+            target: ExportTarget::Named(name),
+          },
+        );
+      }
+      for (name, request, target) in exports.reexports {
+        if let Some(resolved) = self.resolve(&context, &request).await {
+          self.add_files(&mut barrel, &resolved).await;
+          barrel.exports.insert(
+            name,
+            ExportSource {
+              request: if resolved.path == path {
+                None
+              } else {
+                resource_package_request(&resolved)
+              },
+              path: resolved.path,
+              target,
+            },
+          );
+        }
+      }
+      // names exported explicitly take precedence over names from `export *`
+      if depth < MAX_STAR_EXPORT_DEPTH {
+        for request in exports.star_reexports {
+          let Some(resolved) = self.resolve(&context, &request).await else {
+            continue;
+          };
+          let star = self.analyze(resolved, depth + 1).await;
+          for (name, source) in &star.exports {
+            if name != "default" && !barrel.exports.contains_key(name) {
+              barrel.exports.insert(name.clone(), source.clone());
+            }
+          }
+          for (file, hash) in &star.files {
+            barrel.files.insert(file.clone(), *hash);
+          }
+        }
+      }
+
+      let barrel = Arc::new(barrel);
+      self.cache.insert(self.compilation_id, barrel.clone());
+      barrel
+    })
+  }
+}
+
+/// Returns the request of `resource` relative to its package, e.g. `ui/button/index.js`.
+fn resource_package_request(resource: &Resource) -> Option<String> {
+  let description = resource.description_data.as_ref()?;
+  let name = description.json().get("name")?.as_str()?;
+  let package_dir = description.path().to_path_buf().assert_utf8();
+  package_request(name, &package_dir, &resource.path)
+}
+
+/// The request of `path` relative to the package `name` located at `package_dir`.
+fn package_request(name: &str, package_dir: &Utf8Path, path: &Utf8Path) -> Option<String> {
+  let subpath = path.strip_prefix(package_dir).ok()?;
+  let subpath = subpath.as_std_path().to_slash_lossy();
+  (!subpath.is_empty()).then(|| format!("{name}/{subpath}"))
+}
+
+/// A quick check of whether the module may import `request`, to skip analyzing the barrels of
+/// packages which are not imported at all.
+///
+/// The source of a static import is always a quoted string literal, so a module without the
+/// quoted request can't import it. A match, e.g. in a comment, only costs an analysis, the
+/// imports are rewritten by matching the parsed import sources in [`OptimizePackageImports`].
+fn contains_request(source: &str, request: &str) -> bool {
+  ['"', '\'']
+    .iter()
+    .any(|quote| source.contains(&format!("{quote}{request}{quote}")))
+}
+
+/// The imports of a barrel which can be rewritten in a module, the verified request of the
+/// declaring module and the name it is exported with there, by the name imported from the barrel.
+pub(crate) type BarrelImports = FxHashMap<Atom, (String, ExportTarget)>;
+
+/// Analyzes the barrel files of the listed packages which the module may import.
+pub(crate) async fn analyze_barrels(
+  loader_context: &mut LoaderContext<RunnerContext>,
+  packages: &[String],
+  cache: &BarrelCache,
+) -> FxHashMap<String, BarrelImports> {
+  let mut barrels = FxHashMap::default();
+  let (Some(source), Some(context)) = (
+    loader_context
+      .content()
+      .map(|content| String::from_utf8_lossy(content.as_bytes())),
+    loader_context
+      .resource_path()
+      .and_then(|path| path.parent()),
+  ) else {
+    return barrels;
+  };
+  let packages = packages
+    .iter()
+    .filter(|package| contains_request(&source, package))
+    .collect::<Vec<_>>();
+  if packages.is_empty() {
+    return barrels;
+  }
+
+  let context = context.to_path_buf();
+  let runner_context = &loader_context.context;
+  let resolver = runner_context
+    .resolver_factory
+    .get(ResolveOptionsWithDependencyType {
+      resolve_options: runner_context
+        .module
+        .get_resolve_options()
+        .map(|options| Box::new(Arc::unwrap_or_clone(options))),
+      resolve_to_context: false,
+      dependency_category: DependencyCategory::Esm,
+    });
+  let analyzer = Analyzer {
+    compilation_id: runner_context.compilation_id,
+    resolver: &resolver,
+    fs: resolver.inner_fs(),
+    cache,
+  };
+  let mut files = vec![];
+  // whether a package-relative request resolves to the declaring module from the importer
+  let mut verified: FxHashMap<String, bool> = FxHashMap::default();
+  for package in packages {
+    let Some(resource) = analyzer.resolve(&context, package).await else {
+      continue;
+    };
+    let barrel = analyzer.analyze(resource, 0).await;
+    let mut imports = BarrelImports::default();
+    for (name, source) in &barrel.exports {
+      let Some(request) = &source.request else {
+        continue;
+      };
+      let valid = match verified.get(request) {
+        Some(valid) => *valid,
+        None => {
+          let valid = analyzer
+            .resolve(&context, request)
+            .await
+            .is_some_and(|resolved| resolved.path == source.path);
+          verified.insert(request.clone(), valid);
+          valid
+        }
+      };
+      if valid {
+        imports.insert(name.clone(), (request.clone(), source.target.clone()));
+      }
+    }
+    files.extend(barrel.files.keys().cloned());
+    barrels.insert(package.clone(), imports);
+  }
+
+  loader_context
+    .file_dependencies
+    .extend(files.into_iter().map(|file| file.into_std_path_buf()));
+  barrels
+}
+
+struct OptimizePackageImports<'a> {
+  barrels: &'a FxHashMap<String, BarrelImports>,
+}
+
+impl OptimizePackageImports<'_> {
+  /// Splits an import of a barrel into imports of the modules which declare the imported names,
+  /// the names which can't be found stay imported from the barrel.
+  fn split_import(&self, import: &mut ImportDecl, barrel: &BarrelImports) -> Vec<ImportDecl> {
+    let mut named_imports: Vec<(String, Vec<ImportSpecifier>)> = vec![];
+    let mut namespace_imports = vec![];
+    let mut rest = vec![];
+    for specifier in mem::take(&mut import.specifiers) {
+      let (local, name) = match &specifier {
+        ImportSpecifier::Named(named) if !named.is_type_only => (
+          named.local.clone(),
+          named
+            .imported
+            .as_ref()
+            .map_or_else(|| named.local.sym.clone(), module_export_name),
+        ),
+        ImportSpecifier::Default(default) => (default.local.clone(), "default".into()),
+        _ => {
+          rest.push(specifier);
+          continue;
+        }
+      };
+      let Some((request, target)) = barrel.get(&name) else {
+        rest.push(specifier);
+        continue;
+      };
+      let request = request.clone();
+      match target {
+        ExportTarget::Namespace => namespace_imports.push((
+          request,
+          ImportSpecifier::Namespace(ImportStarAsSpecifier {
+            span: DUMMY_SP,
+            local,
+          }),
+        )),
+        ExportTarget::Named(imported) => {
+          let specifier = named_import_specifier(local, imported);
+          match named_imports.iter_mut().find(|(r, _)| *r == request) {
+            Some((_, specifiers)) => specifiers.push(specifier),
+            None => named_imports.push((request, vec![specifier])),
+          }
+        }
+      }
+    }
+    import.specifiers = rest;
+
+    named_imports
+      .into_iter()
+      .chain(
+        namespace_imports
+          .into_iter()
+          .map(|(request, specifier)| (request, vec![specifier])),
+      )
+      .map(|(request, specifiers)| ImportDecl {
+        span: import.span,
+        specifiers,
+        src: Box::new(Str {
+          span: DUMMY_SP,
+          value: Wtf8Atom::from(request.as_str()),
+          raw: None,
+        }),
+        type_only: false,
+        with: None,
+        phase: Default::default(),
+      })
+      .collect()
+  }
+}
+
+fn named_import_specifier(local: Ident, imported: &Atom) -> ImportSpecifier {
+  if imported == "default" {
+    return ImportSpecifier::Default(ImportDefaultSpecifier {
+      span: DUMMY_SP,
+      local,
+    });
+  }
+  ImportSpecifier::Named(ImportNamedSpecifier {
+    span: DUMMY_SP,
+    imported: (local.sym != *imported)
+      .then(|| ModuleExportName::Ident(Ident::new_no_ctxt(imported.clone(), DUMMY_SP))),
+    local,
+    is_type_only: false,
+  })
+}
+
+impl VisitMut for OptimizePackageImports<'_> {
+  fn visit_mut_module(&mut self, module: &mut SwcModule) {
+    let mut body = Vec::with_capacity(module.body.len());
+    for mut item in mem::take(&mut module.body) {
+      if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = &mut item
+        && !import.type_only
+        && import.with.is_none()
+        && let Some(barrel) = self
+          .barrels
+          .get(import.src.value.to_string_lossy().as_ref())
+      {
+        let imports = self.split_import(import, barrel);
+        // the barrel itself is not imported anymore if all the names are found
+        let keep_barrel = !import.specifiers.is_empty() || imports.is_empty();
+        body.extend(
+          imports
+            .into_iter()
+            .map(|import| ModuleItem::ModuleDecl(ModuleDecl::Import(import))),
+        );
+        if keep_barrel {
+          body.push(item);
+        }
+        continue;
+      }
+      body.push(item);
+    }
+    module.body = body;
+  }
+}
+
+pub(crate) fn optimize_package_imports(
+  barrels: &FxHashMap<String, BarrelImports>,
+) -> impl Pass + '_ {
+  visit_mut_pass(OptimizePackageImports { barrels })
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_paths::Utf8Path;
+
+  use super::{ExportTarget, package_request, parse_module_exports};
+
+  #[test]
+  fn should_collect_barrel_exports() {
+    let exports = parse_module_exports(
+      Utf8Path::new("index.ts"),
+      r#"
+        import Card from "./card";
+        import * as icons from "./icons";
+        export { Button, Button as DefaultButton } from "./button";
+        export * as utils from "./utils";
+        export * from "./hooks";
+        export { Card, icons };
+        export const version = "1.0.0";
+        export const { theme, colors: [primary] } = config;
+        export type { Props } from "./types";
+      "#
+      .to_string(),
+    )
+    .expect("should parse the barrel");
+
+    assert_eq!(exports.local, vec!["version", "theme", "primary"]);
+    assert_eq!(exports.star_reexports, vec!["./hooks"]);
+    let reexports = exports
+      .reexports
+      .iter()
+      .map(|(name, request, target)| (name.as_str(), request.as_str(), target.clone()))
+      .collect::<Vec<_>>();
+    assert_eq!(
+      reexports,
+      vec![
+        ("Button", "./button", ExportTarget::Named("Button".into())),
+        (
+          "DefaultButton",
+          "./button",
+          ExportTarget::Named("Button".into())
+        ),
+        ("utils", "./utils", ExportTarget::Namespace),
+        ("Card", "./card", ExportTarget::Named("default".into())),
+        ("icons", "./icons", ExportTarget::Namespace),
+      ]
+    );
+  }
+
+  #[test]
+  fn should_create_package_requests() {
+    let package_dir = Utf8Path::new("/project/node_modules/@scope/ui");
+    assert_eq!(
+      package_request(
+        "@scope/ui",
+        package_dir,
+        Utf8Path::new("/project/node_modules/@scope/ui/button/index.js")
+      )
+      .as_deref(),
+      Some("@scope/ui/button/index.js")
+    );
+    assert_eq!(package_request("@scope/ui", package_dir, package_dir), None);
+    assert_eq!(
+      package_request(
+        "@scope/ui",
+        package_dir,
+        Utf8Path::new("/project/src/button.js")
+      ),
+      None
+    );
+  }
+}
@@ -16,6 +16,7 @@ pub struct RawRspackExperiments {
   pub import: Option<Vec<RawImportOptions>>,
   #[serde(default)]
   pub react_server_components: bool,
+  pub optimize_package_imports: Option<Vec<String>>,
 }
 
 #[derive(Default, Deserialize, Debug)]
@@ -25,10 +26,20 @@ pub struct RawCollectTypeScriptInfoOptions {
   pub exported_enum: Option<String>,
 }
 
+#[derive(Default, Deserialize, Debug)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RawIsolatedDeclarationsOptions {
+  pub out_dir: Option<String>,
+  pub root_dir: Option<String>,
+  pub entry: Option<bool>,
+}
+
 #[derive(Default, Debug)]
 pub(crate) struct RspackExperiments {
   pub(crate) import: Option<Vec<ImportOptions>>,
   pub(crate) react_server_components: bool,
+  /// The barrel packages whose named imports are rewritten into imports of the declaring modules.
+  pub(crate) optimize_package_imports: Option<Vec<String>>,
 }
 
 #[derive(Default, Debug)]
@@ -37,6 +48,16 @@ pub(crate) struct CollectTypeScriptInfoOptions {
   pub(crate) exported_enum: Option<CollectingEnumKind>,
 }
 
+#[derive(Default, Debug)]
+pub(crate) struct IsolatedDeclarationsOptions {
+  /// The directory in the output path to emit declarations to.
+  pub(crate) out_dir: String,
+  /// The directory which the layout of declarations mirrors, defaults to the context.
+  pub(crate) root_dir: Option<String>,
+  /// Whether to emit `[name].d.ts` re-exporting the declaration of the entry module for each entry.
+  pub(crate) entry: bool,
+}
+
 #[derive(Default, Debug)]
 pub(crate) enum CollectingEnumKind {
   All,
@@ -51,6 +72,9 @@ impl From<RawRspackExperiments> for RspackExperiments {
         .import
         .map(|i| i.into_iter().map(|v| v.into()).collect()),
       react_server_components: value.react_server_components,
+      optimize_package_imports: value
+        .optimize_package_imports
+        .filter(|packages| !packages.is_empty()),
     }
   }
 }
@@ -68,6 +92,16 @@ impl From<RawCollectTypeScriptInfoOptions> for CollectTypeScriptInfoOptions {
   }
 }
 
+impl From<RawIsolatedDeclarationsOptions> for IsolatedDeclarationsOptions {
+  fn from(value: RawIsolatedDeclarationsOptions) -> Self {
+    Self {
+      out_dir: value.out_dir.unwrap_or_default(),
+      root_dir: value.root_dir,
+      entry: value.entry.unwrap_or_default(),
+    }
+  }
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
 pub struct SwcLoaderJsOptions {
@@ -117,6 +151,9 @@ pub struct SwcLoaderJsOptions {
   #[serde(default)]
   pub collect_type_script_info: Option<RawCollectTypeScriptInfoOptions>,
 
+  #[serde(default)]
+  pub isolated_declarations: Option<RawIsolatedDeclarationsOptions>,
+
   #[serde(default)]
   pub rspack_experiments: Option<RawRspackExperiments>,
 }
@@ -128,6 +165,7 @@ pub(crate) struct SwcCompilerOptionsWithAdditional {
   pub(crate) swc_options: Options,
   pub(crate) rspack_experiments: RspackExperiments,
   pub(crate) collect_typescript_info: Option<CollectTypeScriptInfoOptions>,
+  pub(crate) isolated_declarations: Option<IsolatedDeclarationsOptions>,
 }
 
 impl AsRefStrConverter for SwcCompilerOptionsWithAdditional {
@@ -162,6 +200,7 @@ impl TryFrom<&str> for SwcCompilerOptionsWithAdditional {
       is_module,
       schema,
       collect_type_script_info,
+      isolated_declarations,
       rspack_experiments,
       source_map_ignore_list,
     } = option;
@@ -198,6 +237,7 @@ impl TryFrom<&str> for SwcCompilerOptionsWithAdditional {
       },
       rspack_experiments: rspack_experiments.unwrap_or_default().into(),
       collect_typescript_info: collect_type_script_info.map(|v| v.into()),
+      isolated_declarations: isolated_declarations.map(|v| v.into()),
     })
   }
 }
@@ -4,14 +4,16 @@ use std::{
 };
 
 use rspack_core::{
-  BoxLoader, Context, ModuleRuleUseLoader, NormalModuleFactoryResolveLoader, Plugin, Resolver,
+  AssetInfo, BoxLoader, Compilation, CompilationAsset, CompilationProcessAssets, Context,
+  ModuleRuleUseLoader, NormalModuleFactoryResolveLoader, Plugin, Resolver,
+  rspack_sources::{RawStringSource, SourceExt},
 };
 use rspack_error::{Result, SerdeResultToRspackResultExt};
 use rspack_hook::{plugin, plugin_hook};
 use rustc_hash::FxHashMap;
 use tokio::sync::RwLock;
 
-use crate::{SWC_LOADER_IDENTIFIER, SwcLoader};
+use crate::{ISOLATED_DECLARATION_ASSET_INFO_KEY, SWC_LOADER_IDENTIFIER, SwcLoader};
 
 #[plugin]
 #[derive(Debug)]
@@ -39,6 +41,10 @@ impl Plugin for SwcLoaderPlugin {
       .normal_module_factory_hooks
       .resolve_loader
       .tap(resolve_loader::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
     Ok(())
   }
 }
@@ -83,3 +89,79 @@ pub(crate) async fn resolve_loader(
 
   Ok(None)
 }
+
+/// Emit `[name].d.ts` for entries whose entry modules have isolated declarations,
+/// which re-exports the declarations of the entry modules.
+#[plugin_hook(CompilationProcessAssets for SwcLoaderPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let mut entry_declarations = vec![];
+  for (name, chunk_group_ukey) in compilation.entrypoints() {
+    let chunk_group = compilation
+      .build_chunk_graph_artifact
+      .chunk_group_by_ukey
+      .expect_get(chunk_group_ukey);
+    let mut entry_modules = compilation
+      .build_chunk_graph_artifact
+      .chunk_graph
+      .get_chunk_entry_modules(&chunk_group.get_entrypoint_chunk());
+    entry_modules.sort();
+
+    let mut lines = vec![];
+    for module_identifier in entry_modules {
+      let Some(module) = module_graph.module_by_identifier(&module_identifier) else {
+        continue;
+      };
+      for (filename, asset) in module.build_info().assets.iter() {
+        let Some(has_default_export) = asset
+          .info
+          .extras
+          .get(ISOLATED_DECLARATION_ASSET_INFO_KEY)
+          .and_then(|value| value.as_bool())
+        else {
+          continue;
+        };
+        let request = declaration_request(name, filename);
+        lines.push(format!("export * from \"{request}\";"));
+        if has_default_export {
+          lines.push(format!("export {{ default }} from \"{request}\";"));
+        }
+      }
+    }
+    if !lines.is_empty() {
+      lines.push(String::new());
+      entry_declarations.push((format!("{name}.d.ts"), lines.join("\n")));
+    }
+  }
+
+  for (filename, content) in entry_declarations {
+    compilation.emit_asset(
+      filename,
+      CompilationAsset::new(
+        Some(RawStringSource::from(content).boxed()),
+        AssetInfo::default(),
+      ),
+    );
+  }
+  Ok(())
+}
+
+/// The request of `declaration` relative to the `[name].d.ts` of the entry.
+fn declaration_request(entry_name: &str, declaration: &str) -> String {
+  let depth = entry_name.matches('/').count();
+  let request = if let Some(stem) = declaration.strip_suffix(".d.mts") {
+    format!("{stem}.mjs")
+  } else if let Some(stem) = declaration.strip_suffix(".d.cts") {
+    format!("{stem}.cjs")
+  } else {
+    declaration
+      .strip_suffix(".d.ts")
+      .unwrap_or(declaration)
+      .to_string()
+  };
+  if depth == 0 {
+    format!("./{request}")
+  } else {
+    format!("{}{request}", "../".repeat(depth))
+  }
+}
@@ -0,0 +1,450 @@
+//! The image pipeline of asset modules, enabled by the `image` parser option of `asset`,
+//! `asset/resource` and `asset/inline` modules.
+//!
+//! Images are decoded and encoded with the `image` crate, and PNG images are optimized
+//! losslessly with oxipng. Resource queries like `?w=640&format=webp` create responsive
+//! variants of an image, and `?metadata` exports its dimensions and placeholders.
+
+use std::io::Cursor;
+
+use cow_utils::CowUtils;
+use image::{
+  DynamicImage, ImageDecoder, ImageReader,
+  codecs::{avif::AvifEncoder, jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder},
+  imageops::FilterType,
+};
+use rspack_cacheable::cacheable;
+use rspack_core::AssetParserImageOptions;
+use rspack_error::{Result, error};
+use rspack_util::base64;
+
+/// The longer side of placeholders, in pixels.
+const PLACEHOLDER_SIZE: u32 = 8;
+/// The longer side of the thumbnails that blurhashes are computed from, in pixels.
+const BLURHASH_SOURCE_SIZE: u32 = 64;
+/// The quality of lossy encodings when none is given.
+const DEFAULT_QUALITY: u8 = 80;
+/// Trades the compression of AVIF images for encoding speed, from 1 (slowest) to 10.
+const AVIF_SPEED: u8 = 6;
+
+#[cacheable]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+  Png,
+  Jpeg,
+  Gif,
+  Webp,
+  Avif,
+}
+
+impl ImageFormat {
+  /// Parses the formats images can be converted to.
+  fn from_name(name: &str) -> Option<Self> {
+    match name {
+      "png" => Some(Self::Png),
+      "jpeg" | "jpg" => Some(Self::Jpeg),
+      "webp" => Some(Self::Webp),
+      "avif" => Some(Self::Avif),
+      _ => None,
+    }
+  }
+
+  fn from_image_format(format: image::ImageFormat) -> Option<Self> {
+    match format {
+      image::ImageFormat::Png => Some(Self::Png),
+      image::ImageFormat::Jpeg => Some(Self::Jpeg),
+      image::ImageFormat::Gif => Some(Self::Gif),
+      image::ImageFormat::WebP => Some(Self::Webp),
+      image::ImageFormat::Avif => Some(Self::Avif),
+      _ => None,
+    }
+  }
+
+  fn name(&self) -> &'static str {
+    match self {
+      Self::Png => "PNG",
+      Self::Jpeg => "JPEG",
+      Self::Gif => "GIF",
+      Self::Webp => "WebP",
+      Self::Avif => "AVIF",
+    }
+  }
+
+  pub fn extension(&self) -> &'static str {
+    match self {
+      Self::Png => "png",
+      Self::Jpeg => "jpg",
+      Self::Gif => "gif",
+      Self::Webp => "webp",
+      Self::Avif => "avif",
+    }
+  }
+
+  pub fn mime_type(&self) -> &'static str {
+    match self {
+      Self::Png => "image/png",
+      Self::Jpeg => "image/jpeg",
+      Self::Gif => "image/gif",
+      Self::Webp => "image/webp",
+      Self::Avif => "image/avif",
+    }
+  }
+}
+
+/// The transformations and the metadata requested through the resource query of an image.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ImageQuery {
+  width: Option<u32>,
+  height: Option<u32>,
+  format: Option<ImageFormat>,
+  quality: Option<u8>,
+  pub metadata: bool,
+}
+
+impl ImageQuery {
+  /// Parses `w`, `h`, `format`, `quality` and `metadata` from a resource query, ignoring
+  /// other parameters.
+  pub fn from_query(query: &str) -> Result<Self> {
+    let mut image_query = Self::default();
+    for (key, value) in form_urlencoded::parse(query.trim_start_matches('?').as_bytes()) {
+      let parse_size = || {
+        value
+          .parse::<u32>()
+          .ok()
+          .filter(|size| *size > 0)
+          .ok_or_else(|| error!("Invalid image size \"{value}\" in the resource query"))
+      };
+      match key.as_ref() {
+        "w" => image_query.width = Some(parse_size()?),
+        "h" => image_query.height = Some(parse_size()?),
+        "format" => {
+          let format = ImageFormat::from_name(&value.cow_to_ascii_lowercase()).ok_or_else(|| {
+            error!(
+              "Invalid image format \"{value}\" in the resource query, expected \"png\", \"jpeg\", \"webp\" or \"avif\""
+            )
+          })?;
+          image_query.format = Some(format);
+        }
+        "quality" => {
+          let quality = value
+            .parse::<u8>()
+            .ok()
+            .filter(|quality| (1..=100).contains(quality))
+            .ok_or_else(|| error!("Invalid image quality \"{value}\" in the resource query"))?;
+          image_query.quality = Some(quality);
+        }
+        "metadata" => image_query.metadata = true,
+        _ => {}
+      }
+    }
+    Ok(image_query)
+  }
+
+  pub fn is_transform(&self) -> bool {
+    self.width.is_some() || self.height.is_some() || self.format.is_some()
+  }
+
+  /// Fits the image into the requested size keeping its aspect ratio. Images are never
+  /// enlarged.
+  fn target_size(&self, width: u32, height: u32) -> (u32, u32) {
+    let scale = [
+      self.width.map(|w| f64::from(w) / f64::from(width)),
+      self.height.map(|h| f64::from(h) / f64::from(height)),
+    ]
+    .into_iter()
+    .flatten()
+    .fold(1.0, f64::min);
+    (
+      ((f64::from(width) * scale).round() as u32).max(1),
+      ((f64::from(height) * scale).round() as u32).max(1),
+    )
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageMetadata {
+  pub width: u32,
+  pub height: u32,
+  pub blurhash: String,
+  /// A data URL of a tiny PNG version of the image.
+  pub placeholder: String,
+}
+
+#[derive(Debug, Default)]
+pub struct ProcessedImage {
+  /// The new content of the image, `None` when the image is kept as is.
+  pub content: Option<Vec<u8>>,
+  /// The format the image is converted to, `None` when the format is kept.
+  pub format: Option<ImageFormat>,
+  pub metadata: Option<ImageMetadata>,
+  /// Why the processed image should be checked, it's still used.
+  pub warning: Option<String>,
+}
+
+pub fn process_image(
+  bytes: &[u8],
+  query: &ImageQuery,
+  options: &AssetParserImageOptions,
+) -> Result<ProcessedImage> {
+  let optimize = options.optimize.unwrap_or(true);
+  let quality = query.quality.or(options.quality);
+
+  let reader = ImageReader::new(Cursor::new(bytes))
+    .with_guessed_format()
+    .map_err(|e| error!("Failed to read the image: {e}"))?;
+  let Some(source_format) = reader.format().and_then(ImageFormat::from_image_format) else {
+    if query.is_transform() || query.metadata {
+      return Err(error!(
+        "Unsupported image, only PNG, JPEG, GIF and WebP images can be processed"
+      ));
+    }
+    // other images like SVG are only processed when requested
+    return Ok(ProcessedImage::default());
+  };
+  if source_format == ImageFormat::Gif && query.is_transform() {
+    return Err(error!(
+      "Transforming GIF images is not supported, as their animations would be lost"
+    ));
+  }
+
+  let recompress_jpeg = optimize && source_format == ImageFormat::Jpeg && quality.is_some();
+  if !query.is_transform() && !query.metadata && !recompress_jpeg {
+    let content = if optimize && source_format == ImageFormat::Png {
+      Some(optimize_png(bytes)?).filter(|content| content.len() < bytes.len())
+    } else {
+      None
+    };
+    return Ok(ProcessedImage {
+      content,
+      ..Default::default()
+    });
+  }
+
+  let decode_error =
+    |e: image::ImageError| error!("Failed to decode the {} image: {e}", source_format.name());
+  let mut decoder = reader.into_decoder().map_err(decode_error)?;
+  let orientation = decoder.orientation().map_err(decode_error)?;
+  let mut decoded = DynamicImage::from_decoder(decoder).map_err(decode_error)?;
+  // the orientation is lost when the image is encoded again
+  decoded.apply_orientation(orientation);
+
+  let (width, height) = query.target_size(decoded.width(), decoded.height());
+  let resized = (width, height) != (decoded.width(), decoded.height());
+  if resized {
+    decoded = decoded.resize_exact(width, height, FilterType::Lanczos3);
+  }
+
+  let target_format = query.format.unwrap_or(source_format);
+  let quality = quality.unwrap_or(DEFAULT_QUALITY);
+  let content = if resized || target_format != source_format {
+    Some(encode(&decoded, target_format, quality, optimize)?)
+  } else if optimize {
+    let optimized = match source_format {
+      ImageFormat::Png => Some(optimize_png(bytes)?),
+      ImageFormat::Jpeg if recompress_jpeg => {
+        Some(encode(&decoded, source_format, quality, false)?)
+      }
+      _ => None,
+    };
+    optimized.filter(|content| content.len() < bytes.len())
+  } else {
+    None
+  };
+
+  // converted images replace the original even if they are larger, as the format was requested
+  let warning = content
+    .as_ref()
+    .filter(|content| target_format != source_format && content.len() > bytes.len())
+    .map(|content| {
+      let hint = if target_format == ImageFormat::Webp {
+        ", as WebP images are encoded losslessly. Convert it to AVIF or JPEG for lossy compression"
+      } else {
+        ""
+      };
+      format!(
+        "The image converted to {} ({} bytes) is larger than the original {} image ({} bytes){hint}.",
+        target_format.name(),
+        content.len(),
+        source_format.name(),
+        bytes.len()
+      )
+    });
+
+  let metadata = if query.metadata {
+    Some(ImageMetadata {
+      width: decoded.width(),
+      height: decoded.height(),
+      blurhash: blurhash(&decoded)?,
+      placeholder: placeholder(&decoded)?,
+    })
+  } else {
+    None
+  };
+
+  Ok(ProcessedImage {
+    content,
+    format: (target_format != source_format).then_some(target_format),
+    metadata,
+    warning,
+  })
+}
+
+fn encode(
+  image: &DynamicImage,
+  format: ImageFormat,
+  quality: u8,
+  optimize: bool,
+) -> Result<Vec<u8>> {
+  let mut content = vec![];
+  match format {
+    ImageFormat::Png => image.write_with_encoder(PngEncoder::new(&mut content)),
+    ImageFormat::Jpeg => {
+      image.write_with_encoder(JpegEncoder::new_with_quality(&mut content, quality))
+    }
+    // the encoder of the image crate only supports lossless WebP images
+    ImageFormat::Webp => image.write_with_encoder(WebPEncoder::new_lossless(&mut content)),
+    ImageFormat::Avif => image.write_with_encoder(AvifEncoder::new_with_speed_quality(
+      &mut content,
+      AVIF_SPEED,
+      quality,
+    )),
+    ImageFormat::Gif => return Err(error!("Encoding GIF images is not supported")),
+  }
+  .map_err(|e| error!("Failed to encode the {} image: {e}", format.name()))?;
+
+  if format == ImageFormat::Png && optimize {
+    return optimize_png(&content);
+  }
+  Ok(content)
+}
+
+/// Recompresses a PNG image losslessly, stripping the chunks that do not affect rendering.
+fn optimize_png(bytes: &[u8]) -> Result<Vec<u8>> {
+  let mut options = oxipng::Options::from_preset(2);
+  options.strip = oxipng::StripChunks::Safe;
+  oxipng::optimize_from_memory(bytes, &options)
+    .map_err(|e| error!("Failed to optimize the PNG image: {e}"))
+}
+
+fn blurhash(image: &DynamicImage) -> Result<String> {
+  let thumbnail = image
+    .thumbnail(BLURHASH_SOURCE_SIZE, BLURHASH_SOURCE_SIZE)
+    .to_rgba8();
+  blurhash::encode(
+    4,
+    3,
+    thumbnail.width(),
+    thumbnail.height(),
+    thumbnail.as_raw(),
+  )
+  .map_err(|e| error!("Failed to compute the blurhash of the image: {e}"))
+}
+
+fn placeholder(image: &DynamicImage) -> Result<String> {
+  let thumbnail = image.thumbnail(PLACEHOLDER_SIZE, PLACEHOLDER_SIZE);
+  let png = encode(&thumbnail, ImageFormat::Png, DEFAULT_QUALITY, false)?;
+  Ok(format!(
+    "data:image/png;base64,{}",
+    base64::encode_to_string(png)
+  ))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn png(width: u32, height: u32) -> Vec<u8> {
+    let image = DynamicImage::ImageRgba8(image::RgbaImage::from_fn(width, height, |x, y| {
+      image::Rgba([(x * 8) as u8, (y * 8) as u8, 128, 255])
+    }));
+    encode(&image, ImageFormat::Png, DEFAULT_QUALITY, false).expect("should encode")
+  }
+
+  #[test]
+  fn test_query() {
+    assert_eq!(
+      ImageQuery::from_query("?w=640&format=JPG&metadata&v=1").expect("should parse"),
+      ImageQuery {
+        width: Some(640),
+        format: Some(ImageFormat::Jpeg),
+        metadata: true,
+        ..Default::default()
+      }
+    );
+    assert!(ImageQuery::from_query("?w=0").is_err());
+    assert!(ImageQuery::from_query("?format=gif").is_err());
+    assert!(ImageQuery::from_query("?quality=101").is_err());
+  }
+
+  #[test]
+  fn test_target_size() {
+    let query = ImageQuery {
+      width: Some(100),
+      ..Default::default()
+    };
+    assert_eq!(query.target_size(400, 300), (100, 75));
+    assert_eq!(query.target_size(50, 40), (50, 40));
+  }
+
+  #[test]
+  fn test_process_image() {
+    let options = AssetParserImageOptions {
+      optimize: Some(true),
+      quality: None,
+    };
+    let query = ImageQuery::from_query("?w=16&format=webp&metadata").expect("should parse");
+    let processed = process_image(&png(32, 24), &query, &options).expect("should process");
+    assert_eq!(processed.format, Some(ImageFormat::Webp));
+    let content = processed.content.expect("should convert the image");
+    assert_eq!(
+      image::guess_format(&content).expect("should guess"),
+      image::ImageFormat::WebP
+    );
+    let metadata = processed.metadata.expect("should have metadata");
+    assert_eq!((metadata.width, metadata.height), (16, 12));
+    assert!(metadata.placeholder.starts_with("data:image/png;base64,"));
+  }
+
+  #[test]
+  fn test_warn_larger_converted_image() {
+    let options = AssetParserImageOptions {
+      optimize: Some(true),
+      quality: None,
+    };
+    // noise is much smaller as a lossy JPEG than as a lossless WebP
+    let mut seed = 1u32;
+    let noise = DynamicImage::ImageRgb8(image::RgbImage::from_fn(64, 64, |_, _| {
+      seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+      let [r, g, b, _] = seed.to_le_bytes();
+      image::Rgb([r, g, b])
+    }));
+    let jpeg = encode(&noise, ImageFormat::Jpeg, 30, false).expect("should encode");
+
+    let query = ImageQuery::from_query("?format=webp").expect("should parse");
+    let processed = process_image(&jpeg, &query, &options).expect("should process");
+    assert!(processed.content.is_some());
+    let warning = processed.warning.expect("should warn");
+    assert!(warning.contains("WebP images are encoded losslessly"));
+
+    let query = ImageQuery::from_query("?w=16").expect("should parse");
+    let processed = process_image(&png(32, 24), &query, &options).expect("should process");
+    assert!(processed.warning.is_none());
+  }
+
+  #[test]
+  fn test_process_invalid_image() {
+    let options = AssetParserImageOptions {
+      optimize: Some(true),
+      quality: None,
+    };
+    let mut bytes = png(8, 8);
+    bytes.truncate(40);
+    let query = ImageQuery::from_query("?metadata").expect("should parse");
+    assert!(process_image(&bytes, &query, &options).is_err());
+    let svg = b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>";
+    let processed =
+      process_image(svg, &ImageQuery::default(), &options).expect("should skip the image");
+    assert!(processed.content.is_none());
+  }
+}
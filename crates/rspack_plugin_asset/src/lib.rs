@@ -5,14 +5,14 @@ use rayon::prelude::*;
 use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_core::{
   AssetGeneratorDataUrl, AssetGeneratorDataUrlFnCtx, AssetGeneratorImportMode, AssetInfo,
-  AssetParserDataUrl, BuildMetaDefaultObject, BuildMetaExportsType, ChunkGraph, ChunkUkey,
-  CodeGenerationDataAssetInfo, CodeGenerationDataFilename, CodeGenerationDataUrl,
-  CodeGenerationPublicPathAutoReplace, Compilation, CompilationRenderManifest, CompilerOptions,
-  DependencyType, Filename, GenerateContext, GeneratorOptions, ManifestAssetType, Module,
-  ModuleArgument, ModuleGraph, NAMESPACE_OBJECT_EXPORT, NormalModule, ParseContext,
-  ParserAndGenerator, PathData, Plugin, PublicPath, RenderManifestEntry, ResourceData,
-  RuntimeGlobals, RuntimeSpec, SourceType,
-  rspack_sources::{BoxSource, RawStringSource, SourceExt},
+  AssetParserDataUrl, AssetParserImageOptions, BuildMetaDefaultObject, BuildMetaExportsType,
+  ChunkGraph, ChunkUkey, CodeGenerationDataAssetInfo, CodeGenerationDataFilename,
+  CodeGenerationDataUrl, CodeGenerationPublicPathAutoReplace, Compilation,
+  CompilationRenderManifest, CompilerOptions, DependencyType, Filename, GenerateContext,
+  GeneratorOptions, ManifestAssetType, Module, ModuleArgument, ModuleGraph,
+  NAMESPACE_OBJECT_EXPORT, NormalModule, ParseContext, ParserAndGenerator, PathData, Plugin,
+  PublicPath, RenderManifestEntry, ResourceData, RuntimeGlobals, RuntimeSpec, SourceType,
+  rspack_sources::{BoxSource, RawBufferSource, RawStringSource, SourceExt},
 };
 use rspack_error::{
   Diagnostic, IntoTWithDiagnosticArray, Result, ToStringResultToRspackResultExt, error,
@@ -21,7 +21,10 @@ use rspack_hash::{RspackHash, RspackHashDigest};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::{base64, ext::DynHash, identifier::make_paths_relative};
 
+use crate::image::{ImageFormat, ImageMetadata, ImageQuery};
+
 mod asset_exports_dependency;
+mod image;
 
 pub const AUTO_PUBLIC_PATH_PLACEHOLDER: &str = "__RSPACK_PLUGIN_ASSET_AUTO_PUBLIC_PATH__";
 
@@ -89,7 +92,13 @@ impl CanonicalizedDataUrlOption {
 pub struct AssetParserAndGenerator {
   emit: bool,
   data_url: DataUrlOptions,
+  image: Option<AssetParserImageOptions>,
   pub parsed_asset_config: Option<CanonicalizedDataUrlOption>,
+  /// The format the image is converted to through the resource query.
+  image_format: Option<ImageFormat>,
+  /// Whether the metadata of the image is requested through the resource query.
+  exports_image_metadata: bool,
+  image_metadata: Option<ImageMetadata>,
 }
 
 impl AssetParserAndGenerator {
@@ -97,7 +106,11 @@ impl AssetParserAndGenerator {
     Self {
       emit,
       data_url: DataUrlOptions::Auto(option),
+      image: None,
       parsed_asset_config: None,
+      image_format: None,
+      exports_image_metadata: false,
+      image_metadata: None,
     }
   }
 
@@ -105,7 +118,11 @@ impl AssetParserAndGenerator {
     Self {
       emit: false,
       data_url: DataUrlOptions::Inline(true),
+      image: None,
       parsed_asset_config: None,
+      image_format: None,
+      exports_image_metadata: false,
+      image_metadata: None,
     }
   }
 
@@ -113,7 +130,11 @@ impl AssetParserAndGenerator {
     Self {
       emit,
       data_url: DataUrlOptions::Inline(false),
+      image: None,
       parsed_asset_config: None,
+      image_format: None,
+      exports_image_metadata: false,
+      image_metadata: None,
     }
   }
 
@@ -121,7 +142,11 @@ impl AssetParserAndGenerator {
     Self {
       emit: false,
       data_url: DataUrlOptions::Source,
+      image: None,
       parsed_asset_config: None,
+      image_format: None,
+      exports_image_metadata: false,
+      image_metadata: None,
     }
   }
 
@@ -129,7 +154,27 @@ impl AssetParserAndGenerator {
     Self {
       emit: false,
       data_url: DataUrlOptions::Bytes,
+      image: None,
       parsed_asset_config: None,
+      image_format: None,
+      exports_image_metadata: false,
+      image_metadata: None,
+    }
+  }
+
+  pub fn with_image(mut self, image: Option<AssetParserImageOptions>) -> Self {
+    self.image = image;
+    self
+  }
+
+  fn is_image(resource_data: &ResourceData) -> bool {
+    match resource_data.mimetype() {
+      Some(mimetype) => mimetype.starts_with("image/"),
+      None => resource_data.path().is_some_and(|path| {
+        mime_guess::MimeGuess::from_path(path)
+          .first_raw()
+          .is_some_and(|mimetype| mimetype.starts_with("image/"))
+      }),
     }
   }
 
@@ -191,6 +236,9 @@ impl AssetParserAndGenerator {
     {
       return Ok(mimetype.to_owned());
     }
+    if let Some(format) = self.image_format {
+      return Ok(format.mime_type().to_owned());
+    }
     if let Some(mimetype) = resource_data.mimetype()
       && let Some(parameters) = resource_data.parameters()
     {
@@ -266,6 +314,24 @@ impl AssetParserAndGenerator {
     relative
   }
 
+  /// The file name used in the filename templates, whose extension is replaced when the
+  /// image is converted to another format.
+  fn get_template_file_name<'a>(&self, source_file_name: &'a str) -> Cow<'a, str> {
+    let Some(format) = self.image_format else {
+      return Cow::Borrowed(source_file_name);
+    };
+    let (path, query) = source_file_name.split_at(
+      source_file_name
+        .find(['?', '#'])
+        .unwrap_or(source_file_name.len()),
+    );
+    let stem = match path.rfind(['.', '/']) {
+      Some(index) if path[index..].starts_with('.') => &path[..index],
+      _ => path,
+    };
+    Cow::Owned(format!("{stem}.{}{query}", format.extension()))
+  }
+
   async fn get_asset_module_filename(
     &self,
     module: &NormalModule,
@@ -471,14 +537,48 @@ impl ParserAndGenerator for AssetParserAndGenerator {
     parse_context: rspack_core::ParseContext<'a>,
   ) -> Result<rspack_error::TWithDiagnosticArray<rspack_core::ParseResult>> {
     let ParseContext {
-      source,
+      mut source,
       build_meta,
       build_info,
+      resource_data,
       ..
     } = parse_context;
     build_info.strict = true;
     build_meta.exports_type = BuildMetaExportsType::Default;
     build_meta.default_object = BuildMetaDefaultObject::False;
+
+    // the processed image is stored as the source of the module, so it is cached along with the module
+    let mut diagnostics = vec![];
+    self.image_format = None;
+    self.exports_image_metadata = false;
+    self.image_metadata = None;
+    if let Some(image) = &self.image
+      && Self::is_image(resource_data)
+    {
+      let query = ImageQuery::from_query(resource_data.query().unwrap_or_default())?;
+      self.exports_image_metadata = query.metadata;
+      if image.optimize.unwrap_or(true) || query.is_transform() || query.metadata {
+        match image::process_image(&source.buffer(), &query, image) {
+          Ok(processed) => {
+            if let Some(content) = processed.content {
+              source = RawBufferSource::from(content).boxed();
+            }
+            self.image_format = processed.format;
+            self.image_metadata = processed.metadata;
+            if let Some(warning) = processed.warning {
+              diagnostics.push(Diagnostic::warn(
+                "ImageProcessingWarning".to_string(),
+                warning,
+              ));
+            }
+          }
+          Err(e) => diagnostics.push(Diagnostic::warn(
+            "ImageProcessingWarning".to_string(),
+            format!("{e}, the original image is used instead."),
+          )),
+        }
+      }
+    }
     let size = source.size();
 
     self.parsed_asset_config = match &self.data_url {
@@ -519,7 +619,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         code_generation_dependencies: vec![],
         side_effects_bailout: None,
       }
-      .with_empty_diagnostic(),
+      .with_diagnostic(diagnostics),
     )
   }
 
@@ -594,13 +694,14 @@ impl ParserAndGenerator for AssetParserAndGenerator {
           let contenthash = contenthash.rendered(compilation.options.output.hash_digest_length);
 
           let source_file_name = self.get_source_file_name(normal_module, compilation);
+          let template_file_name = self.get_template_file_name(&source_file_name);
           let (original_filename, filename, mut asset_info) = self
             .get_asset_module_filename(
               normal_module,
               module_generator_options,
               compilation,
               Some(contenthash),
-              &source_file_name,
+              &template_file_name,
               true,
             )
             .await?;
@@ -623,7 +724,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
                     normal_module,
                     compilation,
                     Some(contenthash),
-                    &source_file_name,
+                    &template_file_name,
                     template,
                   )
                   .await?;
@@ -673,6 +774,23 @@ impl ParserAndGenerator for AssetParserAndGenerator {
           return Ok(RawStringSource::from_static("").boxed());
         }
 
+        let exported_content = if self.exports_image_metadata && !import_mode.is_preserve() {
+          let mut exported_object = format!("{{src: {exported_content}");
+          if let Some(metadata) = &self.image_metadata {
+            exported_object.push_str(&format!(
+              ", width: {}, height: {}, blurhash: {}, placeholder: {}",
+              metadata.width,
+              metadata.height,
+              serde_json::to_string(&metadata.blurhash).to_rspack_result()?,
+              serde_json::to_string(&metadata.placeholder).to_rspack_result()?
+            ));
+          }
+          exported_object.push('}');
+          exported_object
+        } else {
+          exported_content
+        };
+
         if import_mode.is_preserve() && parsed_asset_config.is_resource() {
           let is_module = compilation.options.output.module;
           if let Some(ref mut scope) = generate_context.concatenation_scope {
@@ -744,7 +862,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
               module_generator_options,
               compilation,
               Some(contenthash),
-              &source_file_name,
+              &self.get_template_file_name(&source_file_name),
               true,
             )
             .await?;
@@ -805,13 +923,14 @@ impl ParserAndGenerator for AssetParserAndGenerator {
       data_url_options.dyn_hash(&mut hasher);
     } else if parsed_asset_config.is_resource() {
       let source_file_name = self.get_source_file_name(module, compilation);
+      let template_file_name = self.get_template_file_name(&source_file_name);
       let (filename, _, _) = self
         .get_asset_module_filename(
           module,
           module_generator_options,
           compilation,
           None,
-          &source_file_name,
+          &template_file_name,
           false,
         )
         .await?;
@@ -820,7 +939,7 @@ impl ParserAndGenerator for AssetParserAndGenerator {
         Some(public_path) => match public_path {
           PublicPath::Filename(template) => {
             let (public_path, _) = self
-              .get_public_path(module, compilation, None, &source_file_name, template)
+              .get_public_path(module, compilation, None, &template_file_name, template)
               .await?;
             public_path.dyn_hash(&mut hasher);
           }
@@ -914,26 +1033,40 @@ impl Plugin for AssetPlugin {
           .and_then(|x| x.get_asset())
           .and_then(|x| x.emit);
 
-        Box::new(AssetParserAndGenerator::with_auto(
-          data_url_condition,
-          emit.unwrap_or(true),
-        ))
+        let image = parser_options
+          .and_then(|x| x.get_asset())
+          .and_then(|x| x.image.clone());
+
+        Box::new(
+          AssetParserAndGenerator::with_auto(data_url_condition, emit.unwrap_or(true))
+            .with_image(image),
+        )
       }),
     );
 
     ctx.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetInline,
-      Box::new(|_, _| Box::new(AssetParserAndGenerator::with_inline())),
+      Box::new(|parser_options, _| {
+        let image = parser_options
+          .and_then(|x| x.get_asset_inline())
+          .and_then(|x| x.image.clone());
+
+        Box::new(AssetParserAndGenerator::with_inline().with_image(image))
+      }),
     );
 
     ctx.register_parser_and_generator_builder(
       rspack_core::ModuleType::AssetResource,
-      Box::new(move |_, generator_options| {
+      Box::new(move |parser_options, generator_options| {
         let emit = generator_options
           .and_then(|x| x.get_asset_resource())
           .and_then(|x| x.emit);
 
-        Box::new(AssetParserAndGenerator::with_resource(emit.unwrap_or(true)))
+        let image = parser_options
+          .and_then(|x| x.get_asset_resource())
+          .and_then(|x| x.image.clone());
+
+        Box::new(AssetParserAndGenerator::with_resource(emit.unwrap_or(true)).with_image(image))
       }),
     );
 
@@ -303,7 +303,8 @@ installedChunks[chunkId] = 0;
           &self.template_id(TemplateId::WithLoading),
           Some(serde_json::json!({
             "_css_matcher": &has_css_matcher.render("chunkId"),
-            "_is_neutral_platform": is_neutral_platform
+            "_is_neutral_platform": is_neutral_platform,
+            "_with_retry": runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY_URL),
           })),
         )?;
         source.push_str(&source_with_loading);
@@ -1,23 +1,29 @@
 use std::{borrow::Cow, hash::Hash, sync::Arc};
 
 use async_trait::async_trait;
-use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_cacheable::{
+  cacheable, cacheable_dyn,
+  with::{AsOption, AsPreset},
+};
 use rspack_collections::{Identifiable, Identifier};
 use rspack_core::{
   AsyncDependenciesBlockIdentifier, BoxDependency, BoxModule, BuildContext, BuildInfo, BuildMeta,
   BuildResult, CodeGenerationResult, Compilation, Context, DependenciesBlock, DependencyId,
   FactoryMeta, LibIdentOptions, Module, ModuleArgument, ModuleCodeGenerationContext,
-  ModuleDependency, ModuleGraph, ModuleId, ModuleType, RuntimeSpec, SourceType,
+  ModuleDependency, ModuleGraph, ModuleId, ModuleType, ResourceData, RuntimeSpec, SourceType,
   StaticExportsDependency, StaticExportsSpec, ValueCacheVersions, impl_module_meta_info,
   impl_source_map_config, module_update_hash,
   rspack_sources::{BoxSource, OriginalSource, RawStringSource},
 };
-use rspack_error::{Result, impl_empty_diagnosable_trait};
+use rspack_error::{Diagnosable, Diagnostic, Result};
 use rspack_hash::{RspackHash, RspackHashDigest};
+use rspack_paths::Utf8PathBuf;
 use rspack_util::{json_stringify, source_map::ModuleSourceMapConfig};
 
 use super::delegated_source_dependency::DelegatedSourceDependency;
-use crate::{DllManifestContentItem, DllManifestContentItemExports};
+use crate::{
+  DllManifestContentItem, DllManifestContentItemExports, DllManifestPackage, hash_file_content,
+};
 
 pub type SourceRequest = String;
 
@@ -31,6 +37,10 @@ pub struct DelegatedModule {
   user_request: String,
   original_request: Option<String>,
   delegate_data: DllManifestContentItem,
+  #[cacheable(with=AsOption<AsPreset>)]
+  resource_path: Option<Utf8PathBuf>,
+  package: Option<DllManifestPackage>,
+  diagnostics: Vec<Diagnostic>,
   dependencies: Vec<DependencyId>,
   blocks: Vec<AsyncDependenciesBlockIdentifier>,
   factory_meta: Option<FactoryMeta>,
@@ -56,6 +66,45 @@ impl DelegatedModule {
       ..Default::default()
     }
   }
+
+  /// Sets the resource the request resolves to in the current compilation, which is
+  /// validated against the package and hash recorded in the manifest when building.
+  pub fn with_resource(mut self, resource_data: &ResourceData) -> Self {
+    self.resource_path = resource_data.path().map(|path| path.to_owned());
+    self.package = DllManifestPackage::from_resource_data(resource_data);
+    self
+  }
+
+  async fn validate_resource(&self, build_context: &BuildContext) -> Option<Diagnostic> {
+    if let Some(expected) = &self.delegate_data.package
+      && let Some(actual) = &self.package
+      && expected != actual
+    {
+      return Some(self.create_stale_warning(format!(
+        "it was built from {expected}, but {actual} is resolved now"
+      )));
+    }
+
+    if let Some(expected) = &self.delegate_data.hash
+      && let Some(path) = &self.resource_path
+      && let Ok(content) = build_context.fs.read(path).await
+      && *expected != hash_file_content(&content)
+    {
+      return Some(self.create_stale_warning(format!("{path} has changed since it was built")));
+    }
+
+    None
+  }
+
+  fn create_stale_warning(&self, reason: String) -> Diagnostic {
+    Diagnostic::warn(
+      "DllManifestStaleWarning".to_string(),
+      format!(
+        "The manifest of {} is stale for \"{}\": {reason}.\nThe module id in the manifest may point to a different module, rebuild the dll to update the manifest.",
+        self.source_request, self.user_request
+      ),
+    )
+  }
 }
 
 #[cacheable_dyn]
@@ -93,9 +142,20 @@ impl Module for DelegatedModule {
 
   async fn build(
     mut self: Box<Self>,
-    _build_context: BuildContext,
+    build_context: BuildContext,
     _compilation: Option<&Compilation>,
   ) -> Result<BuildResult> {
+    self.diagnostics.clear();
+    if let Some(diagnostic) = self.validate_resource(&build_context).await {
+      self.diagnostics.push(diagnostic);
+    }
+    if let Some(path) = &self.resource_path {
+      self
+        .build_info
+        .file_dependencies
+        .insert(path.as_path().into());
+    }
+
     let dependencies = vec![
       Box::new(DelegatedSourceDependency::new(self.source_request.clone())),
       Box::new(StaticExportsDependency::new(
@@ -243,4 +303,16 @@ impl DependenciesBlock for DelegatedModule {
   }
 }
 
-impl_empty_diagnosable_trait!(DelegatedModule);
+impl Diagnosable for DelegatedModule {
+  fn add_diagnostic(&mut self, diagnostic: Diagnostic) {
+    self.diagnostics.push(diagnostic);
+  }
+
+  fn add_diagnostics(&mut self, mut diagnostics: Vec<Diagnostic>) {
+    self.diagnostics.append(&mut diagnostics);
+  }
+
+  fn diagnostics(&self) -> Cow<'_, [Diagnostic]> {
+    Cow::Borrowed(&self.diagnostics)
+  }
+}
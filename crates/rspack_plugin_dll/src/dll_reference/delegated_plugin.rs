@@ -125,7 +125,7 @@ async fn factorize(&self, data: &mut ModuleFactoryCreateData) -> Result<Option<B
 async fn nmf_module(
   &self,
   _data: &mut ModuleFactoryCreateData,
-  _create_data: &mut NormalModuleCreateData,
+  create_data: &mut NormalModuleCreateData,
   module: &mut BoxModule,
 ) -> Result<()> {
   if self.options.scope.is_none()
@@ -145,6 +145,7 @@ async fn nmf_module(
       request.to_string(),
       original_request.map(|request| request.to_string()),
     )
+    .with_resource(&create_data.resource_resolve_data)
     .boxed();
   };
 
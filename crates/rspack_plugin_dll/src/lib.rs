@@ -1,8 +1,11 @@
+use std::hash::Hasher;
+
 use rspack_cacheable::{
   cacheable,
   with::{AsPreset, AsVec},
 };
-use rspack_core::{BuildMeta, LibraryType, ModuleId};
+use rspack_core::{BuildMeta, LibraryType, ModuleId, ResourceData};
+use rspack_hash::{HashDigest, HashFunction, RspackHash};
 use rspack_util::atom::Atom;
 use rustc_hash::FxHashMap as HashMap;
 use serde::{Serialize, ser::SerializeSeq};
@@ -51,6 +54,46 @@ pub struct DllManifestContentItem {
 
   #[serde(skip_serializing_if = "Option::is_none")]
   pub id: Option<ModuleId>,
+
+  /// The package the module was resolved from when the dll was built.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub package: Option<DllManifestPackage>,
+
+  /// The hash of the file content of the module when the dll was built.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub hash: Option<String>,
+}
+
+#[cacheable]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DllManifestPackage {
+  pub name: String,
+  pub version: String,
+}
+
+impl DllManifestPackage {
+  /// Reads the name and version of the package.json a resource is resolved from.
+  pub fn from_resource_data(resource_data: &ResourceData) -> Option<Self> {
+    let json = resource_data.description()?.json();
+    Some(Self {
+      name: json.get("name")?.as_str()?.to_string(),
+      version: json.get("version")?.as_str()?.to_string(),
+    })
+  }
+}
+
+impl std::fmt::Display for DllManifestPackage {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{}@{}", self.name, self.version)
+  }
+}
+
+/// Hashes the file content of a module, both the dll and its references use it so
+/// the hashes recorded in the manifest stay comparable.
+pub(crate) fn hash_file_content(content: &[u8]) -> String {
+  let mut hasher = RspackHash::new(&HashFunction::Xxhash64);
+  hasher.write(content);
+  hasher.digest(&HashDigest::Hex).encoded().to_string()
 }
 
 #[derive(Debug, Clone, Serialize)]
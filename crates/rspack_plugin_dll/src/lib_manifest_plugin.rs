@@ -10,6 +10,7 @@ use rustc_hash::FxHashMap as HashMap;
 
 use crate::{
   DllManifest, DllManifestContent, DllManifestContentItem, DllManifestContentItemExports,
+  DllManifestPackage, hash_file_content,
 };
 
 #[derive(Debug, Clone)]
@@ -139,12 +140,38 @@ async fn emit(&self, compilation: &mut Compilation) -> Result<()> {
 
         let id = ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier());
 
+        // record where the module comes from, so references can detect a stale dll
+        let (package, hash) = match module.as_normal_module() {
+          Some(normal_module) => {
+            let resource_data = normal_module.resource_resolved_data();
+            let hash = match resource_data.path() {
+              Some(path) if resource_data.encoded_content().is_none() => compilation
+                .input_filesystem
+                .read(path)
+                .await
+                .ok()
+                .map(|content| hash_file_content(&content)),
+              _ => None,
+            };
+            // the version of the project itself is irrelevant, only record installed packages
+            let package = resource_data
+              .path()
+              .is_some_and(|path| path.components().any(|c| c.as_str() == "node_modules"))
+              .then(|| DllManifestPackage::from_resource_data(resource_data))
+              .flatten();
+            (package, hash)
+          }
+          None => (None, None),
+        };
+
         manifest_content.insert(
           ident.into_owned(),
           DllManifestContentItem {
             id: id.map(|id| id.to_owned()),
             build_meta: module.build_meta().clone(),
             exports: provided_exports,
+            package,
+            hash,
           },
         );
       }
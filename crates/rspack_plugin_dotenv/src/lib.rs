@@ -0,0 +1,198 @@
+mod parse;
+
+use std::sync::Arc;
+
+use atomic_refcell::AtomicRefCell;
+use rspack_core::{
+  Compilation, CompilationParams, CompilerCompilation, ModuleType, NormalModuleFactoryParser,
+  ParserAndGenerator, ParserOptions, Plugin,
+};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::Utf8PathBuf;
+use rspack_plugin_javascript::{
+  define_plugin::{DefineParserPlugin, DefineValue, WalkData, register_value_cache_versions},
+  parser_and_generator::JavaScriptParserAndGenerator,
+};
+use rustc_hash::FxHashMap as HashMap;
+use serde_json::Value;
+
+pub use self::parse::{EnvValue, Expanded, expand, parse};
+
+#[derive(Debug)]
+pub struct DotenvPluginOptions {
+  /// The directory to load the env files from.
+  pub dir: Utf8PathBuf,
+  /// Also load `.env.[mode]` and `.env.[mode].local` when set.
+  pub mode: Option<String>,
+  /// Only variables whose names start with one of the prefixes are exposed.
+  /// All variables are exposed if it's empty.
+  pub prefixes: Vec<String>,
+  /// Expose variables as `process.env.[name]`.
+  pub process_env: bool,
+  /// Expose variables as `import.meta.env.[name]`.
+  pub import_meta_env: bool,
+}
+
+impl DotenvPluginOptions {
+  /// The env files to load, from the lowest to the highest priority.
+  fn env_files(&self) -> Vec<Utf8PathBuf> {
+    let mut files = vec![".env".to_string(), ".env.local".to_string()];
+    if let Some(mode) = &self.mode {
+      files.push(format!(".env.{mode}"));
+      files.push(format!(".env.{mode}.local"));
+    }
+    files.into_iter().map(|file| self.dir.join(file)).collect()
+  }
+}
+
+/// Load variables from `.env` files and expose them through the [DefinePlugin](rspack_plugin_javascript::define_plugin::DefinePlugin) machinery.
+#[plugin]
+#[derive(Debug)]
+pub struct DotenvPlugin {
+  options: DotenvPluginOptions,
+  /// The definitions of the current compilation, the env files are reloaded for each compilation.
+  walk_data: AtomicRefCell<Arc<WalkData>>,
+}
+
+impl DotenvPlugin {
+  pub fn new(options: DotenvPluginOptions) -> Self {
+    Self::new_inner(options, Default::default())
+  }
+
+  /// Read all env files, variables of the process environment take precedence over the files.
+  async fn load(&self, compilation: &mut Compilation) -> HashMap<String, String> {
+    let fs = compilation.input_filesystem.clone();
+    let mut parsed = HashMap::default();
+    for file in self.options.env_files() {
+      match fs.read_to_string(&file).await {
+        Ok(content) => {
+          parsed.extend(parse(&content));
+          compilation.file_dependencies.insert(file.as_path().into());
+        }
+        // the file may be created later, watch it as a missing dependency
+        Err(_) => {
+          compilation
+            .missing_dependencies
+            .insert(file.as_path().into());
+        }
+      }
+    }
+
+    let lookup = |key: &str| std::env::var(key).ok();
+    let Expanded { mut values, cycles } = expand(&parsed, &lookup);
+    for cycle in cycles {
+      let path = cycle
+        .iter()
+        .chain(cycle.first())
+        .map(|key| format!("${key}"))
+        .collect::<Vec<_>>()
+        .join(" -> ");
+      compilation.push_diagnostic(Diagnostic::warn(
+        "DotenvPlugin".into(),
+        format!(
+          "Cyclic reference in env files: {path}, the reference is expanded to an empty string."
+        ),
+      ));
+    }
+    for (key, value) in values.iter_mut() {
+      if let Some(process_value) = lookup(key) {
+        *value = process_value;
+      }
+    }
+    values
+  }
+
+  fn to_definitions(
+    &self,
+    env: HashMap<String, String>,
+    compilation: &mut Compilation,
+  ) -> DefineValue {
+    let mut definitions = DefineValue::default();
+    let mut env = env.into_iter().collect::<Vec<_>>();
+    // keep the order of diagnostics stable
+    env.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    for (key, value) in env {
+      if !self.options.prefixes.is_empty()
+        && !self
+          .options
+          .prefixes
+          .iter()
+          .any(|prefix| key.starts_with(prefix.as_str()))
+      {
+        continue;
+      }
+      // keys like `API-VERSION` can only be referenced by other variables, as
+      // `process.env.API-VERSION` is never a member expression
+      if !is_identifier(&key) {
+        compilation.push_diagnostic(Diagnostic::warn(
+          "DotenvPlugin".into(),
+          format!("Env variable \"{key}\" is not a valid identifier and is not exposed."),
+        ));
+        continue;
+      }
+      // define values are code, so the value needs to be a string literal
+      let code = Value::String(Value::String(value).to_string());
+      if self.options.process_env {
+        definitions.insert(format!("process.env.{key}"), code.clone());
+      }
+      if self.options.import_meta_env {
+        definitions.insert(format!("import.meta.env.{key}"), code);
+      }
+    }
+    definitions
+  }
+}
+
+fn is_identifier(key: &str) -> bool {
+  let mut chars = key.chars();
+  chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || matches!(c, '_' | '$'))
+    && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+}
+
+#[plugin_hook(CompilerCompilation for DotenvPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let env = self.load(compilation).await;
+  let definitions = self.to_definitions(env, compilation);
+  let walk_data = Arc::new(WalkData::new(&definitions));
+  register_value_cache_versions(&walk_data, compilation);
+  *self.walk_data.borrow_mut() = walk_data;
+  Ok(())
+}
+
+#[plugin_hook(NormalModuleFactoryParser for DotenvPlugin, tracing=false)]
+async fn nmf_parser(
+  &self,
+  module_type: &ModuleType,
+  parser: &mut Box<dyn ParserAndGenerator>,
+  _parser_options: Option<&ParserOptions>,
+) -> Result<()> {
+  if module_type.is_js_like()
+    && let Some(parser) = parser.downcast_mut::<JavaScriptParserAndGenerator>()
+  {
+    let walk_data = self.walk_data.borrow().clone();
+    parser.add_parser_plugin(Box::new(DefineParserPlugin::new(walk_data)));
+  }
+  Ok(())
+}
+
+impl Plugin for DotenvPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DotenvPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .normal_module_factory_hooks
+      .parser
+      .tap(nmf_parser::new(self));
+    Ok(())
+  }
+}
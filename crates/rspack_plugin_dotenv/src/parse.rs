@@ -0,0 +1,336 @@
+use cow_utils::CowUtils;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// A parsed value of an env file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvValue {
+  pub value: String,
+  /// Values in single quotes are taken literally and never expanded.
+  pub expand: bool,
+}
+
+/// Parse the content of an env file, following the rules of [dotenv](https://github.com/motdotla/dotenv#what-rules-does-the-parsing-engine-follow).
+///
+/// The returned entries keep the order in the file, later entries override the earlier ones.
+pub fn parse(content: &str) -> Vec<(String, EnvValue)> {
+  let content = content.cow_replace("\r\n", "\n");
+  let mut result = vec![];
+  let mut rest = content.as_ref();
+
+  while !rest.is_empty() {
+    let (line, next) = rest.split_once('\n').unwrap_or((rest, ""));
+    rest = next;
+
+    let line = line.trim_start();
+    if line.is_empty() || line.starts_with('#') {
+      continue;
+    }
+    let line = line.strip_prefix("export ").unwrap_or(line);
+    let Some((key, value)) = line.split_once(['=', ':']) else {
+      continue;
+    };
+    let key = key.trim();
+    if key.is_empty()
+      || !key
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    {
+      continue;
+    }
+    let value = value.trim_start();
+
+    let parsed = match value.chars().next() {
+      Some(quote @ ('"' | '\'' | '`')) => {
+        let body = &value[1..];
+        let inner = if let Some(end) = body.find(quote) {
+          body[..end].to_string()
+        } else {
+          // quoted values may span multiple lines
+          let mut inner = body.to_string();
+          let mut closed = false;
+          while !closed && !rest.is_empty() {
+            let (line, next) = rest.split_once('\n').unwrap_or((rest, ""));
+            rest = next;
+            inner.push('\n');
+            match line.find(quote) {
+              Some(end) => {
+                inner.push_str(&line[..end]);
+                closed = true;
+              }
+              None => inner.push_str(line),
+            }
+          }
+          if closed {
+            inner
+          } else {
+            // an unterminated quote is taken literally
+            format!("{quote}{inner}")
+          }
+        };
+        let value = if quote == '"' {
+          inner
+            .cow_replace("\\n", "\n")
+            .cow_replace("\\r", "\r")
+            .into_owned()
+        } else {
+          inner
+        };
+        EnvValue {
+          value,
+          expand: quote != '\'',
+        }
+      }
+      _ => {
+        let value = match value.find(" #") {
+          Some(comment) => &value[..comment],
+          None => value,
+        };
+        EnvValue {
+          value: value.trim_end().to_string(),
+          expand: true,
+        }
+      }
+    };
+
+    result.push((key.to_string(), parsed));
+  }
+
+  result
+}
+
+/// The values of env files after expansion.
+#[derive(Debug, Default)]
+pub struct Expanded {
+  pub values: HashMap<String, String>,
+  /// Cyclic references like `A=$B` and `B=$A`, each one starts with its smallest key.
+  /// References that close a cycle are expanded to empty strings.
+  pub cycles: Vec<Vec<String>>,
+}
+
+/// Expand `$KEY`, `${KEY}`, `${KEY:-default}` and `${KEY-default}` references, like [dotenv-expand](https://github.com/motdotla/dotenv-expand).
+///
+/// References are looked up in `lookup` first, which is usually the process environment,
+/// then in the parsed values. `\$` escapes a literal `$`.
+pub fn expand(
+  parsed: &HashMap<String, EnvValue>,
+  lookup: &dyn Fn(&str) -> Option<String>,
+) -> Expanded {
+  let mut expanded = Expanded::default();
+  let mut cycles = HashSet::default();
+  for key in parsed.keys() {
+    let mut visiting = vec![];
+    let value = expand_key(key, parsed, lookup, &mut visiting, &mut cycles);
+    expanded.values.insert(key.clone(), value);
+  }
+  expanded.cycles = cycles.into_iter().collect();
+  expanded.cycles.sort();
+  expanded
+}
+
+fn expand_key(
+  key: &str,
+  parsed: &HashMap<String, EnvValue>,
+  lookup: &dyn Fn(&str) -> Option<String>,
+  visiting: &mut Vec<String>,
+  cycles: &mut HashSet<Vec<String>>,
+) -> String {
+  let Some(entry) = parsed.get(key) else {
+    return String::new();
+  };
+  if !entry.expand {
+    return entry.value.clone();
+  }
+  if let Some(start) = visiting.iter().position(|visited| visited == key) {
+    let mut cycle = visiting[start..].to_vec();
+    // the same cycle is found from each of its keys, rotate it to report it once
+    let smallest = cycle
+      .iter()
+      .enumerate()
+      .min_by_key(|(_, key)| *key)
+      .map_or(0, |(index, _)| index);
+    cycle.rotate_left(smallest);
+    cycles.insert(cycle);
+    return String::new();
+  }
+  visiting.push(key.to_string());
+  let value = expand_value(&entry.value, parsed, lookup, visiting, cycles);
+  visiting.pop();
+  value
+}
+
+fn expand_value(
+  value: &str,
+  parsed: &HashMap<String, EnvValue>,
+  lookup: &dyn Fn(&str) -> Option<String>,
+  visiting: &mut Vec<String>,
+  cycles: &mut HashSet<Vec<String>>,
+) -> String {
+  let mut resolve = |name: &str| -> Option<String> {
+    lookup(name).or_else(|| {
+      parsed
+        .contains_key(name)
+        .then(|| expand_key(name, parsed, lookup, visiting, cycles))
+    })
+  };
+
+  let mut result = String::with_capacity(value.len());
+  let mut chars = value.char_indices().peekable();
+  while let Some((index, c)) = chars.next() {
+    match c {
+      '\\' if matches!(chars.peek(), Some((_, '$'))) => {
+        chars.next();
+        result.push('$');
+      }
+      '$' if matches!(chars.peek(), Some((_, '{'))) => {
+        let Some(end) = value[index..].find('}') else {
+          result.push_str(&value[index..]);
+          break;
+        };
+        let expression = &value[index + 2..index + end];
+        let (name, default) = split_expression(expression, &|name| {
+          lookup(name).is_some() || parsed.contains_key(name)
+        });
+        let replaced = resolve(name)
+          .filter(|v| !v.is_empty() || default.is_none())
+          .unwrap_or_else(|| default.unwrap_or_default().to_string());
+        result.push_str(&replaced);
+        while chars.next_if(|(i, _)| *i <= index + end).is_some() {}
+      }
+      '$' => {
+        let name_len = value[index + 1..]
+          .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+          .unwrap_or(value.len() - index - 1);
+        if name_len == 0 {
+          result.push('$');
+          continue;
+        }
+        let name = &value[index + 1..index + 1 + name_len];
+        result.push_str(&resolve(name).unwrap_or_default());
+        while chars.next_if(|(i, _)| *i <= index + name_len).is_some() {}
+      }
+      c => result.push(c),
+    }
+  }
+  result
+}
+
+/// Split the expression of `${...}` into the key and the optional default value.
+///
+/// Keys may contain `-`, so `:-` and `-` are only taken as the default operator after the
+/// longest key that is defined. When no key is defined, `:-` follows the longest valid key,
+/// otherwise the key is the longest `[\w.]+` prefix, like the regex of dotenv-expand.
+fn split_expression<'a>(
+  expression: &'a str,
+  is_defined: &dyn Fn(&str) -> bool,
+) -> (&'a str, Option<&'a str>) {
+  let key_len = expression
+    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-')))
+    .unwrap_or(expression.len());
+  let split_at = |at: usize| -> Option<(&'a str, Option<&'a str>)> {
+    let (name, rest) = expression.split_at(at);
+    if name.is_empty() {
+      return None;
+    }
+    if rest.is_empty() {
+      return Some((name, None));
+    }
+    rest
+      .strip_prefix(":-")
+      .or_else(|| rest.strip_prefix('-'))
+      .map(|default| (name, Some(default)))
+  };
+
+  let mut candidates = (1..=key_len).rev().filter_map(&split_at);
+  if let Some(defined) = candidates.clone().find(|(name, _)| is_defined(name)) {
+    return defined;
+  }
+  if expression[key_len..].starts_with(":-") {
+    return split_at(key_len).unwrap_or((expression, None));
+  }
+  let word_len = expression
+    .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '.')))
+    .unwrap_or(expression.len());
+  split_at(word_len)
+    .or_else(|| candidates.next())
+    .unwrap_or((expression, None))
+}
+
+#[cfg(test)]
+mod test {
+  use super::*;
+
+  fn parse_to_map(content: &str) -> HashMap<String, EnvValue> {
+    parse(content).into_iter().collect()
+  }
+
+  #[test]
+  fn should_parse_env_files() {
+    let parsed = parse_to_map(
+      r#"
+# comment
+BASIC=basic
+export EXPORTED=exported
+SPACED =  spaced value
+INLINE_COMMENT=value # comment
+SINGLE='single $BASIC'
+DOUBLE="double\nline"
+MULTILINE="first
+second"
+EMPTY=
+"#,
+    );
+    let value = |key: &str| parsed.get(key).map(|v| v.value.as_str());
+    assert_eq!(value("BASIC"), Some("basic"));
+    assert_eq!(value("EXPORTED"), Some("exported"));
+    assert_eq!(value("SPACED"), Some("spaced value"));
+    assert_eq!(value("INLINE_COMMENT"), Some("value"));
+    assert_eq!(value("SINGLE"), Some("single $BASIC"));
+    assert_eq!(value("DOUBLE"), Some("double\nline"));
+    assert_eq!(value("MULTILINE"), Some("first\nsecond"));
+    assert_eq!(value("EMPTY"), Some(""));
+    assert!(!parsed["SINGLE"].expand);
+  }
+
+  #[test]
+  fn should_expand_references() {
+    let parsed = parse_to_map(
+      r#"
+HOST=localhost
+URL=http://${HOST}:$PORT/api
+FALLBACK=${MISSING:-fallback}
+ESCAPED=\$HOST
+LITERAL='$HOST'
+FROM_ENV=${FROM_PROCESS}
+CYCLE_A=$CYCLE_B
+CYCLE_B=$CYCLE_A
+DASHED-KEY=dashed
+DASHED=${DASHED-KEY}
+DASHED_DEFAULT=${OTHER-MISSING:-fallback}
+WORD_DEFAULT=${MISSING-default-value}
+"#,
+    );
+    let lookup = |key: &str| match key {
+      "PORT" => Some("8080".to_string()),
+      "FROM_PROCESS" => Some("process".to_string()),
+      _ => None,
+    };
+    let Expanded {
+      values: expanded,
+      cycles,
+    } = expand(&parsed, &lookup);
+    assert_eq!(expanded["URL"], "http://localhost:8080/api");
+    assert_eq!(expanded["FALLBACK"], "fallback");
+    assert_eq!(expanded["ESCAPED"], "$HOST");
+    assert_eq!(expanded["LITERAL"], "$HOST");
+    assert_eq!(expanded["FROM_ENV"], "process");
+    assert_eq!(expanded["CYCLE_A"], "");
+    assert_eq!(expanded["CYCLE_B"], "");
+    assert_eq!(
+      cycles,
+      vec![vec!["CYCLE_A".to_string(), "CYCLE_B".to_string()]]
+    );
+    assert_eq!(expanded["DASHED"], "dashed");
+    assert_eq!(expanded["DASHED_DEFAULT"], "fallback");
+    assert_eq!(expanded["WORD_DEFAULT"], "default-value");
+  }
+}
@@ -0,0 +1,310 @@
+use std::{
+  collections::BTreeMap,
+  path::{Component, Path, PathBuf},
+};
+
+use itertools::Itertools;
+use rspack_core::{Compilation, CompilationSeal, Logger, ModuleIdentifier, Plugin, contextify};
+use rspack_error::{Diagnostic, Result};
+use rspack_hook::{plugin, plugin_hook};
+use rspack_regex::RspackRegex;
+use rspack_util::size::format_size;
+use rustc_hash::FxHashSet as HashSet;
+
+/// The number of issuer chains listed for each copy of a duplicated package.
+const MAX_ISSUER_CHAINS: usize = 3;
+
+#[derive(Debug)]
+pub enum DuplicatePackageAllowlistEntry {
+  String(String),
+  Pattern(RspackRegex),
+}
+
+impl DuplicatePackageAllowlistEntry {
+  pub fn test(&self, name: &str) -> bool {
+    match self {
+      Self::String(string) => name == string,
+      Self::Pattern(pattern) => pattern.test(name),
+    }
+  }
+}
+
+#[derive(Debug)]
+pub struct DuplicatePackageRspackPluginOptions {
+  /// When `true`, the plugin will emit Error diagnostics rather than the
+  /// default Warn severity.
+  pub fail_on_error: bool,
+  /// Packages that are allowed to be bundled several times, matched against
+  /// the package name.
+  pub allowlist: Vec<DuplicatePackageAllowlistEntry>,
+}
+
+/// A copy of a package, installed at `root`, that modules are bundled from.
+#[derive(Debug)]
+struct PackageCopy {
+  version: String,
+  root: PathBuf,
+  modules: Vec<ModuleIdentifier>,
+  size: f64,
+}
+
+#[plugin]
+#[derive(Debug)]
+pub struct DuplicatePackageRspackPlugin {
+  options: DuplicatePackageRspackPluginOptions,
+}
+
+impl DuplicatePackageRspackPlugin {
+  pub fn new(options: DuplicatePackageRspackPluginOptions) -> Self {
+    Self::new_inner(options)
+  }
+
+  fn is_allowed(&self, name: &str) -> bool {
+    self.options.allowlist.iter().any(|entry| entry.test(name))
+  }
+
+  fn create_report(&self, name: &str, copies: &[PackageCopy], compilation: &Compilation) -> String {
+    let context = &compilation.options.context;
+    let total_size: f64 = copies.iter().map(|copy| copy.size).sum();
+    let largest_size = copies.iter().map(|copy| copy.size).fold(0.0, f64::max);
+
+    let mut report = format!(
+      "Package \"{name}\" is bundled {} times, duplicating {}:\n",
+      copies.len(),
+      format_size(total_size - largest_size)
+    );
+    for copy in copies {
+      report.push_str(&format!(
+        "  - {name}@{} at {} ({} in {} modules)\n",
+        copy.version,
+        contextify(context, &copy.root.to_string_lossy()),
+        format_size(copy.size),
+        copy.modules.len()
+      ));
+      let chains = issuer_chains(copy, compilation);
+      for chain in chains.iter().take(MAX_ISSUER_CHAINS) {
+        report.push_str(&format!("      imported through {chain}\n"));
+      }
+      if chains.len() > MAX_ISSUER_CHAINS {
+        report.push_str(&format!(
+          "      and {} more\n",
+          chains.len() - MAX_ISSUER_CHAINS
+        ));
+      }
+    }
+
+    let compatible = copies
+      .iter()
+      .map(|copy| compatible_range(&copy.version))
+      .all_equal();
+    if compatible {
+      // alias to the newest copy, and to the most hoisted one among the same versions
+      let target = copies
+        .iter()
+        .max_by(|a, b| {
+          parse_version(&a.version)
+            .cmp(&parse_version(&b.version))
+            .then_with(|| b.root.as_os_str().len().cmp(&a.root.as_os_str().len()))
+        })
+        .expect("should have copies");
+      report.push_str(&format!(
+        "Hint: the versions are compatible, aliasing \"{name}\" to {} with `resolve.alias` would dedupe them.",
+        contextify(context, &target.root.to_string_lossy())
+      ));
+    } else {
+      report.push_str(
+        "Hint: the versions are incompatible, align the version ranges required by the dependents to dedupe them.",
+      );
+    }
+    report
+  }
+}
+
+/// Groups the modules of the compilation by the name of the package they
+/// belong to, and the copy of the package they are bundled from.
+fn collect_packages(compilation: &Compilation) -> BTreeMap<String, Vec<PackageCopy>> {
+  let module_graph = compilation.get_module_graph();
+  let mut packages: BTreeMap<String, BTreeMap<PathBuf, PackageCopy>> = BTreeMap::default();
+
+  for (identifier, module) in module_graph.modules() {
+    let Some(normal_module) = module.as_normal_module() else {
+      continue;
+    };
+    let Some(description) = normal_module.resource_resolved_data().description() else {
+      continue;
+    };
+    let json = description.json();
+    let (Some(name), Some(version)) = (
+      json.get("name").and_then(|name| name.as_str()),
+      json.get("version").and_then(|version| version.as_str()),
+    ) else {
+      continue;
+    };
+    // the path of the description data is the directory of the package.json
+    let root = description.path();
+
+    let copy = packages
+      .entry(name.to_string())
+      .or_default()
+      .entry(root.to_path_buf())
+      .or_insert_with(|| PackageCopy {
+        version: version.to_string(),
+        root: root.to_path_buf(),
+        modules: vec![],
+        size: 0.0,
+      });
+    copy.modules.push(*identifier);
+    copy.size += module.size(None, Some(compilation));
+  }
+
+  packages
+    .into_iter()
+    .map(|(name, copies)| {
+      // nested package.json files of a package repeating its name are not separate copies
+      let mut merged: Vec<PackageCopy> = vec![];
+      for (root, mut copy) in copies {
+        if let Some(parent) = merged
+          .iter_mut()
+          .find(|parent| is_same_copy(&parent.root, &root))
+        {
+          parent.modules.append(&mut copy.modules);
+          parent.size += copy.size;
+        } else {
+          merged.push(copy);
+        }
+      }
+      for copy in &mut merged {
+        copy.modules.sort_unstable();
+      }
+      (name, merged)
+    })
+    .collect()
+}
+
+fn is_same_copy(root: &Path, nested: &Path) -> bool {
+  nested.strip_prefix(root).is_ok_and(|relative| {
+    !relative
+      .components()
+      .any(|component| component == Component::Normal("node_modules".as_ref()))
+  })
+}
+
+/// Returns the issuer chains of the modules a copy of a package is entered
+/// from, starting at the entry module.
+fn issuer_chains(copy: &PackageCopy, compilation: &Compilation) -> Vec<String> {
+  let module_graph = compilation.get_module_graph();
+  let context = &compilation.options.context;
+  let modules: HashSet<&ModuleIdentifier> = copy.modules.iter().collect();
+
+  copy
+    .modules
+    .iter()
+    .filter(|identifier| {
+      module_graph
+        .get_issuer(identifier)
+        .is_none_or(|issuer| !modules.contains(&issuer.identifier()))
+    })
+    .filter_map(|identifier| {
+      let mut chain = vec![];
+      let mut visited = HashSet::default();
+      let mut current = module_graph.module_by_identifier(identifier);
+      while let Some(module) = current
+        && visited.insert(module.identifier())
+      {
+        chain.push(module.readable_identifier(context).into_owned());
+        current = module_graph.get_issuer(&module.identifier());
+      }
+      (!chain.is_empty()).then(|| chain.into_iter().rev().join(" -> "))
+    })
+    .sorted()
+    .dedup()
+    .collect()
+}
+
+fn parse_version(version: &str) -> Vec<u64> {
+  version
+    .split(['-', '+'])
+    .next()
+    .unwrap_or_default()
+    .split('.')
+    .map(|part| part.parse().unwrap_or_default())
+    .collect()
+}
+
+/// Versions with the same result are compatible according to the caret range
+/// of semver, e.g. `1.2.0` and `1.3.0`, or `0.2.0` and `0.2.1`.
+fn compatible_range(version: &str) -> Vec<u64> {
+  let version = parse_version(version);
+  let significant = version
+    .iter()
+    .position(|part| *part != 0)
+    .unwrap_or(version.len().saturating_sub(1));
+  version.into_iter().take(significant + 1).collect()
+}
+
+#[plugin_hook(CompilationSeal for DuplicatePackageRspackPlugin)]
+async fn seal(&self, compilation: &Compilation, diagnostics: &mut Vec<Diagnostic>) -> Result<()> {
+  let logger = compilation.get_logger(self.name());
+  let start = logger.time("check duplicate packages");
+
+  let diagnostic_factory = if self.options.fail_on_error {
+    Diagnostic::error
+  } else {
+    Diagnostic::warn
+  };
+
+  for (name, copies) in collect_packages(compilation) {
+    if copies.len() < 2 || self.is_allowed(&name) {
+      continue;
+    }
+    diagnostics.push(diagnostic_factory(
+      "Duplicate Package".to_string(),
+      self.create_report(&name, &copies, compilation),
+    ));
+  }
+
+  logger.time_end(start);
+  Ok(())
+}
+
+impl Plugin for DuplicatePackageRspackPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.DuplicatePackageRspackPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compilation_hooks.seal.tap(seal::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_compatible_range() {
+    assert_eq!(compatible_range("1.2.3"), compatible_range("1.4.0"));
+    assert_ne!(compatible_range("1.2.3"), compatible_range("2.0.0"));
+    assert_eq!(compatible_range("0.2.0"), compatible_range("0.2.5"));
+    assert_ne!(compatible_range("0.2.0"), compatible_range("0.3.0"));
+    assert_ne!(compatible_range("0.0.1"), compatible_range("0.0.2"));
+    assert_eq!(compatible_range("1.0.0-beta.1"), compatible_range("1.1.0"));
+  }
+
+  #[test]
+  fn test_is_same_copy() {
+    assert!(is_same_copy(
+      Path::new("/app/node_modules/rxjs"),
+      Path::new("/app/node_modules/rxjs/operators")
+    ));
+    assert!(!is_same_copy(
+      Path::new("/app/node_modules/rxjs"),
+      Path::new("/app/node_modules/rxjs/node_modules/rxjs")
+    ));
+    assert!(!is_same_copy(
+      Path::new("/app/node_modules/a"),
+      Path::new("/app/node_modules/b")
+    ));
+  }
+}
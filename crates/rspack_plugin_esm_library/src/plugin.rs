@@ -54,6 +54,8 @@ pub static RSPACK_ESM_RUNTIME_CHUNK: &str = "RSPACK_ESM_RUNTIME";
 pub struct EsmLibraryPlugin {
   pub(crate) preserve_modules: Option<PathBuf>,
   pub(crate) split_chunks: Option<Vec<CacheGroup>>,
+  /// The chunks of preserved modules and their paths without extension.
+  pub(crate) preserved_chunks: AtomicRefCell<UkeyMap<ChunkUkey, String>>,
 
   // module instance will hold this map till compile done, we can't mutate it,
   // normal concatenateModule just read the info from it
@@ -83,6 +85,7 @@ impl EsmLibraryPlugin {
       Default::default(),
       Default::default(),
       Default::default(),
+      Default::default(),
     )
   }
 
@@ -741,10 +744,11 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
 async fn optimize_chunks(&self, compilation: &mut Compilation) -> Result<Option<bool>> {
   // check if we have to generate proxy chunks
   if let Some(preserve_modules_root) = &self.preserve_modules {
-    let errors = preserve_modules(preserve_modules_root, compilation).await;
+    let (errors, preserved_chunks) = preserve_modules(preserve_modules_root, compilation).await;
     if !errors.is_empty() {
       compilation.extend_diagnostics(errors);
     }
+    *self.preserved_chunks.borrow_mut() = preserved_chunks;
   } else if let Some(cache_groups) = &self.split_chunks {
     crate::split_chunks::split(cache_groups, compilation).await?;
   }
@@ -1,8 +1,8 @@
 use std::{borrow::Cow, path::Path, sync::LazyLock};
 
 use regex::Regex;
-use rspack_collections::{IdentifierMap, IdentifierSet};
-use rspack_core::Compilation;
+use rspack_collections::{Identifiable, IdentifierMap, IdentifierSet, UkeyMap};
+use rspack_core::{ChunkUkey, Compilation, SourceType};
 use rspack_util::fx_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 
@@ -47,11 +47,23 @@ pub fn entry_name_for_module(
   entry_name_for_module
 }
 
+/// The source type of the CSS modules of `CssExtractRspackPlugin`.
+const CSS_EXTRACT_SOURCE_TYPE: &str = "css/mini-extract";
+
+const STYLE_EXTENSIONS: &[&str] = &["css", "less", "sass", "scss", "styl", "stylus", "pcss"];
+
+/// Splits each module under `root` into its own chunk, whose file mirrors the path of the module
+/// relative to `root`.
+///
+/// Returns the preserved chunks and their paths without extension. The CSS of a chunk is emitted
+/// to a side file at the path, e.g. `button.css` next to `button.mjs`.
 pub async fn preserve_modules(
   root: &Path,
   compilation: &mut Compilation,
-) -> Vec<rspack_error::Diagnostic> {
+) -> (Vec<rspack_error::Diagnostic>, UkeyMap<ChunkUkey, String>) {
   let mut errors = vec![];
+  let mut preserved_chunks = UkeyMap::default();
+  let mut module_chunks = IdentifierMap::default();
   let modules = compilation
     .get_module_graph()
     .modules_keys()
@@ -114,17 +126,29 @@ pub async fn preserve_modules(
             Cow::Borrowed(".js")
           }
         });
-      let new_filename = if let Some(extension) = extension {
-        let ext_lossy = extension.to_string_lossy();
-        let file_path_lossy = file_path.to_string_lossy();
-        let suffix = format!(".{ext_lossy}");
-        let base = file_path_lossy
-          .strip_suffix(&suffix)
-          .unwrap_or(&file_path_lossy);
-        format!("{base}{new_extension}").into()
-      } else {
-        file_path.to_string_lossy().to_string().into()
+      let file_path_lossy = file_path.to_slash_lossy();
+      let (base, new_filename) = match extension.map(|extension| extension.to_string_lossy()) {
+        // keep the extension of styles, so `a.css` doesn't conflict with `a.js`
+        Some(extension) if STYLE_EXTENSIONS.contains(&extension.as_ref()) => {
+          let base = file_path_lossy
+            .strip_suffix(&format!(".{extension}"))
+            .unwrap_or(&file_path_lossy);
+          (
+            base.to_string(),
+            format!("{base}.{extension}{new_extension}"),
+          )
+        }
+        Some(extension) => {
+          let base = file_path_lossy
+            .strip_suffix(&format!(".{extension}"))
+            .unwrap_or(&file_path_lossy)
+            .to_string();
+          let new_filename = format!("{base}{new_extension}");
+          (base, new_filename)
+        }
+        None => (file_path_lossy.to_string(), file_path_lossy.to_string()),
       };
+      let new_filename = new_filename.into();
 
       if compilation
         .build_chunk_graph_artifact
@@ -135,6 +159,9 @@ pub async fn preserve_modules(
       {
         // this is last module in chunk, we can keep this chunk, just rename it
         old_chunk.set_filename_template(Some(new_filename));
+        old_chunk.set_css_filename_template(Some(format!("{base}.css").into()));
+        preserved_chunks.insert(chunk, base);
+        module_chunks.insert(module_id, chunk);
         continue;
       }
 
@@ -153,6 +180,9 @@ pub async fn preserve_modules(
       };
 
       new_chunk.set_filename_template(Some(new_filename));
+      new_chunk.set_css_filename_template(Some(format!("{base}.css").into()));
+      preserved_chunks.insert(new_chunk_ukey, base);
+      module_chunks.insert(module_id, new_chunk_ukey);
       old_chunk.split(
         new_chunk,
         &mut compilation.build_chunk_graph_artifact.chunk_group_by_ukey,
@@ -206,5 +236,66 @@ pub async fn preserve_modules(
     }
   }
 
-  errors
+  move_derived_modules(compilation, &module_chunks);
+
+  (errors, preserved_chunks)
+}
+
+/// Moves the modules created by preserved modules but not from a file, e.g. the CSS modules of
+/// `CssExtractRspackPlugin`, to the chunks of the modules which create them, so the CSS of a
+/// preserved module is emitted next to it.
+fn move_derived_modules(compilation: &mut Compilation, module_chunks: &IdentifierMap<ChunkUkey>) {
+  let module_graph = compilation.get_module_graph();
+  let mut moves = vec![];
+  for (module_id, module) in module_graph.modules() {
+    if module.as_normal_module().is_some() {
+      continue;
+    }
+    let Some(target) = module_graph
+      .get_issuer(module_id)
+      .and_then(|issuer| module_chunks.get(&issuer.identifier()))
+    else {
+      continue;
+    };
+    let chunks = compilation
+      .build_chunk_graph_artifact
+      .chunk_graph
+      .get_module_chunks(*module_id)
+      .iter()
+      .filter(|chunk| *chunk != target)
+      .copied()
+      .collect::<Vec<_>>();
+    if !chunks.is_empty() {
+      moves.push((*module_id, *target, chunks));
+    }
+  }
+
+  let chunk_graph = &mut compilation.build_chunk_graph_artifact.chunk_graph;
+  for (module_id, target, chunks) in moves {
+    for chunk in chunks {
+      chunk_graph.disconnect_chunk_and_module(&chunk, module_id);
+    }
+    chunk_graph.connect_chunk_and_module(target, module_id);
+  }
+}
+
+/// Renders the import of the CSS side file of a preserved chunk, e.g. `import "./button.css";`,
+/// so the styles are still loaded with the module.
+pub fn render_style_import(
+  compilation: &Compilation,
+  chunk_ukey: &ChunkUkey,
+  base: &str,
+) -> Option<String> {
+  let module_graph = compilation.get_module_graph();
+  let chunk_graph = &compilation.build_chunk_graph_artifact.chunk_graph;
+  let has_css = [SourceType::Css, SourceType::from(CSS_EXTRACT_SOURCE_TYPE)]
+    .into_iter()
+    .any(|source_type| {
+      chunk_graph.has_chunk_module_by_source_type(chunk_ukey, source_type, module_graph)
+    });
+  if !has_css {
+    return None;
+  }
+  let name = Path::new(base).file_name()?.to_string_lossy();
+  Some(format!("import \"./{name}.css\";\n"))
 }
@@ -24,6 +24,7 @@ use rspack_util::{
 use crate::{
   chunk_link::{ChunkLinkContext, ReExportFrom, Ref},
   plugin::RSPACK_ESM_RUNTIME_CHUNK,
+  preserve_modules::render_style_import,
   runtime::EsmRegisterModuleRuntimeModule,
 };
 
@@ -351,6 +352,13 @@ var {} = {{}};
       }
     }
 
+    // the CSS side file of a preserved module is imported first, like the import in its source
+    if let Some(base) = self.preserved_chunks.borrow().get(chunk_ukey)
+      && let Some(style_import) = render_style_import(compilation, chunk_ukey, base)
+    {
+      import_source.add(RawStringSource::from(style_import));
+    }
+
     if !runtime_requirements.is_empty() {
       let runtime_chunk = Self::get_runtime_chunk(*chunk_ukey, compilation);
       if &runtime_chunk != chunk_ukey && runtime_requirements.contains(RuntimeGlobals::REQUIRE) {
@@ -569,7 +569,11 @@ async fn runtime_requirement_in_tree(
             .content_hash(&compilation.chunk_hashes_artifact)?
             .contains_key(&SOURCE_TYPE[0])
             .then(|| {
-              if chunk.can_be_initial(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey) {
+              if let Some(css_filename_template) = chunk.css_filename_template() {
+                css_filename_template.clone()
+              } else if chunk
+                .can_be_initial(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey)
+              {
                 filename.clone()
               } else {
                 chunk_filename.clone()
@@ -664,12 +668,14 @@ async fn render_manifest(
     return Ok(());
   }
 
-  let filename_template =
-    if chunk.can_be_initial(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey) {
-      &self.options.filename
-    } else {
-      &self.options.chunk_filename
-    };
+  // the css filename of the chunk takes precedence, e.g. the side files of preserved modules
+  let filename_template = if let Some(css_filename_template) = chunk.css_filename_template() {
+    css_filename_template
+  } else if chunk.can_be_initial(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey) {
+    &self.options.filename
+  } else {
+    &self.options.chunk_filename
+  };
 
   let mut asset_info =
     AssetInfo::default().with_asset_type(ManifestAssetType::Custom("extract-css".into()));
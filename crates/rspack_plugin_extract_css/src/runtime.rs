@@ -199,6 +199,7 @@ impl RuntimeModule for CssLoadingRuntimeModule {
       &self.template_id(TemplateId::Raw),
       Some(serde_json::json!({
         "_create_link": &create_link.code,
+        "_with_retry": runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY_URL),
         "_insert": match &self.insert {
           InsertType::Fn(f) => format!("({f})(linkTag);"),
           InsertType::Selector(sel) => format!("var target = document.querySelector({sel});\ntarget.parentNode.insertBefore(linkTag, target.nextSibling);"),
@@ -1,6 +1,6 @@
 use std::{
   borrow::Cow,
-  collections::HashMap,
+  collections::{HashMap, HashSet, VecDeque},
   env,
   hash::Hasher,
   path::{Path, PathBuf},
@@ -11,8 +11,8 @@ use cow_utils::CowUtils;
 use itertools::Itertools;
 use rayon::prelude::*;
 use rspack_core::{
-  AssetInfo, Compilation, CompilationAsset, Filename, PathData,
-  rspack_sources::{RawBufferSource, RawStringSource, SourceExt},
+  AssetInfo, ChunkGroupOrderKey, ChunkGroupUkey, Compilation, CompilationAsset, Filename, PathData,
+  rspack_sources::{RawBufferSource, RawStringSource, Source, SourceExt},
 };
 use rspack_error::{AnyhowResultToRspackResultExt, Result};
 use rspack_hash::RspackHash;
@@ -21,7 +21,9 @@ use serde::{Deserialize, Serialize};
 use sugar_path::SugarPath;
 
 use crate::{
-  config::{HtmlChunkSortMode, HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading},
+  config::{
+    HtmlChunkSortMode, HtmlInject, HtmlRspackPluginOptions, HtmlScriptLoading, HtmlSpeculationRules,
+  },
   tag::HtmlPluginTag,
 };
 
@@ -32,6 +34,12 @@ pub struct HtmlPluginAssets {
   pub js: Vec<String>,
   pub css: Vec<String>,
   pub favicon: Option<String>,
+  /// The content of the import map emitted with `output.importMap`.
+  pub import_map: Option<String>,
+  /// The chunks statically imported by the entry chunks, injected as `modulepreload` links.
+  pub module_preload: Vec<String>,
+  /// The speculation rules for the named async chunks reachable from the entries.
+  pub speculation_rules: Option<String>,
   // manifest: Option<String>,
   pub js_integrity: Option<Vec<Option<String>>>,
   pub css_integrity: Option<Vec<Option<String>>>,
@@ -96,6 +104,36 @@ impl HtmlPluginAssets {
       })
       .collect::<Vec<_>>();
 
+    // the entry chunks of esm entries statically import the other chunks of the entries,
+    // which only need to be preloaded instead of being injected as scripts, other chunk
+    // formats don't import them, so they are still injected as scripts
+    let module_preload_assets: HashSet<&String> = if config.module_preload.unwrap_or_default()
+      && matches!(config.script_loading, HtmlScriptLoading::Module)
+      && compilation.options.output.module
+    {
+      let chunk_by_ukey = &compilation.build_chunk_graph_artifact.chunk_by_ukey;
+      let entrypoints = sorted_entry_names
+        .iter()
+        .map(|entry_name| compilation.entrypoint_by_name(entry_name))
+        .collect::<Vec<_>>();
+      let entry_chunk_files = entrypoints
+        .iter()
+        .flat_map(|entry| {
+          chunk_by_ukey
+            .expect_get(&entry.get_entrypoint_chunk())
+            .files()
+        })
+        .collect::<HashSet<_>>();
+      entrypoints
+        .iter()
+        .flat_map(|entry| entry.chunks.iter())
+        .flat_map(|chunk_ukey| chunk_by_ukey.expect_get(chunk_ukey).files())
+        .filter(|file| !entry_chunk_files.contains(file))
+        .collect()
+    } else {
+      Default::default()
+    };
+
     for (asset_name, asset) in included_assets {
       if let Some(extension) =
         Path::new(asset_name.split("?").next().unwrap_or_default()).extension()
@@ -115,7 +153,11 @@ impl HtmlPluginAssets {
           // keep the `if` to make the code more readable
           #[allow(clippy::collapsible_if)]
           if asset_map.insert(final_path.to_string(), asset).is_none() {
-            assets.js.push(final_path.to_string());
+            if module_preload_assets.contains(&asset_name) {
+              assets.module_preload.push(final_path.to_string());
+            } else {
+              assets.js.push(final_path.to_string());
+            }
           }
         }
       }
@@ -167,10 +209,83 @@ impl HtmlPluginAssets {
       None
     };
 
+    assets.import_map = compilation
+      .options
+      .output
+      .import_map
+      .as_ref()
+      .and_then(|import_map| compilation.assets().get(&import_map.filename))
+      .and_then(|asset| asset.get_source())
+      .map(|source| source.source().into_string_lossy().into_owned());
+
+    assets.speculation_rules = config
+      .speculation_rules
+      .as_ref()
+      .and_then(|speculation_rules| {
+        create_speculation_rules(speculation_rules, compilation, &sorted_entry_names)
+      });
+
     Ok((assets, asset_map))
   }
 }
 
+/// Maps the named async chunks which are prefetched or preloaded from the entries
+/// to `prefetch` rules for the configured page urls. Prerendering runs the scripts of
+/// the pages, which preloading a chunk doesn't imply, so no `prerender` rules are created.
+fn create_speculation_rules(
+  speculation_rules: &HtmlSpeculationRules,
+  compilation: &Compilation,
+  entry_names: &[&String],
+) -> Option<String> {
+  let chunk_group_by_ukey = &compilation.build_chunk_graph_artifact.chunk_group_by_ukey;
+  let mut urls: Vec<&str> = vec![];
+
+  let mut visited: HashSet<ChunkGroupUkey> = HashSet::new();
+  let mut queue: VecDeque<ChunkGroupUkey> = entry_names
+    .iter()
+    .map(|entry_name| compilation.entrypoint_by_name(entry_name).ukey)
+    .collect();
+  while let Some(chunk_group_ukey) = queue.pop_front() {
+    if !visited.insert(chunk_group_ukey) {
+      continue;
+    }
+    let Some(chunk_group) = chunk_group_by_ukey.get(&chunk_group_ukey) else {
+      continue;
+    };
+    let children_by_orders = chunk_group.get_children_by_orders(compilation);
+    for children in [ChunkGroupOrderKey::Prefetch, ChunkGroupOrderKey::Preload]
+      .iter()
+      .filter_map(|order_key| children_by_orders.get(order_key))
+    {
+      for child_urls in children
+        .iter()
+        .filter_map(|child_ukey| chunk_group_by_ukey.get(child_ukey))
+        .filter_map(|child| child.name())
+        .filter_map(|name| speculation_rules.urls.get(name))
+      {
+        for url in child_urls {
+          if !urls.contains(&url.as_str()) {
+            urls.push(url);
+          }
+        }
+      }
+    }
+    queue.extend(chunk_group.children_iterable().copied());
+  }
+
+  if urls.is_empty() {
+    return None;
+  }
+  let mut rule = serde_json::json!({
+    "source": "list",
+    "urls": urls,
+  });
+  if let Some(eagerness) = &speculation_rules.eagerness {
+    rule["eagerness"] = eagerness.as_str().into();
+  }
+  Some(serde_json::json!({ "prefetch": [rule] }).to_string())
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct HtmlPluginAssetTags {
   pub scripts: Vec<HtmlPluginTag>,
@@ -200,6 +315,13 @@ impl HtmlPluginAssetTags {
         .collect::<Vec<_>>(),
     );
 
+    // create import map tag, which has to precede the module scripts resolved by it
+    if let Some(import_map) = &assets.import_map {
+      asset_tags
+        .meta
+        .push(HtmlPluginTag::create_import_map(import_map));
+    }
+
     // create base tag
     if let Some(base) = &config.base
       && let Some(tag) = HtmlPluginTag::create_base(base)
@@ -222,6 +344,21 @@ impl HtmlPluginAssetTags {
       asset_tags.meta.push(HtmlPluginTag::create_favicon(favicon));
     }
 
+    // create modulepreload tags
+    asset_tags.meta.extend(
+      assets
+        .module_preload
+        .iter()
+        .map(|x| HtmlPluginTag::create_module_preload(x.as_str())),
+    );
+
+    // create speculation rules tag
+    if let Some(speculation_rules) = &assets.speculation_rules {
+      asset_tags
+        .meta
+        .push(HtmlPluginTag::create_speculation_rules(speculation_rules));
+    }
+
     asset_tags
   }
 
@@ -138,6 +138,15 @@ impl FromStr for HtmlChunkSortMode {
   }
 }
 
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HtmlSpeculationRules {
+  /// async chunk name to the urls of the pages loading it
+  pub urls: FxHashMap<String, Vec<String>>,
+  /// `immediate`, `eager`, `moderate` or `conservative`
+  pub eagerness: Option<String>,
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct HtmlRspackPluginOptions {
@@ -172,6 +181,9 @@ pub struct HtmlRspackPluginOptions {
   pub meta: Option<FxHashMap<String, FxHashMap<String, String>>>,
   pub hash: Option<bool>,
   pub base: Option<HtmlRspackPluginBaseOptions>,
+  /// inject the chunks imported by the entry chunks as `modulepreload` links
+  pub module_preload: Option<bool>,
+  pub speculation_rules: Option<HtmlSpeculationRules>,
   /// uid is used to identify the plugin instance on javascript side
   pub uid: Option<u32>,
 }
@@ -212,6 +224,8 @@ impl Default for HtmlRspackPluginOptions {
       meta: None,
       hash: None,
       base: None,
+      module_preload: None,
+      speculation_rules: None,
       uid: None,
     }
   }
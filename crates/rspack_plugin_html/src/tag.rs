@@ -153,6 +153,49 @@ impl HtmlPluginTag {
     }
   }
 
+  pub fn create_module_preload(href: &str) -> HtmlPluginTag {
+    HtmlPluginTag {
+      tag_name: "link".to_string(),
+      attributes: vec![
+        HtmlPluginAttribute {
+          attr_name: "rel".to_string(),
+          attr_value: Some("modulepreload".to_string()),
+        },
+        HtmlPluginAttribute {
+          attr_name: "href".to_string(),
+          attr_value: Some(href.to_string()),
+        },
+      ],
+      void_tag: true,
+      asset: Some(href.to_string()),
+      ..Default::default()
+    }
+  }
+
+  pub fn create_speculation_rules(speculation_rules: &str) -> HtmlPluginTag {
+    HtmlPluginTag {
+      tag_name: "script".to_string(),
+      attributes: vec![HtmlPluginAttribute {
+        attr_name: "type".to_string(),
+        attr_value: Some("speculationrules".to_string()),
+      }],
+      inner_html: Some(speculation_rules.to_string()),
+      ..Default::default()
+    }
+  }
+
+  pub fn create_import_map(import_map: &str) -> HtmlPluginTag {
+    HtmlPluginTag {
+      tag_name: "script".to_string(),
+      attributes: vec![HtmlPluginAttribute {
+        attr_name: "type".to_string(),
+        attr_value: Some("importmap".to_string()),
+      }],
+      inner_html: Some(import_map.to_string()),
+      ..Default::default()
+    }
+  }
+
   pub fn create_base(base: &HtmlRspackPluginBaseOptions) -> Option<HtmlPluginTag> {
     let mut attributes = vec![];
 
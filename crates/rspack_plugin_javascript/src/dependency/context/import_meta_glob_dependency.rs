@@ -0,0 +1,323 @@
+use std::{collections::BTreeMap, path::Path};
+
+use itertools::Itertools;
+use rspack_cacheable::{cacheable, cacheable_dyn};
+use rspack_core::{
+  AsModuleDependency, ContextDependency, ContextMode, ContextOptions, DependenciesBlock,
+  Dependency, DependencyCategory, DependencyCodeGeneration, DependencyId, DependencyRange,
+  DependencyTemplate, DependencyTemplateType, DependencyType, ExportsInfoArtifact, FactorizeInfo,
+  ModuleGraph, ModuleGraphCacheArtifact, ModuleIdentifier, ResourceIdentifier, TemplateContext,
+  TemplateReplaceSource,
+};
+use rspack_error::Diagnostic;
+use rspack_util::json_stringify;
+use rustc_hash::FxHashMap;
+use sugar_path::SugarPath;
+
+use super::create_resource_identifier_for_context_dependency;
+
+/// How the keys of the glob object are created from the paths of the matched modules, like Vite.
+#[cacheable]
+#[derive(Debug, Clone)]
+pub enum ImportMetaGlobKeys {
+  /// Relative to the root for root-absolute patterns, e.g. `/src/a.js`.
+  Root(String),
+  /// Relative to the directory of the importer, e.g. `./a.js` or `../lib/a.js`.
+  Importer(String),
+}
+
+impl ImportMetaGlobKeys {
+  fn key(&self, path: &Path) -> String {
+    match self {
+      Self::Root(root) => format!("/{}", path.relative(root).to_slash_lossy()),
+      Self::Importer(dir) => {
+        let key = path.relative(dir).to_slash_lossy().into_owned();
+        if key.starts_with("../") {
+          key
+        } else {
+          format!("./{key}")
+        }
+      }
+    }
+  }
+}
+
+#[cacheable]
+#[derive(Debug, Clone)]
+pub struct ImportMetaGlobDependency {
+  id: DependencyId,
+  options: ContextOptions,
+  range: DependencyRange,
+  resource_identifier: ResourceIdentifier,
+  optional: bool,
+  critical: Option<Diagnostic>,
+  factorize_info: FactorizeInfo,
+  keys: ImportMetaGlobKeys,
+  // the export picked from each matched module, `None` for the namespace object
+  import: Option<String>,
+  // the dependencies of the other base directories of the same call, whose modules are rendered
+  // by this one, `None` when this one is rendered by a sibling
+  siblings: Option<Vec<DependencyId>>,
+}
+
+impl ImportMetaGlobDependency {
+  pub fn new(
+    options: ContextOptions,
+    range: DependencyRange,
+    optional: bool,
+    keys: ImportMetaGlobKeys,
+    import: Option<String>,
+  ) -> Self {
+    let resource_identifier = create_resource_identifier_for_context_dependency(None, &options);
+    Self {
+      options,
+      range,
+      resource_identifier,
+      optional,
+      keys,
+      import,
+      id: DependencyId::new(),
+      critical: None,
+      factorize_info: Default::default(),
+      siblings: Some(Vec::new()),
+    }
+  }
+
+  pub fn set_siblings(&mut self, siblings: Vec<DependencyId>) {
+    self.siblings = Some(siblings);
+  }
+
+  pub fn set_rendered_by_sibling(&mut self) {
+    self.siblings = None;
+  }
+}
+
+#[cacheable_dyn]
+impl Dependency for ImportMetaGlobDependency {
+  fn id(&self) -> &DependencyId {
+    &self.id
+  }
+
+  fn category(&self) -> &DependencyCategory {
+    &DependencyCategory::Esm
+  }
+
+  fn dependency_type(&self) -> &DependencyType {
+    &DependencyType::ImportMetaGlob
+  }
+
+  fn range(&self) -> Option<DependencyRange> {
+    Some(self.range)
+  }
+
+  // the keys of the glob object are rendered from the elements of the context module
+  fn could_affect_referencing_module(&self) -> rspack_core::AffectType {
+    rspack_core::AffectType::True
+  }
+
+  fn get_diagnostics(
+    &self,
+    _module_graph: &ModuleGraph,
+    _module_graph_cache: &ModuleGraphCacheArtifact,
+    _exports_info_artifact: &ExportsInfoArtifact,
+  ) -> Option<Vec<Diagnostic>> {
+    if let Some(critical) = self.critical() {
+      return Some(vec![critical.clone()]);
+    }
+    None
+  }
+}
+
+impl ContextDependency for ImportMetaGlobDependency {
+  fn request(&self) -> &str {
+    &self.options.request
+  }
+
+  fn options(&self) -> &ContextOptions {
+    &self.options
+  }
+
+  fn get_context(&self) -> Option<&str> {
+    None
+  }
+
+  fn resource_identifier(&self) -> &str {
+    &self.resource_identifier
+  }
+
+  fn get_optional(&self) -> bool {
+    self.optional
+  }
+
+  fn type_prefix(&self) -> rspack_core::ContextTypePrefix {
+    rspack_core::ContextTypePrefix::Normal
+  }
+
+  fn critical(&self) -> &Option<Diagnostic> {
+    &self.critical
+  }
+
+  fn critical_mut(&mut self) -> &mut Option<Diagnostic> {
+    &mut self.critical
+  }
+
+  fn factorize_info(&self) -> &FactorizeInfo {
+    &self.factorize_info
+  }
+
+  fn factorize_info_mut(&mut self) -> &mut FactorizeInfo {
+    &mut self.factorize_info
+  }
+}
+
+#[cacheable_dyn]
+impl DependencyCodeGeneration for ImportMetaGlobDependency {
+  fn dependency_template(&self) -> Option<DependencyTemplateType> {
+    self
+      .siblings
+      .is_some()
+      .then(ImportMetaGlobDependencyTemplate::template_type)
+  }
+}
+
+impl AsModuleDependency for ImportMetaGlobDependency {}
+
+/// Collects the requests of the modules in the context module, the context module factory adds
+/// alternative requests like `./a` for `./a.js`, only the longest one (the file path) is kept.
+fn get_glob_requests<'a>(
+  module_graph: &'a ModuleGraph,
+  dep_id: &DependencyId,
+) -> Option<Vec<&'a str>> {
+  let module = module_graph.get_module_by_dependency_id(dep_id)?;
+  let element_dependencies = module.get_dependencies().iter().chain(
+    module
+      .get_blocks()
+      .iter()
+      .filter_map(|block| module_graph.block_by_id(block))
+      .flat_map(|block| block.get_dependencies()),
+  );
+  let mut requests: FxHashMap<ModuleIdentifier, &str> = FxHashMap::default();
+  for dep_id in element_dependencies {
+    let Some(module_identifier) = module_graph.module_identifier_by_dependency_id(dep_id) else {
+      continue;
+    };
+    let Some(dependency) = module_graph.dependency_by_id(dep_id).as_module_dependency() else {
+      continue;
+    };
+    let request = dependency.user_request();
+    requests
+      .entry(*module_identifier)
+      .and_modify(|existing| {
+        if request.len() > existing.len() {
+          *existing = request;
+        }
+      })
+      .or_insert(request);
+  }
+  Some(requests.into_values().sorted_unstable().collect())
+}
+
+#[cacheable]
+#[derive(Debug, Clone, Default)]
+pub struct ImportMetaGlobDependencyTemplate;
+
+impl ImportMetaGlobDependencyTemplate {
+  pub fn template_type() -> DependencyTemplateType {
+    DependencyTemplateType::Dependency(DependencyType::ImportMetaGlob)
+  }
+}
+
+impl DependencyTemplate for ImportMetaGlobDependencyTemplate {
+  fn render(
+    &self,
+    dep: &dyn DependencyCodeGeneration,
+    source: &mut TemplateReplaceSource,
+    code_generatable_context: &mut TemplateContext,
+  ) {
+    let dep = dep
+      .as_any()
+      .downcast_ref::<ImportMetaGlobDependency>()
+      .expect("ImportMetaGlobDependencyTemplate should be used for ImportMetaGlobDependency");
+
+    let TemplateContext {
+      compilation,
+      runtime_template,
+      ..
+    } = code_generatable_context;
+
+    let module_graph = compilation.get_module_graph();
+    let dependencies = std::iter::once(dep)
+      .chain(dep.siblings.iter().flatten().filter_map(|id| {
+        module_graph
+          .dependency_by_id(id)
+          .downcast_ref::<ImportMetaGlobDependency>()
+      }))
+      .collect::<Vec<_>>();
+    let single = dependencies.len() == 1;
+    let eager = matches!(dep.options.mode, ContextMode::Sync);
+    let import = dep
+      .import
+      .as_ref()
+      .map(|import| format!("[{}]", json_stringify(import)));
+
+    let mut params = Vec::with_capacity(dependencies.len());
+    let mut contexts = Vec::with_capacity(dependencies.len());
+    let mut entries = BTreeMap::new();
+    for (index, dependency) in dependencies.into_iter().enumerate() {
+      let context = runtime_template.module_raw(
+        compilation,
+        &dependency.id,
+        &dependency.options.request,
+        dependency.optional,
+      );
+      let Some(requests) = get_glob_requests(module_graph, &dependency.id) else {
+        // the context module is missing, `context` throws the error
+        source.replace(dep.range.start, dep.range.end, &context, None);
+        return;
+      };
+      let param = if single {
+        "ctx".to_string()
+      } else {
+        format!("ctx{index}")
+      };
+      for request in requests {
+        let key = dep.keys.key(
+          &Path::new(&dependency.options.context)
+            .join(request)
+            .normalize(),
+        );
+        // a module matched in several base directories is loaded from the first one
+        if entries.contains_key(&key) {
+          continue;
+        }
+        let load = format!("{param}({})", json_stringify(request));
+        let value = match (eager, &import) {
+          (true, Some(import)) => format!("{load}{import}"),
+          (true, None) => load,
+          (false, Some(import)) => runtime_template.returning_function(
+            &format!(
+              "{load}.then({})",
+              runtime_template.returning_function(&format!("m{import}"), "m")
+            ),
+            "",
+          ),
+          (false, None) => runtime_template.returning_function(&load, ""),
+        };
+        entries.insert(key, value);
+      }
+      params.push(param);
+      contexts.push(context);
+    }
+    let entries = entries
+      .into_iter()
+      .map(|(key, value)| format!("{}: {value}", json_stringify(&key)))
+      .join(",\n");
+
+    let content = format!(
+      "({})({})",
+      runtime_template.basic_function(&params.join(", "), &format!("return {{\n{entries}\n}};")),
+      contexts.join(", ")
+    );
+    source.replace(dep.range.start, dep.range.end, &content, None);
+  }
+}
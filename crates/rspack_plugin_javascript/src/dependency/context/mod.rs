@@ -2,6 +2,7 @@ mod amd_require_context_dependency;
 mod common_js_require_context_dependency;
 mod import_context_dependency;
 mod import_meta_context_dependency;
+mod import_meta_glob_dependency;
 mod require_context_dependency;
 mod require_resolve_context_dependency;
 mod url_context_dependency;
@@ -16,6 +17,9 @@ pub use import_context_dependency::{ImportContextDependency, ImportContextDepend
 pub use import_meta_context_dependency::{
   ImportMetaContextDependency, ImportMetaContextDependencyTemplate,
 };
+pub use import_meta_glob_dependency::{
+  ImportMetaGlobDependency, ImportMetaGlobDependencyTemplate, ImportMetaGlobKeys,
+};
 use itertools::Itertools;
 pub use require_context_dependency::{RequireContextDependency, RequireContextDependencyTemplate};
 pub use require_resolve_context_dependency::{
@@ -4,7 +4,6 @@ mod walk_data;
 
 use std::sync::Arc;
 
-use parser::DefineParserPlugin;
 use rspack_core::{
   Compilation, CompilationParams, CompilerCompilation, ModuleType, NormalModuleFactoryParser,
   ParserAndGenerator, ParserOptions, Plugin,
@@ -14,7 +13,7 @@ use rspack_hook::{plugin, plugin_hook};
 use rustc_hash::FxHashMap;
 use serde_json::Value;
 
-use self::walk_data::WalkData;
+pub use self::{parser::DefineParserPlugin, walk_data::WalkData};
 use crate::parser_and_generator::JavaScriptParserAndGenerator;
 
 const VALUE_DEP_PREFIX: &str = "rspack/DefinePlugin ";
@@ -46,14 +45,11 @@ impl DefinePlugin {
   }
 }
 
-#[plugin_hook(CompilerCompilation for DefinePlugin, tracing=false)]
-async fn compilation(
-  &self,
-  compilation: &mut Compilation,
-  _params: &mut CompilationParams,
-) -> Result<()> {
-  compilation.extend_diagnostics(self.walk_data.diagnostics.clone());
-  for (key, value) in self.walk_data.tiling_definitions.iter() {
+/// Record the definitions in `value_cache_versions`, so modules that use them
+/// are rebuilt when their values change, and report conflicting definitions.
+pub fn register_value_cache_versions(walk_data: &WalkData, compilation: &mut Compilation) {
+  compilation.extend_diagnostics(walk_data.diagnostics.clone());
+  for (key, value) in walk_data.tiling_definitions.iter() {
     let cache_key = format!("{VALUE_DEP_PREFIX}{key}");
     if let Some(prev) = compilation.value_cache_versions.get(&cache_key)
       && prev != value
@@ -67,7 +63,15 @@ async fn compilation(
         .insert(cache_key, value.clone());
     }
   }
+}
 
+#[plugin_hook(CompilerCompilation for DefinePlugin, tracing=false)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  register_value_cache_versions(&self.walk_data, compilation);
   Ok(())
 }
 
@@ -0,0 +1,575 @@
+use std::path::{Path, PathBuf};
+
+use rspack_core::{
+  ContextMode, ContextNameSpaceObject, ContextOptions, Dependency, DependencyCategory,
+};
+use rspack_error::Error;
+use rspack_regex::RspackRegex;
+use rspack_util::{SpanExt, ryu_js};
+use sugar_path::SugarPath;
+use swc_core::{
+  common::Spanned,
+  ecma::ast::{CallExpr, Expr, PropName},
+};
+
+use super::JavascriptParserPlugin;
+use crate::{
+  dependency::{ImportMetaGlobDependency, ImportMetaGlobKeys},
+  utils::{
+    eval::{self, BasicEvaluatedExpression},
+    object_properties::{get_bool_by_obj_prop, get_literal_str_by_obj_prop, get_value_by_obj_prop},
+  },
+  visitors::{JavascriptParser, create_traceable_error, expr_name},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+struct GlobPattern<'a> {
+  negated: bool,
+  // the directory before the first segment with glob characters, e.g. `./dir` for `./dir/*.js`
+  dir: &'a str,
+  // the rest of the pattern, e.g. `*.js` for `./dir/*.js`
+  glob: &'a str,
+}
+
+fn is_glob_segment(segment: &str) -> bool {
+  segment.contains(['*', '?', '[', '{'])
+}
+
+fn parse_glob_pattern(pattern: &str) -> GlobPattern<'_> {
+  let (negated, pattern) = pattern
+    .strip_prefix('!')
+    .map_or((false, pattern), |pattern| (true, pattern));
+  let mut dir_end = 0;
+  let mut segment_start = 0;
+  for segment in pattern.split('/') {
+    let segment_end = segment_start + segment.len();
+    if segment_end == pattern.len() || is_glob_segment(segment) {
+      break;
+    }
+    segment_start = segment_end + 1;
+    dir_end = segment_start;
+  }
+  let dir = &pattern[..dir_end];
+  GlobPattern {
+    negated,
+    // keep the `/` of root-absolute patterns like `/*.js`
+    dir: if dir.len() > 1 {
+      dir.trim_end_matches('/')
+    } else {
+      dir
+    },
+    glob: &pattern[dir_end..],
+  }
+}
+
+/// Converts a glob to a regexp source matching paths relative to the directory of the glob.
+fn glob_to_reg_exp(glob: &str) -> String {
+  let mut reg_exp = String::with_capacity(glob.len() * 2);
+  let mut chars = glob.chars().peekable();
+  let mut braces = 0;
+  while let Some(c) = chars.next() {
+    match c {
+      '*' if chars.peek() == Some(&'*') => {
+        chars.next();
+        if chars.peek() == Some(&'/') {
+          chars.next();
+          reg_exp.push_str("(?:[^/]*/)*");
+        } else {
+          reg_exp.push_str(".*");
+        }
+      }
+      '*' => reg_exp.push_str("[^/]*"),
+      '?' => reg_exp.push_str("[^/]"),
+      '{' => {
+        braces += 1;
+        reg_exp.push_str("(?:");
+      }
+      '}' if braces > 0 => {
+        braces -= 1;
+        reg_exp.push(')');
+      }
+      ',' if braces > 0 => reg_exp.push('|'),
+      '[' => {
+        reg_exp.push('[');
+        if chars.peek() == Some(&'!') {
+          chars.next();
+          reg_exp.push('^');
+        }
+        for c in chars.by_ref() {
+          if c == '\\' {
+            reg_exp.push('\\');
+          }
+          reg_exp.push(c);
+          if c == ']' {
+            break;
+          }
+        }
+      }
+      '.' | '+' | '(' | ')' | '|' | '^' | '$' | '\\' | ']' | '}' => {
+        reg_exp.push('\\');
+        reg_exp.push(c);
+      }
+      c => reg_exp.push(c),
+    }
+  }
+  reg_exp
+}
+
+/// Escapes a path relative to the context to match it literally.
+fn escape_reg_exp(path: &str) -> String {
+  let mut reg_exp = String::with_capacity(path.len() * 2);
+  for c in path.chars() {
+    if matches!(
+      c,
+      '.' | '+' | '*' | '?' | '(' | ')' | '|' | '^' | '$' | '\\' | '[' | ']' | '{' | '}'
+    ) {
+      reg_exp.push('\\');
+    }
+    reg_exp.push(c);
+  }
+  reg_exp
+}
+
+fn to_request(path: &Path) -> String {
+  let path = path.to_slash_lossy();
+  if path.is_empty() {
+    String::new()
+  } else {
+    format!("{path}/")
+  }
+}
+
+#[derive(Debug)]
+struct GlobContext {
+  /// the absolute base directory of the patterns
+  context: String,
+  reg_exp: String,
+  recursive: bool,
+}
+
+#[derive(Debug)]
+struct ImportMetaGlob {
+  /// a context for each base directory of the positive patterns, so a pattern never lists the
+  /// directories of the other patterns
+  contexts: Vec<GlobContext>,
+  keys: ImportMetaGlobKeys,
+}
+
+fn resolve_import_meta_glob(
+  patterns: &[String],
+  importer: &Path,
+  root: &Path,
+) -> Result<ImportMetaGlob, String> {
+  let importer_dir = importer.parent().unwrap_or(importer);
+  let parsed_patterns = patterns
+    .iter()
+    .map(|pattern| parse_glob_pattern(pattern))
+    .collect::<Vec<_>>();
+  let resolve_dir = |pattern: &GlobPattern| -> Option<PathBuf> {
+    if let Some(dir) = pattern.dir.strip_prefix('/') {
+      Some(root.join(dir).normalize().into_owned())
+    } else if pattern.dir == "." || pattern.dir.starts_with("./") || pattern.dir.starts_with("..") {
+      Some(importer_dir.join(pattern.dir).normalize().into_owned())
+    } else {
+      None
+    }
+  };
+
+  let mut positives: Vec<(PathBuf, Vec<&str>)> = Vec::new();
+  for (raw, pattern) in patterns.iter().zip(&parsed_patterns) {
+    if pattern.negated {
+      continue;
+    }
+    let dir = resolve_dir(pattern).ok_or_else(|| {
+      format!("Invalid glob pattern \"{raw}\", patterns must start with \"./\", \"../\" or \"/\".")
+    })?;
+    match positives.iter_mut().find(|(base, _)| *base == dir) {
+      Some((_, globs)) => globs.push(pattern.glob),
+      None => positives.push((dir, vec![pattern.glob])),
+    }
+  }
+  if positives.is_empty() {
+    return Err("At least one positive glob pattern is required.".to_string());
+  }
+
+  let contexts = positives
+    .into_iter()
+    .map(|(context, globs)| {
+      let to_reg_exp = |dir: &Path, glob: &str| {
+        format!(
+          "{}{}",
+          glob_to_reg_exp(&to_request(&dir.relative(&context))),
+          glob_to_reg_exp(glob)
+        )
+      };
+      let mut negative_reg_exps = parsed_patterns
+        .iter()
+        .filter(|pattern| pattern.negated)
+        .filter_map(|pattern| match resolve_dir(pattern) {
+          Some(dir) => dir
+            .starts_with(&context)
+            .then(|| to_reg_exp(&dir, pattern.glob)),
+          // `!**/foo.js` is matched in the base directory
+          None if pattern.dir.is_empty() => Some(glob_to_reg_exp(pattern.glob)),
+          None => Some(to_reg_exp(
+            &context.join(pattern.dir).normalize(),
+            pattern.glob,
+          )),
+        })
+        .collect::<Vec<_>>();
+      // the importer never imports itself, like Vite
+      if importer.starts_with(&context) {
+        negative_reg_exps.push(escape_reg_exp(
+          &importer.relative(&context).to_slash_lossy(),
+        ));
+      }
+      let negative = if negative_reg_exps.is_empty() {
+        String::new()
+      } else {
+        format!("(?!(?:{})$)", negative_reg_exps.join("|"))
+      };
+      let positive = globs
+        .iter()
+        .map(|glob| glob_to_reg_exp(glob))
+        .collect::<Vec<_>>()
+        .join("|");
+      GlobContext {
+        context: context.to_string_lossy().into_owned(),
+        reg_exp: format!(r"^\./{negative}(?:{positive})$"),
+        // `*/index.js` lists the direct subdirectories as well
+        recursive: globs
+          .iter()
+          .any(|glob| glob.contains("**") || glob.contains('/')),
+      }
+    })
+    .collect();
+
+  let keys = if parsed_patterns
+    .iter()
+    .filter(|pattern| !pattern.negated)
+    .all(|pattern| pattern.dir.starts_with('/'))
+  {
+    ImportMetaGlobKeys::Root(root.to_string_lossy().into_owned())
+  } else {
+    ImportMetaGlobKeys::Importer(importer_dir.to_string_lossy().into_owned())
+  };
+
+  Ok(ImportMetaGlob { contexts, keys })
+}
+
+/// Converts the `query` option to a query string, objects are serialized like `URLSearchParams`.
+fn get_glob_query(parser: &mut JavascriptParser, expr: &Expr) -> Result<String, String> {
+  let query = if let Some(obj) = expr.as_object() {
+    let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+    for prop in &obj.props {
+      let Some(kv) = prop.as_prop().and_then(|prop| prop.as_key_value()) else {
+        return Err("The query option must only contain key-value properties.".into());
+      };
+      let key = match &kv.key {
+        PropName::Ident(ident) => ident.sym.to_string(),
+        PropName::Str(str) => str.value.to_string_lossy().into_owned(),
+        PropName::Num(num) => ryu_js::Buffer::new().format(num.value).to_string(),
+        _ => return Err("The keys of the query option must be static.".into()),
+      };
+      let evaluated = parser.evaluate_expression(&kv.value);
+      let value = if evaluated.is_string() {
+        evaluated.string().clone()
+      } else if evaluated.is_number() {
+        ryu_js::Buffer::new().format(evaluated.number()).to_string()
+      } else if evaluated.is_bool() {
+        evaluated.bool().to_string()
+      } else {
+        return Err(format!(
+          "The query option \"{key}\" must be a string, number or boolean."
+        ));
+      };
+      serializer.append_pair(&key, &value);
+    }
+    serializer.finish()
+  } else {
+    let evaluated = parser.evaluate_expression(expr);
+    if !evaluated.is_string() {
+      return Err("The query option must be a string or an object literal.".into());
+    }
+    evaluated.string().clone()
+  };
+  Ok(if query.starts_with('?') || query.is_empty() {
+    query
+  } else {
+    format!("?{query}")
+  })
+}
+
+fn get_glob_patterns(parser: &mut JavascriptParser, expr: &Expr) -> Option<Vec<String>> {
+  let evaluate_pattern = |parser: &mut JavascriptParser, expr: &Expr| {
+    let evaluated = parser.evaluate_expression(expr);
+    evaluated.is_string().then(|| evaluated.string().clone())
+  };
+  if let Some(array) = expr.as_array() {
+    array
+      .elems
+      .iter()
+      .map(|elem| {
+        let elem = elem.as_ref()?;
+        if elem.spread.is_some() {
+          return None;
+        }
+        evaluate_pattern(parser, &elem.expr)
+      })
+      .collect()
+  } else {
+    evaluate_pattern(parser, expr).map(|pattern| vec![pattern])
+  }
+}
+
+fn create_import_meta_glob_dependencies(
+  node: &CallExpr,
+  parser: &mut JavascriptParser,
+  eager_by_default: bool,
+) -> Result<Vec<ImportMetaGlobDependency>, String> {
+  let Some(patterns_arg) = node.args.first() else {
+    return Ok(vec![]);
+  };
+  if patterns_arg.spread.is_some() {
+    return Ok(vec![]);
+  }
+  let Some(patterns) = get_glob_patterns(parser, &patterns_arg.expr) else {
+    return Err("The glob patterns must be string literals or an array of string literals.".into());
+  };
+
+  let mut eager = eager_by_default;
+  let mut import = None;
+  let mut query = String::new();
+  if let Some(arg) = node.args.get(1) {
+    let Some(obj) = arg.expr.as_object() else {
+      return Err("The options must be an object literal.".into());
+    };
+    if let Some(value) = get_bool_by_obj_prop(obj, "eager") {
+      eager = value.value;
+    }
+    import = get_literal_str_by_obj_prop(obj, "import")
+      .map(|str| str.value.to_string_lossy().to_string())
+      .filter(|import| import != "*");
+    if let Some(value) = get_value_by_obj_prop(obj, "query") {
+      query = get_glob_query(parser, value)?;
+    }
+  }
+
+  let Some(importer) = parser.resource_data.path() else {
+    return Ok(vec![]);
+  };
+  let glob = resolve_import_meta_glob(
+    &patterns,
+    importer.as_std_path(),
+    parser.compiler_options.context.as_path().as_std_path(),
+  )?;
+
+  let mut dependencies = glob
+    .contexts
+    .into_iter()
+    .map(|glob_context| {
+      let reg_exp = RspackRegex::new(&glob_context.reg_exp).map_err(|e| e.to_string())?;
+      let options = ContextOptions {
+        mode: if eager {
+          ContextMode::Sync
+        } else {
+          ContextMode::Lazy
+        },
+        recursive: glob_context.recursive,
+        reg_exp: Some(reg_exp),
+        include: None,
+        exclude: None,
+        category: DependencyCategory::Esm,
+        request: format!("{}{query}", glob_context.context),
+        context: glob_context.context,
+        namespace_object: if parser.build_meta.strict_esm_module {
+          ContextNameSpaceObject::Strict
+        } else {
+          ContextNameSpaceObject::Bool(true)
+        },
+        group_options: None,
+        replaces: Vec::new(),
+        start: node.span().real_lo(),
+        end: node.span().real_hi(),
+        referenced_exports: import
+          .as_ref()
+          .map(|import| vec![vec![import.as_str().into()]]),
+        attributes: None,
+        phase: None,
+      };
+      Ok(ImportMetaGlobDependency::new(
+        options,
+        node.span.into(),
+        parser.in_try,
+        glob.keys.clone(),
+        import.clone(),
+      ))
+    })
+    .collect::<Result<Vec<_>, String>>()?;
+  // the first dependency renders the glob object with the modules of all the contexts
+  let siblings = dependencies
+    .iter()
+    .skip(1)
+    .map(|dependency| *dependency.id())
+    .collect::<Vec<_>>();
+  for dependency in dependencies.iter_mut().skip(1) {
+    dependency.set_rendered_by_sibling();
+  }
+  if let Some(first) = dependencies.first_mut() {
+    first.set_siblings(siblings);
+  }
+  Ok(dependencies)
+}
+
+pub struct ImportMetaGlobDependencyParserPlugin;
+
+impl JavascriptParserPlugin for ImportMetaGlobDependencyParserPlugin {
+  fn evaluate_identifier(
+    &self,
+    _parser: &mut JavascriptParser,
+    for_name: &str,
+    start: u32,
+    end: u32,
+  ) -> Option<BasicEvaluatedExpression<'static>> {
+    if for_name == expr_name::IMPORT_META_GLOB || for_name == expr_name::IMPORT_META_GLOB_EAGER {
+      Some(eval::evaluate_to_identifier(
+        for_name.into(),
+        expr_name::IMPORT_META.into(),
+        Some(true),
+        start,
+        end,
+      ))
+    } else {
+      None
+    }
+  }
+
+  fn call(&self, parser: &mut JavascriptParser, expr: &CallExpr, for_name: &str) -> Option<bool> {
+    if for_name != expr_name::IMPORT_META_GLOB && for_name != expr_name::IMPORT_META_GLOB_EAGER {
+      return None;
+    }
+    match create_import_meta_glob_dependencies(
+      expr,
+      parser,
+      for_name == expr_name::IMPORT_META_GLOB_EAGER,
+    ) {
+      Ok(dependencies) if dependencies.is_empty() => None,
+      Ok(dependencies) => {
+        for dependency in dependencies {
+          parser.add_dependency(Box::new(dependency));
+        }
+        Some(true)
+      }
+      Err(message) => {
+        let error: Error = create_traceable_error(
+          format!("Invalid {for_name}()"),
+          message,
+          parser.source.to_string(),
+          expr.span.into(),
+        );
+        parser.add_error(error.into());
+        Some(true)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_parse_glob_pattern() {
+    assert_eq!(
+      parse_glob_pattern("./dir/*.js"),
+      GlobPattern {
+        negated: false,
+        dir: "./dir",
+        glob: "*.js"
+      }
+    );
+    assert_eq!(
+      parse_glob_pattern("!**/bar.js"),
+      GlobPattern {
+        negated: true,
+        dir: "",
+        glob: "**/bar.js"
+      }
+    );
+    assert_eq!(
+      parse_glob_pattern("/src/pages/**/*.tsx"),
+      GlobPattern {
+        negated: false,
+        dir: "/src/pages",
+        glob: "**/*.tsx"
+      }
+    );
+    assert_eq!(
+      parse_glob_pattern("../dir/foo.js"),
+      GlobPattern {
+        negated: false,
+        dir: "../dir",
+        glob: "foo.js"
+      }
+    );
+  }
+
+  #[test]
+  fn test_glob_to_reg_exp() {
+    assert_eq!(glob_to_reg_exp("*.js"), r"[^/]*\.js");
+    assert_eq!(
+      glob_to_reg_exp("**/*.{js,ts}"),
+      r"(?:[^/]*/)*[^/]*\.(?:js|ts)"
+    );
+    assert_eq!(glob_to_reg_exp("[!a]?.js"), r"[^a][^/]\.js");
+    assert_eq!(escape_reg_exp("[id].page.js"), r"\[id\]\.page\.js");
+  }
+
+  #[test]
+  fn test_resolve_import_meta_glob() {
+    let root = Path::new("/project");
+    let importer = Path::new("/project/src/index.js");
+    let glob = resolve_import_meta_glob(
+      &[
+        "./dir/*.js".into(),
+        "./dir/sub/**/*.ts".into(),
+        "./dir/*.json".into(),
+        "!**/bar.js".into(),
+      ],
+      importer,
+      root,
+    )
+    .expect("should resolve");
+    assert_eq!(glob.contexts.len(), 2);
+    assert_eq!(glob.contexts[0].context, "/project/src/dir");
+    assert_eq!(
+      glob.contexts[0].reg_exp,
+      r"^\./(?!(?:(?:[^/]*/)*bar\.js)$)(?:[^/]*\.js|[^/]*\.json)$"
+    );
+    assert!(!glob.contexts[0].recursive);
+    assert_eq!(glob.contexts[1].context, "/project/src/dir/sub");
+    assert_eq!(
+      glob.contexts[1].reg_exp,
+      r"^\./(?!(?:(?:[^/]*/)*bar\.js)$)(?:(?:[^/]*/)*[^/]*\.ts)$"
+    );
+    assert!(glob.contexts[1].recursive);
+    assert!(matches!(glob.keys, ImportMetaGlobKeys::Importer(dir) if dir == "/project/src"));
+
+    let glob =
+      resolve_import_meta_glob(&["../lib/*.js".into()], importer, root).expect("should resolve");
+    assert_eq!(glob.contexts[0].context, "/project/lib");
+    assert!(!glob.contexts[0].recursive);
+
+    let glob =
+      resolve_import_meta_glob(&["/src/*.js".into()], importer, root).expect("should resolve");
+    assert_eq!(glob.contexts[0].context, "/project/src");
+    assert_eq!(
+      glob.contexts[0].reg_exp,
+      r"^\./(?!(?:index\.js)$)(?:[^/]*\.js)$"
+    );
+    assert!(matches!(glob.keys, ImportMetaGlobKeys::Root(root) if root == "/project"));
+
+    assert!(resolve_import_meta_glob(&["dir/*.js".into()], importer, root).is_err());
+    assert!(resolve_import_meta_glob(&["!./dir/*.js".into()], importer, root).is_err());
+  }
+}
@@ -2,7 +2,8 @@ use std::hash::Hash;
 
 use itertools::Itertools;
 use rspack_core::{
-  AsyncDependenciesBlock, ConstDependency, DependencyRange, EntryOptions, GroupOptions,
+  AsyncDependenciesBlock, ChunkLoading, ConstDependency, DependencyRange, EntryOptions,
+  GroupOptions,
 };
 use rspack_hash::RspackHash;
 use rspack_util::SpanExt;
@@ -80,6 +81,7 @@ fn add_dependencies(
   parsed_path: ParsedNewWorkerPath,
   parsed_options: Option<ParsedNewWorkerOptions>,
   need_new_url: bool,
+  worklet: bool,
 ) {
   let output_options = &parser.compiler_options.output;
   let mut hasher = RspackHash::from(output_options);
@@ -107,7 +109,12 @@ fn add_dependencies(
   block.set_group_options(GroupOptions::Entrypoint(Box::new(EntryOptions {
     name,
     runtime: Some(runtime.into()),
-    chunk_loading: Some(output_options.worker_chunk_loading.clone()),
+    // worklets can't load chunks by themselves, so everything they need is bundled into a single file
+    chunk_loading: Some(if worklet {
+      ChunkLoading::Disable
+    } else {
+      output_options.worker_chunk_loading.clone()
+    }),
     wasm_loading: Some(output_options.worker_wasm_loading.clone()),
     async_chunks: None,
     public_path: None,
@@ -127,7 +134,10 @@ fn add_dependencies(
     )));
   }
 
-  if let Some(options_range) = options_range {
+  // the options of `addModule()` only accept `credentials`
+  if let Some(options_range) = options_range
+    && !worklet
+  {
     parser.add_presentational_dependency(Box::new(ConstDependency::new(
       (options_range.0, options_range.0).into(),
       "Object.assign({}, ".into(),
@@ -240,13 +250,22 @@ pub struct WorkerPlugin {
 }
 
 const WORKER_SPECIFIER_TAG: &str = "_identifier__worker_specifier_tag__";
-const DEFAULT_SYNTAX: [&str; 4] = [
+const DEFAULT_SYNTAX: [&str; 9] = [
   "Worker",
   "SharedWorker",
   "navigator.serviceWorker.register()",
   "Worker from worker_threads",
+  "CSS.paintWorklet.addModule()",
+  "CSS.layoutWorklet.addModule()",
+  "CSS.animationWorklet.addModule()",
+  "*new AudioContext().audioWorklet.addModule()",
+  "*new OfflineAudioContext().audioWorklet.addModule()",
 ];
 
+fn is_worklet(syntax: &str) -> bool {
+  syntax.ends_with("Worklet.addModule")
+}
+
 #[derive(Debug, Clone)]
 struct WorkerSpecifierData {
   key: Atom,
@@ -325,6 +344,24 @@ impl JavascriptParserPlugin for WorkerPlugin {
       );
       return Some(true);
     }
+    // `*new AudioContext().audioWorklet.addModule()` matches variables initialized with `new AudioContext()`
+    if let Some(ident) = decl.name.as_ident()
+      && let Some(init) = &decl.init
+      && let Some(new_expr) = init.as_new()
+      && let Some(callee) = new_expr.callee.as_ident()
+    {
+      let key: Atom = format!("new {}()", callee.sym).into();
+      if self.pattern_syntax.contains_key(key.as_str())
+        && parser.get_variable_info(&callee.sym).is_none()
+      {
+        parser.tag_variable(
+          ident.sym.clone(),
+          WORKER_SPECIFIER_TAG,
+          Some(WorkerSpecifierData { key }),
+        );
+        return Some(true);
+      }
+    }
     None
   }
 
@@ -358,9 +395,11 @@ impl JavascriptParserPlugin for WorkerPlugin {
       .definitions_db
       .expect_get_tag_info(parser.current_tag_info?);
     let data = WorkerSpecifierData::downcast(tag_info.data.clone()?);
+    let members = members.iter().map(|id| id.as_str()).join(".");
     if let Some(value) = self.pattern_syntax.get(data.key.as_str())
-      && value.contains(&members.iter().map(|id| id.as_str()).join("."))
+      && value.contains(&members)
     {
+      let worklet = is_worklet(&format!("{}.{members}", data.key));
       return handle_worker(parser, &call_expr.args, call_expr.span).map(
         |(parsed_path, parsed_options, first_arg, need_new_url)| {
           add_dependencies(
@@ -370,6 +409,7 @@ impl JavascriptParserPlugin for WorkerPlugin {
             parsed_path,
             parsed_options,
             need_new_url,
+            worklet,
           );
           if let Some(callee) = call_expr.callee.as_expr() {
             parser.walk_expression(callee);
@@ -396,6 +436,7 @@ impl JavascriptParserPlugin for WorkerPlugin {
         .expect_get_tag_info(parser.current_tag_info?);
       let settings = ESMSpecifierData::downcast(tag_info.data.clone()?);
       let ids = settings.ids.iter().map(|id| id.as_str()).join(".");
+      let worklet = is_worklet(&ids);
       if self
         .from_call_syntax
         .contains(&(ids, settings.source.to_string()))
@@ -409,6 +450,7 @@ impl JavascriptParserPlugin for WorkerPlugin {
               parsed_path,
               parsed_options,
               need_new_url,
+              worklet,
             );
             if let Some(callee) = call_expr.callee.as_expr() {
               parser.walk_expression(callee);
@@ -434,6 +476,7 @@ impl JavascriptParserPlugin for WorkerPlugin {
           parsed_path,
           parsed_options,
           need_new_url,
+          is_worklet(for_name),
         );
         if let Some(callee) = call_expr.callee.as_expr() {
           parser.walk_expression(callee);
@@ -474,6 +517,7 @@ impl JavascriptParserPlugin for WorkerPlugin {
               parsed_path,
               parsed_options,
               need_new_url,
+              false,
             );
             parser.walk_expression(&new_expr.callee);
             if let Some(args) = &new_expr.args
@@ -501,6 +545,7 @@ impl JavascriptParserPlugin for WorkerPlugin {
           parsed_path,
           parsed_options,
           need_new_url,
+          false,
         );
         parser.walk_expression(&new_expr.callee);
         if let Some(args) = &new_expr.args
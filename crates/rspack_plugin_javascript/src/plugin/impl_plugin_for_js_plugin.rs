@@ -28,12 +28,13 @@ use crate::{
     ESMImportSideEffectDependencyTemplate, ESMImportSpecifierDependencyTemplate,
     ExportInfoDependencyTemplate, ExternalModuleDependencyTemplate,
     ImportContextDependencyTemplate, ImportDependencyTemplate, ImportEagerDependencyTemplate,
-    ImportMetaContextDependencyTemplate, ImportMetaHotAcceptDependencyTemplate,
-    ImportMetaHotDeclineDependencyTemplate, ImportMetaResolveDependencyTemplate,
-    ImportMetaResolveHeaderDependencyTemplate, IsIncludedDependencyTemplate,
-    ModuleArgumentDependencyTemplate, ModuleDecoratorDependencyTemplate,
-    ModuleHotAcceptDependencyTemplate, ModuleHotDeclineDependencyTemplate,
-    ProvideDependencyTemplate, PureExpressionDependencyTemplate, RequireContextDependencyTemplate,
+    ImportMetaContextDependencyTemplate, ImportMetaGlobDependencyTemplate,
+    ImportMetaHotAcceptDependencyTemplate, ImportMetaHotDeclineDependencyTemplate,
+    ImportMetaResolveDependencyTemplate, ImportMetaResolveHeaderDependencyTemplate,
+    IsIncludedDependencyTemplate, ModuleArgumentDependencyTemplate,
+    ModuleDecoratorDependencyTemplate, ModuleHotAcceptDependencyTemplate,
+    ModuleHotDeclineDependencyTemplate, ProvideDependencyTemplate,
+    PureExpressionDependencyTemplate, RequireContextDependencyTemplate,
     RequireEnsureDependencyTemplate, RequireHeaderDependencyTemplate,
     RequireMainDependencyTemplate, RequireResolveContextDependencyTemplate,
     RequireResolveDependencyTemplate, RequireResolveHeaderDependencyTemplate,
@@ -136,6 +137,10 @@ async fn compilation(
     DependencyType::ImportMetaContext,
     params.context_module_factory.clone(),
   );
+  compilation.set_dependency_factory(
+    DependencyType::ImportMetaGlob,
+    params.context_module_factory.clone(),
+  );
   compilation.set_dependency_factory(
     DependencyType::ImportMetaResolve,
     params.normal_module_factory.clone(),
@@ -312,6 +317,10 @@ async fn compilation(
     ImportMetaContextDependencyTemplate::template_type(),
     Arc::new(ImportMetaContextDependencyTemplate::default()),
   );
+  compilation.set_dependency_template(
+    ImportMetaGlobDependencyTemplate::template_type(),
+    Arc::new(ImportMetaGlobDependencyTemplate::default()),
+  );
   compilation.set_dependency_template(
     ImportMetaResolveDependencyTemplate::template_type(),
     Arc::new(ImportMetaResolveDependencyTemplate::default()),
@@ -417,6 +417,9 @@ impl<'parser> JavascriptParser<'parser> {
       plugins.push(Box::new(
         parser_plugin::ImportMetaContextDependencyParserPlugin,
       ));
+      plugins.push(Box::new(
+        parser_plugin::ImportMetaGlobDependencyParserPlugin,
+      ));
       if let Some(true) = javascript_options.import_meta {
         plugins.push(Box::new(parser_plugin::ImportMetaPlugin));
       } else {
@@ -12,11 +12,12 @@ use lightningcss::{
 };
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_cacheable::{cacheable, with::AsPreset};
 use rspack_core::{
   ChunkUkey, Compilation, CompilationChunkHash, CompilationProcessAssets, Plugin,
   diagnostics::MinifyError,
   rspack_sources::{
-    MapOptions, ObjectPool, RawStringSource, SourceExt, SourceMap, SourceMapSource,
+    BoxSource, MapOptions, ObjectPool, RawStringSource, SourceExt, SourceMap, SourceMapSource,
     SourceMapSourceOptions,
   },
 };
@@ -26,6 +27,8 @@ use rspack_hook::{plugin, plugin_hook};
 use rspack_util::asset_condition::{AssetConditions, AssetConditionsObject, match_object};
 use thread_local::ThreadLocal;
 
+const PLUGIN_NAME: &str = "rspack.LightningCssMinimizerRspackPlugin";
+
 static CSS_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.css(\?.*)?$").expect("Invalid RegExp"));
 
@@ -98,6 +101,13 @@ impl Hash for MinimizerOptions {
   }
 }
 
+/// The minified asset saved in the persistent cache.
+#[cacheable]
+struct MinifiedAsset {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct LightningCssMinimizerRspackPlugin {
@@ -132,6 +142,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     exclude: options.exclude.as_ref(),
   };
 
+  let cache = compilation.process_assets_cache_artifact.clone();
+  let compiler_options = compilation.options.clone();
+
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
   compilation
     .assets_mut()
@@ -158,6 +171,35 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         let input = original_source.source().into_string_lossy().into_owned();
         let object_pool = tls.get_or(ObjectPool::default);
         let input_source_map = original_source.map(object_pool, &MapOptions::default());
+        let css_unused_idents = if self.options.remove_unused_local_idents {
+          original.info.css_unused_idents.take()
+        } else {
+          None
+        };
+
+        let cache_key = cache.is_enabled().then(|| {
+          let mut hasher = RspackHash::from(&compiler_options.output);
+          options.hash(&mut hasher);
+          filename.hash(&mut hasher);
+          input.hash(&mut hasher);
+          input_source_map.hash(&mut hasher);
+          if let Some(css_unused_idents) = &css_unused_idents {
+            let mut idents = css_unused_idents.iter().collect::<Vec<_>>();
+            idents.sort();
+            idents.hash(&mut hasher);
+          }
+          hasher
+            .digest(&compiler_options.output.hash_digest)
+            .encoded()
+            .to_string()
+        });
+        if let Some(cache_key) = &cache_key
+          && let Some(cached) = cache.get::<MinifiedAsset>(PLUGIN_NAME, cache_key)
+        {
+          original.set_source(Some(cached.source));
+          original.get_info_mut().minimized.replace(true);
+          return Ok(());
+        }
 
         let mut parser_flags = ParserFlags::empty();
         parser_flags.set(
@@ -206,9 +248,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
               .map_or(Features::empty(), |exclude| Features::from_bits_truncate(*exclude)),
           };
           let mut unused_symbols = HashSet::from_iter(minimizer_options.unused_symbols.clone());
-          if self.options.remove_unused_local_idents
-            && let Some(css_unused_idents) = original.info.css_unused_idents.take()
-          {
+          if let Some(css_unused_idents) = css_unused_idents {
             unused_symbols.extend(css_unused_idents);
           }
           stylesheet
@@ -277,6 +317,15 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           RawStringSource::from(result.code).boxed()
         };
 
+        if let Some(cache_key) = &cache_key {
+          cache.set(
+            PLUGIN_NAME,
+            cache_key,
+            &MinifiedAsset {
+              source: minimized_source.clone(),
+            },
+          );
+        }
         original.set_source(Some(minimized_source));
       }
       original.get_info_mut().minimized.replace(true);
@@ -290,7 +339,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
 
 impl Plugin for LightningCssMinimizerRspackPlugin {
   fn name(&self) -> &'static str {
-    "rspack.LightningCssMinimizerRspackPlugin"
+    PLUGIN_NAME
   }
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
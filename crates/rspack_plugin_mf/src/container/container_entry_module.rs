@@ -1,16 +1,16 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, collections::BTreeMap};
 
 use async_trait::async_trait;
 use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_collections::{Identifiable, Identifier};
 use rspack_core::{
   AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BoxDependency, BoxModule, BuildContext,
-  BuildInfo, BuildMeta, BuildMetaExportsType, BuildResult, ChunkGroupOptions, CodeGenerationResult,
-  Compilation, Context, DependenciesBlock, Dependency, DependencyId, DependencyType,
-  ExportsArgument, FactoryMeta, GroupOptions, LibIdentOptions, Module, ModuleCodeGenerationContext,
-  ModuleCodeTemplate, ModuleDependency, ModuleGraph, ModuleIdentifier, ModuleType, RuntimeGlobals,
-  RuntimeSpec, SourceType, StaticExportsDependency, StaticExportsSpec, impl_module_meta_info,
-  impl_source_map_config, module_update_hash,
+  BuildInfo, BuildMeta, BuildMetaExportsType, BuildResult, ChunkGraph, ChunkGroupOptions,
+  CodeGenerationResult, Compilation, Context, DependenciesBlock, Dependency, DependencyId,
+  DependencyType, ExportsArgument, FactoryMeta, GroupOptions, LibIdentOptions, Module,
+  ModuleCodeGenerationContext, ModuleCodeTemplate, ModuleDependency, ModuleGraph, ModuleId,
+  ModuleIdentifier, ModuleType, RuntimeGlobals, RuntimeSpec, SourceType, StaticExportsDependency,
+  StaticExportsSpec, impl_module_meta_info, impl_source_map_config, module_update_hash,
   rspack_sources::{BoxSource, RawStringSource, SourceExt},
 };
 use rspack_error::{Result, impl_empty_diagnosable_trait};
@@ -116,6 +116,36 @@ impl ContainerEntryModule {
   pub fn name(&self) -> &str {
     &self.name
   }
+
+  /// The ids of the modules of each exposed name, which are accepted by the container when
+  /// hot module replacement is enabled.
+  pub fn exposed_module_ids<'a>(
+    &self,
+    compilation: &'a Compilation,
+  ) -> BTreeMap<&'a str, Vec<&'a ModuleId>> {
+    let module_graph = compilation.get_module_graph();
+    let mut exposed_module_ids: BTreeMap<&str, Vec<&ModuleId>> = BTreeMap::new();
+    for block_id in self.get_blocks() {
+      let block = module_graph
+        .block_by_id(block_id)
+        .expect("should have block");
+      for dependency_id in block.get_dependencies() {
+        let dep = module_graph
+          .dependency_by_id(dependency_id)
+          .downcast_ref::<ContainerExposedDependency>()
+          .expect("dependencies of ContainerEntryModule should be ContainerExposedDependency");
+        let ids = exposed_module_ids
+          .entry(dep.exposed_name.as_str())
+          .or_default();
+        if let Some(module) = module_graph.module_identifier_by_dependency_id(dependency_id)
+          && let Some(id) = ChunkGraph::get_module_id(&compilation.module_ids_artifact, *module)
+        {
+          ids.push(id);
+        }
+      }
+    }
+    exposed_module_ids
+  }
 }
 
 impl Identifiable for ContainerEntryModule {
@@ -13,7 +13,8 @@ use serde::Serialize;
 use super::{
   container_entry_dependency::ContainerEntryDependency,
   container_entry_module_factory::ContainerEntryModuleFactory,
-  expose_runtime_module::ExposeRuntimeModule, federation_modules_plugin::FederationModulesPlugin,
+  expose_hmr_runtime_module::ExposeHmrRuntimeModule, expose_runtime_module::ExposeRuntimeModule,
+  federation_modules_plugin::FederationModulesPlugin,
 };
 
 #[derive(Debug)]
@@ -141,7 +142,7 @@ async fn runtime_requirements_in_tree(
   &self,
   compilation: &Compilation,
   chunk_ukey: &ChunkUkey,
-  _all_runtime_requirements: &RuntimeGlobals,
+  all_runtime_requirements: &RuntimeGlobals,
   runtime_requirements: &RuntimeGlobals,
   runtime_requirements_mut: &mut RuntimeGlobals,
   runtime_modules_to_add: &mut Vec<(ChunkUkey, Box<dyn RuntimeModule>)>,
@@ -155,6 +156,18 @@ async fn runtime_requirements_in_tree(
       ));
     }
   }
+  // added once both the container and the hmr runtime are required, whichever comes last
+  let hmr_runtime_requirements =
+    RuntimeGlobals::CURRENT_REMOTE_GET_SCOPE | RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS;
+  if !self.options.enhanced
+    && all_runtime_requirements.contains(hmr_runtime_requirements)
+    && runtime_requirements.intersects(hmr_runtime_requirements)
+  {
+    runtime_modules_to_add.push((
+      *chunk_ukey,
+      Box::new(ExposeHmrRuntimeModule::new(&compilation.runtime_template)),
+    ));
+  }
   Ok(None)
 }
 
@@ -0,0 +1,93 @@
+use std::sync::LazyLock;
+
+use rspack_collections::Identifiable;
+use rspack_core::{
+  ChunkGraph, ChunkUkey, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleGenerateContext,
+  RuntimeModuleStage, RuntimeTemplate, SourceType, impl_runtime_module,
+};
+use rspack_plugin_runtime::extract_runtime_globals_from_ejs;
+
+use super::container_entry_module::ContainerEntryModule;
+use crate::utils::json_stringify;
+
+static EXPOSES_WITH_HMR_TEMPLATE: &str = include_str!("./exposesWithHmr.ejs");
+static EXPOSES_WITH_HMR_RUNTIME_REQUIREMENTS: LazyLock<RuntimeGlobals> =
+  LazyLock::new(|| extract_runtime_globals_from_ejs(EXPOSES_WITH_HMR_TEMPLATE));
+
+/// Lets the hosts apply the updates of the exposed modules, by accepting them in the
+/// container and adding a `hot` export to the container. It's only added when hot module
+/// replacement is enabled, so the output of containers stays the same otherwise.
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct ExposeHmrRuntimeModule {}
+
+impl ExposeHmrRuntimeModule {
+  #[allow(clippy::new_without_default)]
+  pub fn new(runtime_template: &RuntimeTemplate) -> Self {
+    Self::with_name(runtime_template, "exposes_with_hmr")
+  }
+}
+
+impl ExposeHmrRuntimeModule {
+  fn find_container_entry_module<'a>(
+    &self,
+    chunk_ukey: &ChunkUkey,
+    compilation: &'a Compilation,
+  ) -> Option<&'a ContainerEntryModule> {
+    let chunk = compilation
+      .build_chunk_graph_artifact
+      .chunk_by_ukey
+      .expect_get(chunk_ukey);
+    let module_graph = compilation.get_module_graph();
+    chunk
+      .get_all_initial_chunks(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey)
+      .into_iter()
+      .flat_map(|c| {
+        compilation
+          .build_chunk_graph_artifact
+          .chunk_graph
+          .get_chunk_modules_by_source_type(&c, SourceType::Expose, module_graph)
+      })
+      .find_map(|module| module.as_any().downcast_ref::<ContainerEntryModule>())
+  }
+}
+
+#[async_trait::async_trait]
+impl RuntimeModule for ExposeHmrRuntimeModule {
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+
+  fn template(&self) -> Vec<(String, String)> {
+    vec![(self.id.to_string(), EXPOSES_WITH_HMR_TEMPLATE.to_string())]
+  }
+
+  async fn generate(
+    &self,
+    context: &RuntimeModuleGenerateContext<'_>,
+  ) -> rspack_error::Result<String> {
+    let compilation = context.compilation;
+    let chunk_ukey = self
+      .chunk
+      .expect("should have chunk in <ExposeHmrRuntimeModule as RuntimeModule>::generate");
+    let Some(module) = self.find_container_entry_module(&chunk_ukey, compilation) else {
+      return Ok(String::new());
+    };
+    let Some(container_module_id) =
+      ChunkGraph::get_module_id(&compilation.module_ids_artifact, module.identifier())
+    else {
+      return Ok(String::new());
+    };
+    context.runtime_template.render(
+      self.id.as_str(),
+      Some(serde_json::json!({
+        "_container_module_id": json_stringify(container_module_id),
+        "_exposed_module_ids": json_stringify(&module.exposed_module_ids(compilation)),
+      })),
+    )
+  }
+
+  fn additional_runtime_requirements(&self, _compilation: &Compilation) -> RuntimeGlobals {
+    *EXPOSES_WITH_HMR_RUNTIME_REQUIREMENTS
+  }
+}
@@ -14,6 +14,7 @@ use super::remote_module::RemoteModule;
 use crate::utils::json_stringify;
 
 static REMOTES_LOADING_TEMPLATE: &str = include_str!("./remotesLoading.ejs");
+static REMOTES_LOADING_WITH_HMR_TEMPLATE: &str = include_str!("./remotesLoadingWithHmr.ejs");
 static REMOTES_LOADING_RUNTIME_REQUIREMENTS: LazyLock<RuntimeGlobals> =
   LazyLock::new(|| extract_runtime_globals_from_ejs(REMOTES_LOADING_TEMPLATE));
 
@@ -27,6 +28,18 @@ impl RemoteRuntimeModule {
   pub fn new(runtime_template: &RuntimeTemplate, enhanced: bool) -> Self {
     Self::with_name(runtime_template, "remotes_loading", enhanced)
   }
+
+  fn get_template_id(&self, template_id: TemplateId) -> String {
+    match template_id {
+      TemplateId::Loading => self.id.to_string(),
+      TemplateId::WithHmr => format!("{}_with_hmr", self.id),
+    }
+  }
+}
+
+enum TemplateId {
+  Loading,
+  WithHmr,
 }
 
 #[async_trait::async_trait]
@@ -36,7 +49,16 @@ impl RuntimeModule for RemoteRuntimeModule {
   }
 
   fn template(&self) -> Vec<(String, String)> {
-    vec![(self.id.to_string(), REMOTES_LOADING_TEMPLATE.to_string())]
+    vec![
+      (
+        self.get_template_id(TemplateId::Loading),
+        REMOTES_LOADING_TEMPLATE.to_string(),
+      ),
+      (
+        self.get_template_id(TemplateId::WithHmr),
+        REMOTES_LOADING_WITH_HMR_TEMPLATE.to_string(),
+      ),
+    ]
   }
 
   async fn generate(
@@ -114,7 +136,20 @@ impl RuntimeModule for RemoteRuntimeModule {
           runtime_template.render_runtime_globals(&RuntimeGlobals::ENSURE_CHUNK_HANDLERS),
       )
     } else {
-      runtime_template.render(self.id.as_str(), None)?
+      // the runtime globals used for hot updates are required by the hmr runtime already
+      let with_hmr = ChunkGraph::get_chunk_runtime_requirements(compilation, &chunk_ukey)
+        .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
+      let mut source = String::new();
+      if with_hmr {
+        source += &runtime_template.render(&self.get_template_id(TemplateId::WithHmr), None)?;
+      }
+      source += &runtime_template.render(
+        &self.get_template_id(TemplateId::Loading),
+        Some(serde_json::json!({
+          "_with_hmr": with_hmr,
+        })),
+      )?;
+      source
     };
     Ok(format!(
       r#"
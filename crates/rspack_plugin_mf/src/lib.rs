@@ -14,8 +14,9 @@ pub use container::{
   },
 };
 pub use manifest::{
-  ManifestExposeOption, ManifestSharedOption, ModuleFederationManifestPlugin,
-  ModuleFederationManifestPluginOptions, RemoteAliasTarget, StatsBuildInfo,
+  ManifestExposeOption, ManifestRemoteTypesOption, ManifestSharedCheckOption, ManifestSharedOption,
+  ManifestTypesOption, ModuleFederationManifestPlugin, ModuleFederationManifestPluginOptions,
+  RemoteAliasTarget, StatsBuildInfo,
 };
 pub use sharing::{
   collect_shared_entry_plugin::{CollectSharedEntryPlugin, CollectSharedEntryPluginOptions},
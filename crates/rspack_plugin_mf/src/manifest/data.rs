@@ -50,6 +50,10 @@ pub struct StatsShared {
   pub requiredVersion: Option<String>,
   #[serde(default)]
   pub singleton: Option<bool>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub shareScope: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub strictVersion: Option<bool>,
   #[serde(default)]
   pub assets: StatsAssetsGroup,
   #[serde(default)]
@@ -82,6 +86,23 @@ pub struct BasicStatsMetaData {
   pub remoteEntry: RemoteEntryMeta,
   #[serde(skip_serializing_if = "Option::is_none")]
   pub r#type: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub types: Option<StatsTypes>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct StatsTypes {
+  /// The directory of the declarations of the exposed modules in the archive.
+  #[serde(default)]
+  pub path: String,
+  /// The name of the types, the filename of the archive without the extension.
+  #[serde(default)]
+  pub name: String,
+  #[serde(default)]
+  pub zip: String,
+  /// The filename of the declaration of the remote module keys.
+  #[serde(default)]
+  pub api: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -105,6 +126,44 @@ pub struct StatsRoot {
   pub remotes: Vec<StatsRemote>,
   #[serde(default)]
   pub exposes: Vec<StatsExpose>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub sharedConflicts: Option<Vec<StatsSharedConflict>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsSharedConflictKind {
+  /// The version of a shared singleton doesn't satisfy the required version of a consumer.
+  UnsatisfiedSingleton,
+  /// A shared module is provided more than once by the same container.
+  DuplicateProvision,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct StatsSharedProvider {
+  pub container: String,
+  pub version: String,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub request: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StatsSharedConflict {
+  pub kind: StatsSharedConflictKind,
+  pub name: String,
+  pub shareScope: String,
+  /// The version the singleton resolves to at runtime.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub version: Option<String>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub requiredVersion: Option<String>,
+  /// The container which requires `requiredVersion`.
+  #[serde(skip_serializing_if = "Option::is_none")]
+  pub consumer: Option<String>,
+  #[serde(default)]
+  pub strictVersion: bool,
+  #[serde(default)]
+  pub providers: Vec<StatsSharedProvider>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -124,6 +183,10 @@ pub struct ManifestShared {
   pub requiredVersion: Option<String>,
   #[serde(default)]
   pub singleton: Option<bool>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub shareScope: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub strictVersion: Option<bool>,
   #[serde(default)]
   pub assets: StatsAssetsGroup,
 }
@@ -3,9 +3,12 @@
 mod asset;
 mod data;
 mod options;
+mod semver;
+mod shared_check;
+mod types;
 mod utils;
 
-use std::path::Path;
+use std::{hash::Hasher, path::Path, sync::Mutex};
 
 use asset::{
   collect_assets_for_module, collect_assets_from_chunk, collect_usage_files_for_module,
@@ -13,38 +16,173 @@ use asset::{
 };
 use data::{
   BasicStatsMetaData, ManifestExpose, ManifestRemote, ManifestRoot, ManifestShared,
-  RemoteEntryMeta, StatsAssetsGroup, StatsExpose, StatsRemote, StatsShared,
+  RemoteEntryMeta, StatsAssetsGroup, StatsExpose, StatsRemote, StatsShared, StatsTypes,
 };
 pub use data::{StatsBuildInfo, StatsRoot};
 pub use options::{
-  ManifestExposeOption, ManifestSharedOption, ModuleFederationManifestPluginOptions,
-  RemoteAliasTarget,
+  ManifestExposeOption, ManifestRemoteTypesOption, ManifestSharedCheckOption, ManifestSharedOption,
+  ManifestTypesOption, ModuleFederationManifestPluginOptions, RemoteAliasTarget,
 };
 use rspack_core::{
-  Compilation, CompilationAsset, CompilationProcessAssets, ModuleIdentifier, ModuleType, Plugin,
-  PublicPath,
-  rspack_sources::{RawStringSource, SourceExt},
+  Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets, CompilerCompilation,
+  DependenciesBlock, ModuleIdentifier, ModuleType, Plugin, PublicPath,
+  rspack_sources::{RawBufferSource, RawStringSource, SourceExt},
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hook::{plugin, plugin_hook};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
 use rspack_util::fx_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use rustc_hash::FxHasher;
+use shared_check::{SharedUsages, local_manifest_path, read_remote_manifests};
+use types::{
+  COMPILED_TYPES_DIR, DeclarationCache, collect_expose_types, create_types_api,
+  create_types_archive, read_types_archive,
+};
 use utils::{
   collect_entry_files, collect_expose_requirements, compose_id_with_separator,
   ensure_configured_remotes, ensure_shared_entry, filter_assets, is_hot_file,
   parse_consume_shared_identifier, parse_provide_shared_identifier, record_shared_usage, strip_ext,
 };
 
-use crate::container::{container_entry_module::ContainerEntryModule, remote_module::RemoteModule};
+use crate::{
+  ConsumeSharedModule, ProvideSharedModule,
+  container::{container_entry_module::ContainerEntryModule, remote_module::RemoteModule},
+};
 
 #[plugin]
 #[derive(Debug)]
 pub struct ModuleFederationManifestPlugin {
   options: ModuleFederationManifestPluginOptions,
+  /// The content hashes of the unpacked types archives of remotes, by their paths.
+  unpacked_types: Mutex<HashMap<Utf8PathBuf, u64>>,
+  declarations: DeclarationCache,
 }
 impl ModuleFederationManifestPlugin {
   pub fn new(options: ModuleFederationManifestPluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default(), Default::default())
+  }
+}
+
+/// Returns the expose keys and the resource paths of the exposed modules.
+fn collect_expose_resources(compilation: &Compilation) -> Vec<(String, Utf8PathBuf)> {
+  let module_graph = compilation.get_module_graph();
+  let mut resources = vec![];
+  for (_, module) in module_graph.modules() {
+    let Some(container_entry) = module
+      .as_ref()
+      .as_any()
+      .downcast_ref::<ContainerEntryModule>()
+    else {
+      continue;
+    };
+    let blocks = module.get_blocks();
+    for (index, (expose_key, _)) in container_entry.exposes().iter().enumerate() {
+      let Some(block) = blocks
+        .get(index)
+        .and_then(|block_id| module_graph.block_by_id(block_id))
+      else {
+        continue;
+      };
+      let resource = block.get_dependencies().iter().find_map(|dependency_id| {
+        module_graph
+          .get_module_by_dependency_id(dependency_id)?
+          .as_normal_module()?
+          .resource_resolved_data()
+          .path()
+          .map(Utf8Path::to_path_buf)
+      });
+      if let Some(resource) = resource {
+        resources.push((expose_key.clone(), resource));
+      }
+    }
   }
+  resources
+}
+
+#[plugin_hook(CompilerCompilation for ModuleFederationManifestPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let types_folder = compilation
+    .options
+    .context
+    .as_path()
+    .join(&self.options.types_folder);
+  let fs = compilation.intermediate_filesystem.clone();
+  for remote in &self.options.remote_types {
+    let archive = Utf8PathBuf::from(&remote.archive);
+    let dir = types_folder.join(&remote.alias);
+    let content = match compilation.input_filesystem.read(&archive).await {
+      Ok(content) => content,
+      Err(_) => {
+        compilation
+          .missing_dependencies
+          .insert(archive.as_path().into());
+        // the types unpacked from a removed archive are stale
+        let unpacked = self
+          .unpacked_types
+          .lock()
+          .expect("should lock unpacked types")
+          .remove(&archive);
+        if unpacked.is_some() && fs.stat(&dir).await.is_ok() {
+          fs.remove_dir_all(&dir).await?;
+        }
+        compilation.push_diagnostic(Diagnostic::warn(
+          "ModuleFederationTypesWarning".to_string(),
+          format!(
+            "Types archive of remote \"{}\" is not found at {archive}",
+            remote.alias
+          ),
+        ));
+        continue;
+      }
+    };
+    compilation
+      .file_dependencies
+      .insert(archive.as_path().into());
+
+    let mut hasher = FxHasher::default();
+    hasher.write(&content);
+    let hash = hasher.finish();
+    if self
+      .unpacked_types
+      .lock()
+      .expect("should lock unpacked types")
+      .get(&archive)
+      == Some(&hash)
+    {
+      continue;
+    }
+    let files = match read_types_archive(&content) {
+      Ok(files) => files,
+      Err(error) => {
+        compilation.push_diagnostic(Diagnostic::warn(
+          "ModuleFederationTypesWarning".to_string(),
+          format!("Failed to unpack types archive {archive}: {error}"),
+        ));
+        continue;
+      }
+    };
+    // files of the previous archive may be removed from the new one
+    if fs.stat(&dir).await.is_ok() {
+      fs.remove_dir_all(&dir).await?;
+    }
+    for (path, content) in files {
+      let path = dir.join(path);
+      if let Some(parent) = path.parent() {
+        fs.create_dir_all(parent).await?;
+      }
+      fs.write(&path, &content).await?;
+    }
+    self
+      .unpacked_types
+      .lock()
+      .expect("should lock unpacked types")
+      .insert(archive, hash);
+  }
+  Ok(())
 }
 fn get_remote_entry_name(compilation: &Compilation, container_name: &str) -> Option<String> {
   let chunk_group_ukey = compilation
@@ -138,6 +276,85 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     PublicPath::Auto => Some("auto".to_string()),
     PublicPath::Filename(f) => Some(PublicPath::render_filename(compilation, f).await),
   };
+  let types = match &self.options.types {
+    Some(types) => {
+      let exposes = collect_expose_resources(compilation);
+      let expose_types = collect_expose_types(
+        &*compilation.input_filesystem,
+        &self.declarations,
+        compilation.options.context.as_path(),
+        &exposes,
+      )
+      .await;
+      compilation.extend_diagnostics(expose_types.diagnostics);
+      // the files only imported by types are out of the module graph
+      compilation.file_dependencies.extend(
+        expose_types
+          .dependencies
+          .iter()
+          .map(|file| file.as_path().into()),
+      );
+      let archive = create_types_archive(&expose_types.files)?;
+      compilation.emit_asset(
+        types.zip_name.clone(),
+        CompilationAsset::new(
+          Some(RawBufferSource::from(archive).boxed()),
+          Default::default(),
+        ),
+      );
+      let (path, name) = match types.zip_name.rsplit_once('/') {
+        Some((path, name)) => (Some(path), name),
+        None => (None, types.zip_name.as_str()),
+      };
+      let name = name.strip_suffix(".zip").unwrap_or(name);
+      let api = match path {
+        Some(path) => format!("{path}/{name}.d.ts"),
+        None => format!("{name}.d.ts"),
+      };
+      compilation.emit_asset(
+        api.clone(),
+        CompilationAsset::new(
+          Some(RawStringSource::from(create_types_api(&expose_types.exposes)).boxed()),
+          Default::default(),
+        ),
+      );
+      Some(StatsTypes {
+        path: COMPILED_TYPES_DIR.to_string(),
+        name: name.to_string(),
+        zip: types.zip_name.clone(),
+        api,
+      })
+    }
+    None => None,
+  };
+  let shared_conflicts = match &self.options.shared_check {
+    Some(shared_check) => {
+      let context = compilation.options.context.clone();
+      let mut paths = shared_check
+        .remote_manifests
+        .iter()
+        .map(Utf8PathBuf::from)
+        .chain(
+          self
+            .options
+            .remote_alias_map
+            .values()
+            .filter_map(|target| local_manifest_path(target, context.as_path())),
+        )
+        .collect::<Vec<_>>();
+      paths.sort_unstable();
+      paths.dedup();
+      let mut usages = SharedUsages::default();
+      usages.add_compilation(compilation, &container_name);
+      for manifest in read_remote_manifests(compilation, paths).await {
+        usages.add_manifest(&manifest);
+      }
+      let (conflicts, diagnostics) = usages.check();
+      compilation.extend_diagnostics(diagnostics);
+      Some(conflicts)
+    }
+    None => None,
+  };
   let meta = BasicStatsMetaData {
     name: container_name.clone(),
     globalName: global_name,
@@ -154,6 +371,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         .map_or_else(|| "global".to_string(), |l| l.library_type.clone()),
     },
     r#type: None,
+    types,
   };
   let (exposes, shared, remote_list) = if self.options.disable_assets_analyze {
     let exposes = self
@@ -183,6 +401,8 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         requiredVersion: shared.required_version.clone(),
         // default singleton to true when not provided by user
         singleton: shared.singleton.or(Some(true)),
+        shareScope: None,
+        strictVersion: None,
         assets: StatsAssetsGroup::default(),
         usedIn: Vec::new(),
         usedExports: Vec::new(),
@@ -323,6 +543,9 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           if entry.version.is_empty() {
             entry.version = ver;
           }
+          if let Some(provide) = module.as_any().downcast_ref::<ProvideSharedModule>() {
+            entry.shareScope = Some(provide.share_scope().to_string());
+          }
           // overlay user-configured shared options (singleton/requiredVersion/version)
           if let Some(opt) = self.options.shared.iter().find(|s| s.name == pkg) {
             if let Some(singleton) = opt.singleton {
@@ -379,6 +602,11 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         if entry.requiredVersion.is_none() && required.is_some() {
           entry.requiredVersion = required;
         }
+        if let Some(consume) = module.as_any().downcast_ref::<ConsumeSharedModule>() {
+          let options = consume.options();
+          entry.shareScope = Some(options.share_scope.clone());
+          entry.strictVersion = Some(options.strict_version);
+        }
         // overlay user-configured shared options
         if let Some(opt) = self.options.shared.iter().find(|s| s.name == pkg) {
           if let Some(singleton) = opt.singleton {
@@ -621,6 +849,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
     shared,
     remotes: remote_list.clone(),
     exposes: exposes.clone(),
+    sharedConflicts: shared_conflicts,
   };
   // emit stats
   let stats_json = serde_json::to_string_pretty(&stats_root).expect("serialize stats");
@@ -659,6 +888,8 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         version: s.version,
         requiredVersion: s.requiredVersion,
         singleton: s.singleton,
+        shareScope: s.shareScope,
+        strictVersion: s.strictVersion,
         assets: s.assets,
       })
       .collect(),
@@ -687,6 +918,7 @@ impl Plugin for ModuleFederationManifestPlugin {
     "rspack.ModuleFederationManifestPlugin"
   }
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compiler_hooks.compilation.tap(compilation::new(self));
     // Align with webpack's stage: PROCESS_ASSETS_STAGE_OPTIMIZE_TRANSFER
     ctx
       .compilation_hooks
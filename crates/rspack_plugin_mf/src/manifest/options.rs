@@ -22,6 +22,27 @@ pub struct ManifestSharedOption {
   pub singleton: Option<bool>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ManifestTypesOption {
+  /// The filename of the types archive.
+  pub zip_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ManifestRemoteTypesOption {
+  /// The remote alias, the types are unpacked to `[types_folder]/[alias]`.
+  pub alias: String,
+  /// The absolute path of the types archive of the remote.
+  pub archive: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ManifestSharedCheckOption {
+  /// The absolute paths of the manifests of remotes, in addition to the remote entries which
+  /// are manifests on disk.
+  pub remote_manifests: Vec<String>,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct ModuleFederationManifestPluginOptions {
   pub name: Option<String>,
@@ -33,4 +54,11 @@ pub struct ModuleFederationManifestPluginOptions {
   pub exposes: Vec<ManifestExposeOption>,
   pub shared: Vec<ManifestSharedOption>,
   pub build_info: Option<StatsBuildInfo>,
+  /// Emit the declarations of exposes as a types archive.
+  pub types: Option<ManifestTypesOption>,
+  pub remote_types: Vec<ManifestRemoteTypesOption>,
+  /// The directory relative to the context to unpack the types archives of remotes to.
+  pub types_folder: String,
+  /// Check the versions of shared modules against the required versions of the host and remotes.
+  pub shared_check: Option<ManifestSharedCheckOption>,
 }
@@ -0,0 +1,341 @@
+//! A subset of npm semver which is enough to check the `requiredVersion` ranges of shared
+//! modules, following the grammar of https://docs.npmjs.com/cli/v6/using-npm/semver#range-grammar
+//! as the `satisfy` of the consumes runtime does.
+
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PreRelease {
+  Numeric(u64),
+  Alpha(String),
+}
+
+impl PartialOrd for PreRelease {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for PreRelease {
+  fn cmp(&self, other: &Self) -> Ordering {
+    match (self, other) {
+      (Self::Numeric(a), Self::Numeric(b)) => a.cmp(b),
+      (Self::Numeric(_), Self::Alpha(_)) => Ordering::Less,
+      (Self::Alpha(_), Self::Numeric(_)) => Ordering::Greater,
+      (Self::Alpha(a), Self::Alpha(b)) => a.cmp(b),
+    }
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+  major: u64,
+  minor: u64,
+  patch: u64,
+  pre: Vec<PreRelease>,
+}
+
+impl Version {
+  /// Parses a full version like `1.2.3-beta.1+build`, with an optional leading `v` or `=`.
+  pub fn parse(version: &str) -> Option<Self> {
+    let partial = Partial::parse(version.trim().trim_start_matches(['v', '=']))?;
+    Some(Self {
+      major: partial.major?,
+      minor: partial.minor?,
+      patch: partial.patch?,
+      pre: partial.pre,
+    })
+  }
+
+  fn new(major: u64, minor: u64, patch: u64) -> Self {
+    Self {
+      major,
+      minor,
+      patch,
+      pre: vec![],
+    }
+  }
+
+  fn tuple(&self) -> (u64, u64, u64) {
+    (self.major, self.minor, self.patch)
+  }
+}
+
+impl PartialOrd for Version {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for Version {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.tuple().cmp(&other.tuple()).then_with(|| {
+      match (self.pre.is_empty(), other.pre.is_empty()) {
+        (true, true) => Ordering::Equal,
+        // a pre-release is lower than the release
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => self.pre.cmp(&other.pre),
+      }
+    })
+  }
+}
+
+/// A version whose missing or `x` parts match anything.
+#[derive(Debug, Default)]
+struct Partial {
+  major: Option<u64>,
+  minor: Option<u64>,
+  patch: Option<u64>,
+  pre: Vec<PreRelease>,
+}
+
+impl Partial {
+  fn parse(partial: &str) -> Option<Self> {
+    let partial = partial
+      .split_once('+')
+      .map_or(partial, |(partial, _)| partial);
+    let (numbers, pre) = match partial.split_once('-') {
+      Some((numbers, pre)) => (numbers, Some(pre)),
+      None => (partial, None),
+    };
+    let mut parts = [None; 3];
+    if !numbers.is_empty() {
+      let mut numbers = numbers.split('.');
+      for part in parts.iter_mut() {
+        match numbers.next() {
+          Some("x" | "X" | "*") | None => break,
+          Some(number) => *part = Some(number.parse().ok()?),
+        }
+      }
+    }
+    let pre = pre
+      .into_iter()
+      .flat_map(|pre| pre.split('.'))
+      .map(|identifier| match identifier.parse() {
+        Ok(number) => PreRelease::Numeric(number),
+        Err(_) => PreRelease::Alpha(identifier.to_string()),
+      })
+      .collect();
+    let [major, minor, patch] = parts;
+    Some(Self {
+      major,
+      minor,
+      patch,
+      pre,
+    })
+  }
+
+  /// The lowest version matched by the partial.
+  fn floor(&self) -> Version {
+    Version {
+      major: self.major.unwrap_or_default(),
+      minor: self.minor.unwrap_or_default(),
+      patch: self.patch.unwrap_or_default(),
+      pre: self.pre.clone(),
+    }
+  }
+
+  /// The lowest version greater than all versions matched by the partial, `None` for `*`.
+  fn ceil(&self) -> Option<Version> {
+    match (self.major, self.minor, self.patch) {
+      (None, _, _) => None,
+      (Some(major), None, _) => Some(Version::new(major + 1, 0, 0)),
+      (Some(major), Some(minor), None) => Some(Version::new(major, minor + 1, 0)),
+      (Some(major), Some(minor), Some(patch)) => Some(Version::new(major, minor, patch + 1)),
+    }
+  }
+
+  fn is_full(&self) -> bool {
+    self.patch.is_some()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+  Lt,
+  Le,
+  Gt,
+  Ge,
+  Eq,
+}
+
+#[derive(Debug, Clone)]
+struct Comparator {
+  operator: Operator,
+  version: Version,
+}
+
+impl Comparator {
+  fn new(operator: Operator, version: Version) -> Self {
+    Self { operator, version }
+  }
+
+  fn test(&self, version: &Version) -> bool {
+    let ordering = version.cmp(&self.version);
+    match self.operator {
+      Operator::Lt => ordering.is_lt(),
+      Operator::Le => ordering.is_le(),
+      Operator::Gt => ordering.is_gt(),
+      Operator::Ge => ordering.is_ge(),
+      Operator::Eq => ordering.is_eq(),
+    }
+  }
+}
+
+/// Comparators of `[lower, upper)`, or all versions for `*`.
+fn between(lower: Version, upper: Option<Version>) -> Vec<Comparator> {
+  let mut comparators = vec![];
+  if lower != Version::new(0, 0, 0) {
+    comparators.push(Comparator::new(Operator::Ge, lower));
+  }
+  if let Some(upper) = upper {
+    comparators.push(Comparator::new(Operator::Lt, upper));
+  }
+  comparators
+}
+
+fn parse_simple(simple: &str) -> Option<Vec<Comparator>> {
+  let operator = ["<=", ">=", "<", ">", "=", "^", "~", "v"]
+    .into_iter()
+    .find(|operator| simple.starts_with(operator))
+    .unwrap_or_default();
+  let partial = Partial::parse(simple[operator.len()..].trim())?;
+  let comparators = match operator {
+    "^" => {
+      let upper = match (partial.major, partial.minor, partial.patch) {
+        (Some(0), Some(0), Some(patch)) => Some(Version::new(0, 0, patch + 1)),
+        (Some(0), Some(minor), _) => Some(Version::new(0, minor + 1, 0)),
+        _ => partial
+          .ceil()
+          .map(|_| Version::new(partial.floor().major + 1, 0, 0)),
+      };
+      between(partial.floor(), upper)
+    }
+    "~" => {
+      let upper = match (partial.major, partial.minor) {
+        (Some(major), Some(minor)) => Some(Version::new(major, minor + 1, 0)),
+        _ => partial.ceil(),
+      };
+      between(partial.floor(), upper)
+    }
+    ">=" => between(partial.floor(), None),
+    ">" if partial.is_full() => vec![Comparator::new(Operator::Gt, partial.floor())],
+    // `>1.2` is `>=1.3.0` and `>*` matches nothing
+    ">" => vec![Comparator::new(
+      Operator::Ge,
+      partial.ceil().unwrap_or(Version::new(u64::MAX, 0, 0)),
+    )],
+    "<" => vec![Comparator::new(Operator::Lt, partial.floor())],
+    "<=" if partial.is_full() => vec![Comparator::new(Operator::Le, partial.floor())],
+    "<=" => between(Version::new(0, 0, 0), partial.ceil()),
+    _ if partial.is_full() => vec![Comparator::new(Operator::Eq, partial.floor())],
+    _ => between(partial.floor(), partial.ceil()),
+  };
+  Some(comparators)
+}
+
+fn parse_hyphen(lower: &str, upper: &str) -> Option<Vec<Comparator>> {
+  let lower = Partial::parse(lower.trim())?;
+  let upper = Partial::parse(upper.trim())?;
+  let mut comparators = between(lower.floor(), None);
+  if upper.is_full() {
+    comparators.push(Comparator::new(Operator::Le, upper.floor()));
+  } else if let Some(ceil) = upper.ceil() {
+    comparators.push(Comparator::new(Operator::Lt, ceil));
+  }
+  Some(comparators)
+}
+
+/// A union of intersections of comparators.
+#[derive(Debug, Clone)]
+pub struct Range {
+  sets: Vec<Vec<Comparator>>,
+}
+
+impl Range {
+  pub fn parse(range: &str) -> Option<Self> {
+    let mut sets = vec![];
+    for set in range.split("||") {
+      let set = set.trim();
+      if let Some((lower, upper)) = set.split_once(" - ") {
+        sets.push(parse_hyphen(lower, upper)?);
+        continue;
+      }
+      let mut comparators = vec![];
+      let mut operator = String::new();
+      for token in set.split_whitespace() {
+        // operators may be separated from their versions by spaces, e.g. `>= 1.2.3`
+        if token
+          .chars()
+          .all(|c| matches!(c, '<' | '>' | '=' | '^' | '~'))
+        {
+          operator.push_str(token);
+          continue;
+        }
+        comparators.extend(parse_simple(&format!("{operator}{token}"))?);
+        operator.clear();
+      }
+      sets.push(comparators);
+    }
+    Some(Self { sets })
+  }
+
+  pub fn satisfies(&self, version: &Version) -> bool {
+    self.sets.iter().any(|comparators| {
+      comparators.iter().all(|comparator| comparator.test(version))
+        // a pre-release only satisfies comparators of pre-releases of the same version
+        && (version.pre.is_empty()
+          || comparators.iter().any(|comparator| {
+            !comparator.version.pre.is_empty() && comparator.version.tuple() == version.tuple()
+          }))
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Range, Version};
+
+  fn satisfies(range: &str, version: &str) -> Option<bool> {
+    Some(Range::parse(range)?.satisfies(&Version::parse(version)?))
+  }
+
+  #[test]
+  fn should_satisfy_ranges() {
+    let cases = [
+      ("^18.2.0", "18.3.1", true),
+      ("^18.2.0", "19.0.0", false),
+      ("^0.2.3", "0.2.9", true),
+      ("^0.2.3", "0.3.0", false),
+      ("^0.0.3", "0.0.4", false),
+      ("~1.2.3", "1.2.9", true),
+      ("~1.2.3", "1.3.0", false),
+      ("1.x", "1.9.0", true),
+      ("1.2", "1.3.0", false),
+      ("*", "3.0.0", true),
+      ("", "3.0.0", true),
+      (">=1.2.3 <2", "1.5.0", true),
+      (">= 1.2.3 < 2", "2.0.0", false),
+      (">1.2", "1.2.9", false),
+      ("<=1.2", "1.2.9", true),
+      ("1.2.3 - 2.3", "2.3.9", true),
+      ("1.2.3 - 2.3.4", "2.3.5", false),
+      ("^16.0.0 || ^17.0.0", "17.0.2", true),
+      ("^16.0.0 || ^17.0.0", "18.0.0", false),
+      ("=1.2.3", "1.2.3", true),
+      ("^1.2.3", "1.3.0-beta.1", false),
+      ("^1.3.0-beta.0", "1.3.0-beta.1", true),
+    ];
+    for (range, version, expected) in cases {
+      assert_eq!(
+        satisfies(range, version),
+        Some(expected),
+        "{version} satisfies {range}"
+      );
+    }
+    assert_eq!(satisfies("^a.b", "1.0.0"), None);
+    assert!(Version::parse("1.0.0-beta.2").unwrap() < Version::parse("1.0.0-beta.10").unwrap());
+    assert!(Version::parse("1.0.0-rc.1").unwrap() < Version::parse("1.0.0").unwrap());
+  }
+}
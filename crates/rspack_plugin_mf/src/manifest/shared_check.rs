@@ -0,0 +1,396 @@
+use std::collections::BTreeMap;
+
+use rspack_core::Compilation;
+use rspack_error::Diagnostic;
+use rspack_fs::ReadableFileSystem;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+use super::{
+  data::{StatsRoot, StatsSharedConflict, StatsSharedConflictKind, StatsSharedProvider},
+  options::RemoteAliasTarget,
+  semver::{Range, Version},
+};
+use crate::{ConsumeSharedModule, ConsumeVersion, ProvideSharedModule, ProvideVersion};
+
+const DEFAULT_SHARE_SCOPE: &str = "default";
+
+#[derive(Debug)]
+struct Consumption {
+  container: String,
+  required_version: String,
+  strict_version: bool,
+}
+
+#[derive(Debug, Default)]
+struct SharedUsage {
+  singleton: bool,
+  providers: Vec<StatsSharedProvider>,
+  consumptions: Vec<Consumption>,
+}
+
+/// The provided and required versions of shared modules across containers, by share scope
+/// and share key.
+#[derive(Debug, Default)]
+pub struct SharedUsages {
+  usages: BTreeMap<(String, String), SharedUsage>,
+}
+
+impl SharedUsages {
+  fn usage(&mut self, share_scope: &str, share_key: &str) -> &mut SharedUsage {
+    self
+      .usages
+      .entry((share_scope.to_string(), share_key.to_string()))
+      .or_default()
+  }
+
+  /// Collects the shared modules provided and consumed by the compilation.
+  pub fn add_compilation(&mut self, compilation: &Compilation, container_name: &str) {
+    let context = compilation.options.context.as_path();
+    for (_, module) in compilation.get_module_graph().modules() {
+      if let Some(provide) = module.as_any().downcast_ref::<ProvideSharedModule>() {
+        let ProvideVersion::Version(version) = provide.version() else {
+          continue;
+        };
+        let request = Utf8Path::new(provide.request());
+        let request = request.strip_prefix(context).unwrap_or(request);
+        let usage = self.usage(provide.share_scope(), provide.share_key());
+        usage.singleton |= provide.singleton().unwrap_or_default();
+        usage.providers.push(StatsSharedProvider {
+          container: container_name.to_string(),
+          version: version.clone(),
+          request: Some(request.to_string()),
+        });
+      } else if let Some(consume) = module.as_any().downcast_ref::<ConsumeSharedModule>() {
+        let options = consume.options();
+        let usage = self.usage(&options.share_scope, &options.share_key);
+        usage.singleton |= options.singleton;
+        if let Some(ConsumeVersion::Version(required_version)) = &options.required_version {
+          usage.consumptions.push(Consumption {
+            container: container_name.to_string(),
+            required_version: required_version.clone(),
+            strict_version: options.strict_version,
+          });
+        }
+      }
+    }
+  }
+
+  /// Collects the shared modules of a remote from its manifest or stats, manifests without the
+  /// share scope are from the default share scope.
+  pub fn add_manifest(&mut self, manifest: &StatsRoot) {
+    for shared in &manifest.shared {
+      let share_scope = shared.shareScope.as_deref().unwrap_or(DEFAULT_SHARE_SCOPE);
+      let usage = self.usage(share_scope, &shared.name);
+      usage.singleton |= shared.singleton.unwrap_or_default();
+      if !shared.version.is_empty() {
+        usage.providers.push(StatsSharedProvider {
+          container: manifest.name.clone(),
+          version: shared.version.clone(),
+          request: None,
+        });
+      }
+      if let Some(required_version) = &shared.requiredVersion {
+        usage.consumptions.push(Consumption {
+          container: manifest.name.clone(),
+          required_version: required_version.clone(),
+          strict_version: shared.strictVersion.unwrap_or_default(),
+        });
+      }
+    }
+  }
+
+  /// Reports the singletons which resolve to a version out of a required range at runtime, and
+  /// the shared modules a container provides more than once.
+  pub fn check(mut self) -> (Vec<StatsSharedConflict>, Vec<Diagnostic>) {
+    let mut conflicts = vec![];
+    let mut diagnostics = vec![];
+    for ((share_scope, name), usage) in &mut self.usages {
+      usage.providers.sort_unstable();
+      usage.providers.dedup();
+
+      let mut by_container: BTreeMap<&str, Vec<&StatsSharedProvider>> = BTreeMap::new();
+      for provider in &usage.providers {
+        by_container
+          .entry(&provider.container)
+          .or_default()
+          .push(provider);
+      }
+      for (container, providers) in by_container {
+        if providers.len() < 2 {
+          continue;
+        }
+        let list = providers
+          .iter()
+          .map(|provider| match &provider.request {
+            Some(request) => format!("{} ({request})", provider.version),
+            None => provider.version.clone(),
+          })
+          .collect::<Vec<_>>()
+          .join(", ");
+        diagnostics.push(Diagnostic::warn(
+          "ModuleFederationSharedConflict".to_string(),
+          format!(
+            "Shared module \"{name}\" in share scope \"{share_scope}\" is provided more than once by \"{container}\": {list}"
+          ),
+        ));
+        conflicts.push(StatsSharedConflict {
+          kind: StatsSharedConflictKind::DuplicateProvision,
+          name: name.clone(),
+          shareScope: share_scope.clone(),
+          version: None,
+          requiredVersion: None,
+          consumer: None,
+          strictVersion: false,
+          providers: providers.into_iter().cloned().collect(),
+        });
+      }
+
+      if !usage.singleton {
+        continue;
+      }
+      // the runtime loads the highest provided version of a singleton
+      let Some((resolved, provider)) = usage
+        .providers
+        .iter()
+        .filter_map(|provider| Some((Version::parse(&provider.version)?, provider)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+      else {
+        continue;
+      };
+      for consumption in &usage.consumptions {
+        let Some(range) = Range::parse(&consumption.required_version) else {
+          continue;
+        };
+        if range.satisfies(&resolved) {
+          continue;
+        }
+        let message = format!(
+          "Shared singleton module \"{name}\" in share scope \"{share_scope}\" resolves to version {} provided by \"{}\", which doesn't satisfy the required version \"{}\" of \"{}\"",
+          provider.version, provider.container, consumption.required_version, consumption.container
+        );
+        diagnostics.push(if consumption.strict_version {
+          Diagnostic::error("ModuleFederationSharedConflict".to_string(), message)
+        } else {
+          Diagnostic::warn("ModuleFederationSharedConflict".to_string(), message)
+        });
+        conflicts.push(StatsSharedConflict {
+          kind: StatsSharedConflictKind::UnsatisfiedSingleton,
+          name: name.clone(),
+          shareScope: share_scope.clone(),
+          version: Some(provider.version.clone()),
+          requiredVersion: Some(consumption.required_version.clone()),
+          consumer: Some(consumption.container.clone()),
+          strictVersion: consumption.strict_version,
+          providers: usage.providers.clone(),
+        });
+      }
+    }
+    (conflicts, diagnostics)
+  }
+}
+
+/// Returns the path of a remote entry which is a manifest on disk, e.g. `./remote/mf-manifest.json`
+/// or `file:///remote/mf-manifest.json`.
+pub fn local_manifest_path(target: &RemoteAliasTarget, context: &Utf8Path) -> Option<Utf8PathBuf> {
+  let entry = target.entry.as_deref()?;
+  let path = match entry.strip_prefix("file://") {
+    Some(path) => path,
+    None if entry.contains("://") || entry.starts_with("//") => return None,
+    None => entry,
+  };
+  path
+    .ends_with(".json")
+    .then(|| context.join(path.split(['?', '#']).next().unwrap_or(path)))
+}
+
+/// The manifests of remotes read from disk, and the files to be watched.
+#[derive(Debug, Default)]
+struct RemoteManifests {
+  manifests: Vec<StatsRoot>,
+  file_dependencies: Vec<Utf8PathBuf>,
+  missing_dependencies: Vec<Utf8PathBuf>,
+  diagnostics: Vec<Diagnostic>,
+}
+
+async fn read_manifests(fs: &dyn ReadableFileSystem, paths: Vec<Utf8PathBuf>) -> RemoteManifests {
+  let mut result = RemoteManifests::default();
+  for path in paths {
+    let Ok(content) = fs.read_to_string(&path).await else {
+      result.missing_dependencies.push(path);
+      continue;
+    };
+    match serde_json::from_str::<StatsRoot>(&content) {
+      Ok(manifest) => result.manifests.push(manifest),
+      Err(error) => result.diagnostics.push(Diagnostic::warn(
+        "ModuleFederationManifestError".to_string(),
+        format!("Failed to parse the remote manifest {path}: {error}"),
+      )),
+    }
+    result.file_dependencies.push(path);
+  }
+  result
+}
+
+/// Reads the manifests of remotes, the missing ones are skipped but watched.
+pub async fn read_remote_manifests(
+  compilation: &mut Compilation,
+  paths: Vec<Utf8PathBuf>,
+) -> Vec<StatsRoot> {
+  let fs = compilation.input_filesystem.clone();
+  let result = read_manifests(fs.as_ref(), paths).await;
+  for path in result.file_dependencies {
+    compilation.file_dependencies.insert(path.as_path().into());
+  }
+  for path in result.missing_dependencies {
+    compilation
+      .missing_dependencies
+      .insert(path.as_path().into());
+  }
+  compilation.extend_diagnostics(result.diagnostics);
+  result.manifests
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_fs::{MemoryFileSystem, WritableFileSystem};
+  use rspack_paths::Utf8PathBuf;
+
+  use super::{Consumption, SharedUsages, read_manifests};
+  use crate::manifest::data::{StatsRoot, StatsSharedConflictKind, StatsSharedProvider};
+
+  fn provider(container: &str, version: &str, request: Option<&str>) -> StatsSharedProvider {
+    StatsSharedProvider {
+      container: container.to_string(),
+      version: version.to_string(),
+      request: request.map(ToString::to_string),
+    }
+  }
+
+  #[test]
+  fn should_report_conflicts() {
+    let mut usages = SharedUsages::default();
+    let react = usages.usage("default", "react");
+    react.singleton = true;
+    react.providers.push(provider("host", "17.0.2", None));
+    react.providers.push(provider("remote", "18.2.0", None));
+    react.consumptions.push(Consumption {
+      container: "host".to_string(),
+      required_version: "^17.0.0".to_string(),
+      strict_version: true,
+    });
+    react.consumptions.push(Consumption {
+      container: "remote".to_string(),
+      required_version: "^18.0.0".to_string(),
+      strict_version: false,
+    });
+    let lodash = usages.usage("default", "lodash");
+    lodash.providers.push(provider(
+      "host",
+      "4.17.21",
+      Some("node_modules/lodash/lodash.js"),
+    ));
+    lodash.providers.push(provider(
+      "host",
+      "4.17.15",
+      Some("node_modules/a/node_modules/lodash/lodash.js"),
+    ));
+
+    let (conflicts, diagnostics) = usages.check();
+    assert_eq!(conflicts.len(), 2);
+    assert_eq!(
+      conflicts[0].kind,
+      StatsSharedConflictKind::DuplicateProvision
+    );
+    assert_eq!(conflicts[0].providers.len(), 2);
+    assert_eq!(
+      conflicts[1].kind,
+      StatsSharedConflictKind::UnsatisfiedSingleton
+    );
+    assert_eq!(conflicts[1].version.as_deref(), Some("18.2.0"));
+    assert_eq!(conflicts[1].consumer.as_deref(), Some("host"));
+    assert!(conflicts[1].strictVersion);
+    assert_eq!(diagnostics.len(), 2);
+  }
+
+  #[test]
+  fn should_check_manifests_in_their_share_scopes() {
+    let manifest = |name: &str, shared: &str| -> StatsRoot {
+      serde_json::from_str(&format!(
+        r#"{{ "id": "{name}", "name": "{name}", "metaData": {{ "name": "{name}", "globalName": "{name}" }}, "shared": [{shared}] }}"#
+      ))
+      .expect("should parse manifest")
+    };
+    let mut usages = SharedUsages::default();
+    usages.add_manifest(&manifest(
+      "remote_1",
+      r#"{ "id": "remote_1:react", "name": "react", "version": "18.2.0", "singleton": true, "shareScope": "legacy" }"#,
+    ));
+    usages.add_manifest(&manifest(
+      "remote_2",
+      r#"{ "id": "remote_2:react", "name": "react", "version": "17.0.2", "requiredVersion": "^17.0.0", "singleton": true, "strictVersion": true }"#,
+    ));
+    // the versions in different share scopes never conflict
+    let (conflicts, diagnostics) = usages.check();
+    assert!(conflicts.is_empty());
+    assert!(diagnostics.is_empty());
+
+    let mut usages = SharedUsages::default();
+    usages.add_manifest(&manifest(
+      "remote_1",
+      r#"{ "id": "remote_1:react", "name": "react", "version": "18.2.0", "singleton": true }"#,
+    ));
+    usages.add_manifest(&manifest(
+      "remote_2",
+      r#"{ "id": "remote_2:react", "name": "react", "version": "17.0.2", "requiredVersion": "^17.0.0", "singleton": true, "strictVersion": true }"#,
+    ));
+    let (conflicts, diagnostics) = usages.check();
+    assert_eq!(conflicts.len(), 1);
+    assert_eq!(conflicts[0].shareScope, "default");
+    assert!(conflicts[0].strictVersion);
+    assert_eq!(diagnostics[0].severity, rspack_error::Severity::Error);
+  }
+
+  #[tokio::test]
+  async fn should_read_remote_manifests() {
+    let fs = MemoryFileSystem::default();
+    fs.create_dir_all("/remote".into()).await.unwrap();
+    fs.write(
+      "/remote/mf-manifest.json".into(),
+      r#"{ "id": "remote", "name": "remote", "metaData": { "name": "remote", "globalName": "remote" } }"#.as_bytes(),
+    )
+    .await
+    .unwrap();
+    fs.write("/remote/broken.json".into(), "{ \"id\":".as_bytes())
+      .await
+      .unwrap();
+
+    let result = read_manifests(
+      &fs,
+      vec![
+        Utf8PathBuf::from("/remote/mf-manifest.json"),
+        Utf8PathBuf::from("/remote/broken.json"),
+        Utf8PathBuf::from("/missing/mf-manifest.json"),
+      ],
+    )
+    .await;
+    assert_eq!(result.manifests.len(), 1);
+    assert_eq!(result.manifests[0].name, "remote");
+    // the malformed manifest is reported but still watched
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(
+      result.diagnostics[0].code.as_deref(),
+      Some("ModuleFederationManifestError")
+    );
+    assert_eq!(
+      result.file_dependencies,
+      vec![
+        Utf8PathBuf::from("/remote/mf-manifest.json"),
+        Utf8PathBuf::from("/remote/broken.json"),
+      ]
+    );
+    assert_eq!(
+      result.missing_dependencies,
+      vec![Utf8PathBuf::from("/missing/mf-manifest.json")]
+    );
+  }
+}
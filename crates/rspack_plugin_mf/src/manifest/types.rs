@@ -0,0 +1,507 @@
+use std::{
+  collections::BTreeMap,
+  hash::Hasher,
+  io::{Cursor, Read, Write},
+  sync::{Arc, Mutex},
+};
+
+use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
+use rspack_fs::ReadableFileSystem;
+use rspack_javascript_compiler::isolated_declarations::{
+  generate_isolated_declaration, has_default_export, is_declaration_file,
+};
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+use rspack_util::fx_hash::FxHashMap as HashMap;
+use rustc_hash::FxHasher;
+use sugar_path::SugarPath;
+use swc_core::{
+  common::{FileName, GLOBALS, Globals, Mark, SourceMap, comments::SingleThreadedComments},
+  ecma::{
+    ast::{EsVersion, ExportAll, ImportDecl, NamedExport, Program, TsImportType},
+    parser::{Syntax, TsSyntax, parse_file_as_module},
+    transforms::base::resolver,
+    visit::{Visit, VisitMutWith, VisitWith},
+  },
+};
+use zip::{ZipArchive, ZipWriter, write::SimpleFileOptions};
+
+/// The directory in the types archive that mirrors the source tree of the exposed modules.
+pub(crate) const COMPILED_TYPES_DIR: &str = "compiled-types";
+
+const TS_EXTENSIONS: &[&str] = &[".ts", ".tsx", ".mts", ".cts"];
+
+#[derive(Debug, Clone)]
+struct Declaration {
+  code: String,
+  has_default_export: bool,
+  /// Relative specifiers of imports and re-exports in the declaration.
+  requests: Vec<String>,
+}
+
+/// The declarations or the errors of generating them by the source files, cached across
+/// compilations by the content hashes of the sources.
+#[derive(Debug, Default)]
+pub(crate) struct DeclarationCache(Mutex<HashMap<Utf8PathBuf, CachedDeclaration>>);
+
+type CachedDeclaration = (u64, std::result::Result<Declaration, String>);
+
+impl DeclarationCache {
+  fn get(&self, file: &Utf8Path, source: String) -> std::result::Result<Declaration, String> {
+    let mut hasher = FxHasher::default();
+    hasher.write(source.as_bytes());
+    let hash = hasher.finish();
+    if let Some((cached_hash, declaration)) = self.lock().get(file)
+      && *cached_hash == hash
+    {
+      return declaration.clone();
+    }
+    let declaration = if is_declaration_file(file.as_str()) {
+      parse_declaration(file, source)
+    } else {
+      generate_declaration(file, source)
+    };
+    self
+      .lock()
+      .insert(file.to_path_buf(), (hash, declaration.clone()));
+    declaration
+  }
+
+  /// Drops the declarations of the files no longer reachable from the exposes.
+  fn retain(&self, files: &BTreeMap<Utf8PathBuf, Option<bool>>) {
+    self.lock().retain(|file, _| files.contains_key(file));
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<Utf8PathBuf, CachedDeclaration>> {
+    self.0.lock().expect("should lock declaration cache")
+  }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ExposeTypes {
+  /// The files of the types archive by their paths in the archive.
+  pub files: BTreeMap<String, String>,
+  /// The names of the exposes with declarations, e.g. `Button` for `./Button`.
+  pub exposes: Vec<String>,
+  /// The source files the declarations are generated from.
+  pub dependencies: Vec<Utf8PathBuf>,
+  pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Collects the declarations for the exposes, `exposes` are the expose keys and the resource
+/// paths of the exposed modules.
+///
+/// The archive contains the declarations of the exposed modules and the local modules they
+/// import under `compiled-types/`, laid out like the sources relative to `context`, and an
+/// entry declaration for each expose, e.g. `Button.d.ts` for `./Button`.
+pub(crate) async fn collect_expose_types(
+  fs: &dyn ReadableFileSystem,
+  cache: &DeclarationCache,
+  context: &Utf8Path,
+  exposes: &[(String, Utf8PathBuf)],
+) -> ExposeTypes {
+  let mut types = ExposeTypes::default();
+  let mut visited = BTreeMap::<Utf8PathBuf, Option<bool>>::new();
+
+  for (expose_key, resource) in exposes {
+    let mut queue = vec![resource.clone()];
+    while let Some(file) = queue.pop() {
+      if visited.contains_key(&file) {
+        continue;
+      }
+      visited.insert(file.clone(), None);
+      let Some(archive_path) = compiled_types_path(&file, context) else {
+        types.diagnostics.push(Diagnostic::warn(
+          "ModuleFederationTypesWarning".to_string(),
+          format!("Types of {file} are not included in the types archive as it's out of {context}"),
+        ));
+        continue;
+      };
+      let Ok(source) = fs.read_to_string(&file).await else {
+        continue;
+      };
+      types.dependencies.push(file.clone());
+      let declaration = match cache.get(&file, source) {
+        Ok(declaration) => declaration,
+        Err(message) => {
+          types.diagnostics.push(Diagnostic::warn(
+            "ModuleFederationTypesWarning".to_string(),
+            format!("Failed to generate types of {file}:\n{message}"),
+          ));
+          continue;
+        }
+      };
+      let dir = file.parent().unwrap_or(context);
+      for request in &declaration.requests {
+        if let Some(resolved) = resolve_request(fs, dir, request).await {
+          queue.push(resolved);
+        }
+      }
+      visited.insert(file, Some(declaration.has_default_export));
+      types.files.insert(archive_path, declaration.code);
+    }
+
+    let Some(Some(has_default_export)) = visited.get(resource).copied() else {
+      continue;
+    };
+    let Some(archive_path) = compiled_types_path(resource, context) else {
+      continue;
+    };
+    let (entry_path, specifier) = expose_entry(expose_key, &archive_path);
+    let mut code = format!("export * from '{specifier}';\n");
+    if has_default_export {
+      code.push_str(&format!("export {{ default }} from '{specifier}';\n"));
+    }
+    if let Some(name) = entry_path.strip_suffix(".d.ts") {
+      types.exposes.push(name.to_string());
+    }
+    types.files.insert(entry_path, code);
+  }
+  cache.retain(&visited);
+  types
+}
+
+/// Generates the declaration of the remote module keys like the API types of
+/// `@module-federation/dts-plugin`, consumers replace `REMOTE_ALIAS_IDENTIFIER` with the alias
+/// of the remote to type `loadRemote`.
+pub(crate) fn create_types_api(exposes: &[String]) -> String {
+  if exposes.is_empty() {
+    return "export type RemoteKeys = never;\ntype PackageType<T> = any;\n".to_string();
+  }
+  let keys = exposes
+    .iter()
+    .map(|name| format!("'REMOTE_ALIAS_IDENTIFIER/{name}'"))
+    .collect::<Vec<_>>();
+  let mut code = format!("export type RemoteKeys = {};\n", keys.join(" | "));
+  code.push_str("type PackageType<T> = ");
+  for key in &keys {
+    code.push_str(&format!("T extends {key} ? typeof import({key}) :\n  "));
+  }
+  code.push_str("any;\n");
+  code
+}
+
+/// Returns the path of the entry declaration of an expose in the archive, and the specifier
+/// from it to the declaration of the exposed module.
+fn expose_entry(expose_key: &str, archive_path: &str) -> (String, String) {
+  let name = match expose_key.trim_start_matches("./") {
+    "" | "." => "index",
+    name => name,
+  };
+  let depth = name.matches('/').count();
+  let prefix = if depth == 0 {
+    "./".to_string()
+  } else {
+    "../".repeat(depth)
+  };
+  let specifier = strip_declaration_extension(archive_path);
+  (format!("{name}.d.ts"), format!("{prefix}{specifier}"))
+}
+
+fn compiled_types_path(file: &Utf8Path, context: &Utf8Path) -> Option<String> {
+  let relative = file.as_std_path().relative(context.as_std_path());
+  let relative = relative.to_slash_lossy();
+  if relative.starts_with("..") {
+    return None;
+  }
+  if is_declaration_file(file.as_str()) {
+    return Some(format!("{COMPILED_TYPES_DIR}/{relative}"));
+  }
+  let (stem, extension) = match relative.rsplit_once('.') {
+    Some((stem, "mts")) => (stem, "d.mts"),
+    Some((stem, "cts")) => (stem, "d.cts"),
+    Some((stem, _)) => (stem, "d.ts"),
+    None => (relative.as_ref(), "d.ts"),
+  };
+  Some(format!("{COMPILED_TYPES_DIR}/{stem}.{extension}"))
+}
+
+fn strip_declaration_extension(path: &str) -> &str {
+  [".d.ts", ".d.mts", ".d.cts"]
+    .iter()
+    .find_map(|extension| path.strip_suffix(extension))
+    .unwrap_or(path)
+}
+
+/// Resolves a relative request of a declaration like TypeScript, `./a.js` is resolved to
+/// `./a.ts`. Requests to packages are left to the consumer.
+async fn resolve_request(
+  fs: &dyn ReadableFileSystem,
+  dir: &Utf8Path,
+  request: &str,
+) -> Option<Utf8PathBuf> {
+  if !request.starts_with("./") && !request.starts_with("../") {
+    return None;
+  }
+  let base = dir.join(request);
+  let base = Utf8PathBuf::from_path_buf(base.as_std_path().normalize().into_owned()).ok()?;
+  let base = base.as_str();
+  let stem = [".js", ".jsx", ".mjs", ".cjs"]
+    .iter()
+    .find_map(|extension| base.strip_suffix(extension));
+
+  let mut candidates = vec![];
+  if let Some(stem) = stem {
+    candidates.extend(
+      TS_EXTENSIONS
+        .iter()
+        .chain(&[".d.ts"])
+        .map(|extension| format!("{stem}{extension}")),
+    );
+  }
+  if TS_EXTENSIONS
+    .iter()
+    .any(|extension| base.ends_with(extension))
+  {
+    candidates.push(base.to_string());
+  }
+  candidates.extend(
+    TS_EXTENSIONS
+      .iter()
+      .chain(&[".d.ts"])
+      .map(|extension| format!("{base}{extension}")),
+  );
+  candidates.extend(
+    TS_EXTENSIONS
+      .iter()
+      .chain(&[".d.ts"])
+      .map(|extension| format!("{base}/index{extension}")),
+  );
+  for candidate in candidates {
+    let candidate = Utf8PathBuf::from(candidate);
+    if fs
+      .metadata(&candidate)
+      .await
+      .is_ok_and(|metadata| metadata.is_file)
+    {
+      return Some(candidate);
+    }
+  }
+  None
+}
+
+fn parse_program(
+  cm: &Arc<SourceMap>,
+  file: &Utf8Path,
+  source: String,
+  comments: &SingleThreadedComments,
+) -> std::result::Result<Program, String> {
+  let fm = cm.new_source_file(Arc::new(FileName::Real(file.into())), source);
+  let syntax = Syntax::Typescript(TsSyntax {
+    tsx: file.extension() == Some("tsx"),
+    dts: is_declaration_file(file.as_str()),
+    ..Default::default()
+  });
+  let mut errors = vec![];
+  let module = parse_file_as_module(
+    &fm,
+    syntax,
+    EsVersion::latest(),
+    Some(comments),
+    &mut errors,
+  )
+  .map_err(|error| error.kind().msg().to_string())?;
+  if let Some(error) = errors.first() {
+    return Err(error.kind().msg().to_string());
+  }
+  Ok(Program::Module(module))
+}
+
+fn parse_declaration(file: &Utf8Path, source: String) -> std::result::Result<Declaration, String> {
+  let cm: Arc<SourceMap> = Default::default();
+  let comments = SingleThreadedComments::default();
+  let program = parse_program(&cm, file, source.clone(), &comments)?;
+  Ok(Declaration {
+    has_default_export: has_default_export(&program),
+    requests: collect_requests(&program),
+    code: source,
+  })
+}
+
+/// Generates the declaration like `tsc --isolatedDeclarations`, declarations requiring type
+/// inference are reported as errors.
+fn generate_declaration(
+  file: &Utf8Path,
+  source: String,
+) -> std::result::Result<Declaration, String> {
+  let cm: Arc<SourceMap> = Default::default();
+  let comments = SingleThreadedComments::default();
+  let mut program = parse_program(&cm, file, source, &comments)?;
+  GLOBALS.set(&Globals::default(), || {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, true));
+    let declaration = generate_isolated_declaration(
+      &mut program,
+      Arc::new(FileName::Real(file.into())),
+      unresolved_mark,
+      &comments,
+    );
+    if !declaration.issues.is_empty() {
+      let messages = declaration
+        .issues
+        .into_iter()
+        .map(|issue| {
+          let loc = cm.lookup_char_pos(issue.span.lo);
+          format!(
+            "  {file}:{}:{}: {}",
+            loc.line,
+            loc.col_display + 1,
+            issue.message
+          )
+        })
+        .collect::<Vec<_>>();
+      return Err(messages.join("\n"));
+    }
+    Ok(Declaration {
+      has_default_export: declaration.has_default_export,
+      requests: collect_requests(&program),
+      code: declaration.code,
+    })
+  })
+}
+
+fn collect_requests(program: &Program) -> Vec<String> {
+  #[derive(Default)]
+  struct RequestCollector {
+    requests: Vec<String>,
+  }
+
+  impl Visit for RequestCollector {
+    fn visit_import_decl(&mut self, node: &ImportDecl) {
+      self
+        .requests
+        .push(node.src.value.to_string_lossy().into_owned());
+    }
+
+    fn visit_export_all(&mut self, node: &ExportAll) {
+      self
+        .requests
+        .push(node.src.value.to_string_lossy().into_owned());
+    }
+
+    fn visit_named_export(&mut self, node: &NamedExport) {
+      if let Some(src) = &node.src {
+        self.requests.push(src.value.to_string_lossy().into_owned());
+      }
+    }
+
+    fn visit_ts_import_type(&mut self, node: &TsImportType) {
+      self
+        .requests
+        .push(node.arg.value.to_string_lossy().into_owned());
+      node.visit_children_with(self);
+    }
+  }
+
+  let mut collector = RequestCollector::default();
+  program.visit_with(&mut collector);
+  collector.requests
+}
+
+/// Packs the files into a zip archive.
+pub(crate) fn create_types_archive(files: &BTreeMap<String, String>) -> Result<Vec<u8>> {
+  let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+  let options = SimpleFileOptions::default();
+  for (path, content) in files {
+    writer.start_file(path, options).to_rspack_result()?;
+    writer.write_all(content.as_bytes()).to_rspack_result()?;
+  }
+  Ok(writer.finish().to_rspack_result()?.into_inner())
+}
+
+/// Reads the files of a zip archive, entries escaping the archive root are skipped.
+pub(crate) fn read_types_archive(content: &[u8]) -> Result<Vec<(Utf8PathBuf, Vec<u8>)>> {
+  let mut archive = ZipArchive::new(Cursor::new(content)).to_rspack_result()?;
+  let mut files = vec![];
+  for index in 0..archive.len() {
+    let mut file = archive.by_index(index).to_rspack_result()?;
+    if file.is_dir() {
+      continue;
+    }
+    let Some(path) = file
+      .enclosed_name()
+      .and_then(|path| Utf8PathBuf::from_path_buf(path).ok())
+    else {
+      continue;
+    };
+    let mut content = vec![];
+    file.read_to_end(&mut content).to_rspack_result()?;
+    files.push((path, content));
+  }
+  Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::BTreeMap;
+
+  use rspack_paths::Utf8Path;
+
+  use super::{
+    compiled_types_path, create_types_api, create_types_archive, expose_entry, read_types_archive,
+  };
+
+  #[test]
+  fn should_layout_types() {
+    let context = Utf8Path::new("/project");
+    assert_eq!(
+      compiled_types_path(Utf8Path::new("/project/src/Button.tsx"), context).as_deref(),
+      Some("compiled-types/src/Button.d.ts")
+    );
+    assert_eq!(
+      compiled_types_path(Utf8Path::new("/project/src/types.d.ts"), context).as_deref(),
+      Some("compiled-types/src/types.d.ts")
+    );
+    assert_eq!(
+      compiled_types_path(Utf8Path::new("/shared/a.ts"), context),
+      None
+    );
+    assert_eq!(
+      expose_entry("./Button", "compiled-types/src/Button.d.ts"),
+      (
+        "Button.d.ts".to_string(),
+        "./compiled-types/src/Button".to_string()
+      )
+    );
+    assert_eq!(
+      expose_entry("./utils/format", "compiled-types/src/format.d.mts"),
+      (
+        "utils/format.d.ts".to_string(),
+        "../compiled-types/src/format".to_string()
+      )
+    );
+  }
+
+  #[test]
+  fn should_create_types_api() {
+    assert_eq!(
+      create_types_api(&["Button".to_string(), "utils/format".to_string()]),
+      "export type RemoteKeys = 'REMOTE_ALIAS_IDENTIFIER/Button' | 'REMOTE_ALIAS_IDENTIFIER/utils/format';\n\
+       type PackageType<T> = T extends 'REMOTE_ALIAS_IDENTIFIER/Button' ? typeof import('REMOTE_ALIAS_IDENTIFIER/Button') :\n  \
+       T extends 'REMOTE_ALIAS_IDENTIFIER/utils/format' ? typeof import('REMOTE_ALIAS_IDENTIFIER/utils/format') :\n  \
+       any;\n"
+    );
+  }
+
+  #[test]
+  fn should_roundtrip_archive() {
+    let files = BTreeMap::from([
+      (
+        "Button.d.ts".to_string(),
+        "export * from './compiled-types/src/Button';\n".to_string(),
+      ),
+      (
+        "compiled-types/src/Button.d.ts".to_string(),
+        "export declare const Button: () => void;\n".to_string(),
+      ),
+    ]);
+    let archive = create_types_archive(&files).expect("should create archive");
+    let unpacked = read_types_archive(&archive).expect("should read archive");
+    assert_eq!(unpacked.len(), 2);
+    assert_eq!(unpacked[1].0, "compiled-types/src/Button.d.ts");
+    assert_eq!(
+      unpacked[1].1,
+      files["compiled-types/src/Button.d.ts"].as_bytes()
+    );
+  }
+}
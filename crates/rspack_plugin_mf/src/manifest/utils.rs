@@ -167,6 +167,8 @@ pub fn ensure_shared_entry<'a>(
       requiredVersion: None,
       // default singleton to true
       singleton: Some(true),
+      shareScope: None,
+      strictVersion: None,
       assets: super::data::StatsAssetsGroup::default(),
       usedIn: Vec::new(),
       usedExports: Vec::new(),
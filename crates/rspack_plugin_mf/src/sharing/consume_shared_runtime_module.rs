@@ -14,6 +14,7 @@ use crate::utils::json_stringify;
 static CONSUMES_COMMON_TEMPLATE: &str = include_str!("./consumesCommon.ejs");
 static CONSUMES_INITIAL_TEMPLATE: &str = include_str!("./consumesInitial.ejs");
 static CONSUMES_LOADING_TEMPLATE: &str = include_str!("./consumesLoading.ejs");
+static CONSUMES_WITH_HMR_TEMPLATE: &str = include_str!("./consumesWithHmr.ejs");
 static CONSUMES_RUNTIME_REQUIREMENTS: LazyLock<RuntimeGlobals> =
   LazyLock::new(|| extract_runtime_globals_from_ejs(CONSUMES_COMMON_TEMPLATE));
 static CONSUMES_INITIAL_RUNTIME_REQUIREMENTS: LazyLock<RuntimeGlobals> =
@@ -41,6 +42,7 @@ impl ConsumeSharedRuntimeModule {
       TemplateId::Common => format!("{}_consumesCommon", self.id),
       TemplateId::Initial => format!("{}_consumesInitial", self.id),
       TemplateId::Loading => format!("{}_consumesLoading", self.id),
+      TemplateId::WithHmr => format!("{}_consumesWithHmr", self.id),
     }
   }
 }
@@ -49,6 +51,7 @@ enum TemplateId {
   Common,
   Initial,
   Loading,
+  WithHmr,
 }
 
 #[async_trait::async_trait]
@@ -71,6 +74,10 @@ impl RuntimeModule for ConsumeSharedRuntimeModule {
         self.get_template_id(TemplateId::Loading),
         CONSUMES_LOADING_TEMPLATE.to_string(),
       ),
+      (
+        self.get_template_id(TemplateId::WithHmr),
+        CONSUMES_WITH_HMR_TEMPLATE.to_string(),
+      ),
     ]
   }
 
@@ -204,14 +211,28 @@ impl RuntimeModule for ConsumeSharedRuntimeModule {
       }
       return Ok(source);
     }
+    let runtime_requirements = ChunkGraph::get_chunk_runtime_requirements(compilation, &chunk_ukey);
+    // the runtime globals used for hot updates are required by the hmr runtime already
+    let with_hmr = runtime_requirements.contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
     source += &runtime_template.render(&self.get_template_id(TemplateId::Common), None)?;
+    if with_hmr {
+      source += &runtime_template.render(&self.get_template_id(TemplateId::WithHmr), None)?;
+    }
     if !initial_consumes.is_empty() {
-      source += &runtime_template.render(&self.get_template_id(TemplateId::Initial), None)?;
+      source += &runtime_template.render(
+        &self.get_template_id(TemplateId::Initial),
+        Some(serde_json::json!({
+          "_with_hmr": with_hmr,
+        })),
+      )?;
     }
-    if ChunkGraph::get_chunk_runtime_requirements(compilation, &chunk_ukey)
-      .contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
-    {
-      source += &runtime_template.render(&self.get_template_id(TemplateId::Loading), None)?;
+    if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
+      source += &runtime_template.render(
+        &self.get_template_id(TemplateId::Loading),
+        Some(serde_json::json!({
+          "_with_hmr": with_hmr,
+        })),
+      )?;
     }
     Ok(source)
   }
@@ -5,10 +5,11 @@ use rspack_cacheable::{cacheable, cacheable_dyn};
 use rspack_collections::{Identifiable, Identifier};
 use rspack_core::{
   AsyncDependenciesBlock, AsyncDependenciesBlockIdentifier, BoxDependency, BoxModule, BuildContext,
-  BuildInfo, BuildMeta, BuildResult, CodeGenerationResult, Compilation, Context, DependenciesBlock,
-  DependencyId, FactoryMeta, LibIdentOptions, Module, ModuleCodeGenerationContext, ModuleGraph,
-  ModuleIdentifier, ModuleType, RuntimeGlobals, RuntimeSpec, SourceType, impl_module_meta_info,
-  impl_source_map_config, module_update_hash, rspack_sources::BoxSource,
+  BuildInfo, BuildMeta, BuildResult, ChunkGraph, CodeGenerationResult, Compilation, Context,
+  DependenciesBlock, DependencyId, FactoryMeta, LibIdentOptions, Module,
+  ModuleCodeGenerationContext, ModuleGraph, ModuleIdentifier, ModuleType, RuntimeGlobals,
+  RuntimeSpec, SourceType, impl_module_meta_info, impl_source_map_config, module_update_hash,
+  rspack_sources::BoxSource,
 };
 use rspack_error::{Result, impl_empty_diagnosable_trait};
 use rspack_hash::{RspackHash, RspackHashDigest};
@@ -91,6 +92,22 @@ impl ProvideSharedModule {
   pub fn share_key(&self) -> &str {
     &self.name
   }
+
+  pub fn share_scope(&self) -> &str {
+    &self.share_scope
+  }
+
+  pub fn version(&self) -> &ProvideVersion {
+    &self.version
+  }
+
+  pub fn request(&self) -> &str {
+    &self.request
+  }
+
+  pub fn singleton(&self) -> Option<bool> {
+    self.singleton
+  }
 }
 
 impl Identifiable for ProvideSharedModule {
@@ -193,6 +210,20 @@ impl Module for ProvideSharedModule {
     } else {
       runtime_template.async_module_factory(&self.get_blocks()[0], &self.request, compilation)
     };
+    let module_graph = compilation.get_module_graph();
+    let dependency_id = if self.eager {
+      self.get_dependencies().first()
+    } else {
+      self
+        .get_blocks()
+        .first()
+        .and_then(|block| module_graph.block_by_id(block))
+        .and_then(|block| block.get_dependencies().first())
+    };
+    let module_id = dependency_id
+      .and_then(|dependency_id| module_graph.module_identifier_by_dependency_id(dependency_id))
+      .and_then(|module| ChunkGraph::get_module_id(&compilation.module_ids_artifact, *module))
+      .cloned();
     code_generation_result
       .data
       .insert(CodeGenerationDataShareInit {
@@ -203,6 +234,7 @@ impl Module for ProvideSharedModule {
             name: self.name.clone(),
             version: self.version.clone(),
             factory,
+            module_id,
             eager: self.eager,
             singleton: self.singleton,
             strict_version: self.strict_version,
@@ -3,7 +3,7 @@ use std::sync::LazyLock;
 use hashlink::{LinkedHashMap, LinkedHashSet};
 use itertools::Itertools;
 use rspack_core::{
-  Compilation, ModuleId, RuntimeGlobals, RuntimeModule, RuntimeModuleGenerateContext,
+  ChunkGraph, Compilation, ModuleId, RuntimeGlobals, RuntimeModule, RuntimeModuleGenerateContext,
   RuntimeTemplate, SourceType, impl_runtime_module,
 };
 use rspack_plugin_runtime::extract_runtime_globals_from_ejs;
@@ -13,6 +13,7 @@ use super::provide_shared_plugin::ProvideVersion;
 use crate::{ConsumeVersion, utils::json_stringify};
 
 static INITIALIZE_SHARING_TEMPLATE: &str = include_str!("./initializeSharing.ejs");
+static INITIALIZE_SHARING_WITH_HMR_TEMPLATE: &str = include_str!("./initializeSharingWithHmr.ejs");
 static INITIALIZE_SHARING_RUNTIME_REQUIREMENTS: LazyLock<RuntimeGlobals> =
   LazyLock::new(|| extract_runtime_globals_from_ejs(INITIALIZE_SHARING_TEMPLATE));
 
@@ -26,12 +27,33 @@ impl ShareRuntimeModule {
   pub fn new(runtime_template: &RuntimeTemplate, enhanced: bool) -> Self {
     Self::with_name(runtime_template, "sharing", enhanced)
   }
+
+  fn get_template_id(&self, template_id: TemplateId) -> String {
+    match template_id {
+      TemplateId::InitializeSharing => self.id.to_string(),
+      TemplateId::WithHmr => format!("{}_with_hmr", self.id),
+    }
+  }
+}
+
+enum TemplateId {
+  InitializeSharing,
+  WithHmr,
 }
 
 #[async_trait::async_trait]
 impl RuntimeModule for ShareRuntimeModule {
   fn template(&self) -> Vec<(String, String)> {
-    vec![(self.id.to_string(), INITIALIZE_SHARING_TEMPLATE.to_string())]
+    vec![
+      (
+        self.get_template_id(TemplateId::InitializeSharing),
+        INITIALIZE_SHARING_TEMPLATE.to_string(),
+      ),
+      (
+        self.get_template_id(TemplateId::WithHmr),
+        INITIALIZE_SHARING_WITH_HMR_TEMPLATE.to_string(),
+      ),
+    ]
   }
 
   async fn generate(
@@ -47,6 +69,10 @@ impl RuntimeModule for ShareRuntimeModule {
       .build_chunk_graph_artifact
       .chunk_by_ukey
       .expect_get(&chunk_ukey);
+    // the runtime globals used for hot updates are required by the hmr runtime already
+    let with_hmr = !self.enhanced
+      && ChunkGraph::get_chunk_runtime_requirements(compilation, &chunk_ukey)
+        .contains(RuntimeGlobals::HMR_DOWNLOAD_UPDATE_HANDLERS);
     let module_graph = compilation.get_module_graph();
     let mut init_per_scope: FxHashMap<
       String,
@@ -99,6 +125,10 @@ impl RuntimeModule for ShareRuntimeModule {
                 if info.eager { "1" } else { "0" },
                 json_stringify(&info.tree_shaking_mode),
               );
+              if with_hmr && let Some(module_id) = &info.module_id {
+                stage += ", moduleId: ";
+                stage += &json_stringify(module_id);
+              }
               if self.enhanced {
                 if let Some(singleton) = info.singleton {
                   stage += ", singleton: ";
@@ -129,8 +159,13 @@ impl RuntimeModule for ShareRuntimeModule {
         initialize_sharing =
           runtime_template.render_runtime_globals(&RuntimeGlobals::INITIALIZE_SHARING)
       )
+    } else if with_hmr {
+      let mut source =
+        runtime_template.render(&self.get_template_id(TemplateId::InitializeSharing), None)?;
+      source += &runtime_template.render(&self.get_template_id(TemplateId::WithHmr), None)?;
+      source
     } else {
-      runtime_template.render(self.id.as_str(), None)?
+      runtime_template.render(&self.get_template_id(TemplateId::InitializeSharing), None)?
     };
     Ok(format!(
       r#"
@@ -176,6 +211,8 @@ pub struct ProvideSharedInfo {
   pub name: String,
   pub version: ProvideVersion,
   pub factory: String,
+  /// The id of the provided module, used to propagate its hot updates to the consumers.
+  pub module_id: Option<ModuleId>,
   pub eager: bool,
   pub singleton: Option<bool>,
   pub required_version: Option<ConsumeVersion>,
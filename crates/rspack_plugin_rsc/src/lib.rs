@@ -20,4 +20,5 @@ pub use loaders::{
   action_entry_loader_plugin::ActionEntryLoaderPlugin,
   client_entry_loader_plugin::ClientEntryLoaderPlugin,
 };
+pub use reference_manifest::ReferenceManifestOptions;
 pub use server_plugin::{RscServerPlugin, RscServerPluginOptions};
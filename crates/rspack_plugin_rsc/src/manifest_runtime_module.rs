@@ -125,7 +125,7 @@ impl RuntimeModule for RscManifestRuntimeModule {
   }
 }
 
-fn build_server_manifest(
+pub(crate) fn build_server_manifest(
   compilation: &Compilation,
   server_actions: &mut ServerReferenceManifest,
 ) -> Result<()> {
@@ -203,7 +203,7 @@ fn build_server_manifest(
   Ok(())
 }
 
-fn build_server_consumer_module_map(
+pub(crate) fn build_server_consumer_module_map(
   compilation: &Compilation,
   client_modules: &FxHashMap<String, ManifestExport>,
 ) -> FxHashMap<String, ManifestNode> {
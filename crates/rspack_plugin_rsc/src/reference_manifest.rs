@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+
+use rspack_util::fx_hash::FxIndexSet;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 
@@ -34,3 +37,46 @@ pub struct ModuleLoading {
 }
 
 pub type ServerReferenceManifest = FxHashMap<String, ManifestExport>;
+
+/// The version of the schema of the emitted reference manifest assets, bumped on breaking changes.
+pub const REFERENCE_MANIFEST_VERSION: u32 = 1;
+
+#[derive(Debug, Clone)]
+pub struct ReferenceManifestOptions {
+  pub client_filename: String,
+  pub server_filename: String,
+}
+
+impl Default for ReferenceManifestOptions {
+  fn default() -> Self {
+    Self {
+      client_filename: "client-reference-manifest.json".to_string(),
+      server_filename: "server-reference-manifest.json".to_string(),
+    }
+  }
+}
+
+/// The client reference manifest asset, maps are sorted by key to keep the output stable.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientReferenceManifestAsset<'a> {
+  pub version: u32,
+  pub module_loading: &'a ModuleLoading,
+  /// Client module resource -> client reference in the client compiler.
+  pub client_manifest: BTreeMap<&'a str, &'a ManifestExport>,
+  /// Client module id -> module of the SSR layer in the server compiler.
+  pub server_consumer_module_map: BTreeMap<&'a str, BTreeMap<&'a str, &'a ManifestExport>>,
+  /// Entry name -> JS files to load in the browser.
+  pub entry_js_files: BTreeMap<&'a str, &'a FxIndexSet<String>>,
+  /// Entry name -> server entry resource -> CSS files to load in the browser.
+  pub entry_css_files: BTreeMap<&'a str, BTreeMap<&'a str, &'a FxIndexSet<String>>>,
+}
+
+/// The server reference manifest asset.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerReferenceManifestAsset<'a> {
+  pub version: u32,
+  /// Server action id -> server reference in the server compiler.
+  pub server_manifest: BTreeMap<&'a str, &'a ManifestExport>,
+}
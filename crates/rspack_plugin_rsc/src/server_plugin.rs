@@ -5,12 +5,13 @@ use derive_more::Debug;
 use futures::future::BoxFuture;
 use rspack_collections::{Identifiable, IdentifierMap};
 use rspack_core::{
-  BoxDependency, ChunkUkey, Compilation, CompilationParams, CompilationProcessAssets,
-  CompilationRuntimeRequirementInTree, CompilerDone, CompilerFailed, CompilerFinishMake,
-  CompilerThisCompilation, Dependency, DependencyId, EntryDependency, EntryOptions, Logger, Plugin,
-  RuntimeGlobals, RuntimeModule, RuntimeSpec, get_entry_runtime,
+  BoxDependency, ChunkUkey, Compilation, CompilationAsset, CompilationParams,
+  CompilationProcessAssets, CompilationRuntimeRequirementInTree, CompilerDone, CompilerFailed,
+  CompilerFinishMake, CompilerThisCompilation, Dependency, DependencyId, EntryDependency,
+  EntryOptions, Logger, Plugin, RuntimeGlobals, RuntimeModule, RuntimeSpec, get_entry_runtime,
+  rspack_sources::{RawStringSource, SourceExt},
 };
-use rspack_error::Result;
+use rspack_error::{Result, ToStringResultToRspackResultExt};
 use rspack_hook::{plugin, plugin_hook};
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::json;
@@ -26,8 +27,14 @@ use crate::{
     action_entry_loader::ACTION_ENTRY_LOADER_IDENTIFIER,
     client_entry_loader::CLIENT_ENTRY_LOADER_IDENTIFIER,
   },
-  manifest_runtime_module::RscManifestRuntimeModule,
+  manifest_runtime_module::{
+    RscManifestRuntimeModule, build_server_consumer_module_map, build_server_manifest,
+  },
   plugin_state::{ActionIdNamePair, ClientModuleImport, PLUGIN_STATES, PluginState},
+  reference_manifest::{
+    ClientReferenceManifestAsset, REFERENCE_MANIFEST_VERSION, ReferenceManifestOptions,
+    ServerReferenceManifestAsset,
+  },
 };
 
 #[derive(Debug)]
@@ -63,6 +70,9 @@ type OnServerComponentChanges = Box<dyn Fn() -> BoxFuture<'static, Result<()>> +
 pub struct RscServerPluginOptions {
   pub coordinator: Arc<Coordinator>,
   pub on_server_component_changes: Option<OnServerComponentChanges>,
+  /// Emits the client and server reference manifests as JSON assets, for server runtimes which
+  /// don't read them from `__webpack_require__.rscM`.
+  pub reference_manifest: Option<ReferenceManifestOptions>,
 }
 
 #[plugin]
@@ -72,6 +82,7 @@ pub struct RscServerPlugin {
   coordinator: Arc<Coordinator>,
   #[debug(skip)]
   on_server_component_changes: Option<OnServerComponentChanges>,
+  reference_manifest: Option<ReferenceManifestOptions>,
   prev_server_component_hashes: AtomicRefCell<IdentifierMap<u64>>,
 }
 
@@ -80,6 +91,7 @@ impl RscServerPlugin {
     Self::new_inner(
       options.coordinator,
       options.on_server_component_changes,
+      options.reference_manifest,
       Default::default(),
     )
   }
@@ -148,8 +160,15 @@ async fn runtime_requirements_in_tree(
 }
 
 #[plugin_hook(CompilationProcessAssets for RscServerPlugin)]
-async fn process_assets(&self, _compilation: &mut Compilation) -> Result<()> {
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
   self.coordinator.idle().await?;
+
+  if let Some(options) = &self.reference_manifest {
+    let logger = compilation.get_logger("rspack.RscServerPlugin");
+    let start = logger.time("emit reference manifests");
+    self.emit_reference_manifests(compilation, options)?;
+    logger.time_end(start);
+  }
   Ok(())
 }
 
@@ -184,6 +203,98 @@ impl Plugin for RscServerPlugin {
 }
 
 impl RscServerPlugin {
+  fn emit_reference_manifests(
+    &self,
+    compilation: &mut Compilation,
+    options: &ReferenceManifestOptions,
+  ) -> Result<()> {
+    let mut plugin_state = PLUGIN_STATES
+      .get_mut(&compilation.compiler_id())
+      .ok_or_else(|| {
+        rspack_error::error!(
+          "RscServerPlugin: Plugin state not found in process_assets hook for compiler {:#?}.",
+          compilation.compiler_id()
+        )
+      })?;
+    // The runtime module only collects the server actions of chunks which require the manifest.
+    build_server_manifest(compilation, &mut plugin_state.server_actions)?;
+    let module_loading = plugin_state.module_loading.as_ref().ok_or_else(|| {
+      rspack_error::error!(
+        "Missing RSC moduleLoading config in plugin state. Ensure ClientPlugin is applied."
+      )
+    })?;
+    let server_consumer_module_map =
+      build_server_consumer_module_map(compilation, &plugin_state.client_modules);
+
+    let client_manifest = ClientReferenceManifestAsset {
+      version: REFERENCE_MANIFEST_VERSION,
+      module_loading,
+      client_manifest: plugin_state
+        .client_modules
+        .iter()
+        .map(|(resource, export)| (resource.as_str(), export))
+        .collect(),
+      server_consumer_module_map: server_consumer_module_map
+        .iter()
+        .map(|(id, node)| {
+          (
+            id.as_str(),
+            node
+              .iter()
+              .map(|(name, export)| (name.as_str(), export))
+              .collect(),
+          )
+        })
+        .collect(),
+      entry_js_files: plugin_state
+        .entry_js_files
+        .iter()
+        .map(|(entry_name, files)| (entry_name.as_str(), files))
+        .collect(),
+      entry_css_files: plugin_state
+        .entry_css_files
+        .iter()
+        .map(|(entry_name, files)| {
+          (
+            entry_name.as_str(),
+            files
+              .iter()
+              .map(|(server_entry, files)| (server_entry.as_str(), files))
+              .collect(),
+          )
+        })
+        .collect(),
+    };
+    let server_manifest = ServerReferenceManifestAsset {
+      version: REFERENCE_MANIFEST_VERSION,
+      server_manifest: plugin_state
+        .server_actions
+        .iter()
+        .map(|(id, export)| (id.as_str(), export))
+        .collect(),
+    };
+
+    let client_manifest_json = serde_json::to_string_pretty(&client_manifest).to_rspack_result()?;
+    let server_manifest_json = serde_json::to_string_pretty(&server_manifest).to_rspack_result()?;
+    drop(plugin_state);
+
+    compilation.emit_asset(
+      options.client_filename.clone(),
+      CompilationAsset::new(
+        Some(RawStringSource::from(client_manifest_json).boxed()),
+        Default::default(),
+      ),
+    );
+    compilation.emit_asset(
+      options.server_filename.clone(),
+      CompilationAsset::new(
+        Some(RawStringSource::from(server_manifest_json).boxed()),
+        Default::default(),
+      ),
+    );
+    Ok(())
+  }
+
   async fn create_client_entries(&self, compilation: &mut Compilation) -> Result<()> {
     let mut add_ssr_modules_list: Vec<InjectedSsrEntry> = Default::default();
     let mut created_ssr_dependencies_per_entry: FxHashMap<String, Vec<DependencyId>> =
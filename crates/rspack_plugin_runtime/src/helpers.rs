@@ -121,6 +121,14 @@ pub async fn get_runtime_chunk_output_name(
   compilation: &Compilation,
   chunk_ukey: &ChunkUkey,
 ) -> Result<String> {
+  let runtime_chunk = get_runtime_chunk(compilation, chunk_ukey)?;
+  get_chunk_output_name(runtime_chunk, compilation).await
+}
+
+pub fn get_runtime_chunk<'a>(
+  compilation: &'a Compilation,
+  chunk_ukey: &ChunkUkey,
+) -> Result<&'a Chunk> {
   let entry_point = {
     let entry_points = compilation
       .build_chunk_graph_artifact
@@ -138,14 +146,14 @@ pub async fn get_runtime_chunk_output_name(
       .expect_get(entry_point_ukey)
   };
 
-  let runtime_chunk = compilation
-    .build_chunk_graph_artifact
-    .chunk_by_ukey
-    .expect_get(
-      &entry_point.get_runtime_chunk(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey),
-    );
-
-  get_chunk_output_name(runtime_chunk, compilation).await
+  Ok(
+    compilation
+      .build_chunk_graph_artifact
+      .chunk_by_ukey
+      .expect_get(
+        &entry_point.get_runtime_chunk(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey),
+      ),
+  )
 }
 
 pub fn runtime_chunk_has_hash(compilation: &Compilation, chunk_ukey: &ChunkUkey) -> Result<bool> {
@@ -338,6 +346,35 @@ pub fn get_relative_path(base_chunk_output_name: &str, other_chunk_output_name:
   format!("{path}{}", other_chunk_output_name_arr.join("/"))
 }
 
+/// Returns the prefix of the bare specifiers chunks import each other with when `output.importMap`
+/// is enabled, the chunk id is appended to get the specifier of a chunk.
+pub fn get_import_map_specifier_prefix(compilation: &Compilation) -> Option<String> {
+  compilation.options.output.import_map.as_ref()?;
+  let unique_name = &compilation.options.output.unique_name;
+  Some(if unique_name.is_empty() {
+    "@rspack-chunk/".to_string()
+  } else {
+    format!("@rspack-chunk/{unique_name}/")
+  })
+}
+
+/// Returns the request a chunk is imported with from `base_chunk_output_name`, which is the bare
+/// specifier of the chunk in import map mode and the relative path to the chunk file otherwise.
+pub async fn get_chunk_import_request(
+  compilation: &Compilation,
+  base_chunk_output_name: &str,
+  chunk: &Chunk,
+) -> Result<String> {
+  if let Some(prefix) = get_import_map_specifier_prefix(compilation) {
+    return Ok(format!("{prefix}{}", chunk.expect_id()));
+  }
+  let chunk_output_name = get_chunk_output_name(chunk, compilation).await?;
+  Ok(get_relative_path(
+    base_chunk_output_name,
+    &chunk_output_name,
+  ))
+}
+
 pub async fn get_chunk_output_name(chunk: &Chunk, compilation: &Compilation) -> Result<String> {
   let hash = chunk.rendered_hash(
     &compilation.chunk_hashes_artifact,
@@ -389,8 +426,8 @@ pub fn extract_runtime_globals_from_ejs(ejs_content: &str) -> RuntimeGlobals {
   let names = EJS_RUNTIME_GLOBALS_RE
     .captures_iter(ejs_content)
     .map(|cap| cap[1].to_string())
-    // script nonce is always optional
-    .filter(|name| name.as_str() != "SCRIPT_NONCE")
+    // script nonce is always optional, chunk load retry is only required with `output.chunkLoadRetry`
+    .filter(|name| !matches!(name.as_str(), "SCRIPT_NONCE" | "CHUNK_LOAD_RETRY_URL"))
     .collect_vec();
   RuntimeGlobals::from_names(&names)
 }
@@ -466,6 +503,17 @@ mod tests {
     );
   }
 
+  #[test]
+  fn test_extract_runtime_globals_ignore_optional_globals() {
+    let ejs = r#"<%- SCRIPT_NONCE %>
+    url = <%- CHUNK_LOAD_RETRY_URL %>(url, chunkId);
+    <%- LOAD_SCRIPT %>(url);"#;
+    assert_eq!(
+      extract_runtime_globals_from_ejs(ejs),
+      expected_globals(&["LOAD_SCRIPT"])
+    );
+  }
+
   #[test]
   fn test_extract_runtime_globals_uppercase_with_underscores() {
     let ejs = "<%- GET_CHUNK_UPDATE_SCRIPT_FILENAME %> <%- HMR_DOWNLOAD_UPDATE_HANDLERS %>";
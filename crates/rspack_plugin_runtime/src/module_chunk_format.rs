@@ -1,12 +1,14 @@
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
+use rspack_collections::DatabaseItem;
 use rspack_core::{
-  ChunkGraph, ChunkKind, ChunkUkey, Compilation, CompilationAdditionalChunkRuntimeRequirements,
-  CompilationDependentFullHash, CompilationParams, CompilerCompilation, ModuleIdentifier, Plugin,
+  AssetInfo, ChunkGraph, ChunkKind, ChunkUkey, Compilation,
+  CompilationAdditionalChunkRuntimeRequirements, CompilationAsset, CompilationDependentFullHash,
+  CompilationParams, CompilationProcessAssets, CompilerCompilation, ModuleIdentifier, Plugin,
   RuntimeCodeTemplate, RuntimeGlobals, RuntimeModule, RuntimeVariable, SourceType,
   rspack_sources::{ConcatSource, RawStringSource, Source, SourceExt},
 };
-use rspack_error::Result;
+use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_plugin_javascript::{
@@ -19,8 +21,8 @@ use rustc_hash::FxHashSet as HashSet;
 
 use super::update_hash_for_entry_startup;
 use crate::{
-  get_all_chunks, get_chunk_output_name, get_relative_path, get_runtime_chunk_output_name,
-  runtime_chunk_has_hash,
+  get_all_chunks, get_chunk_import_request, get_chunk_output_name, get_import_map_specifier_prefix,
+  get_runtime_chunk, runtime_chunk_has_hash,
 };
 
 const PLUGIN_NAME: &str = "rspack.ModuleChunkFormatPlugin";
@@ -122,7 +124,10 @@ fn compilation_dependent_full_hash(
     return Ok(());
   }
 
-  if runtime_chunk_has_hash(compilation, chunk_ukey)? {
+  // in import map mode the runtime chunk is imported by its stable specifier instead of its filename
+  if compilation.options.output.import_map.is_none()
+    && runtime_chunk_has_hash(compilation, chunk_ukey)?
+  {
     *dependent_full_hash = true;
   }
 
@@ -178,16 +183,18 @@ async fn render_chunk(
   }
 
   if chunk.has_entry_module(&compilation.build_chunk_graph_artifact.chunk_graph) {
-    let runtime_chunk_output_name = get_runtime_chunk_output_name(compilation, chunk_ukey).await?;
+    let runtime_chunk = get_runtime_chunk(compilation, chunk_ukey)?;
     sources.add(RawStringSource::from(format!(
       "import {{ {} }} from '{}';\n",
       runtime_template.render_runtime_globals(&RuntimeGlobals::REQUIRE),
-      get_relative_path(
+      get_chunk_import_request(
+        compilation,
         base_chunk_output_name
           .trim_start_matches("/")
           .trim_start_matches("\\"),
-        &runtime_chunk_output_name
+        runtime_chunk
       )
+      .await?
     )));
 
     let entries = compilation
@@ -248,13 +255,12 @@ async fn render_chunk(
           .build_chunk_graph_artifact
           .chunk_by_ukey
           .expect_get(chunk_ukey);
-        let other_chunk_output_name = get_chunk_output_name(chunk, compilation).await?;
+        let import_request =
+          get_chunk_import_request(compilation, &base_chunk_output_name, chunk).await?;
         let mut index_buffer = itoa::Buffer::new();
         let index_str = index_buffer.format(index);
         startup_source.push(format!(
-          "import * as __rspack_chunk_{} from '{}';",
-          index_str,
-          get_relative_path(&base_chunk_output_name, &other_chunk_output_name)
+          "import * as __rspack_chunk_{index_str} from '{import_request}';"
         ));
         let mut index_buffer2 = itoa::Buffer::new();
         let index_str2 = index_buffer2.format(index);
@@ -372,9 +378,8 @@ async fn render_startup(
 
       let named_import = format!("__rspack_imports_{index}");
 
-      let dependant_chunk_name = get_chunk_output_name(dependant_chunk, compilation).await?;
-
-      let imported = get_relative_path(&base_chunk_output_name, &dependant_chunk_name);
+      let imported =
+        get_chunk_import_request(compilation, &base_chunk_output_name, dependant_chunk).await?;
 
       dependent_load.add(RawStringSource::from(render_chunk_import(
         &named_import,
@@ -397,6 +402,86 @@ async fn render_startup(
   Ok(())
 }
 
+#[plugin_hook(CompilationProcessAssets for ModuleChunkFormatPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_ADDITIONAL)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(prefix) = get_import_map_specifier_prefix(compilation) else {
+    return Ok(());
+  };
+  // import maps are only supported in documents, chunks imported with bare specifiers can't be
+  // resolved elsewhere, e.g. in Node.js or in module workers
+  if !compilation.platform.is_browser() {
+    compilation.push_diagnostic(Diagnostic::warn(
+      "ImportMapWarning".to_string(),
+      "`output.importMap` only works in browsers, the chunks can't be loaded without the import map. Set `target` to \"web\" or disable `output.importMap`.".to_string(),
+    ));
+  }
+  let filename = compilation
+    .options
+    .output
+    .import_map
+    .as_ref()
+    .map(|import_map| import_map.filename.clone())
+    .unwrap_or_default();
+  let public_path = compilation
+    .options
+    .output
+    .public_path
+    .render(compilation, &filename)
+    .await;
+
+  // emitted before `RealContentHashPlugin`, which updates the hashes of the chunk files in the map
+  let mut imports = BTreeMap::new();
+  for chunk in compilation
+    .build_chunk_graph_artifact
+    .chunk_by_ukey
+    .values()
+  {
+    if matches!(chunk.kind(), ChunkKind::HotUpdate) || !chunk_has_js(&chunk.ukey(), compilation) {
+      continue;
+    }
+    let Some(chunk_id) = chunk.id() else {
+      continue;
+    };
+    let chunk_output_name = get_chunk_output_name(chunk, compilation).await?;
+    imports.insert(
+      format!("{prefix}{chunk_id}"),
+      import_map_address(&public_path, &chunk_output_name),
+    );
+  }
+
+  let content = serde_json::json!({ "imports": imports }).to_string();
+  compilation.emit_asset(
+    filename,
+    CompilationAsset::new(
+      Some(RawStringSource::from(content).boxed()),
+      AssetInfo::default(),
+    ),
+  );
+  Ok(())
+}
+
+/// Returns the address of a chunk in the import map, addresses which are not urls have to start with
+/// `/`, `./` or `../`, otherwise they are invalid, e.g. with `publicPath: "auto"` or `publicPath: ""`,
+/// relative addresses are resolved against the document like other relative urls.
+fn import_map_address(public_path: &str, chunk_output_name: &str) -> String {
+  let address = format!("{public_path}{chunk_output_name}");
+  let has_scheme = address.split_once(':').is_some_and(|(scheme, _)| {
+    scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+      && scheme
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+  });
+  if has_scheme
+    || address.starts_with('/')
+    || address.starts_with("./")
+    || address.starts_with("../")
+  {
+    address
+  } else {
+    format!("./{address}")
+  }
+}
+
 impl Plugin for ModuleChunkFormatPlugin {
   fn name(&self) -> &'static str {
     PLUGIN_NAME
@@ -412,6 +497,31 @@ impl Plugin for ModuleChunkFormatPlugin {
       .compilation_hooks
       .dependent_full_hash
       .tap(compilation_dependent_full_hash::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
     Ok(())
   }
 }
+
+#[cfg(test)]
+mod test {
+  use super::import_map_address;
+
+  #[test]
+  fn should_create_valid_import_map_addresses() {
+    assert_eq!(import_map_address("", "app.mjs"), "./app.mjs");
+    assert_eq!(import_map_address("assets/", "app.mjs"), "./assets/app.mjs");
+    assert_eq!(import_map_address("../", "app.mjs"), "../app.mjs");
+    assert_eq!(import_map_address("/", "app.mjs"), "/app.mjs");
+    assert_eq!(
+      import_map_address("//cdn.com/", "app.mjs"),
+      "//cdn.com/app.mjs"
+    );
+    assert_eq!(
+      import_map_address("https://cdn.com/", "app.mjs"),
+      "https://cdn.com/app.mjs"
+    );
+  }
+}
@@ -97,8 +97,13 @@ async fn runtime_requirements_in_tree(
       .extend(ModuleChunkLoadingRuntimeModule::get_runtime_requirements_basic());
   }
   if all_runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS) {
-    runtime_requirements_mut
-      .extend(ModuleChunkLoadingRuntimeModule::get_runtime_requirements_with_loading());
+    let mut with_loading_runtime_requirements =
+      ModuleChunkLoadingRuntimeModule::get_runtime_requirements_with_loading();
+    // chunks are imported by their specifiers in the import map instead of their filenames
+    if compilation.options.output.import_map.is_some() {
+      with_loading_runtime_requirements.remove(RuntimeGlobals::GET_CHUNK_SCRIPT_FILENAME);
+    }
+    runtime_requirements_mut.extend(with_loading_runtime_requirements);
     if all_runtime_requirements.contains(RuntimeGlobals::PREFETCH_CHUNK_HANDLERS) {
       runtime_requirements_mut
         .extend(ModuleChunkLoadingRuntimeModule::get_runtime_requirements_with_prefetch());
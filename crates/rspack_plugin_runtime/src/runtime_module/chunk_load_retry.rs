@@ -0,0 +1,71 @@
+use rspack_core::{
+  ChunkLoadRetry, Compilation, RuntimeGlobals, RuntimeModule, RuntimeModuleGenerateContext,
+  RuntimeModuleStage, RuntimeTemplate, impl_runtime_module,
+};
+
+use crate::get_chunk_runtime_requirements;
+
+#[impl_runtime_module]
+#[derive(Debug)]
+pub struct ChunkLoadRetryRuntimeModule {
+  options: ChunkLoadRetry,
+}
+
+impl ChunkLoadRetryRuntimeModule {
+  pub fn new(runtime_template: &RuntimeTemplate, options: ChunkLoadRetry) -> Self {
+    Self::with_default(runtime_template, options)
+  }
+}
+
+#[async_trait::async_trait]
+impl RuntimeModule for ChunkLoadRetryRuntimeModule {
+  fn template(&self) -> Vec<(String, String)> {
+    vec![(
+      self.id.to_string(),
+      include_str!("runtime/chunk_load_retry.ejs").to_string(),
+    )]
+  }
+
+  async fn generate(
+    &self,
+    context: &RuntimeModuleGenerateContext<'_>,
+  ) -> rspack_error::Result<String> {
+    let compilation = context.compilation;
+    let chunk_ukey = self.chunk.expect("should have chunk");
+    let runtime_requirements = get_chunk_runtime_requirements(compilation, &chunk_ukey);
+    let fetch_priority = if runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY) {
+      ", fetchPriority"
+    } else {
+      ""
+    };
+
+    let source = context.runtime_template.render(
+      &self.id,
+      Some(serde_json::json!({
+        "_fallback_public_paths": serde_json::to_string(&self.options.fallback_public_paths)
+          .expect("failed to serialize fallback public paths"),
+        "_with_fallback_public_paths": !self.options.fallback_public_paths.is_empty(),
+        "_cache_bust": self.options.cache_bust,
+        "_max_retries": self.options.max_retries.to_string(),
+        "_retry_delay": self.options.retry_delay.to_string(),
+        "_with_ensure_chunk": runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK),
+        "_fetch_priority": fetch_priority,
+      })),
+    )?;
+
+    Ok(source)
+  }
+
+  fn additional_runtime_requirements(&self, _compilation: &Compilation) -> RuntimeGlobals {
+    if self.options.fallback_public_paths.is_empty() {
+      RuntimeGlobals::default()
+    } else {
+      RuntimeGlobals::PUBLIC_PATH
+    }
+  }
+
+  // wraps the ensure chunk function after it is defined
+  fn stage(&self) -> RuntimeModuleStage {
+    RuntimeModuleStage::Attach
+  }
+}
@@ -232,6 +232,7 @@ impl RuntimeModule for ImportScriptsChunkLoadingRuntimeModule {
         Some(serde_json::json!({
           "_js_matcher": has_js_matcher.render("chunkId"),
           "_with_create_script_url": self.with_create_script_url,
+          "_with_retry": runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY_URL),
         })),
       )?;
       source.push_str(&render_source);
@@ -237,6 +237,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
         &chunk_has_js,
       );
     let with_fetch_priority = runtime_requirements.contains(RuntimeGlobals::HAS_FETCH_PRIORITY);
+    let with_retry = runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY_URL);
     let cross_origin_loading = &compilation.options.output.cross_origin_loading;
     let script_type = &compilation.options.output.script_type;
 
@@ -290,6 +291,7 @@ impl RuntimeModule for JsonpChunkLoadingRuntimeModule {
             } else {
                ""
             },
+            "_with_retry": with_retry,
           })),
         )?
       };
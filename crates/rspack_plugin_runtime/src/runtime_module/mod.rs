@@ -3,6 +3,7 @@ mod amd_options;
 mod async_module;
 mod auto_public_path;
 mod base_uri;
+mod chunk_load_retry;
 mod chunk_name;
 mod chunk_prefetch_preload_function;
 mod chunk_prefetch_startup;
@@ -50,6 +51,7 @@ pub use amd_options::AmdOptionsRuntimeModule;
 pub use async_module::AsyncRuntimeModule;
 pub use auto_public_path::AutoPublicPathRuntimeModule;
 pub use base_uri::BaseUriRuntimeModule;
+pub use chunk_load_retry::ChunkLoadRetryRuntimeModule;
 pub use chunk_name::ChunkNameRuntimeModule;
 pub use chunk_prefetch_preload_function::ChunkPrefetchPreloadFunctionRuntimeModule;
 pub use chunk_prefetch_startup::ChunkPrefetchStartupRuntimeModule;
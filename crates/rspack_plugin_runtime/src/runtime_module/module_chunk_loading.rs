@@ -12,6 +12,7 @@ use super::utils::get_output_dir;
 use crate::{
   LinkPrefetchData, LinkPreloadData, RuntimeModuleChunkWrapper, RuntimePlugin,
   extract_runtime_globals_from_ejs, get_chunk_runtime_requirements,
+  get_import_map_specifier_prefix,
   runtime_module::{
     generate_javascript_hmr_runtime,
     utils::{get_initial_chunk_ids, stringify_chunks},
@@ -243,6 +244,9 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
     let initial_chunks = get_initial_chunk_ids(self.chunk, compilation, chunk_has_js);
 
     let root_output_dir = get_output_dir(chunk, compilation, true).await?;
+    let import_map_specifier_prefix = get_import_map_specifier_prefix(compilation).map(|prefix| {
+      serde_json::to_string(&prefix).expect("should able to be serde_json::to_string")
+    });
     let import_function_name = &compilation.options.output.import_function_name;
 
     let mut source = String::default();
@@ -302,6 +306,11 @@ impl RuntimeModule for ModuleChunkLoadingRuntimeModule {
             "_js_matcher": &has_js_matcher.render("chunkId"),
             "_import_function_name":&compilation.options.output.import_function_name,
             "_output_dir": &root_output_dir,
+            "_with_import_map": import_map_specifier_prefix.is_some(),
+            "_import_map_specifier_prefix": import_map_specifier_prefix.as_deref().unwrap_or_default(),
+            // retrying with fallback public paths needs urls, bare specifiers are resolved by the import map
+            "_with_retry": import_map_specifier_prefix.is_none()
+              && runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY_URL),
             "_match_fallback":    if matches!(has_js_matcher, BooleanMatcher::Condition(true)) {
               ""
             } else {
@@ -26,10 +26,10 @@ use crate::{
   RuntimePluginHooks,
   runtime_module::{
     AmdDefineRuntimeModule, AmdOptionsRuntimeModule, AsyncRuntimeModule,
-    AutoPublicPathRuntimeModule, BaseUriRuntimeModule, ChunkNameRuntimeModule,
-    ChunkPrefetchPreloadFunctionRuntimeModule, CompatGetDefaultExportRuntimeModule,
-    CreateFakeNamespaceObjectRuntimeModule, CreateScriptRuntimeModule,
-    CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule,
+    AutoPublicPathRuntimeModule, BaseUriRuntimeModule, ChunkLoadRetryRuntimeModule,
+    ChunkNameRuntimeModule, ChunkPrefetchPreloadFunctionRuntimeModule,
+    CompatGetDefaultExportRuntimeModule, CreateFakeNamespaceObjectRuntimeModule,
+    CreateScriptRuntimeModule, CreateScriptUrlRuntimeModule, DefinePropertyGettersRuntimeModule,
     ESMModuleDecoratorRuntimeModule, EnsureChunkRuntimeModule, GetChunkFilenameRuntimeModule,
     GetChunkUpdateFilenameRuntimeModule, GetFullHashRuntimeModule, GetMainFilenameRuntimeModule,
     GetTrustedTypesPolicyRuntimeModule, GlobalRuntimeModule, HasOwnPropertyRuntimeModule,
@@ -186,6 +186,16 @@ async fn runtime_requirements_in_tree(
     runtime_requirements_mut.insert(RuntimeGlobals::ENSURE_CHUNK_HANDLERS);
   }
 
+  if compilation.options.output.chunk_load_retry.is_some()
+    && runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK_HANDLERS)
+  {
+    runtime_requirements_mut.insert(RuntimeGlobals::CHUNK_LOAD_RETRY_URL);
+  }
+
+  if runtime_requirements.contains(RuntimeGlobals::CHUNK_LOAD_RETRY_URL) {
+    runtime_requirements_mut.insert(RuntimeGlobals::CHUNK_LOAD_RETRY);
+  }
+
   if runtime_requirements.contains(RuntimeGlobals::ENSURE_CHUNK) {
     let c = compilation
       .build_chunk_graph_artifact
@@ -505,6 +515,15 @@ async fn runtime_requirements_in_tree(
           ToBinaryRuntimeModule::new(&compilation.runtime_template).boxed(),
         ));
       }
+      RuntimeGlobals::CHUNK_LOAD_RETRY => {
+        if let Some(options) = &compilation.options.output.chunk_load_retry {
+          runtime_modules_to_add.push((
+            *chunk_ukey,
+            ChunkLoadRetryRuntimeModule::new(&compilation.runtime_template, options.clone())
+              .boxed(),
+          ));
+        }
+      }
       _ => {}
     }
   }
@@ -0,0 +1,185 @@
+use std::{collections::BTreeMap, io::Write};
+
+use flate2::{Compression, write::GzEncoder};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SizeCompression {
+  Gzip,
+  Brotli,
+}
+
+impl TryFrom<String> for SizeCompression {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: String) -> Result<Self, rspack_error::Error> {
+    match value.as_str() {
+      "gzip" => Ok(Self::Gzip),
+      "brotli" => Ok(Self::Brotli),
+      _ => Err(rspack_error::Error::error(format!(
+        "Expect budget compression to be 'gzip' or 'brotli', but got '{value}'."
+      ))),
+    }
+  }
+}
+
+impl SizeCompression {
+  /// Returns the size of `buffer` after compression.
+  pub fn compressed_size(&self, buffer: &[u8]) -> usize {
+    match self {
+      Self::Gzip => {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+          .write_all(buffer)
+          .expect("should compress in memory");
+        encoder.finish().expect("should compress in memory").len()
+      }
+      Self::Brotli => {
+        // the highest quality and the default window, which servers use for static assets
+        let mut encoder = brotli::CompressorWriter::new(Vec::new(), 4096, 11, 22);
+        encoder
+          .write_all(buffer)
+          .expect("should compress in memory");
+        encoder.into_inner().len()
+      }
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetChunkType {
+  /// Assets of the chunks that are loaded initially.
+  Initial,
+  /// Assets of the chunks that are loaded on demand.
+  Async,
+  /// CSS assets of any chunk.
+  Css,
+}
+
+impl TryFrom<String> for BudgetChunkType {
+  type Error = rspack_error::Error;
+
+  fn try_from(value: String) -> Result<Self, rspack_error::Error> {
+    match value.as_str() {
+      "initial" => Ok(Self::Initial),
+      "async" => Ok(Self::Async),
+      "css" => Ok(Self::Css),
+      _ => Err(rspack_error::Error::error(format!(
+        "Expect budget chunk type to be 'initial', 'async' or 'css', but got '{value}'."
+      ))),
+    }
+  }
+}
+
+/// A size limit on the total size of the assets selected by `entry`, `asset` and
+/// `chunk_type`. An asset is selected when it matches all the given selectors, all
+/// assets are selected when none is given.
+#[derive(Debug, Default)]
+pub struct SizeBudget {
+  /// Identifies the budget in hints and in the baseline, derived from the selectors when omitted.
+  pub name: Option<String>,
+  /// Selects the initial assets of the named entrypoint.
+  pub entry: Option<String>,
+  /// Selects the assets whose filenames match the glob.
+  pub asset: Option<String>,
+  pub chunk_type: Option<BudgetChunkType>,
+  /// Measures the sizes after compression instead of the raw sizes.
+  pub compression: Option<SizeCompression>,
+  pub max_size: Option<f64>,
+}
+
+impl SizeBudget {
+  pub fn key(&self) -> String {
+    if let Some(name) = &self.name {
+      return name.clone();
+    }
+
+    let mut parts = vec![];
+    if let Some(entry) = &self.entry {
+      parts.push(format!("entry {entry}"));
+    }
+    if let Some(asset) = &self.asset {
+      parts.push(format!("assets {asset}"));
+    }
+    if let Some(chunk_type) = &self.chunk_type {
+      parts.push(
+        match chunk_type {
+          BudgetChunkType::Initial => "initial chunks",
+          BudgetChunkType::Async => "async chunks",
+          BudgetChunkType::Css => "css assets",
+        }
+        .to_string(),
+      );
+    }
+    if parts.is_empty() {
+      parts.push("all assets".to_string());
+    }
+
+    let mut key = parts.join(", ");
+    match self.compression {
+      Some(SizeCompression::Gzip) => key.push_str(" (gzip)"),
+      Some(SizeCompression::Brotli) => key.push_str(" (brotli)"),
+      None => {}
+    }
+    key
+  }
+}
+
+#[derive(Debug)]
+pub struct SizeBaseline {
+  /// The path of the baseline JSON, relative to the context.
+  pub path: String,
+  /// The growth over the baseline size of a budget allowed, in percent.
+  pub max_growth: f64,
+  /// Writes the sizes of the budgets to a baseline JSON of the same name in the output
+  /// directory instead of comparing against the baseline.
+  pub update: bool,
+}
+
+/// The content of a baseline JSON, recording the sizes of the budgets in a build.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SizeBaselineContent {
+  pub budgets: BTreeMap<String, u64>,
+}
+
+/// Returns the growth of `size` over `baseline` in percent, if it exceeds `max_growth`.
+pub fn exceeded_growth(size: f64, baseline: f64, max_growth: f64) -> Option<f64> {
+  if baseline <= 0.0 {
+    return None;
+  }
+  let growth = (size - baseline) / baseline * 100.0;
+  (growth > max_growth).then_some(growth)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn test_budget_key() {
+    let budget = SizeBudget {
+      entry: Some("main".to_string()),
+      chunk_type: Some(BudgetChunkType::Css),
+      compression: Some(SizeCompression::Gzip),
+      ..Default::default()
+    };
+    assert_eq!(budget.key(), "entry main, css assets (gzip)");
+    assert_eq!(SizeBudget::default().key(), "all assets");
+  }
+
+  #[test]
+  fn test_compressed_size() {
+    let buffer = "console.log(\"hello\");\n".repeat(100);
+    for compression in [SizeCompression::Gzip, SizeCompression::Brotli] {
+      let size = compression.compressed_size(buffer.as_bytes());
+      assert!(size > 0 && size < buffer.len());
+    }
+  }
+
+  #[test]
+  fn test_exceeded_growth() {
+    assert_eq!(exceeded_growth(110.0, 100.0, 5.0), Some(10.0));
+    assert_eq!(exceeded_growth(104.0, 100.0, 5.0), None);
+    assert_eq!(exceeded_growth(100.0, 0.0, 5.0), None);
+  }
+}
@@ -1,13 +1,22 @@
-use std::collections::HashMap;
+mod budget;
 
+use std::{
+  collections::{HashMap, HashSet},
+  hash::{Hash, Hasher},
+  sync::Mutex,
+};
+
+pub use budget::*;
 use derive_more::Debug;
 use futures::future::BoxFuture;
+use rayon::prelude::*;
 use rspack_core::{
   ChunkGroup, ChunkGroupUkey, Compilation, CompilationAsset, CompilerAfterEmit, Plugin,
 };
 use rspack_error::{Diagnostic, Result, ToStringResultToRspackResultExt};
 use rspack_hook::{plugin, plugin_hook};
 use rspack_util::size::format_size;
+use rustc_hash::FxHasher;
 
 pub type AssetFilterFn = Box<dyn for<'a> Fn(&'a str) -> BoxFuture<'a, Result<bool>> + Sync + Send>;
 
@@ -18,17 +27,30 @@ pub struct SizeLimitsPluginOptions {
   pub hints: Option<String>,
   pub max_asset_size: Option<f64>,
   pub max_entrypoint_size: Option<f64>,
+  pub budgets: Vec<SizeBudget>,
+  pub baseline: Option<SizeBaseline>,
+}
+
+/// The measured size of a [SizeBudget].
+#[derive(Debug)]
+struct BudgetSize {
+  key: String,
+  size: f64,
+  max_size: Option<f64>,
 }
 
 #[plugin]
 #[derive(Debug)]
 pub struct SizeLimitsPlugin {
   options: SizeLimitsPluginOptions,
+  /// The compressed sizes of the budgeted assets, keyed by the hash of their content, so
+  /// only the changed assets are compressed again in watch mode.
+  compressed_sizes: Mutex<HashMap<(u64, SizeCompression), usize>>,
 }
 
 impl SizeLimitsPlugin {
   pub fn new(options: SizeLimitsPluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
   }
 
   async fn asset_filter(&self, name: &str, asset: &CompilationAsset) -> bool {
@@ -65,6 +87,205 @@ impl SizeLimitsPlugin {
     size
   }
 
+  /// Measures the budgets, returning their sizes and the entrypoints of budgets that
+  /// do not exist.
+  async fn get_budget_sizes(
+    &self,
+    compilation: &Compilation,
+  ) -> (Vec<BudgetSize>, Vec<(String, String)>) {
+    let mut budget_sizes = vec![];
+    let mut missing_entries = vec![];
+    if self.options.budgets.is_empty() {
+      return (budget_sizes, missing_entries);
+    }
+
+    let chunk_graph = &compilation.build_chunk_graph_artifact;
+    let mut assets = vec![];
+    for (name, asset) in compilation.assets() {
+      if asset.get_source().is_some() && self.asset_filter(name, asset).await {
+        assets.push(name.as_str());
+      }
+    }
+    let mut initial_files: HashSet<&str> = HashSet::default();
+    let mut async_files: HashSet<&str> = HashSet::default();
+    for chunk in chunk_graph.chunk_by_ukey.values() {
+      let files = if chunk.can_be_initial(&chunk_graph.chunk_group_by_ukey) {
+        &mut initial_files
+      } else {
+        &mut async_files
+      };
+      files.extend(chunk.files().iter().map(String::as_str));
+    }
+
+    let mut selected_assets = vec![];
+    for budget in &self.options.budgets {
+      let key = budget.key();
+      let entry_files = match &budget.entry {
+        Some(entry) => {
+          let Some(ukey) = chunk_graph.entrypoints.get(entry) else {
+            missing_entries.push((key, entry.clone()));
+            continue;
+          };
+          let entrypoint = chunk_graph.chunk_group_by_ukey.expect_get(ukey);
+          Some(entrypoint.get_files(&chunk_graph.chunk_by_ukey))
+        }
+        None => None,
+      };
+
+      let selected = assets
+        .iter()
+        .copied()
+        .filter(|&name| {
+          entry_files
+            .as_ref()
+            .is_none_or(|files| files.iter().any(|file| file == name))
+            && budget
+              .asset
+              .as_ref()
+              .is_none_or(|glob| fast_glob::glob_match(glob, name))
+            && budget.chunk_type.is_none_or(|chunk_type| match chunk_type {
+              BudgetChunkType::Initial => initial_files.contains(name),
+              BudgetChunkType::Async => async_files.contains(name),
+              BudgetChunkType::Css => name
+                .split(['?', '#'])
+                .next()
+                .is_some_and(|filename| filename.ends_with(".css")),
+            })
+        })
+        .collect::<Vec<_>>();
+      selected_assets.push((budget, key, selected));
+    }
+
+    // only the assets selected by budgets with compression are compressed
+    let compressed = selected_assets
+      .iter()
+      .filter_map(|(budget, _, selected)| Some((budget.compression?, selected)))
+      .flat_map(|(compression, selected)| selected.iter().map(move |&name| (name, compression)))
+      .collect::<HashSet<_>>();
+    let compressed_sizes = self.get_compressed_sizes(compressed, compilation);
+
+    for (budget, key, selected) in selected_assets {
+      let size = selected
+        .into_iter()
+        .map(|name| match budget.compression {
+          Some(compression) => compressed_sizes
+            .get(&(name, compression))
+            .map_or(0.0, |size| *size as f64),
+          None => compilation
+            .assets()
+            .get(name)
+            .and_then(|asset| asset.get_source())
+            .map_or(0.0, |source| source.size() as f64),
+        })
+        .sum();
+
+      budget_sizes.push(BudgetSize {
+        key,
+        size,
+        max_size: budget.max_size,
+      });
+    }
+
+    (budget_sizes, missing_entries)
+  }
+
+  /// Compresses the assets in parallel, reusing the sizes of the contents compressed in the
+  /// previous compilations.
+  fn get_compressed_sizes<'a>(
+    &self,
+    assets: HashSet<(&'a str, SizeCompression)>,
+    compilation: &Compilation,
+  ) -> HashMap<(&'a str, SizeCompression), usize> {
+    let buffers = assets
+      .into_par_iter()
+      .filter_map(|(name, compression)| {
+        let buffer = compilation.assets().get(name)?.get_source()?.buffer();
+        let mut hasher = FxHasher::default();
+        buffer.hash(&mut hasher);
+        Some(((name, compression), (hasher.finish(), buffer)))
+      })
+      .collect::<Vec<_>>();
+
+    let cached = self
+      .compressed_sizes
+      .lock()
+      .expect("should lock compressed sizes")
+      .clone();
+    let sizes = buffers
+      .into_par_iter()
+      .map(|((name, compression), (hash, buffer))| {
+        let size = cached
+          .get(&(hash, compression))
+          .copied()
+          .unwrap_or_else(|| compression.compressed_size(&buffer));
+        ((name, compression), (hash, size))
+      })
+      .collect::<Vec<_>>();
+
+    // drop the sizes of the contents that are no longer emitted
+    *self
+      .compressed_sizes
+      .lock()
+      .expect("should lock compressed sizes") = sizes
+      .iter()
+      .map(|((_, compression), (hash, size))| ((*hash, *compression), *size))
+      .collect();
+    sizes
+      .into_iter()
+      .map(|(key, (_, size))| (key, size))
+      .collect()
+  }
+
+  /// Compares the budgets against the baseline, returning the budgets that grew more than
+  /// allowed, or writes the baseline to the output directory when it should be updated.
+  /// Returns `None` when there is no baseline to compare against.
+  async fn check_baseline(
+    baseline: &SizeBaseline,
+    budget_sizes: &[BudgetSize],
+    compilation: &Compilation,
+  ) -> Result<Option<Vec<(String, f64, f64, f64)>>> {
+    let path = compilation.options.context.as_path().join(&baseline.path);
+
+    if baseline.update {
+      let content = SizeBaselineContent {
+        budgets: budget_sizes
+          .iter()
+          .map(|budget| (budget.key.clone(), budget.size as u64))
+          .collect(),
+      };
+      let json = serde_json::to_string_pretty(&content).to_rspack_result()?;
+      // the baseline in the source tree is replaced by the user, the build only writes
+      // the recorded sizes next to the other outputs
+      let output_path = &compilation.options.output.path;
+      let file_name = path.file_name().unwrap_or("size-baseline.json");
+      let output_filesystem = compilation.output_filesystem.as_ref();
+      output_filesystem.create_dir_all(output_path).await?;
+      output_filesystem
+        .write(&output_path.join(file_name), json.as_bytes())
+        .await?;
+      return Ok(Some(vec![]));
+    }
+
+    let Ok(content) = compilation.input_filesystem.read(&path).await else {
+      return Ok(None);
+    };
+    let content: SizeBaselineContent = serde_json::from_slice(&content)
+      .to_rspack_result_with_message(|e| {
+        format!("SizeLimitsPlugin: failed to parse the baseline {path}: {e}")
+      })?;
+
+    Ok(Some(
+      budget_sizes
+        .iter()
+        .filter_map(|budget| {
+          let baseline_size = *content.budgets.get(&budget.key)? as f64;
+          let growth = exceeded_growth(budget.size, baseline_size, baseline.max_growth)?;
+          Some((budget.key.clone(), baseline_size, budget.size, growth))
+        })
+        .collect(),
+    ))
+  }
+
   fn add_diagnostic(
     hints: &str,
     title: String,
@@ -129,6 +350,57 @@ impl SizeLimitsPlugin {
 
     Self::add_diagnostic(hints, title, message, diagnostics);
   }
+
+  fn add_budgets_over_size_limit_warning(
+    detail: &[&BudgetSize],
+    hints: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) {
+    let budget_list: String = detail
+      .iter()
+      .map(|budget| {
+        format!(
+          "\n  {} ({}, limit {})",
+          budget.key,
+          format_size(budget.size),
+          format_size(budget.max_size.unwrap_or_default())
+        )
+      })
+      .collect::<String>();
+    let title = String::from("budgets over size limit warning");
+    let message = format!(
+      "budget size limit: The following budget(s) exceed their size limit. This can impact web performance.\nBudgets:{budget_list}"
+    );
+
+    Self::add_diagnostic(hints, title, message, diagnostics);
+  }
+
+  fn add_budgets_over_baseline_warning(
+    detail: &[(String, f64, f64, f64)],
+    baseline: &SizeBaseline,
+    hints: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+  ) {
+    let budget_list: String = detail
+      .iter()
+      .map(|(key, baseline_size, size, growth)| {
+        format!(
+          "\n  {} ({} -> {}, +{:.2}%)",
+          key,
+          format_size(*baseline_size),
+          format_size(*size),
+          growth
+        )
+      })
+      .collect::<String>();
+    let title = String::from("budgets over baseline warning");
+    let message = format!(
+      "budget size growth: The following budget(s) grew more than {}% over the baseline {}. This can impact web performance.\nBudgets:{}",
+      baseline.max_growth, baseline.path, budget_list
+    );
+
+    Self::add_diagnostic(hints, title, message, diagnostics);
+  }
 }
 
 #[plugin_hook(CompilerAfterEmit for SizeLimitsPlugin)]
@@ -199,9 +471,64 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
     }
   }
 
-  if let Some(hints) = hints {
-    let mut diagnostics = vec![];
+  let (budget_sizes, missing_entries) = self.get_budget_sizes(compilation).await;
+  let budgets_over_baseline = match &self.options.baseline {
+    Some(baseline) => Self::check_baseline(baseline, &budget_sizes, compilation).await?,
+    None => Some(vec![]),
+  };
+
+  let mut diagnostics = vec![];
+
+  // budgets and baselines are configured explicitly, so they are reported even without hints
+  let budget_hints = hints.as_deref().unwrap_or("warning");
+  for (key, entry) in missing_entries {
+    Self::add_diagnostic(
+      budget_hints,
+      String::from("budget entrypoint not found"),
+      format!("budget size limit: The entrypoint \"{entry}\" of budget \"{key}\" does not exist."),
+      &mut diagnostics,
+    );
+  }
 
+  let budgets_over_size_limit = budget_sizes
+    .iter()
+    .filter(|budget| {
+      budget
+        .max_size
+        .is_some_and(|max_size| budget.size > max_size)
+    })
+    .collect::<Vec<_>>();
+  if !budgets_over_size_limit.is_empty() {
+    Self::add_budgets_over_size_limit_warning(
+      &budgets_over_size_limit,
+      budget_hints,
+      &mut diagnostics,
+    );
+  }
+
+  if let Some(baseline) = &self.options.baseline {
+    match budgets_over_baseline {
+      // a missing baseline never fails the build, it is recorded on demand
+      None => diagnostics.push(Diagnostic::warn(
+        String::from("budget baseline not found"),
+        format!(
+          "budget size growth: The baseline {} does not exist or can't be read, so the budgets are not compared against it. Build with RSPACK_UPDATE_SIZE_BASELINE=true to record the baseline in the output directory, then copy it to {}.",
+          baseline.path, baseline.path
+        ),
+      )),
+      Some(budgets_over_baseline) if !budgets_over_baseline.is_empty() => {
+        Self::add_budgets_over_baseline_warning(
+          &budgets_over_baseline,
+          baseline,
+          budget_hints,
+          &mut diagnostics,
+        );
+      }
+      Some(_) => {}
+    }
+  }
+
+  if let Some(hints) = hints {
     if !assets_over_size_limit.is_empty() {
       Self::add_assets_over_size_limit_warning(
         &assets_over_size_limit,
@@ -237,11 +564,13 @@ async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
 
         Self::add_diagnostic(hints, title, message, &mut diagnostics);
       }
-
-      compilation.extend_diagnostics(diagnostics);
     }
   }
 
+  if !diagnostics.is_empty() {
+    compilation.extend_diagnostics(diagnostics);
+  }
+
   for (name, asset) in compilation.assets_mut() {
     if let Some(checked) = checked_assets.get(name) {
       asset.info.set_is_over_size_limit(*checked)
@@ -515,6 +515,11 @@ impl SplitChunksPlugin {
               return Ok(None);
             }
 
+            if chunk.is_in_non_loading_async_entrypoint(chunk_group_db) {
+              tracing::debug!("Chunk({:?}) skips `maxSize` checking. Reason: it is in an async entrypoint which can't load chunks", chunk.chunk_reason());
+              return Ok(None);
+            }
+
             let min_size = max_size_setting
               .map_or(&fallback_cache_group.min_size, |s| &s.min_size);
             let max_async_size = max_size_setting
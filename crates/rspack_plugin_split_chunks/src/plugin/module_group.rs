@@ -412,6 +412,15 @@ impl SplitChunksPlugin {
                 }).copied().collect::<Vec<_>>()
               };
 
+              // Async entrypoints which can't load chunks, e.g. worklets, have to keep their modules
+              let selected_chunks = selected_chunks.into_iter().filter(|c| {
+                !compilation
+                  .build_chunk_graph_artifact
+                  .chunk_by_ukey
+                  .expect_get(c)
+                  .is_in_non_loading_async_entrypoint(&compilation.build_chunk_graph_artifact.chunk_group_by_ukey)
+              }).collect::<Vec<_>>();
+
               // Filter by `splitChunks.cacheGroups.{cacheGroup}.minChunks`
               if selected_chunks.len() < cache_group.min_chunks as usize {
                 tracing::trace!(
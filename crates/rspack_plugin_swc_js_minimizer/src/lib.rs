@@ -1,31 +1,38 @@
+mod name_cache;
+
 use std::{
-  collections::HashMap,
+  collections::{BTreeSet, HashMap},
   hash::Hash,
   path::Path,
-  sync::{LazyLock, Mutex, mpsc},
+  sync::{Arc, LazyLock, Mutex, mpsc},
 };
 
 use cow_utils::CowUtils;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 use regex::Regex;
+use rspack_cacheable::{cacheable, with::AsPreset};
 use rspack_core::{
   AssetInfo, ChunkUkey, Compilation, CompilationAsset, CompilationParams, CompilationProcessAssets,
-  CompilerCompilation, Plugin,
+  CompilerAfterEmit, CompilerCompilation, Plugin,
   diagnostics::MinifyError,
   rspack_sources::{
-    ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt, SourceMapSource,
-    SourceMapSourceOptions,
+    BoxSource, ConcatSource, MapOptions, ObjectPool, RawStringSource, Source, SourceExt,
+    SourceMapSource, SourceMapSourceOptions,
   },
 };
 use rspack_error::{Diagnostic, Result};
 use rspack_hash::RspackHash;
 use rspack_hook::{plugin, plugin_hook};
 use rspack_javascript_compiler::JavaScriptCompiler;
+use rspack_paths::Utf8PathBuf;
 use rspack_plugin_javascript::{ExtractedCommentsInfo, JavascriptModulesChunkHash, JsPlugin};
+use rspack_regex::RspackRegex;
 use rspack_util::asset_condition::AssetConditions;
+use rustc_hash::{FxHashMap, FxHashSet};
 use swc_config::types::BoolOrDataConfig;
 use swc_core::{
+  atoms::Atom,
   base::config::JsMinifyFormatOptions,
   common::comments::{CommentKind, SingleThreadedComments},
 };
@@ -35,20 +42,36 @@ pub use swc_ecma_minifier::option::{
 };
 use thread_local::ThreadLocal;
 
+use self::name_cache::NameCache;
+
 const PLUGIN_NAME: &str = "rspack.SwcJsMinimizerRspackPlugin";
 
 static JAVASCRIPT_ASSET_REGEXP: LazyLock<Regex> =
   LazyLock::new(|| Regex::new(r"\.[cm]?js(\?.*)?$").expect("Invalid RegExp"));
 
+static IDENTIFIER_REGEXP: LazyLock<Regex> =
+  LazyLock::new(|| Regex::new(r"[A-Za-z_$][A-Za-z0-9_$]*").expect("Invalid RegExp"));
+
 #[derive(Debug, Hash)]
 pub struct PluginOptions {
   pub test: Option<AssetConditions>,
   pub include: Option<AssetConditions>,
   pub exclude: Option<AssetConditions>,
   pub extract_comments: Option<ExtractComments>,
+  pub mangle_properties: Option<ManglePropertiesOptions>,
+  /// Path of the file to read mangled property names from before the build
+  /// and write them to after the build.
+  pub name_cache: Option<String>,
   pub minimizer_options: MinimizerOptions,
 }
 
+#[derive(Debug, Hash)]
+pub struct ManglePropertiesOptions {
+  /// Only properties matching the regex are mangled.
+  pub regex: RspackRegex,
+  pub reserved: Vec<String>,
+}
+
 #[derive(Debug, Default)]
 pub struct MinimizerOptions {
   pub ecma: TerserEcmaVersion,
@@ -120,15 +143,84 @@ struct NormalizedExtractComments<'a> {
   banner: Option<String>,
 }
 
+/// The minified asset saved in the persistent cache.
+#[cacheable]
+struct MinifiedAsset {
+  #[cacheable(with=AsPreset)]
+  source: BoxSource,
+  extracted_comments: Option<String>,
+}
+
 #[plugin]
 #[derive(Debug)]
 pub struct SwcJsMinimizerRspackPlugin {
   options: PluginOptions,
+  /// Mangled property names kept between compilations, `None` before loaded from the name cache file.
+  name_cache: Mutex<Option<NameCache>>,
 }
 
 impl SwcJsMinimizerRspackPlugin {
   pub fn new(options: PluginOptions) -> Self {
-    Self::new_inner(options)
+    Self::new_inner(options, Default::default())
+  }
+
+  fn name_cache_path(&self, compilation: &Compilation) -> Option<Utf8PathBuf> {
+    self
+      .options
+      .name_cache
+      .as_ref()
+      .map(|path| compilation.options.context.as_path().join(path))
+  }
+
+  /// Assign mangled names to properties matching the regex in all assets to be minified,
+  /// names are assigned in a sorted order so they are stable between builds.
+  fn assign_mangled_props(
+    &self,
+    compilation: &Compilation,
+    mangle_properties: &ManglePropertiesOptions,
+  ) -> Arc<FxHashMap<Atom, Atom>> {
+    let props = compilation
+      .assets()
+      .par_iter()
+      .filter(|(filename, asset)| {
+        match_object(&self.options, filename) && !asset.get_info().minimized.unwrap_or(false)
+      })
+      .filter_map(|(filename, asset)| Some((filename, asset.get_source()?)))
+      .map(|(filename, source)| {
+        let content = source.source().into_string_lossy();
+        match JavaScriptCompiler::new().property_names(
+          swc_core::common::FileName::Custom(filename.to_string()),
+          &*content,
+        ) {
+          Ok(names) => names.iter().map(|name| name.to_string()).collect(),
+          // the parse error is reported by the minification, keep every identifier of the asset
+          // as a property name so mangled names never conflict with it
+          Err(_) => IDENTIFIER_REGEXP
+            .find_iter(&content)
+            .map(|token| token.as_str().to_string())
+            .collect::<FxHashSet<_>>(),
+        }
+      })
+      .reduce(FxHashSet::default, |mut a, b| {
+        a.extend(b);
+        a
+      });
+
+    let (candidates, mut taken): (FxHashSet<_>, FxHashSet<_>) =
+      props.into_iter().partition(|prop| {
+        mangle_properties.regex.test(prop) && !mangle_properties.reserved.contains(prop)
+      });
+    let candidates = candidates.into_iter().collect::<BTreeSet<_>>();
+    // mangled names should not conflict with properties that are kept as is,
+    // names of variables are in another namespace and never conflict
+    taken.extend(mangle_properties.reserved.iter().cloned());
+
+    let mut name_cache = self.name_cache.lock().expect("should lock name cache");
+    Arc::new(
+      name_cache
+        .get_or_insert_default()
+        .assign(&candidates, &taken),
+    )
   }
 }
 
@@ -141,6 +233,53 @@ async fn compilation(
   let hooks = JsPlugin::get_compilation_hooks_mut(compilation.id());
   let mut hooks = hooks.write().await;
   hooks.chunk_hash.tap(js_chunk_hash::new(self));
+  drop(hooks);
+
+  if self.options.mangle_properties.is_none()
+    || self
+      .name_cache
+      .lock()
+      .expect("should lock name cache")
+      .is_some()
+  {
+    return Ok(());
+  }
+  let mut name_cache = NameCache::default();
+  if let Some(path) = self.name_cache_path(compilation)
+    && let Ok(content) = compilation.intermediate_filesystem.read_file(&path).await
+  {
+    match NameCache::from_json(&String::from_utf8_lossy(&content)) {
+      Ok(cache) => name_cache = cache,
+      Err(e) => compilation.push_diagnostic(Diagnostic::warn(
+        PLUGIN_NAME.to_string(),
+        format!("Failed to read name cache {path}, {e}"),
+      )),
+    }
+  }
+  *self.name_cache.lock().expect("should lock name cache") = Some(name_cache);
+  Ok(())
+}
+
+#[plugin_hook(CompilerAfterEmit for SwcJsMinimizerRspackPlugin)]
+async fn after_emit(&self, compilation: &mut Compilation) -> Result<()> {
+  let Some(path) = self.name_cache_path(compilation) else {
+    return Ok(());
+  };
+  let content = {
+    let mut name_cache = self.name_cache.lock().expect("should lock name cache");
+    match name_cache.as_mut() {
+      Some(name_cache) if name_cache.is_dirty() => {
+        name_cache.mark_saved();
+        name_cache.to_json()
+      }
+      _ => return Ok(()),
+    }
+  };
+  let fs = &compilation.intermediate_filesystem;
+  if let Some(dir) = path.parent() {
+    fs.create_dir_all(dir).await?;
+  }
+  fs.write(&path, content.as_bytes()).await?;
   Ok(())
 }
 
@@ -173,6 +312,12 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
         .unwrap_or_else(|_| panic!("`{condition}` is invalid extractComments condition"))
     });
   let enter_span = tracing::Span::current();
+  let mangle_props = options
+    .mangle_properties
+    .as_ref()
+    .map(|mangle_properties| self.assign_mangled_props(compilation, mangle_properties));
+  let cache = compilation.process_assets_cache_artifact.clone();
+  let compiler_options = compilation.options.clone();
 
   let tls: ThreadLocal<ObjectPool> = ThreadLocal::new();
   compilation
@@ -217,6 +362,7 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           source_map: BoolOrDataConfig::from_bool(input_source_map.is_some()),
           inline_sources_content: true, /* Using true so original_source can be None in SourceMapSource */
           module: is_module,
+          mangle_props: mangle_props.clone(),
           ..Default::default()
           };
         let extract_comments_option = options.extract_comments.as_ref().map(|extract_comments| {
@@ -238,6 +384,42 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
           }
         });
 
+        // mangled property names depend on other assets, which can't be cached per asset
+        let cache_key = (cache.is_enabled() && mangle_props.is_none()).then(|| {
+          let mut hasher = RspackHash::from(&compiler_options.output);
+          options.hash(&mut hasher);
+          minimizer_options.minify.hash(&mut hasher);
+          serde_json::to_string(&minimizer_options.ecma)
+            .expect("Should be able to serialize")
+            .hash(&mut hasher);
+          filename.hash(&mut hasher);
+          is_module.hash(&mut hasher);
+          input.hash(&mut hasher);
+          input_source_map.hash(&mut hasher);
+          hasher.digest(&compiler_options.output.hash_digest).encoded().to_string()
+        });
+        if let Some(cache_key) = &cache_key
+          && let Some(cached) = cache.get::<MinifiedAsset>(PLUGIN_NAME, cache_key)
+        {
+          if let (Some(comments), Some(extract_comments)) =
+            (cached.extracted_comments, &extract_comments_option)
+          {
+            all_extracted_comments
+              .lock()
+              .expect("all_extract_comments lock failed")
+              .insert(
+                filename.to_string(),
+                ExtractedCommentsInfo {
+                  source: RawStringSource::from(comments).boxed(),
+                  comments_file_name: extract_comments.filename.clone(),
+                },
+              );
+          }
+          original.set_source(Some(cached.source));
+          original.get_info_mut().minimized.replace(true);
+          return Ok(());
+        }
+
         let javascript_compiler = JavaScriptCompiler::new();
         let comments_op = |comments: &SingleThreadedComments| {
           if let Some(ref extract_comments) = extract_comments_option {
@@ -316,10 +498,12 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
             },
         };
 
-        let banner = if all_extracted_comments
+        let extracted_comments = all_extracted_comments
           .lock()
           .expect("all_extract_comments lock failed")
-          .contains_key(filename) {
+          .get(filename)
+          .map(|info| info.source.source().into_string_lossy().into_owned());
+        let banner = if extracted_comments.is_some() {
             extract_comments_option.and_then(|option| option.banner)
           } else {
             None
@@ -390,6 +574,16 @@ async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
             },
         };
 
+        if let Some(cache_key) = &cache_key {
+          cache.set(
+            PLUGIN_NAME,
+            cache_key,
+            &MinifiedAsset {
+              source: source.clone(),
+              extracted_comments,
+            },
+          );
+        }
         original.set_source(Some(source));
         original.get_info_mut().minimized.replace(true);
       }
@@ -449,6 +643,7 @@ impl Plugin for SwcJsMinimizerRspackPlugin {
 
   fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
     ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx.compiler_hooks.after_emit.tap(after_emit::new(self));
     ctx
       .compilation_hooks
       .process_assets
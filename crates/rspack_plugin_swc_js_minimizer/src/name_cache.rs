@@ -0,0 +1,177 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use serde_json::{Map, Value, json};
+use swc_core::atoms::Atom;
+
+const FIRST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_";
+const REST_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ$_0123456789";
+
+/// The mangled property names shared across builds.
+///
+/// It is persisted in the format of terser's `nameCache`, property names are prefixed with `$`:
+/// `{ "vars": { "props": {} }, "props": { "props": { "$foo": "a" } } }`.
+#[derive(Debug, Default)]
+pub struct NameCache {
+  props: BTreeMap<String, String>,
+  /// Whether names are assigned since the cache is loaded or saved.
+  dirty: bool,
+}
+
+impl NameCache {
+  pub fn from_json(content: &str) -> Result<Self, String> {
+    let value: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+    let mut props = BTreeMap::new();
+    if let Some(map) = value.pointer("/props/props") {
+      let map = map
+        .as_object()
+        .ok_or_else(|| "`props.props` should be an object".to_string())?;
+      for (key, value) in map {
+        let (Some(name), Some(mangled)) = (key.strip_prefix('$'), value.as_str()) else {
+          return Err(format!("invalid name cache entry `{key}`"));
+        };
+        props.insert(name.to_string(), mangled.to_string());
+      }
+    }
+    Ok(Self {
+      props,
+      dirty: false,
+    })
+  }
+
+  pub fn to_json(&self) -> String {
+    let props = self
+      .props
+      .iter()
+      .map(|(name, mangled)| (format!("${name}"), Value::String(mangled.clone())))
+      .collect::<Map<_, _>>();
+    let value = json!({
+      "vars": { "props": {} },
+      "props": { "props": props },
+    });
+    serde_json::to_string_pretty(&value).expect("should serialize name cache")
+  }
+
+  pub fn is_dirty(&self) -> bool {
+    self.dirty
+  }
+
+  pub fn mark_saved(&mut self) {
+    self.dirty = false;
+  }
+
+  /// Returns the mangled names of `candidates`, names not in the cache are assigned
+  /// in order with the shortest names not in `taken` and not used by the cache.
+  ///
+  /// Cached names that collide with `taken`, e.g. a newly reserved or unmangled property,
+  /// are dropped and assigned again, so two properties never share the same name.
+  pub fn assign(
+    &mut self,
+    candidates: &BTreeSet<String>,
+    taken: &HashSet<String>,
+  ) -> HashMap<Atom, Atom> {
+    let before = self.props.len();
+    self.props.retain(|_, mangled| !taken.contains(mangled));
+    if self.props.len() != before {
+      self.dirty = true;
+    }
+    let used = self.props.values().cloned().collect::<HashSet<_>>();
+    let mut index = 0;
+    let mut names = HashMap::default();
+    for candidate in candidates {
+      let mangled = match self.props.get(candidate) {
+        Some(mangled) => mangled.clone(),
+        None => {
+          let mangled = loop {
+            let name = base54(index);
+            index += 1;
+            if !taken.contains(&name) && !used.contains(&name) {
+              break name;
+            }
+          };
+          self.props.insert(candidate.clone(), mangled.clone());
+          self.dirty = true;
+          mangled
+        }
+      };
+      names.insert(Atom::from(candidate.as_str()), Atom::from(mangled));
+    }
+    names
+  }
+}
+
+/// Generates the nth shortest identifier, like the name generator of terser.
+fn base54(mut index: usize) -> String {
+  let mut name = String::new();
+  name.push(FIRST_CHARS[index % FIRST_CHARS.len()] as char);
+  index /= FIRST_CHARS.len();
+  while index > 0 {
+    index -= 1;
+    name.push(REST_CHARS[index % REST_CHARS.len()] as char);
+    index /= REST_CHARS.len();
+  }
+  name
+}
+
+#[cfg(test)]
+mod test {
+  use std::collections::BTreeSet;
+
+  use rustc_hash::FxHashSet as HashSet;
+  use swc_core::atoms::Atom;
+
+  use super::{NameCache, base54};
+
+  #[test]
+  fn should_generate_names() {
+    assert_eq!(base54(0), "a");
+    assert_eq!(base54(53), "_");
+    assert_eq!(base54(54), "aa");
+    assert_eq!(base54(54 * 65 - 1), "_9");
+    assert_eq!(base54(54 * 65), "aaa");
+  }
+
+  #[test]
+  fn should_keep_cached_names() {
+    let mut cache =
+      NameCache::from_json(r#"{ "props": { "props": { "$_foo": "a" } } }"#).expect("should parse");
+    let candidates = BTreeSet::from(["_bar".to_string(), "_foo".to_string()]);
+    let taken = HashSet::from_iter(["b".to_string()]);
+    let names = cache.assign(&candidates, &taken);
+    assert_eq!(
+      names.get(&Atom::from("_foo")).map(|n| n.as_str()),
+      Some("a")
+    );
+    assert_eq!(
+      names.get(&Atom::from("_bar")).map(|n| n.as_str()),
+      Some("c")
+    );
+    assert!(cache.is_dirty());
+
+    let cache = NameCache::from_json(&cache.to_json()).expect("should parse");
+    assert_eq!(cache.props.get("_bar").map(String::as_str), Some("c"));
+    assert!(!cache.is_dirty());
+    assert!(NameCache::from_json(r#"{ "props": { "props": { "_foo": "a" } } }"#).is_err());
+  }
+
+  #[test]
+  fn should_reassign_colliding_cached_names() {
+    let mut cache = NameCache::from_json(
+      r#"{ "props": { "props": { "$_foo": "a", "$_bar": "b", "$_baz": "c" } } }"#,
+    )
+    .expect("should parse");
+    let candidates = BTreeSet::from(["_bar".to_string(), "_foo".to_string()]);
+    // `a` is used by an unmangled property now
+    let taken = HashSet::from_iter(["a".to_string()]);
+    let names = cache.assign(&candidates, &taken);
+    assert_eq!(
+      names.get(&Atom::from("_bar")).map(|n| n.as_str()),
+      Some("b")
+    );
+    assert_eq!(
+      names.get(&Atom::from("_foo")).map(|n| n.as_str()),
+      Some("d")
+    );
+    assert!(cache.is_dirty());
+  }
+}
@@ -0,0 +1,450 @@
+mod parse;
+mod worker;
+
+use std::sync::{Arc, Mutex};
+
+use derive_more::Debug;
+use futures::future::BoxFuture;
+use rspack_core::{
+  AsyncModulesArtifact, Compilation, CompilationFinishModules, CompilationParams,
+  CompilationProcessAssets, CompilerCompilation, ExportsInfoArtifact, ModuleIdentifier, Plugin,
+  incremental::{IncrementalPasses, Mutation},
+};
+use rspack_error::{Diagnostic, Error, Result, Severity};
+use rspack_fs::ReadableFileSystem;
+use rspack_hook::{plugin, plugin_hook};
+use rspack_location::{DependencyLocation, RealDependencyLocation};
+use rspack_paths::{ArcPath, Utf8Path, Utf8PathBuf};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use tokio::sync::oneshot;
+
+pub use self::parse::{CheckOutput, TypeCheckIssue, parse_output};
+use self::worker::{CheckResult, CheckTask, TypeCheckWorker};
+
+const TS_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "cts"];
+
+pub type CheckedFn = Arc<dyn Fn() -> BoxFuture<'static, Result<()>> + Sync + Send>;
+
+#[derive(Debug)]
+pub struct TypeCheckPluginOptions {
+  /// The checker executable, e.g. `tsgo` or `tsc`. A bare name is looked up in
+  /// `node_modules/.bin` of the context before `PATH`.
+  pub command: String,
+  /// The tsconfig of the project. Without it, the TS modules of the compilation are passed
+  /// to the checker as root files.
+  pub tsconfig: Option<Utf8PathBuf>,
+  /// The file the checker stores its incremental state in.
+  pub build_info_file: Option<Utf8PathBuf>,
+  /// Extra arguments passed to the checker, before the arguments of the plugin.
+  pub args: Vec<String>,
+  /// Whether the compilation waits for the check. Type errors are reported as errors if it
+  /// does, or as warnings once the check in the background is done otherwise.
+  pub blocking: bool,
+  /// Called when a check in the background is done after the compilation is reported,
+  /// to report the result by a new compilation, e.g. by invalidating the watching.
+  #[debug(skip)]
+  pub on_checked: Option<CheckedFn>,
+}
+
+#[derive(Debug, Default)]
+struct TypeCheckState {
+  /// The TS modules of the last compilation, by their resource paths.
+  modules: HashMap<Utf8PathBuf, ModuleIdentifier>,
+  /// Whether files the checker depends on out of the module graph are changed, e.g. tsconfig.
+  force: bool,
+  /// The number of requested checks, a result is only accepted from the latest one.
+  requested: usize,
+  /// The check the current compilation waits for, only in blocking mode.
+  pending: Option<oneshot::Receiver<CheckResult>>,
+  /// Whether the current compilation is not reported yet. A check in the background done
+  /// before that is reported by the current compilation, otherwise by a new one.
+  reporting: bool,
+  /// The result of the last finished check.
+  last: Option<CheckResult>,
+}
+
+/// Type check the TS modules of the compilation with an external checker in the background.
+///
+/// The checker only runs again when TS modules are added, updated or removed according to
+/// the incremental mutations of the module graph.
+#[plugin]
+#[derive(Debug)]
+pub struct TypeCheckPlugin {
+  options: TypeCheckPluginOptions,
+  worker: TypeCheckWorker,
+  state: Arc<Mutex<TypeCheckState>>,
+}
+
+impl TypeCheckPlugin {
+  pub fn new(options: TypeCheckPluginOptions) -> Self {
+    Self::new_inner(options, Default::default(), Default::default())
+  }
+
+  async fn task(
+    &self,
+    context: &Utf8Path,
+    modules: &HashMap<Utf8PathBuf, ModuleIdentifier>,
+    fs: &dyn ReadableFileSystem,
+  ) -> CheckTask {
+    let mut args = self.options.args.clone();
+    args.extend([
+      "--noEmit".to_string(),
+      "--pretty".to_string(),
+      "false".to_string(),
+      // the files out of the module graph, e.g. only imported by `import type`, are
+      // watched as the file dependencies of the compilation
+      "--listFiles".to_string(),
+    ]);
+    if let Some(build_info_file) = &self.options.build_info_file {
+      args.push("--incremental".to_string());
+      args.push("--tsBuildInfoFile".to_string());
+      args.push(build_info_file.to_string());
+    }
+    match &self.options.tsconfig {
+      Some(tsconfig) => {
+        args.push("--project".to_string());
+        args.push(tsconfig.to_string());
+      }
+      None => {
+        let mut files = modules
+          .keys()
+          .map(|file| file.to_string())
+          .collect::<Vec<_>>();
+        files.sort_unstable();
+        args.extend(files);
+      }
+    }
+    CheckTask {
+      command: resolve_command(&self.options.command, context, fs).await,
+      args,
+      cwd: context.to_path_buf(),
+    }
+  }
+
+  fn state(&self) -> std::sync::MutexGuard<'_, TypeCheckState> {
+    lock_state(&self.state)
+  }
+
+  /// Waits for a check in the background, and calls `on_checked` if it's done after the
+  /// current compilation is reported.
+  fn check_in_background(&self, receiver: oneshot::Receiver<CheckResult>, id: usize) {
+    let state = self.state.clone();
+    let on_checked = self.options.on_checked.clone();
+    tokio::spawn(async move {
+      // the receiver is closed if the check is superseded by a newer one
+      let Ok(result) = receiver.await else {
+        return;
+      };
+      let reported = {
+        let mut state = lock_state(&state);
+        if state.requested != id {
+          return;
+        }
+        state.last = Some(result);
+        !state.reporting
+      };
+      if reported
+        && let Some(on_checked) = on_checked
+        && let Err(error) = on_checked().await
+      {
+        tracing::error!("Failed to report the type check result: {error}");
+      }
+    });
+  }
+}
+
+fn lock_state(state: &Mutex<TypeCheckState>) -> std::sync::MutexGuard<'_, TypeCheckState> {
+  state
+    .lock()
+    .expect("Mutex poisoned: failed to acquire lock on type check state")
+}
+
+async fn resolve_command(command: &str, context: &Utf8Path, fs: &dyn ReadableFileSystem) -> String {
+  if command.contains(['/', '\\']) {
+    return command.to_string();
+  }
+  let bin = context.join("node_modules/.bin").join(if cfg!(windows) {
+    format!("{command}.cmd")
+  } else {
+    command.to_string()
+  });
+  if fs.metadata(&bin).await.is_ok() {
+    bin.into_string()
+  } else {
+    command.to_string()
+  }
+}
+
+fn is_ts_file(path: &Utf8Path) -> bool {
+  path
+    .extension()
+    .is_some_and(|extension| TS_EXTENSIONS.contains(&extension))
+}
+
+fn is_declaration_file(path: &Utf8Path) -> bool {
+  path.file_name().is_some_and(|name| {
+    name.ends_with(".d.ts") || name.ends_with(".d.mts") || name.ends_with(".d.cts")
+  })
+}
+
+/// Converts the 1-based line and UTF-16 column of `tsc` to the byte offset in `source`.
+fn byte_offset(source: &str, line: usize, column: usize) -> Option<usize> {
+  let line_start = if line <= 1 {
+    0
+  } else {
+    source.match_indices('\n').nth(line - 2)?.0 + 1
+  };
+  let mut units = 1;
+  for (offset, char) in source[line_start..].char_indices() {
+    if units >= column || char == '\n' {
+      return Some(line_start + offset);
+    }
+    units += char.len_utf16();
+  }
+  Some(source.len())
+}
+
+fn to_diagnostic(
+  issue: &TypeCheckIssue,
+  source: Option<String>,
+  module: Option<ModuleIdentifier>,
+  context: &Utf8Path,
+  severity: Severity,
+) -> Diagnostic {
+  // errors out of the module graph, e.g. in declaration files, are not attached to a module
+  let title = match (&issue.file, module, issue.position) {
+    (Some(file), None, Some((line, column))) => {
+      let file = file.strip_prefix(context).unwrap_or(file);
+      format!("{file}({line},{column}) {}", issue.code)
+    }
+    (Some(file), None, None) => {
+      let file = file.strip_prefix(context).unwrap_or(file);
+      format!("{file} {}", issue.code)
+    }
+    _ => issue.code.clone(),
+  };
+  let offset = source
+    .as_deref()
+    .zip(issue.position)
+    .and_then(|(source, (line, column))| byte_offset(source, line, column));
+  let mut error = match (source, offset) {
+    (Some(source), Some(offset)) => {
+      let end = source[offset..]
+        .char_indices()
+        .find(|(_, char)| !(char.is_alphanumeric() || *char == '_' || *char == '$'))
+        .map_or(source.len(), |(end, _)| offset + end)
+        .max(offset + source[offset..].chars().next().map_or(0, char::len_utf8));
+      Error::from_string(Some(source), offset, end, title, issue.message.clone())
+    }
+    _ => Error::error(format!("{title}: {}", issue.message)),
+  };
+  error.severity = severity;
+  error.code = Some(issue.code.clone());
+  let mut diagnostic = Diagnostic::from(error);
+  diagnostic.module_identifier = module;
+  diagnostic.loc = issue
+    .source_position()
+    .map(|start| DependencyLocation::Real(RealDependencyLocation::new(start, None)));
+  diagnostic
+}
+
+#[plugin_hook(CompilerCompilation for TypeCheckPlugin)]
+async fn compilation(
+  &self,
+  compilation: &mut Compilation,
+  _params: &mut CompilationParams,
+) -> Result<()> {
+  let mut state = self.state();
+  if let Some(tsconfig) = &self.options.tsconfig {
+    let tsconfig = tsconfig.as_path().into();
+    if compilation.modified_files.contains(&tsconfig) {
+      state.force = true;
+    }
+    compilation.file_dependencies.insert(tsconfig);
+  }
+  // files out of the module graph are only known to the checker, e.g. the files only
+  // imported by `import type`
+  if checked_files(&state).any(|file| compilation.modified_files.contains(&file)) {
+    state.force = true;
+  }
+  Ok(())
+}
+
+/// The files of the program of the last check, except the ones in `node_modules`, which are
+/// covered by the managed paths and are usually a lot.
+fn checked_files(state: &TypeCheckState) -> impl Iterator<Item = ArcPath> + '_ {
+  let files = match &state.last {
+    Some(Ok(checked)) => checked.files.as_slice(),
+    _ => &[],
+  };
+  files
+    .iter()
+    .filter(|file| {
+      !file
+        .components()
+        .any(|component| component.as_str() == "node_modules")
+    })
+    .map(|file| file.as_path().into())
+}
+
+/// Whether TS modules are added, updated or removed according to the incremental mutations.
+fn modules_changed(
+  compilation: &Compilation,
+  last: &HashMap<Utf8PathBuf, ModuleIdentifier>,
+  current: &HashMap<Utf8PathBuf, ModuleIdentifier>,
+) -> bool {
+  let Some(mutations) = compilation
+    .incremental
+    .mutations_read(IncrementalPasses::BUILD_MODULE_GRAPH)
+  else {
+    // the changed modules are unknown without incremental
+    return true;
+  };
+  let ts_modules = last
+    .values()
+    .chain(current.values())
+    .collect::<HashSet<_>>();
+  mutations.iter().any(|mutation| match mutation {
+    Mutation::ModuleAdd { module }
+    | Mutation::ModuleUpdate { module }
+    | Mutation::ModuleRemove { module } => ts_modules.contains(module),
+    _ => false,
+  })
+}
+
+#[plugin_hook(CompilationFinishModules for TypeCheckPlugin)]
+async fn finish_modules(
+  &self,
+  compilation: &Compilation,
+  _async_modules_artifact: &mut AsyncModulesArtifact,
+  _exports_info_artifact: &mut ExportsInfoArtifact,
+) -> Result<()> {
+  let module_graph = compilation.get_module_graph();
+  let modules = module_graph
+    .modules()
+    .filter_map(|(identifier, module)| {
+      let path = module.as_normal_module()?.resource_resolved_data().path()?;
+      is_ts_file(path).then(|| (path.to_path_buf(), *identifier))
+    })
+    .collect::<HashMap<_, _>>();
+
+  let changed = {
+    let state = self.state();
+    state.force || state.requested == 0 || modules_changed(compilation, &state.modules, &modules)
+  };
+
+  if changed {
+    let task = self
+      .task(
+        compilation.options.context.as_path(),
+        &modules,
+        compilation.input_filesystem.as_ref(),
+      )
+      .await;
+    let receiver = self.worker.check(task);
+    let mut state = self.state();
+    state.requested += 1;
+    state.force = false;
+    if self.options.blocking {
+      state.pending = Some(receiver);
+    } else {
+      self.check_in_background(receiver, state.requested);
+    }
+  }
+  let mut state = self.state();
+  state.modules = modules;
+  state.reporting = true;
+  Ok(())
+}
+
+#[plugin_hook(CompilationProcessAssets for TypeCheckPlugin, stage = Compilation::PROCESS_ASSETS_STAGE_REPORT)]
+async fn process_assets(&self, compilation: &mut Compilation) -> Result<()> {
+  let pending = self.state().pending.take();
+  if let Some(pending) = pending {
+    let result = pending
+      .await
+      .unwrap_or_else(|_| Err("The type check worker exited unexpectedly".to_string()));
+    self.state().last = Some(result);
+  }
+
+  let (issues, modules) = {
+    let mut state = self.state();
+    // a check in the background done from now on is reported by a new compilation
+    state.reporting = false;
+    compilation.file_dependencies.extend(checked_files(&state));
+    match &state.last {
+      Some(Ok(checked)) => (checked.issues.clone(), state.modules.clone()),
+      Some(Err(message)) => {
+        compilation.push_diagnostic(Diagnostic::error(
+          "TypeCheckError".to_string(),
+          message.clone(),
+        ));
+        return Ok(());
+      }
+      None => return Ok(()),
+    }
+  };
+
+  let severity = if self.options.blocking {
+    Severity::Error
+  } else {
+    Severity::Warning
+  };
+  let context = compilation.options.context.as_path().to_path_buf();
+  let fs = compilation.input_filesystem.clone();
+  let mut diagnostics = vec![];
+  for issue in &issues {
+    let module = issue
+      .file
+      .as_ref()
+      .and_then(|file| modules.get(file).copied());
+    // issues of files out of the compilation are skipped, except declaration files
+    // which affect the types of all modules
+    if let Some(file) = &issue.file
+      && module.is_none()
+      && !is_declaration_file(file)
+    {
+      continue;
+    }
+    let source = match &issue.file {
+      Some(file) => fs.read_to_string(file).await.ok(),
+      None => None,
+    };
+    diagnostics.push(to_diagnostic(issue, source, module, &context, severity));
+  }
+  compilation.extend_diagnostics(diagnostics);
+  Ok(())
+}
+
+impl Plugin for TypeCheckPlugin {
+  fn name(&self) -> &'static str {
+    "rspack.TypeCheckPlugin"
+  }
+
+  fn apply(&self, ctx: &mut rspack_core::ApplyContext<'_>) -> Result<()> {
+    ctx.compiler_hooks.compilation.tap(compilation::new(self));
+    ctx
+      .compilation_hooks
+      .finish_modules
+      .tap(finish_modules::new(self));
+    ctx
+      .compilation_hooks
+      .process_assets
+      .tap(process_assets::new(self));
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::byte_offset;
+
+  #[test]
+  fn should_convert_utf16_columns() {
+    let source = "const a = 1;\nconst 中文 = \"😀\" + b;\n";
+    assert_eq!(byte_offset(source, 1, 7), Some(6));
+    assert_eq!(byte_offset(source, 2, 7), Some(19));
+    assert_eq!(&source[byte_offset(source, 2, 19).unwrap()..][..1], "b");
+    assert_eq!(byte_offset(source, 5, 1), None);
+  }
+}
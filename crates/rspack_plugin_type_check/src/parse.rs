@@ -0,0 +1,127 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use rspack_location::SourcePosition;
+use rspack_paths::{Utf8Path, Utf8PathBuf};
+
+static LOCATED_ISSUE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^(?<file>.+?)\((?<line>\d+),(?<column>\d+)\): error (?<code>TS\d+): (?<message>.*)$")
+    .expect("should be valid regex")
+});
+
+static GLOBAL_ISSUE: LazyLock<Regex> = LazyLock::new(|| {
+  Regex::new(r"^error (?<code>TS\d+): (?<message>.*)$").expect("should be valid regex")
+});
+
+/// A type error reported by the checker.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeCheckIssue {
+  /// The absolute path of the file, `None` for errors of the whole program, e.g. invalid options.
+  pub file: Option<Utf8PathBuf>,
+  /// 1-based line and column in UTF-16 code units, the same as `tsc`.
+  pub position: Option<(usize, usize)>,
+  pub code: String,
+  pub message: String,
+}
+
+impl TypeCheckIssue {
+  pub fn source_position(&self) -> Option<SourcePosition> {
+    self
+      .position
+      .map(|(line, column)| SourcePosition { line, column })
+  }
+}
+
+/// The output of a check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckOutput {
+  pub issues: Vec<TypeCheckIssue>,
+  /// The files of the checked program, listed by `--listFiles`.
+  pub files: Vec<Utf8PathBuf>,
+}
+
+/// Parses the output of `tsc --pretty false --listFiles`, paths in the output are relative to `cwd`.
+///
+/// Each issue starts a line like `src/index.ts(1,7): error TS2322: message` or
+/// `error TS5083: message`, the indented lines following it are the rest of the message.
+/// The files of the program are listed one absolute path per line.
+pub fn parse_output(output: &str, cwd: &Utf8Path) -> CheckOutput {
+  let mut issues: Vec<TypeCheckIssue> = vec![];
+  let mut files = vec![];
+  for line in output.lines() {
+    if let Some(captures) = LOCATED_ISSUE.captures(line) {
+      let file = Utf8Path::new(&captures["file"]);
+      issues.push(TypeCheckIssue {
+        file: Some(if file.is_absolute() {
+          file.to_path_buf()
+        } else {
+          cwd.join(file)
+        }),
+        position: captures["line"]
+          .parse()
+          .ok()
+          .zip(captures["column"].parse().ok()),
+        code: captures["code"].to_string(),
+        message: captures["message"].to_string(),
+      });
+    } else if let Some(captures) = GLOBAL_ISSUE.captures(line) {
+      issues.push(TypeCheckIssue {
+        file: None,
+        position: None,
+        code: captures["code"].to_string(),
+        message: captures["message"].to_string(),
+      });
+    } else if line.starts_with(char::is_whitespace)
+      && let Some(issue) = issues.last_mut()
+    {
+      issue.message.push('\n');
+      issue.message.push_str(line.trim_start());
+    } else if Utf8Path::new(line).is_absolute() {
+      files.push(Utf8PathBuf::from(line));
+    }
+  }
+  CheckOutput { issues, files }
+}
+
+#[cfg(test)]
+mod tests {
+  use rspack_paths::Utf8Path;
+
+  use super::parse_output;
+
+  #[test]
+  fn should_parse_issues() {
+    let output = r#"src/index.ts(3,7): error TS2322: Type 'number' is not assignable to type 'string'.
+src/a.ts(10,1): error TS2345: Argument of type '{ a: number; }' is not assignable to parameter of type 'Options'.
+  Object literal may only specify known properties, and 'a' does not exist in type 'Options'.
+error TS5083: Cannot read file '/project/tsconfig.base.json'.
+/project/node_modules/typescript/lib/lib.es5.d.ts
+/project/src/types.ts
+/project/src/index.ts
+Found 3 errors.
+"#;
+    let output = parse_output(output, Utf8Path::new("/project"));
+    assert_eq!(
+      output.files,
+      vec![
+        Utf8Path::new("/project/node_modules/typescript/lib/lib.es5.d.ts"),
+        Utf8Path::new("/project/src/types.ts"),
+        Utf8Path::new("/project/src/index.ts"),
+      ]
+    );
+    let issues = output.issues;
+    assert_eq!(issues.len(), 3);
+    assert_eq!(
+      issues[0].file.as_deref(),
+      Some(Utf8Path::new("/project/src/index.ts"))
+    );
+    assert_eq!(issues[0].position, Some((3, 7)));
+    assert_eq!(issues[0].code, "TS2322");
+    assert_eq!(
+      issues[1].message,
+      "Argument of type '{ a: number; }' is not assignable to parameter of type 'Options'.\nObject literal may only specify known properties, and 'a' does not exist in type 'Options'."
+    );
+    assert_eq!(issues[2].file, None);
+    assert_eq!(issues[2].code, "TS5083");
+  }
+}
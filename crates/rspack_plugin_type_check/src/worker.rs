@@ -0,0 +1,90 @@
+use std::{
+  process::Command,
+  sync::{Mutex, mpsc},
+  thread,
+};
+
+use rspack_paths::Utf8PathBuf;
+use tokio::sync::oneshot;
+
+use crate::parse::{CheckOutput, parse_output};
+
+pub(crate) type CheckResult = Result<CheckOutput, String>;
+
+#[derive(Debug)]
+pub(crate) struct CheckTask {
+  pub(crate) command: String,
+  pub(crate) args: Vec<String>,
+  pub(crate) cwd: Utf8PathBuf,
+}
+
+#[derive(Debug)]
+struct CheckRequest {
+  task: CheckTask,
+  respond: oneshot::Sender<CheckResult>,
+}
+
+/// Runs the checker in a background thread, one check at a time, so the incremental
+/// build info of the checker is never written concurrently.
+#[derive(Debug, Default)]
+pub(crate) struct TypeCheckWorker {
+  sender: Mutex<Option<mpsc::Sender<CheckRequest>>>,
+}
+
+impl TypeCheckWorker {
+  pub(crate) fn check(&self, task: CheckTask) -> oneshot::Receiver<CheckResult> {
+    let (respond, receiver) = oneshot::channel();
+    let mut sender = self
+      .sender
+      .lock()
+      .expect("Mutex poisoned: failed to acquire lock on type check worker");
+    let sender = sender.get_or_insert_with(spawn_worker);
+    // the receiver is closed if the worker is gone, which is reported when it's awaited
+    let _ = sender.send(CheckRequest { task, respond });
+    receiver
+  }
+}
+
+fn spawn_worker() -> mpsc::Sender<CheckRequest> {
+  let (sender, receiver) = mpsc::channel::<CheckRequest>();
+  thread::Builder::new()
+    .name("rspack-type-check".to_string())
+    .spawn(move || {
+      while let Ok(mut request) = receiver.recv() {
+        // only the latest module graph is worth checking
+        while let Ok(next) = receiver.try_recv() {
+          request = next;
+        }
+        if request.respond.is_closed() {
+          continue;
+        }
+        let result = run(&request.task);
+        let _ = request.respond.send(result);
+      }
+    })
+    .expect("should spawn type check worker");
+  sender
+}
+
+fn run(task: &CheckTask) -> CheckResult {
+  let output = Command::new(&task.command)
+    .args(&task.args)
+    .current_dir(&task.cwd)
+    .output()
+    .map_err(|e| format!("Failed to run `{}`: {e}", task.command))?;
+  let stdout = String::from_utf8_lossy(&output.stdout);
+  let checked = parse_output(&stdout, &task.cwd);
+  // the checker exits with a non-zero code on type errors, which is only a failure if
+  // nothing is reported
+  if !output.status.success() && checked.issues.is_empty() {
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    return Err(format!(
+      "`{}` exited with {}:\n{}{}",
+      task.command,
+      output.status,
+      stderr.trim(),
+      stdout.trim()
+    ));
+  }
+  Ok(checked)
+}
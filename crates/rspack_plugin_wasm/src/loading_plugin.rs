@@ -1,6 +1,7 @@
 use rspack_core::{
   BoxPlugin, ChunkUkey, Compilation, CompilationRuntimeRequirementInTree, Plugin, PluginExt,
-  RuntimeGlobals, RuntimeModule, RuntimeModuleExt, WasmLoading, WasmLoadingType,
+  RuntimeCodeTemplate, RuntimeGlobals, RuntimeModule, RuntimeModuleExt, WasmLoading,
+  WasmLoadingType,
 };
 use rspack_error::Result;
 use rspack_hook::{plugin, plugin_hook};
@@ -15,6 +16,45 @@ pub fn enable_wasm_loading_plugin(wasm_loading_type: WasmLoadingType) -> BoxPlug
   }
 }
 
+/// Wraps the `fetch` of a wasm file in the retry of `output.chunkLoadRetry`, `url` is the
+/// expression of the requested url and `fetch` renders the request of the (retried) url.
+fn fetch_with_retry(
+  compilation: &Compilation,
+  runtime_template: &RuntimeCodeTemplate,
+  runtime_requirements_mut: &mut RuntimeGlobals,
+  url: &str,
+  fetch: impl FnOnce(&str) -> String,
+) -> String {
+  if compilation.options.output.chunk_load_retry.is_none() {
+    return fetch(url);
+  }
+  runtime_requirements_mut
+    .insert(RuntimeGlobals::CHUNK_LOAD_RETRY | RuntimeGlobals::CHUNK_LOAD_RETRY_URL);
+  let retry_url = format!(
+    "{}({url}, {url})",
+    runtime_template.render_runtime_globals(&RuntimeGlobals::CHUNK_LOAD_RETRY_URL)
+  );
+  // a failed response is retried as well, unlike a fetch which only rejects on network errors
+  let check_response = runtime_template.basic_function(
+    "res",
+    r#"if (!res.ok) {
+  var error = new Error("Loading wasm module " + wasmModuleId + " failed.\n(" + res.status + ": " + res.url + ")");
+  error.name = "ChunkLoadError";
+  error.request = res.url;
+  throw error;
+}
+return res;"#,
+  );
+  format!(
+    "{}({url}, {})",
+    runtime_template.render_runtime_globals(&RuntimeGlobals::CHUNK_LOAD_RETRY),
+    runtime_template.basic_function(
+      "",
+      &format!("return {}.then({check_response});", fetch(&retry_url))
+    )
+  )
+}
+
 #[plugin]
 #[derive(Debug, Default)]
 pub struct FetchCompileAsyncWasmPlugin;
@@ -35,17 +75,21 @@ async fn fetch_compile_async_wasm_plugin_runtime_requirements_in_tree(
 
   let runtime_template = compilation.runtime_template.create_runtime_code_template();
   runtime_requirements_mut.insert(RuntimeGlobals::PUBLIC_PATH);
+  let url = format!(
+    "{} + $PATH",
+    runtime_template.render_runtime_globals(&RuntimeGlobals::PUBLIC_PATH)
+  );
+  let load_binary_code = fetch_with_retry(
+    compilation,
+    &runtime_template,
+    runtime_requirements_mut,
+    &url,
+    |url| format!("fetch({url})"),
+  );
   runtime_modules_to_add.push((
     *chunk_ukey,
-    AsyncWasmLoadingRuntimeModule::new(
-      &compilation.runtime_template,
-      format!(
-        "fetch({} + $PATH)",
-        runtime_template.render_runtime_globals(&RuntimeGlobals::PUBLIC_PATH)
-      ),
-      true,
-    )
-    .boxed(),
+    AsyncWasmLoadingRuntimeModule::new(&compilation.runtime_template, load_binary_code, true)
+      .boxed(),
   ));
 
   Ok(None)
@@ -138,7 +182,7 @@ async fn universal_compile_async_wasm_plugin_runtime_requirements_in_tree(
   chunk_ukey: &ChunkUkey,
   _all_runtime_requirements: &RuntimeGlobals,
   runtime_requirements: &RuntimeGlobals,
-  _runtime_requirements_mut: &mut RuntimeGlobals,
+  runtime_requirements_mut: &mut RuntimeGlobals,
   runtime_modules_to_add: &mut Vec<(ChunkUkey, Box<dyn RuntimeModule>)>,
 ) -> Result<Option<()>> {
   if !runtime_requirements.contains(RuntimeGlobals::INSTANTIATE_WASM) {
@@ -173,9 +217,17 @@ var wasmUrl = $PATH;"#
       .to_string();
 
   // Generate load binary code: use fetch in browser, fs.readFile in Node.js
+  let runtime_template = compilation.runtime_template.create_runtime_code_template();
+  let fetch_code = fetch_with_retry(
+    compilation,
+    &runtime_template,
+    runtime_requirements_mut,
+    "wasmUrl",
+    |url| format!("fetch(new URL({url}, {import_meta_name}.url))"),
+  );
   let generate_load_binary_code = format!(
     r#"(useFetch
-  ? fetch(new URL(wasmUrl, {import_meta_name}.url))
+  ? {fetch_code}
   : Promise.all([import('fs'), import('url')]).then(([{{ readFile }}, {{ URL }}]) => new Promise((resolve, reject) => {{
       readFile(new URL(wasmUrl, {import_meta_name}.url), (err, buffer) => {{
         if (err) return reject(err);
@@ -1,4 +1,4 @@
-use std::{fmt::Debug, hash::Hash};
+use std::{borrow::Cow, fmt::Debug, hash::Hash};
 
 use regex::RegexBuilder;
 use regex_syntax::hir::{Hir, HirKind, Look, literal::ExtractKind};
@@ -99,6 +99,14 @@ impl HashRustRegex {
       )),
     }
   }
+
+  /// Like [HashRustRegex::new], but the pattern is translated to match like the JS regex
+  /// it comes from, see [to_rust_syntax].
+  pub(crate) fn new_js_compatible(expr: &str, flags: &str) -> Result<Self, Error> {
+    let mut regex = Self::new(&to_rust_syntax(expr, flags), flags)?;
+    regex.expr = expr.to_string();
+    Ok(regex)
+  }
 }
 
 #[derive(Clone, Debug, Hash)]
@@ -116,9 +124,8 @@ pub enum Algo {
 
 impl Algo {
   pub(crate) fn new(expr: &str, flags: &str) -> Result<Algo, Error> {
-    let ignore_case = flags.contains('i') || flags.contains('g') || flags.contains('y');
     if let Some(algo) = Self::try_compile_to_end_with_fast_path(expr)
-      && !ignore_case
+      && !has_ignore_case_flags(flags)
     {
       Ok(algo)
     } else {
@@ -133,6 +140,54 @@ impl Algo {
     HashRustRegex::new(expr, flags).map(Algo::RustRegex)
   }
 
+  /// Only use algos that guarantee linear-time matching, fail if the pattern needs backtracking.
+  pub(crate) fn new_linear(expr: &str, flags: &str) -> Result<Algo, Error> {
+    if let Some(algo) = Self::try_compile_to_end_with_fast_path(expr)
+      && !has_ignore_case_flags(flags)
+    {
+      return Ok(algo);
+    }
+    match HashRustRegex::new_js_compatible(expr, flags) {
+      Ok(regex) => Ok(Algo::RustRegex(regex)),
+      Err(_) => {
+        // make sure the pattern is valid before reporting it as backtracking-only
+        HashRegressRegex::new(expr, flags)?;
+        Err(error!(
+          "Regex `/{expr}/{flags}` can't be matched in linear time: {}",
+          backtracking_reason(expr, flags)
+        ))
+      }
+    }
+  }
+
+  /// Explain why this algo was chosen for the pattern.
+  pub(crate) fn reason(&self) -> String {
+    match self {
+      Algo::EndWith { pats } => format!(
+        "the pattern only matches the literal suffixes {}",
+        pats
+          .iter()
+          .map(|pat| format!("`{pat}`"))
+          .collect::<Vec<_>>()
+          .join(", ")
+      ),
+      Algo::RustRegex(_) => "the pattern is supported by the linear-time engine".to_string(),
+      Algo::Regress(regex) => {
+        if HashRustRegex::new_js_compatible(&regex.expr, &regex.flags).is_ok() {
+          if has_ignore_case_flags(&regex.flags)
+            && Self::try_compile_to_end_with_fast_path(&regex.expr).is_some()
+          {
+            "the `i`, `g` and `y` flags disable the literal suffix fast path, but the pattern is supported by the linear-time engine".to_string()
+          } else {
+            "the pattern is not a literal suffix match, but it is supported by the linear-time engine".to_string()
+          }
+        } else {
+          backtracking_reason(&regex.expr, &regex.flags)
+        }
+      }
+    }
+  }
+
   fn try_compile_to_end_with_fast_path(expr: &str) -> Option<Algo> {
     let hir = regex_syntax::parse(expr).ok()?;
     let seq = regex_syntax::hir::literal::Extractor::new()
@@ -176,6 +231,91 @@ impl Algo {
   }
 }
 
+/// Translate the differences between the syntax of JS regexes and the `regex` crate:
+///
+/// - Without the `u` or `v` flag, the perl classes `\d`, `\w` and the word boundaries `\b`
+///   are ASCII-only in JS. `\s` matches unicode whitespaces in JS regardless of the flags.
+/// - Character classes don't nest in JS, so `[`, `&&` and `~~` inside a class are literal.
+fn to_rust_syntax<'a>(expr: &'a str, flags: &str) -> Cow<'a, str> {
+  if !expr.contains(['\\', '[']) {
+    return Cow::Borrowed(expr);
+  }
+  let ascii = !flags.contains('u') && !flags.contains('v');
+  let mut result = String::with_capacity(expr.len());
+  let mut in_class = false;
+  let mut chars = expr.chars().peekable();
+  while let Some(c) = chars.next() {
+    match c {
+      '\\' => {
+        let Some(escaped) = chars.next() else {
+          result.push(c);
+          break;
+        };
+        match (escaped, in_class) {
+          ('d', false) if ascii => result.push_str("[0-9]"),
+          ('d', true) if ascii => result.push_str("0-9"),
+          ('w', false) if ascii => result.push_str("[0-9A-Za-z_]"),
+          ('w', true) if ascii => result.push_str("0-9A-Za-z_"),
+          ('D', _) if ascii => result.push_str("[^0-9]"),
+          ('W', _) if ascii => result.push_str("[^0-9A-Za-z_]"),
+          ('b', false) if ascii => result.push_str("(?-u:\\b)"),
+          ('B', false) if ascii => result.push_str("(?-u:\\B)"),
+          _ => {
+            result.push(c);
+            result.push(escaped);
+          }
+        }
+      }
+      '[' if !in_class => {
+        in_class = true;
+        result.push(c);
+        // a leading `]` closes the class in JS but is literal in the `regex` crate,
+        // `[]` never matches and `[^]` matches anything
+        let negated = chars.next_if_eq(&'^').is_some();
+        if chars.next_if_eq(&']').is_some() {
+          in_class = false;
+          result.pop();
+          result.push_str(if negated { "(?s:.)" } else { "[^\\s\\S]" });
+        } else if negated {
+          result.push('^');
+        }
+      }
+      '[' | '&' | '~' if in_class => {
+        result.push('\\');
+        result.push(c);
+      }
+      ']' if in_class => {
+        in_class = false;
+        result.push(c);
+      }
+      c => result.push(c),
+    }
+  }
+  Cow::Owned(result)
+}
+
+fn has_ignore_case_flags(flags: &str) -> bool {
+  flags.contains('i') || flags.contains('g') || flags.contains('y')
+}
+
+/// Describe the syntax or flags that prevent the pattern from being matched in linear time.
+fn backtracking_reason(expr: &str, flags: &str) -> String {
+  if let Some(flag) = flags
+    .chars()
+    .find(|flag| !matches!(flag, 'i' | 'm' | 's' | 'u' | 'g' | 'y'))
+  {
+    return format!("the `{flag}` flag is only supported by the backtracking engine");
+  }
+  match regex_syntax::parse(expr) {
+    Ok(_) => "the pattern is only supported by the backtracking engine".to_string(),
+    Err(regex_syntax::Error::Parse(err)) => format!(
+      "the pattern uses syntax that needs backtracking, {}",
+      err.kind()
+    ),
+    Err(err) => format!("the pattern is only supported by the backtracking engine, {err}"),
+  }
+}
+
 fn is_ends_with_regex(hir: &Hir) -> bool {
   if let HirKind::Concat(list) = hir.kind() {
     list[0].kind() != &HirKind::Look(Look::Start)
@@ -248,6 +388,80 @@ mod test_algo {
     assert!(Algo::new_rust_regex("\\.js$", "").unwrap().is_rust_regex());
   }
 
+  #[test]
+  fn check_linear_path() {
+    assert!(Algo::new_linear("\\.js$", "").unwrap().is_end_with());
+    assert!(Algo::new_linear("\\.js$", "i").unwrap().is_rust_regex());
+    assert!(
+      Algo::new_linear("^\\.(svg|png)$", "")
+        .unwrap()
+        .is_rust_regex()
+    );
+    let err = Algo::new_linear("foo(?=bar)", "").unwrap_err();
+    assert!(err.to_string().contains("look-around"));
+    let err = Algo::new_linear("(a)\\1", "").unwrap_err();
+    assert!(err.to_string().contains("backreferences"));
+    // invalid patterns are reported as they are
+    let err = Algo::new_linear("(", "").unwrap_err();
+    assert!(err.to_string().contains("Can't construct regex"));
+  }
+
+  #[test]
+  fn explain_algo() {
+    assert!(Algo::new("\\.js$", "").unwrap().reason().contains("`.js`"));
+    assert!(
+      Algo::new("\\.js$", "i")
+        .unwrap()
+        .reason()
+        .contains("disable the literal suffix fast path")
+    );
+    assert!(
+      Algo::new("foo(?!bar)", "")
+        .unwrap()
+        .reason()
+        .contains("look-around")
+    );
+  }
+
+  fn new_linear_rust_regex(expr: &str, flags: &str) -> regex::Regex {
+    let Algo::RustRegex(regex) = Algo::new_linear(expr, flags).unwrap() else {
+      unreachable!()
+    };
+    regex.regex
+  }
+
+  #[test]
+  fn linear_regex_ascii_classes() {
+    let regex = new_linear_rust_regex("^\\d+\\w$", "");
+    assert!(regex.is_match("12a"));
+    assert!(!regex.is_match("١٢a"));
+    assert!(!regex.is_match("12é"));
+    assert!(new_linear_rust_regex("^a\\b", "").is_match("aé"));
+    let regex = new_linear_rust_regex("^[\\d\\W]+$", "");
+    assert!(regex.is_match("1-2"));
+    assert!(!regex.is_match("1a"));
+    assert!(new_linear_rust_regex("^\\d+$", "u").is_match("١٢"));
+    // regexes outside of `experiments.strictRegex` keep the unicode classes
+    let Algo::RustRegex(regex) = Algo::new_rust_regex("^\\d+$", "").unwrap() else {
+      unreachable!()
+    };
+    assert!(regex.regex.is_match("١٢"));
+  }
+
+  #[test]
+  fn linear_regex_literal_brackets_in_classes() {
+    let regex = new_linear_rust_regex("^[[a]+$", "");
+    assert!(regex.is_match("[a["));
+    assert!(!regex.is_match("]"));
+    let regex = new_linear_rust_regex("^[a&&b~~]$", "");
+    assert!(regex.is_match("&"));
+    assert!(regex.is_match("~"));
+    assert!(regex.is_match("b"));
+    assert!(new_linear_rust_regex("^a[^]b$", "").is_match("a\nb"));
+    assert!(!new_linear_rust_regex("a[]b", "").is_match("ab"));
+    assert!(new_linear_rust_regex("^[\\]a]$", "").is_match("]"));
+  }
+
   #[test]
   fn rust_regex_flags() {
     let regex = Algo::new_rust_regex("foo", "g").unwrap();
@@ -13,6 +13,34 @@ use swc_core::ecma::ast::Regex as SwcRegex;
 
 use self::algo::Algo;
 
+/// The engine used to match a [RspackRegex].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RegexEngine {
+  /// Plain literal suffix matching.
+  EndWith,
+  /// The `regex` crate, which guarantees linear-time matching.
+  RustRegex,
+  /// The backtracking `regress` engine, which may take exponential time on pathological patterns.
+  Regress,
+}
+
+impl RegexEngine {
+  /// Whether the engine guarantees linear-time matching.
+  pub fn is_linear(&self) -> bool {
+    !matches!(self, Self::Regress)
+  }
+}
+
+impl std::fmt::Display for RegexEngine {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::EndWith => write!(f, "ends-with"),
+      Self::RustRegex => write!(f, "linear-time regex"),
+      Self::Regress => write!(f, "backtracking regex"),
+    }
+  }
+}
+
 /// Using wrapper type required by [TryFrom] trait
 #[cacheable(with=AsString)]
 #[derive(Clone)]
@@ -97,6 +125,41 @@ impl RspackRegex {
     })
   }
 
+  /// Construct a regex that is guaranteed to match in linear time.
+  ///
+  /// Returns an error explaining why if the pattern can only be matched by the backtracking engine.
+  pub fn with_flags_linear(expr: &str, flags: &str) -> Result<Self, Error> {
+    let mut chars = flags.chars().collect::<Vec<char>>();
+    chars.sort_unstable();
+    Ok(Self {
+      flags: chars.into_iter().collect::<String>(),
+      source: expr.to_string(),
+      algo: Box::new(Algo::new_linear(expr, flags)?),
+    })
+  }
+
+  /// Recompile this regex with [RspackRegex::with_flags_linear].
+  pub fn to_linear(&self) -> Result<Self, Error> {
+    if self.engine().is_linear() {
+      return Ok(self.clone());
+    }
+    Self::with_flags_linear(&self.source, &self.flags)
+  }
+
+  /// The engine chosen to match this regex.
+  pub fn engine(&self) -> RegexEngine {
+    match &*self.algo {
+      Algo::EndWith { .. } => RegexEngine::EndWith,
+      Algo::RustRegex(_) => RegexEngine::RustRegex,
+      Algo::Regress(_) => RegexEngine::Regress,
+    }
+  }
+
+  /// Explain why [RspackRegex::engine] was chosen for this regex.
+  pub fn engine_reason(&self) -> String {
+    self.algo.reason()
+  }
+
   // https://github.com/webpack/webpack/blob/4baf1c075d59babd028f8201526cb8c4acfd24a0/lib/dependencies/ContextDependency.js#L30
   #[inline]
   pub fn to_source_string(&self) -> String {
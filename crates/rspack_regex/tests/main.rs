@@ -40,4 +40,23 @@ mod test_regex {
     assert!(regex.test("foo"));
     assert!(!regex.test("foobar"));
   }
+
+  #[test]
+  fn linear_constructor() {
+    use rspack_regex::RegexEngine;
+
+    let regex = regex!("\\.(js|ts)$");
+    assert_eq!(regex.engine(), RegexEngine::EndWith);
+    let regex = regex!("^node_modules/.*\\.js$");
+    assert_eq!(regex.engine(), RegexEngine::Regress);
+    let linear = regex.to_linear().expect("should compile");
+    assert_eq!(linear.engine(), RegexEngine::RustRegex);
+    assert!(linear.test("node_modules/a.js"));
+    assert!(!linear.test("src/node_modules/a.js"));
+
+    let regex = regex!("(a+)+(?=b)");
+    assert!(!regex.engine().is_linear());
+    let err = regex.to_linear().expect_err("should not compile");
+    assert!(err.to_string().contains("can't be matched in linear time"));
+  }
 }
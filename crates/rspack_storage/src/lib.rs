@@ -1,12 +1,17 @@
 mod error;
 mod fs;
 mod pack;
+mod remote;
 
 use std::sync::Arc;
 
 pub use error::Result;
 pub use fs::{BridgeFileSystem, FSError, FSOperation, FSResult, FileSystem, Reader, Writer};
 pub use pack::{PackStorage, PackStorageOptions};
+pub use remote::{
+  DirRemoteCache, HttpRemoteCache, RemoteCache, RemoteMode, RemoteNamespace, RemoteStorage,
+  RemoteStorageOptions,
+};
 use tokio::sync::oneshot::Receiver;
 
 type ItemKey = Vec<u8>;
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use rspack_paths::Utf8PathBuf;
+
+use super::{RemoteCache, RemoteNamespace};
+use crate::{FileSystem, error::Result};
+
+/// A remote cache backed by a directory, entries are stored as `[root]/[namespace]/[digest]`.
+///
+/// It works with a directory shared between machines, and acts as the server in tests.
+#[derive(Debug)]
+pub struct DirRemoteCache {
+  root: Utf8PathBuf,
+  fs: Arc<dyn FileSystem>,
+}
+
+impl DirRemoteCache {
+  pub fn new(root: Utf8PathBuf, fs: Arc<dyn FileSystem>) -> Self {
+    Self { root, fs }
+  }
+}
+
+#[async_trait::async_trait]
+impl RemoteCache for DirRemoteCache {
+  async fn get(&self, namespace: RemoteNamespace, digest: &str) -> Result<Option<Vec<u8>>> {
+    let path = self.root.join(namespace.name()).join(digest);
+    if !self.fs.exists(&path).await? {
+      return Ok(None);
+    }
+    let mut reader = self.fs.read_file(&path).await?;
+    Ok(Some(reader.read_to_end().await?))
+  }
+
+  async fn put(&self, namespace: RemoteNamespace, digest: &str, data: Vec<u8>) -> Result<()> {
+    let dir = self.root.join(namespace.name());
+    self.fs.ensure_dir(&dir).await?;
+    // write to a temporary file first, so readers never see a partial entry
+    let temp_path = dir.join(format!("{digest}.temp"));
+    let mut writer = self.fs.write_file(&temp_path).await?;
+    writer.write_all(&data).await?;
+    writer.flush().await?;
+    self.fs.move_file(&temp_path, &dir.join(digest)).await?;
+    Ok(())
+  }
+}
@@ -0,0 +1,425 @@
+mod dir;
+mod http;
+
+use std::sync::{
+  Arc, Mutex,
+  atomic::{AtomicBool, Ordering},
+};
+
+use futures::future::join_all;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use sha2::{Digest, Sha256};
+use tokio::sync::{
+  OnceCell,
+  oneshot::{Receiver, channel},
+};
+
+pub use self::{dir::DirRemoteCache, http::HttpRemoteCache};
+use crate::{
+  ItemKey, ItemPairs, ItemValue, Storage,
+  error::{Error, ErrorType, Result},
+};
+
+/// The namespaces of a content-addressed cache, the same as the HTTP protocol of Bazel remote cache.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RemoteNamespace {
+  /// Entries addressed by a key, which point to the latest generation of all scopes.
+  ActionCache,
+  /// Entries addressed by the sha256 digest of their content.
+  ContentAddressableStorage,
+}
+
+impl RemoteNamespace {
+  pub fn name(&self) -> &'static str {
+    match self {
+      Self::ActionCache => "ac",
+      Self::ContentAddressableStorage => "cas",
+    }
+  }
+}
+
+#[async_trait::async_trait]
+pub trait RemoteCache: std::fmt::Debug + Sync + Send {
+  /// Get the entry by digest, returns `None` if the entry does not exist.
+  async fn get(&self, namespace: RemoteNamespace, digest: &str) -> Result<Option<Vec<u8>>>;
+  async fn put(&self, namespace: RemoteNamespace, digest: &str, data: Vec<u8>) -> Result<()>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RemoteMode {
+  /// Only download scopes from the remote cache.
+  ReadOnly,
+  /// Also upload the saved scopes to the remote cache.
+  WriteThrough,
+}
+
+pub struct RemoteStorageOptions {
+  pub local: Arc<dyn Storage>,
+  pub remote: Arc<dyn RemoteCache>,
+  pub mode: RemoteMode,
+  pub version: String,
+}
+
+/// The max size of an entry, the content of a scope is uploaded in chunks of this size.
+pub(crate) const MAX_CHUNK_SIZE: usize = 64 * 1024 * 1024;
+
+/// The digests of the chunks of all scopes saved together by one build, keyed by scope name.
+type Generation = HashMap<String, Vec<String>>;
+
+/// A storage that shares its scopes through a remote content-addressed cache.
+///
+/// When the local storage is empty, all scopes of the latest remote generation of the
+/// same version are downloaded at the first load and seed the local storage. Otherwise
+/// only the local storage is used, so the scopes of different generations never mix.
+/// With [RemoteMode::WriteThrough], all scopes are uploaded as a new generation after
+/// they are written to the local storage.
+///
+/// Failures of the remote cache fall back to the local storage.
+#[derive(Debug)]
+pub struct RemoteStorage {
+  local: Arc<dyn Storage>,
+  remote: Arc<dyn RemoteCache>,
+  mode: RemoteMode,
+  version: String,
+  /// Scopes loaded or updated by this storage, they are uploaded together as a generation.
+  known_scopes: Mutex<HashSet<&'static str>>,
+  /// Whether any scope is updated since the last save.
+  updated: AtomicBool,
+  /// All scopes of the remote generation, `None` if the local storage is used.
+  snapshot: OnceCell<Option<HashMap<String, ItemPairs>>>,
+  /// The remote scopes are outdated after the storage is reset, stop using them.
+  detached: AtomicBool,
+}
+
+impl RemoteStorage {
+  pub fn new(options: RemoteStorageOptions) -> Self {
+    Self {
+      local: options.local,
+      remote: options.remote,
+      mode: options.mode,
+      version: options.version,
+      known_scopes: Default::default(),
+      updated: AtomicBool::new(false),
+      snapshot: Default::default(),
+      detached: AtomicBool::new(false),
+    }
+  }
+
+  /// Download the remote snapshot if the local storage is empty, failures are treated as
+  /// no snapshot.
+  async fn snapshot(&self) -> Option<&HashMap<String, ItemPairs>> {
+    self
+      .snapshot
+      .get_or_init(|| async {
+        if matches!(self.local.scopes().await, Ok(scopes) if !scopes.is_empty()) {
+          return None;
+        }
+        match self.download().await {
+          Ok(snapshot) => snapshot,
+          Err(err) => {
+            tracing::warn!("fall back to local storage, {err}");
+            None
+          }
+        }
+      })
+      .await
+      .as_ref()
+  }
+
+  /// Download all scopes of the latest generation, `None` if there is no generation.
+  async fn download(&self) -> Result<Option<HashMap<String, ItemPairs>>> {
+    let Some(digest) = self
+      .remote
+      .get(RemoteNamespace::ActionCache, &generation_key(&self.version))
+      .await?
+    else {
+      return Ok(None);
+    };
+    let digest = String::from_utf8(digest)
+      .map_err(|e| Error::from_error(Some(ErrorType::Load), None, Box::new(e)))?;
+    let content = self.get_content(&digest, None).await?;
+    let generation = decode_generation(&content).ok_or_else(|| {
+      Error::from_reason(
+        Some(ErrorType::Load),
+        None,
+        "remote generation is broken".to_string(),
+      )
+    })?;
+
+    let scopes = join_all(generation.into_iter().map(|(scope, digests)| async move {
+      let mut content = vec![];
+      for digest in &digests {
+        content.extend(self.get_content(digest, None).await?);
+      }
+      let items = decode_items(&content).ok_or_else(|| {
+        Error::from_reason(
+          Some(ErrorType::Load),
+          None,
+          format!("remote content of scope `{scope}` is broken"),
+        )
+      })?;
+      Ok((scope, items))
+    }))
+    .await;
+    scopes.into_iter().collect::<Result<_>>().map(Some)
+  }
+
+  /// Get the content by digest and verify it.
+  async fn get_content(&self, digest: &str, scope: Option<&'static str>) -> Result<Vec<u8>> {
+    let Some(content) = self
+      .remote
+      .get(RemoteNamespace::ContentAddressableStorage, digest)
+      .await?
+    else {
+      return Err(Error::from_reason(
+        Some(ErrorType::Load),
+        scope,
+        format!("remote content `{digest}` is missing"),
+      ));
+    };
+    if content_digest(&content) != digest {
+      return Err(Error::from_reason(
+        Some(ErrorType::Load),
+        scope,
+        format!("remote content does not match the digest `{digest}`"),
+      ));
+    }
+    Ok(content)
+  }
+}
+
+/// Upload the full content of all scopes saved in local storage as a new generation.
+async fn upload(
+  local: &dyn Storage,
+  remote: &dyn RemoteCache,
+  version: &str,
+  scopes: HashSet<&'static str>,
+) -> Result<()> {
+  let to_save_error = |scope: Option<&'static str>, e: Error| {
+    Error::from_reason(
+      Some(ErrorType::Save),
+      scope,
+      format!("upload to remote cache failed, {e}"),
+    )
+  };
+  let generation = join_all(scopes.into_iter().map(|scope| async move {
+    let content = encode_items(&local.load(scope).await?);
+    let mut digests = vec![];
+    for chunk in content.chunks(MAX_CHUNK_SIZE) {
+      let digest = content_digest(chunk);
+      remote
+        .put(
+          RemoteNamespace::ContentAddressableStorage,
+          &digest,
+          chunk.to_vec(),
+        )
+        .await
+        .map_err(|e| to_save_error(Some(scope), e))?;
+      digests.push(digest);
+    }
+    Ok((scope.to_string(), digests))
+  }))
+  .await
+  .into_iter()
+  .collect::<Result<Generation>>()?;
+
+  // the generation is published after all of its scopes are uploaded
+  let content = encode_generation(&generation);
+  let digest = content_digest(&content);
+  remote
+    .put(RemoteNamespace::ContentAddressableStorage, &digest, content)
+    .await
+    .map_err(|e| to_save_error(None, e))?;
+  remote
+    .put(
+      RemoteNamespace::ActionCache,
+      &generation_key(version),
+      digest.into_bytes(),
+    )
+    .await
+    .map_err(|e| to_save_error(None, e))
+}
+
+fn encode_generation(generation: &Generation) -> Vec<u8> {
+  let items = generation
+    .iter()
+    .map(|(scope, digests)| {
+      (
+        Arc::new(scope.as_bytes().to_vec()),
+        Arc::new(digests.join(",").into_bytes()),
+      )
+    })
+    .collect::<ItemPairs>();
+  encode_items(&items)
+}
+
+fn decode_generation(content: &[u8]) -> Option<Generation> {
+  decode_items(content)?
+    .into_iter()
+    .map(|(scope, digests)| {
+      let scope = String::from_utf8(scope.to_vec()).ok()?;
+      let digests = std::str::from_utf8(&digests).ok()?;
+      let digests = digests
+        .split(',')
+        .filter(|digest| !digest.is_empty())
+        .map(ToString::to_string)
+        .collect();
+      Some((scope, digests))
+    })
+    .collect()
+}
+
+fn content_digest(content: &[u8]) -> String {
+  hex::encode(Sha256::digest(content))
+}
+
+fn generation_key(version: &str) -> String {
+  content_digest(format!("{version}/generation").as_bytes())
+}
+
+fn encode_items(items: &ItemPairs) -> Vec<u8> {
+  let mut content = vec![];
+  for (key, value) in items {
+    content.extend((key.len() as u64).to_le_bytes());
+    content.extend(key.iter());
+    content.extend((value.len() as u64).to_le_bytes());
+    content.extend(value.iter());
+  }
+  content
+}
+
+fn decode_items(mut content: &[u8]) -> Option<ItemPairs> {
+  fn take<'a>(content: &mut &'a [u8]) -> Option<&'a [u8]> {
+    let (len, rest) = content.split_first_chunk::<8>()?;
+    let len = usize::try_from(u64::from_le_bytes(*len)).ok()?;
+    if rest.len() < len {
+      return None;
+    }
+    let (data, rest) = rest.split_at(len);
+    *content = rest;
+    Some(data)
+  }
+
+  let mut items = vec![];
+  while !content.is_empty() {
+    let key = take(&mut content)?;
+    let value = take(&mut content)?;
+    items.push((Arc::new(key.to_vec()), Arc::new(value.to_vec())));
+  }
+  Some(items)
+}
+
+#[async_trait::async_trait]
+impl Storage for RemoteStorage {
+  async fn load(&self, scope: &'static str) -> Result<ItemPairs> {
+    let first_load = self
+      .known_scopes
+      .lock()
+      .expect("should get lock")
+      .insert(scope);
+    if !self.detached.load(Ordering::Relaxed)
+      && let Some(snapshot) = self.snapshot().await
+    {
+      let items = snapshot.get(scope).cloned().unwrap_or_default();
+      if first_load {
+        // seed the local storage, so the downloaded items are persisted locally by the next save
+        for (key, value) in &items {
+          self
+            .local
+            .set(scope, key.as_ref().clone(), value.as_ref().clone());
+        }
+      }
+      return Ok(items);
+    }
+    self.local.load(scope).await
+  }
+
+  fn set(&self, scope: &'static str, key: ItemKey, value: ItemValue) {
+    self.local.set(scope, key, value);
+    self
+      .known_scopes
+      .lock()
+      .expect("should get lock")
+      .insert(scope);
+    self.updated.store(true, Ordering::Relaxed);
+  }
+
+  fn remove(&self, scope: &'static str, key: &[u8]) {
+    self.local.remove(scope, key);
+    self
+      .known_scopes
+      .lock()
+      .expect("should get lock")
+      .insert(scope);
+    self.updated.store(true, Ordering::Relaxed);
+  }
+
+  fn trigger_save(&self) -> Result<Receiver<Result<()>>> {
+    let local_rx = self.local.trigger_save()?;
+    if self.mode == RemoteMode::ReadOnly || !self.updated.swap(false, Ordering::Relaxed) {
+      return Ok(local_rx);
+    }
+    let scopes = self.known_scopes.lock().expect("should get lock").clone();
+
+    let (tx, rx) = channel();
+    let local = self.local.clone();
+    let remote = self.remote.clone();
+    let version = self.version.clone();
+    tokio::spawn(async move {
+      let res = match local_rx.await {
+        Ok(Ok(())) => upload(local.as_ref(), remote.as_ref(), &version, scopes).await,
+        Ok(Err(err)) => Err(err),
+        Err(err) => Err(Error::from_error(
+          Some(ErrorType::Save),
+          None,
+          Box::new(err),
+        )),
+      };
+      let _ = tx.send(res);
+    });
+    Ok(rx)
+  }
+
+  async fn reset(&self) {
+    self.local.reset().await;
+    self.detached.store(true, Ordering::Relaxed);
+  }
+
+  async fn scopes(&self) -> Result<Vec<String>> {
+    self.local.scopes().await
+  }
+}
+
+#[cfg(test)]
+mod test {
+  use std::sync::Arc;
+
+  use rustc_hash::FxHashMap as HashMap;
+
+  use super::{decode_generation, decode_items, encode_generation, encode_items};
+
+  #[test]
+  fn should_encode_items() {
+    let items = vec![
+      (Arc::new(b"key1".to_vec()), Arc::new(b"value1".to_vec())),
+      (Arc::new(vec![]), Arc::new(vec![])),
+      (Arc::new(b"key3".to_vec()), Arc::new(vec![0; 100])),
+    ];
+    let content = encode_items(&items);
+    assert_eq!(decode_items(&content), Some(items));
+    assert_eq!(decode_items(&content[..content.len() - 1]), None);
+  }
+
+  #[test]
+  fn should_encode_generation() {
+    let generation = HashMap::from_iter([
+      (
+        "scope_1".to_string(),
+        vec!["a".to_string(), "b".to_string()],
+      ),
+      ("scope_2".to_string(), vec![]),
+    ]);
+    let content = encode_generation(&generation);
+    assert_eq!(decode_generation(&content), Some(generation));
+  }
+}
@@ -0,0 +1,252 @@
+#[cfg(test)]
+mod test_storage_remote {
+  use std::{
+    collections::HashMap,
+    convert::Infallible,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+  };
+
+  use bytes::Bytes;
+  use http_body_util::{BodyExt, Full};
+  use hyper::{Method, Request, Response, StatusCode, body::Incoming, service::service_fn};
+  use hyper_util::rt::TokioIo;
+  use rspack_fs::MemoryFileSystem;
+  use rspack_paths::{AssertUtf8, Utf8PathBuf};
+  use rspack_storage::{
+    BridgeFileSystem, DirRemoteCache, FileSystem, HttpRemoteCache, PackStorage, PackStorageOptions,
+    RemoteCache, RemoteMode, RemoteStorage, RemoteStorageOptions, Result, Storage,
+  };
+
+  pub fn get_memory_path(p: &str) -> (PathBuf, PathBuf) {
+    let base = PathBuf::from("/rspack_test/storage/test_storage_remote/").join(p);
+    (base.join("cache"), base.join("temp"))
+  }
+
+  fn create_storage(case: &str, remote: Arc<dyn RemoteCache>, mode: RemoteMode) -> RemoteStorage {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    create_storage_with_fs(case, remote, mode, fs)
+  }
+
+  fn create_storage_with_fs(
+    case: &str,
+    remote: Arc<dyn RemoteCache>,
+    mode: RemoteMode,
+    fs: Arc<BridgeFileSystem>,
+  ) -> RemoteStorage {
+    let (root, temp_root) = get_memory_path(case);
+    let version = "xxx".to_string();
+    let local = PackStorage::new(PackStorageOptions {
+      version: version.clone(),
+      root: root.assert_utf8().into(),
+      temp_root: temp_root.assert_utf8().into(),
+      fs,
+      bucket_size: 5,
+      pack_size: 200,
+      expire: 7 * 24 * 60 * 60 * 1000,
+      clean: true,
+      fresh_generation: Some(1),
+      release_generation: Some(2),
+    });
+    RemoteStorage::new(RemoteStorageOptions {
+      local: Arc::new(local),
+      remote,
+      mode,
+      version,
+    })
+  }
+
+  async fn load_map(storage: &dyn Storage, scope: &'static str) -> Result<HashMap<String, String>> {
+    Ok(
+      storage
+        .load(scope)
+        .await?
+        .into_iter()
+        .map(|(k, v)| {
+          (
+            String::from_utf8(k.to_vec()).expect("should be utf8"),
+            String::from_utf8(v.to_vec()).expect("should be utf8"),
+          )
+        })
+        .collect(),
+    )
+  }
+
+  async fn test_share(remote: Arc<dyn RemoteCache>) -> Result<()> {
+    // the first machine builds and uploads
+    let storage = create_storage("machine_1", remote.clone(), RemoteMode::WriteThrough);
+    assert!(storage.load("scope_1").await?.is_empty());
+    for i in 0..50 {
+      storage.set(
+        "scope_1",
+        format!("key_{i:0>3}").as_bytes().to_vec(),
+        format!("val_{i:0>3}").as_bytes().to_vec(),
+      );
+    }
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // the second machine downloads without local cache
+    let storage = create_storage("machine_2", remote.clone(), RemoteMode::ReadOnly);
+    let data = load_map(&storage, "scope_1").await?;
+    assert_eq!(data.len(), 50);
+    assert_eq!(data.get("key_010").map(String::as_str), Some("val_010"));
+    assert!(storage.load("scope_2").await?.is_empty());
+
+    // read-only storage never uploads its changes
+    storage.set("scope_1", b"key_010".to_vec(), b"new_010".to_vec());
+    storage.trigger_save()?.await.expect("should save")?;
+    let storage = create_storage("machine_3", remote, RemoteMode::ReadOnly);
+    let data = load_map(&storage, "scope_1").await?;
+    assert_eq!(data.get("key_010").map(String::as_str), Some("val_010"));
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_dir_remote() -> Result<()> {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let root = Utf8PathBuf::from("/rspack_test/storage/test_storage_remote/server");
+    fs.remove_dir(&root).await?;
+    test_share(Arc::new(DirRemoteCache::new(root, fs))).await
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_generation() -> Result<()> {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let root = Utf8PathBuf::from("/rspack_test/storage/test_storage_remote/generation_server");
+    fs.remove_dir(&root).await?;
+    let remote: Arc<dyn RemoteCache> = Arc::new(DirRemoteCache::new(root, fs));
+
+    // the first machine uploads two scopes
+    let storage = create_storage("generation_1", remote.clone(), RemoteMode::WriteThrough);
+    storage.set("scope_1", b"key".to_vec(), b"val_1".to_vec());
+    storage.set("scope_2", b"key".to_vec(), b"val_1".to_vec());
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // the second machine only knows one scope
+    let storage = create_storage("generation_2", remote.clone(), RemoteMode::WriteThrough);
+    storage.set("scope_1", b"key".to_vec(), b"val_2".to_vec());
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // scopes are downloaded from the latest generation only, never mixed
+    let storage = create_storage("generation_3", remote, RemoteMode::ReadOnly);
+    let data = load_map(&storage, "scope_1").await?;
+    assert_eq!(data.get("key").map(String::as_str), Some("val_2"));
+    assert!(storage.load("scope_2").await?.is_empty());
+    Ok(())
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_local_first() -> Result<()> {
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let root = Utf8PathBuf::from("/rspack_test/storage/test_storage_remote/local_first_server");
+    fs.remove_dir(&root).await?;
+    let remote: Arc<dyn RemoteCache> = Arc::new(DirRemoteCache::new(root, fs));
+
+    // another machine uploads two scopes
+    let storage = create_storage("local_first_1", remote.clone(), RemoteMode::WriteThrough);
+    storage.set("scope_1", b"key".to_vec(), b"remote".to_vec());
+    storage.set("scope_2", b"key".to_vec(), b"remote".to_vec());
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // the local storage only has one scope
+    let local_fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let fs = Arc::new(BridgeFileSystem(Arc::new(MemoryFileSystem::default())));
+    let empty_root = Utf8PathBuf::from("/rspack_test/storage/test_storage_remote/empty_server");
+    let empty_remote: Arc<dyn RemoteCache> = Arc::new(DirRemoteCache::new(empty_root, fs));
+    let storage = create_storage_with_fs(
+      "local_first_2",
+      empty_remote,
+      RemoteMode::ReadOnly,
+      local_fs.clone(),
+    );
+    assert!(storage.load("scope_1").await?.is_empty());
+    storage.set("scope_1", b"key".to_vec(), b"local".to_vec());
+    storage.trigger_save()?.await.expect("should save")?;
+
+    // the remote scopes are not mixed into a local storage
+    let storage = create_storage_with_fs("local_first_2", remote, RemoteMode::ReadOnly, local_fs);
+    let data = load_map(&storage, "scope_1").await?;
+    assert_eq!(data.get("key").map(String::as_str), Some("local"));
+    assert!(storage.load("scope_2").await?.is_empty());
+    Ok(())
+  }
+
+  /// Serve entries from memory, like a Bazel remote cache server.
+  async fn serve() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0")
+      .await
+      .expect("should bind");
+    let url = format!(
+      "http://{}/cache",
+      listener.local_addr().expect("should get addr")
+    );
+    let entries = Arc::new(Mutex::new(HashMap::<String, Bytes>::new()));
+    tokio::spawn(async move {
+      loop {
+        let (stream, _) = listener.accept().await.expect("should accept");
+        let entries = entries.clone();
+        let service = service_fn(move |request: Request<Incoming>| {
+          let entries = entries.clone();
+          async move {
+            let path = request.uri().path().to_string();
+            let response = match *request.method() {
+              Method::PUT => {
+                let body = request
+                  .into_body()
+                  .collect()
+                  .await
+                  .expect("should read body")
+                  .to_bytes();
+                entries.lock().expect("should lock").insert(path, body);
+                Response::new(Full::default())
+              }
+              _ => match entries.lock().expect("should lock").get(&path) {
+                Some(body) => Response::new(Full::new(body.clone())),
+                None => {
+                  let mut response = Response::new(Full::default());
+                  *response.status_mut() = StatusCode::NOT_FOUND;
+                  response
+                }
+              },
+            };
+            Ok::<_, Infallible>(response)
+          }
+        });
+        tokio::spawn(
+          hyper::server::conn::http1::Builder::new()
+            .serve_connection(TokioIo::new(stream), service),
+        );
+      }
+    });
+    url
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_http_remote() -> Result<()> {
+    let remote = HttpRemoteCache::new(&serve().await)?;
+    test_share(Arc::new(remote)).await
+  }
+
+  #[tokio::test]
+  #[cfg_attr(miri, ignore)]
+  async fn test_unreachable_remote() -> Result<()> {
+    // nothing listens on the port after the listener is dropped
+    let port = std::net::TcpListener::bind("127.0.0.1:0")
+      .and_then(|listener| listener.local_addr())
+      .expect("should bind")
+      .port();
+    let remote = HttpRemoteCache::new(&format!("http://127.0.0.1:{port}"))?;
+    let storage = create_storage("unreachable", Arc::new(remote), RemoteMode::WriteThrough);
+    assert!(storage.load("scope_1").await?.is_empty());
+    storage.set("scope_1", b"key".to_vec(), b"value".to_vec());
+    // the local storage is saved even if the upload fails
+    assert!(storage.trigger_save()?.await.expect("should save").is_err());
+    let data = load_map(&storage, "scope_1").await?;
+    assert_eq!(data.get("key").map(String::as_str), Some("value"));
+    Ok(())
+  }
+}
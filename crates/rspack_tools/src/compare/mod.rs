@@ -62,6 +62,7 @@ pub fn load_storages_from_path(path: &Utf8PathBuf) -> HashMap<String, Arc<dyn St
     let storage = create_storage(
       StorageOptions::FileSystem {
         directory: path.clone().into(),
+        remote: None,
       },
       v.clone(),
       fs.clone(),
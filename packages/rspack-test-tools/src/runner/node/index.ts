@@ -4,6 +4,7 @@ import { fileURLToPath, pathToFileURL } from 'node:url';
 import vm, { SourceTextModule } from 'node:vm';
 import type { RspackOptions, StatsCompilation } from '@rspack/core';
 import { enableEsmLibraryPlugin } from '../../case/config';
+import { isCss } from '../../helper';
 import asModule from '../../helper/legacy/asModule';
 import createFakeWorker from '../../helper/legacy/createFakeWorker';
 import urlToRelativePath from '../../helper/legacy/urlToRelativePath';
@@ -308,6 +309,11 @@ export class NodeRunner implements ITestRunner {
         });
       }
 
+      if (isCss(file.path)) {
+        // css side files imported by esm output, e.g. `import "./style.css"`
+        return {};
+      }
+
       if (
         file.path.endsWith('.mjs') ||
         enableEsmLibraryPlugin(this._options.compilerOptions)
@@ -215,6 +215,9 @@ declare namespace Rspack {
       [key: string]: any;
     };
   }
+
+  /** The query of the glob requests, objects are serialized like `URLSearchParams`. */
+  type ImportMetaGlobQuery = string | Record<string, string | number | boolean>;
 }
 
 interface ImportMeta {
@@ -235,6 +238,20 @@ interface ImportMeta {
       mode?: 'sync' | 'eager' | 'weak' | 'lazy' | 'lazy-once';
     },
   ) => Rspack.Context;
+  glob: {
+    <M = unknown>(
+      patterns: string | string[],
+      options?: { eager?: false; import?: string; query?: Rspack.ImportMetaGlobQuery },
+    ): Record<string, () => Promise<M>>;
+    <M = unknown>(
+      patterns: string | string[],
+      options: { eager: true; import?: string; query?: Rspack.ImportMetaGlobQuery },
+    ): Record<string, M>;
+  };
+  globEager: <M = unknown>(
+    patterns: string | string[],
+    options?: { import?: string; query?: Rspack.ImportMetaGlobQuery },
+  ) => Record<string, M>;
 }
 
 declare const __resourceQuery: string;
@@ -48,6 +48,7 @@ function __to_binding_stat(stat: IStats): NodeFsStats {
     birthtimeMs: stat.birthtimeMs ?? toMs(stat.birthtime),
     size: stat.size,
     mode: stat.mode,
+    ino: stat.ino,
   };
 }
 
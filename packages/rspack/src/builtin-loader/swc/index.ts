@@ -5,6 +5,7 @@ import { resolvePluginImport } from './pluginImport';
 export type { CollectTypeScriptInfoOptions } from './collectTypeScriptInfo';
 export type { PluginImportOptions } from './pluginImport';
 export type {
+  IsolatedDeclarationsOptions,
   SwcLoaderEnvConfig,
   SwcLoaderEsParserConfig,
   SwcLoaderJscConfig,
@@ -25,6 +25,11 @@ export type SwcLoaderOptions = Config & {
    * providing better TypeScript development experience and smaller output bundle size.
    */
   collectTypeScriptInfo?: CollectTypeScriptInfoOptions;
+  /**
+   * Generates `.d.ts` declarations for TypeScript modules with isolated declarations,
+   * and emits them as assets.
+   */
+  isolatedDeclarations?: IsolatedDeclarationsOptions;
   /**
    * Experimental features provided by Rspack.
    * @experimental
@@ -35,9 +40,32 @@ export type SwcLoaderOptions = Config & {
      * Enable React Server Components support.
      */
     reactServerComponents?: boolean;
+    /**
+     * Rewrite named imports of the listed barrel packages into imports of the modules
+     * which declare the imported names, so that unused re-exports are never built.
+     */
+    optimizePackageImports?: string[];
   };
 };
 
+export type IsolatedDeclarationsOptions = {
+  /**
+   * The directory in the output path to emit declarations to.
+   * @default ''
+   */
+  outDir?: string;
+  /**
+   * The directory which the layout of declarations mirrors, relative to the context.
+   * @default context
+   */
+  rootDir?: string;
+  /**
+   * Whether to emit `[name].d.ts` for each entry, which re-exports the declaration of the entry module.
+   * @default false
+   */
+  entry?: boolean;
+};
+
 export interface TerserCompressOptions {
   arguments?: boolean;
   arrows?: boolean;
@@ -0,0 +1,48 @@
+import {
+  BuiltinPluginName,
+  type RawDotenvPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export type DotenvPluginOptions = {
+  /**
+   * The directory to load the env files from.
+   * @default compiler.context
+   */
+  dir?: string;
+  /**
+   * Also load `.env.[mode]` and `.env.[mode].local`.
+   * @default compiler.options.mode
+   */
+  mode?: string;
+  /**
+   * Only variables whose names start with one of the prefixes are exposed.
+   * @default ["PUBLIC_"]
+   */
+  prefixes?: string | string[];
+  /**
+   * Expose variables as `process.env.[name]`.
+   * @default true
+   */
+  processEnv?: boolean;
+  /**
+   * Expose variables as `import.meta.env.[name]`.
+   * @default true
+   */
+  importMetaEnv?: boolean;
+};
+
+export const DotenvPlugin = create(
+  BuiltinPluginName.DotenvPlugin,
+  function (options: DotenvPluginOptions = {}): RawDotenvPluginOptions {
+    const { prefixes } = options;
+    return {
+      ...options,
+      dir: options.dir ?? this.context,
+      mode: options.mode ?? this.options.mode,
+      prefixes: typeof prefixes === 'string' ? [prefixes] : prefixes,
+    };
+  },
+  'compilation',
+);
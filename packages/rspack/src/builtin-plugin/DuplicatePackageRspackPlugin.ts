@@ -0,0 +1,31 @@
+import {
+  BuiltinPluginName,
+  type RawDuplicatePackageRspackPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export type DuplicatePackageRspackPluginOptions = {
+  /**
+   * When `true`, the plugin will emit `ERROR` diagnostics rather than the
+   * default `WARN` level, which fails the build.
+   */
+  failOnError?: boolean;
+  /**
+   * Packages that are allowed to be bundled several times. When an entry is a
+   * String, it should equal the package name. When the entry is a RegExp, it
+   * is tested against the package name.
+   */
+  allowlist?: (string | RegExp)[];
+};
+
+export const DuplicatePackageRspackPlugin = create(
+  BuiltinPluginName.DuplicatePackageRspackPlugin,
+  (
+    options: DuplicatePackageRspackPluginOptions = {},
+  ): RawDuplicatePackageRspackPluginOptions => ({
+    failOnError: options.failOnError,
+    allowlist: options.allowlist,
+  }),
+  'compilation',
+);
@@ -10,7 +10,12 @@ export const SizeLimitsPlugin = create(
   BuiltinPluginName.SizeLimitsPlugin,
   (options: Exclude<Performance, false>): RawSizeLimitsPluginOptions => {
     const hints = options.hints === false ? undefined : options.hints;
+    // the baseline is only recorded on demand, so configs can't update it by accident
+    const baseline = options.baseline && {
+      ...options.baseline,
+      update: process.env.RSPACK_UPDATE_SIZE_BASELINE === 'true',
+    };
 
-    return { ...options, hints };
+    return { ...options, hints, baseline };
   },
 );
@@ -19,11 +19,32 @@ type ExtractCommentsObject = {
 };
 type ExtractCommentsOptions = ExtractCommentsCondition | ExtractCommentsObject;
 
+export type ManglePropertiesOptions = {
+  /**
+   * Only properties matching the regex are mangled.
+   */
+  regex: RegExp | string;
+  /**
+   * Properties that should never be mangled.
+   */
+  reserved?: string[];
+};
+
 export type SwcJsMinimizerRspackPluginOptions = {
   test?: AssetConditions;
   exclude?: AssetConditions;
   include?: AssetConditions;
   extractComments?: ExtractCommentsOptions | undefined;
+  /**
+   * Mangle properties matching the regex, the same property gets the same name in all assets.
+   */
+  mangleProperties?: ManglePropertiesOptions;
+  /**
+   * Path of a file in the format of terser's `nameCache`, which the mangled property names are
+   * read from before the build and written to after the build.
+   * It's resolved relative to the context.
+   */
+  nameCache?: string;
   minimizerOptions?: {
     minify?: boolean;
     ecma?: TerserEcmaVersion;
@@ -308,6 +329,14 @@ export const SwcJsMinimizerRspackPlugin = create(
       include: options?.include,
       exclude: options?.exclude,
       extractComments: getRawExtractCommentsOptions(options?.extractComments),
+      mangleProperties: options?.mangleProperties && {
+        regex:
+          typeof options.mangleProperties.regex === 'string'
+            ? new RegExp(options.mangleProperties.regex)
+            : options.mangleProperties.regex,
+        reserved: options.mangleProperties.reserved,
+      },
+      nameCache: options?.nameCache,
       minimizerOptions: {
         compress,
         mangle,
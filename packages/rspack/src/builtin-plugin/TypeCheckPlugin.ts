@@ -0,0 +1,68 @@
+import { existsSync } from 'node:fs';
+import path from 'node:path';
+import {
+  BuiltinPluginName,
+  type RawTypeCheckPluginOptions,
+} from '@rspack/binding';
+
+import { create } from './base';
+
+export type TypeCheckPluginOptions = {
+  /**
+   * The checker executable, looked up in `node_modules/.bin` of the context before `PATH`.
+   * It should accept the options of `tsc`.
+   * @default "tsgo"
+   */
+  command?: string;
+  /**
+   * The tsconfig of the project, relative to the context. If it doesn't exist, the TypeScript
+   * modules of the compilation are checked as root files.
+   * @default "tsconfig.json"
+   */
+  tsconfig?: string;
+  /**
+   * The file the checker stores its incremental state in, relative to the context.
+   * Set to `false` to check from scratch on every compilation.
+   * @default "node_modules/.cache/rspack/type-check.tsbuildinfo"
+   */
+  buildInfoFile?: string | false;
+  /**
+   * Extra arguments passed to the checker, before the arguments of the plugin.
+   */
+  args?: string[];
+  /**
+   * Check in the background without blocking the compilation. Type errors are reported
+   * as warnings, by the current compilation if the check is done before its assets are
+   * processed, otherwise by a new compilation triggered once the check is done in watch mode.
+   * @default compiler.options.mode === "development"
+   */
+  async?: boolean;
+};
+
+export const TypeCheckPlugin = create(
+  BuiltinPluginName.TypeCheckPlugin,
+  function (options: TypeCheckPluginOptions = {}): RawTypeCheckPluginOptions {
+    const context = this.context;
+    const tsconfig = path.resolve(context, options.tsconfig ?? 'tsconfig.json');
+    const buildInfoFile =
+      options.buildInfoFile === false
+        ? undefined
+        : path.resolve(
+            context,
+            options.buildInfoFile ??
+              'node_modules/.cache/rspack/type-check.tsbuildinfo',
+          );
+    return {
+      command: options.command ?? 'tsgo',
+      tsconfig:
+        options.tsconfig || existsSync(tsconfig) ? tsconfig : undefined,
+      buildInfoFile,
+      args: options.args,
+      blocking: !(options.async ?? this.options.mode === 'development'),
+      onChecked: () => {
+        this.watching?.invalidate();
+      },
+    };
+  },
+  'compilation',
+);
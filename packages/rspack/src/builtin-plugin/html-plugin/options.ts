@@ -11,6 +11,14 @@ export type TemplateParamFunction = (
   params: Record<string, any>,
 ) => Record<string, any> | Promise<Record<string, any>>;
 
+export type HtmlSpeculationRules = {
+  /** Maps the names of async chunks to the URLs of the pages that load them. */
+  urls: Record<string, string | string[]>;
+
+  /** How eagerly the browser should act on the generated rules. */
+  eagerness?: 'immediate' | 'eager' | 'moderate' | 'conservative';
+};
+
 export type HtmlRspackPluginOptions = {
   /** The title to use for the generated HTML document. */
   title?: string;
@@ -92,6 +100,21 @@ export type HtmlRspackPluginOptions = {
    */
   hash?: boolean;
 
+  /**
+   * Inject the chunks statically imported by the entry chunks as [`<link rel="modulepreload">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Attributes/rel/modulepreload) tags instead of `<script>` tags, so the browser fetches the whole import graph of the entries in parallel.
+   *
+   * Only takes effect when `scriptLoading` is `'module'` and `output.module` is enabled, other chunk formats don't import the chunks of the entries, so they are still injected as `<script>` tags.
+   * @default false
+   */
+  modulePreload?: boolean;
+
+  /**
+   * Inject a [`<script type="speculationrules">`](https://developer.mozilla.org/en-US/docs/Web/HTML/Element/script/type/speculationrules) tag for the pages that load named async chunks.
+   *
+   * Chunks imported with `webpackPrefetch` or `webpackPreload` are mapped to `prefetch` rules.
+   */
+  speculationRules?: HtmlSpeculationRules;
+
   /**
    * Any other options will be passed by hooks.
    */
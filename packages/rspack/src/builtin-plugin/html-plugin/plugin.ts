@@ -66,6 +66,15 @@ const HtmlRspackPluginImpl = create(
           : configInject;
     const base = typeof c.base === 'string' ? { href: c.base } : c.base;
     const chunksSortMode = c.chunksSortMode ?? 'auto';
+    const speculationRules = c.speculationRules && {
+      urls: Object.fromEntries(
+        Object.entries(c.speculationRules.urls).map(([chunkName, urls]) => [
+          chunkName,
+          Array.isArray(urls) ? urls : [urls],
+        ]),
+      ),
+      eagerness: c.speculationRules.eagerness,
+    };
 
     let compilation: Compilation | null = null;
     this.hooks.compilation.tap('HtmlRspackPlugin', (compilationInstance) => {
@@ -215,6 +224,8 @@ const HtmlRspackPluginImpl = create(
       scriptLoading,
       inject,
       base,
+      modulePreload: c.modulePreload,
+      speculationRules,
       templateFn,
       templateContent,
       templateParameters,
@@ -20,6 +20,8 @@ export * from './DeterministicChunkIdsPlugin';
 export * from './DeterministicModuleIdsPlugin';
 export * from './DllEntryPlugin';
 export * from './DllReferenceAgencyPlugin';
+export * from './DotenvPlugin';
+export * from './DuplicatePackageRspackPlugin';
 export * from './DynamicEntryPlugin';
 export * from './ElectronTargetPlugin';
 export * from './EnableChunkLoadingPlugin';
@@ -80,6 +82,7 @@ export * from './SourceMapDevToolPlugin';
 export * from './SplitChunksPlugin';
 export * from './SubresourceIntegrityPlugin';
 export * from './SwcJsMinimizerPlugin';
+export * from './TypeCheckPlugin';
 export * from './URLPlugin';
 export * from './WebWorkerTemplatePlugin';
 export * from './WorkerPlugin';
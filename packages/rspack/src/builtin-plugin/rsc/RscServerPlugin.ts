@@ -6,8 +6,34 @@ import { type Coordinator, GET_OR_INIT_BINDING } from './Coordinator';
 export type RscServerPluginOptions = {
   coordinator: Coordinator;
   onServerComponentChanges?: () => Promise<void>;
+  /**
+   * Emit the client and server reference manifests as versioned JSON assets, so that
+   * server runtimes which are not built by Rspack can read them.
+   * @default false
+   */
+  referenceManifest?:
+    | boolean
+    | {
+        /** @default 'client-reference-manifest.json' */
+        clientFilename?: string;
+        /** @default 'server-reference-manifest.json' */
+        serverFilename?: string;
+      };
 };
 
+function getRawReferenceManifest(
+  referenceManifest: RscServerPluginOptions['referenceManifest'],
+): binding.JsRscReferenceManifestOptions | undefined {
+  if (!referenceManifest) {
+    return undefined;
+  }
+  const options = referenceManifest === true ? {} : referenceManifest;
+  return {
+    clientFilename: options.clientFilename ?? 'client-reference-manifest.json',
+    serverFilename: options.serverFilename ?? 'server-reference-manifest.json',
+  };
+}
+
 export class RscServerPlugin extends RspackBuiltinPlugin {
   name = 'RscServerPlugin';
   #options: RscServerPluginOptions;
@@ -24,6 +50,9 @@ export class RscServerPlugin extends RspackBuiltinPlugin {
       // @ts-ignore
       coordinator: this.#options.coordinator[GET_OR_INIT_BINDING](),
       onServerComponentChanges: this.#options.onServerComponentChanges,
+      referenceManifest: getRawReferenceManifest(
+        this.#options.referenceManifest,
+      ),
     });
   }
 }
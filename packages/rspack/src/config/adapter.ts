@@ -499,6 +499,18 @@ function getRawParserOptions(
       asset: getRawAssetParserOptions(parser),
     };
   }
+  if (type === 'asset/inline') {
+    return {
+      type: 'asset/inline',
+      assetInline: { image: parser.image },
+    };
+  }
+  if (type === 'asset/resource') {
+    return {
+      type: 'asset/resource',
+      assetResource: { image: parser.image },
+    };
+  }
   if (type === 'javascript') {
     return {
       type: 'javascript',
@@ -604,6 +616,7 @@ function getRawAssetParserOptions(
     dataUrlCondition: parser.dataUrlCondition
       ? getRawAssetParserDataUrl(parser.dataUrlCondition)
       : undefined,
+    image: parser.image,
   };
 }
 
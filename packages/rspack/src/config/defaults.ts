@@ -215,6 +215,7 @@ const applyExperimentsDefaults = (experiments: ExperimentsNormalized) => {
   // IGNORE(experiments.asyncWebAssembly): Rspack enable async WebAssembly by default
   D(experiments, 'asyncWebAssembly', true);
   D(experiments, 'deferImport', false);
+  D(experiments, 'strictRegex', false);
 
   D(experiments, 'buildHttp', undefined);
   if (experiments.buildHttp && typeof experiments.buildHttp === 'object') {
@@ -829,6 +830,19 @@ const applyOutputDefaults = (
   F(output, 'scriptType', () => (output.module ? 'module' : false));
   D(output, 'chunkLoadTimeout', 120000);
 
+  const { chunkLoadRetry } = output;
+  if (chunkLoadRetry) {
+    D(chunkLoadRetry, 'maxRetries', 3);
+    D(chunkLoadRetry, 'retryDelay', 1000);
+    D(chunkLoadRetry, 'cacheBust', true);
+    D(chunkLoadRetry, 'fallbackPublicPaths', []);
+  }
+
+  const { importMap } = output;
+  if (importMap) {
+    D(importMap, 'filename', 'importmap.json');
+  }
+
   const { trustedTypes } = output;
   if (trustedTypes) {
     F(
@@ -18,6 +18,7 @@ import type {
   Bail,
   BundlerInfoOptions,
   ChunkFilename,
+  ChunkLoadRetry,
   ChunkLoading,
   ChunkLoadingGlobal,
   Clean,
@@ -52,6 +53,7 @@ import type {
   IgnoreWarnings,
   Iife,
   ImportFunctionName,
+  ImportMap,
   ImportMetaName,
   Incremental,
   IncrementalPresets,
@@ -221,6 +223,22 @@ export const getNormalizedRspackOptions = (
         devtoolFallbackModuleFilenameTemplate:
           output.devtoolFallbackModuleFilenameTemplate,
         chunkLoadTimeout: output.chunkLoadTimeout,
+        chunkLoadRetry: optionalNestedConfig(
+          output.chunkLoadRetry || undefined,
+          (chunkLoadRetry) => {
+            if (chunkLoadRetry === true) return {};
+            return {
+              ...chunkLoadRetry,
+              fallbackPublicPaths: chunkLoadRetry.fallbackPublicPaths && [
+                ...chunkLoadRetry.fallbackPublicPaths,
+              ],
+            };
+          },
+        ),
+        importMap: optionalNestedConfig(
+          output.importMap || undefined,
+          (importMap) => (importMap === true ? {} : { ...importMap }),
+        ),
         environment: cloneObject(output.environment),
         compareBeforeEmit: output.compareBeforeEmit,
         bundlerInfo: output.bundlerInfo,
@@ -292,6 +310,7 @@ export const getNormalizedRspackOptions = (
           managedPaths: optionalNestedArray(snapshot.managedPaths, (p) => [
             ...p,
           ]) || [/[\\/]node_modules[\\/][^.]/],
+          mode: snapshot.mode || 'timestamp',
         },
         storage: {
           type: 'filesystem',
@@ -299,6 +318,15 @@ export const getNormalizedRspackOptions = (
             config.context || process.cwd(),
             cache.storage?.directory || 'node_modules/.cache/rspack',
           ),
+          remote: cache.storage?.remote && {
+            url: /^https?:\/\//.test(cache.storage.remote.url)
+              ? cache.storage.remote.url
+              : path.resolve(
+                  config.context || process.cwd(),
+                  cache.storage.remote.url,
+                ),
+            mode: cache.storage.remote.mode || 'read-only',
+          },
         },
         portable: cache.portable,
         readonly: cache.readonly,
@@ -578,6 +606,8 @@ export interface OutputNormalized {
   devtoolFallbackModuleFilenameTemplate?: DevtoolFallbackModuleFilenameTemplate;
   environment?: Environment;
   chunkLoadTimeout?: number;
+  chunkLoadRetry?: ChunkLoadRetry;
+  importMap?: ImportMap;
   compareBeforeEmit?: boolean;
   bundlerInfo?: BundlerInfoOptions;
 }
@@ -603,10 +633,15 @@ export type CacheNormalized =
         immutablePaths: (string | RegExp)[];
         unmanagedPaths: (string | RegExp)[];
         managedPaths: (string | RegExp)[];
+        mode: 'timestamp' | 'content';
       };
       storage: {
         type: 'filesystem';
         directory: string;
+        remote?: {
+          url: string;
+          mode: 'read-only' | 'write-through';
+        };
       };
       portable?: boolean;
     };
@@ -619,6 +654,7 @@ export interface ExperimentsNormalized {
   useInputFileSystem?: false | RegExp[];
   nativeWatcher?: boolean;
   deferImport?: boolean;
+  strictRegex?: boolean;
 }
 
 export type IgnoreWarningsNormalized = ((
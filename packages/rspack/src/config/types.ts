@@ -343,6 +343,43 @@ export type TrustedTypes = {
   onPolicyCreationFailure?: 'continue' | 'stop';
 };
 
+/** Controls how failed chunk loads are retried at runtime. */
+export type ChunkLoadRetry = {
+  /**
+   * How many times a failed chunk load is retried before it rejects with a `ChunkLoadError`.
+   * @default 3
+   */
+  maxRetries?: number;
+  /**
+   * The delay in milliseconds before the first retry. The delay doubles for every following retry.
+   * @default 1000
+   */
+  retryDelay?: number;
+  /**
+   * Whether to append the attempt number as a query parameter to the url of a retried request,
+   * so that a cached failed response is not reused. Chunks loaded by `import()` always get it,
+   * as failed imports are cached by the module map.
+   * @default true
+   */
+  cacheBust?: boolean;
+  /**
+   * Public paths used in turn instead of `output.publicPath` for the retried requests,
+   * e.g. the origin server as a fallback for a CDN. Chunks loaded by `import()` use them instead
+   * of the output directory their urls are relative to.
+   * @default []
+   */
+  fallbackPublicPaths?: string[];
+};
+
+/** Controls the import map emitted for chunks in ES module format. */
+export type ImportMap = {
+  /**
+   * The filename of the import map, relative to `output.path`.
+   * @default 'importmap.json'
+   */
+  filename?: string;
+};
+
 /** The encoding to use when generating the hash. */
 export type HashDigest = string;
 
@@ -674,6 +711,20 @@ export type Output = {
    * */
   chunkLoadTimeout?: number;
 
+  /**
+   * Retry failed chunk loads (JavaScript, CSS and async WebAssembly) with exponential backoff.
+   * @default false
+   * */
+  chunkLoadRetry?: boolean | ChunkLoadRetry;
+
+  /**
+   * Import chunks through stable bare specifiers and emit an import map that resolves them to the hashed chunk files,
+   * so that a change in one chunk does not change the hashes of the chunks importing it.
+   * Only takes effect when `output.module` is enabled.
+   * @default false
+   * */
+  importMap?: boolean | ImportMap;
+
   /** Tell Rspack what kind of ES-features may be used in the generated runtime-code. */
   environment?: Environment;
 
@@ -833,6 +884,12 @@ export type ResolveOptions = {
   pnp?: boolean;
   /** Path to PnP manifest file */
   pnpManifest?: string | false;
+
+  /**
+   * Absolute path to an [import map](https://html.spec.whatwg.org/multipage/webappapis.html#import-maps) file.
+   * Its `imports` and `scopes` are applied before the normal resolution.
+   */
+  importMap?: string;
 };
 
 /** Used to configure the Rspack module resolution */
@@ -996,6 +1053,42 @@ export type AssetParserOptions = {
    * @default { maxSize: 8096 }
    * */
   dataUrlCondition?: AssetParserDataUrlOptions;
+  /**
+   * Processes images natively: optimizes images, resizes and converts them through the
+   * `w`, `h` and `format` resource queries, and exports their metadata with the
+   * `metadata` resource query.
+   */
+  image?: AssetParserImageOptions;
+};
+
+/** Options object for `asset/inline` modules. */
+export type AssetInlineParserOptions = {
+  /**
+   * Processes images natively, like the `image` option of `asset` modules.
+   */
+  image?: AssetParserImageOptions;
+};
+
+/** Options object for `asset/resource` modules. */
+export type AssetResourceParserOptions = {
+  /**
+   * Processes images natively, like the `image` option of `asset` modules.
+   */
+  image?: AssetParserImageOptions;
+};
+
+/** Options for the image pipeline of `asset`, `asset/inline` and `asset/resource` modules. */
+export type AssetParserImageOptions = {
+  /**
+   * Losslessly recompresses PNG images, and recompresses JPEG images at `quality` when it is set.
+   * @default true
+   */
+  optimize?: boolean;
+  /**
+   * The quality of lossy encodings from 1 to 100, used for JPEG and AVIF images.
+   * @default 80
+   */
+  quality?: number;
 };
 
 export type CssParserNamedExports = boolean;
@@ -1236,6 +1329,12 @@ export type ParserOptionsByModuleTypeKnown = {
   /** Parser options for `asset` modules. */
   asset?: AssetParserOptions;
 
+  /** Parser options for `asset/inline` modules. */
+  'asset/inline'?: AssetInlineParserOptions;
+
+  /** Parser options for `asset/resource` modules. */
+  'asset/resource'?: AssetResourceParserOptions;
+
   /** Parser options for `css` modules. */
   css?: CssParserOptions;
 
@@ -1814,6 +1913,13 @@ export type CacheSnapshotOptions = {
    * @default [/[\\/]node_modules[\\/][^.]/]
    */
   managedPaths?: (string | RegExp)[];
+  /**
+   * How files outside of managed paths are validated.
+   * - `timestamp`: files with an unchanged modified time are considered unchanged.
+   * - `content`: files are compared by size and content hash, the modified time is ignored, which lets fresh checkouts (e.g. in CI) reuse the cache. Content hashes are cached by inode, size and modified time, so unchanged files are not read again.
+   * @default 'timestamp'
+   */
+  mode?: 'timestamp' | 'content';
 };
 
 /**
@@ -1829,6 +1935,30 @@ export type CacheStorageOptions = {
    * @default 'node_modules/.cache/rspack'
    */
   directory?: string;
+  /**
+   * Share the cache through a remote content-addressed cache, e.g. between CI runners.
+   * The local cache directory is always used first, and failures of the remote cache fall back to it.
+   */
+  remote?: CacheRemoteStorageOptions;
+};
+
+/**
+ * Remote storage options for persistent cache.
+ */
+export type CacheRemoteStorageOptions = {
+  /**
+   * An `http://` or `https://` url of a cache server, or a directory shared between machines.
+   * The cache server should respond to `GET` and `PUT` requests of `/ac/<digest>` and `/cas/<digest>`,
+   * like the HTTP protocol of bazel-remote. Credentials in the url are not supported.
+   * The remote cache is shared between different directories, so `portable: true` is required.
+   */
+  url: string;
+  /**
+   * - `read-only`: only download the cache.
+   * - `write-through`: also upload the cache after it is saved locally.
+   * @default 'read-only'
+   */
+  mode?: 'read-only' | 'write-through';
 };
 
 /**
@@ -2883,6 +3013,13 @@ export type Experiments = {
    * @default false
    */
   deferImport?: boolean;
+  /**
+   * Reject regexes in `module.rules` that can't be matched in linear time,
+   * such as patterns with look-around or backreferences.
+   * Set `stats.logging` to `verbose` to see the engine chosen for each regex.
+   * @default false
+   */
+  strictRegex?: boolean;
 };
 //#endregion
 
@@ -2974,6 +3111,50 @@ export type Bail = boolean;
 //#endregion
 
 //#region Performance
+/**
+ * A size limit on the total size of the selected assets. An asset is selected when it matches
+ * all the given selectors, all assets are selected when none is given.
+ */
+export type PerformanceBudget = {
+  /**
+   * Identifies the budget in hints and in the baseline, derived from the selectors when omitted.
+   */
+  name?: string;
+  /**
+   * Selects the initial assets of the named entrypoint.
+   */
+  entry?: string;
+  /**
+   * Selects the assets whose filenames match the glob.
+   */
+  asset?: string;
+  /**
+   * Selects the assets of initial chunks, async chunks, or the CSS assets.
+   */
+  chunkType?: 'initial' | 'async' | 'css';
+  /**
+   * Measures the sizes after compression instead of the raw sizes.
+   */
+  compression?: 'gzip' | 'brotli';
+  /**
+   * Total size limit (in bytes) of the selected assets.
+   */
+  maxSize?: number;
+};
+
+/** The baseline JSON that the sizes of the budgets are compared against. */
+export type PerformanceBaseline = {
+  /**
+   * The path of the baseline JSON, relative to the context.
+   */
+  path: string;
+  /**
+   * The growth over the baseline size of a budget allowed, in percent.
+   * @default 0
+   */
+  maxGrowth?: number;
+};
+
 /** Options to control how Rspack notifies you of assets and entry points that exceed a specific file limit.   */
 export type Performance =
   | false
@@ -2996,6 +3177,14 @@ export type Performance =
        * @default 250000
        */
       maxEntrypointSize?: number;
+      /**
+       * Size limits on the assets of entry points, asset globs and chunk types.
+       */
+      budgets?: PerformanceBudget[];
+      /**
+       * Compares the sizes of the budgets against a baseline from a previous build.
+       */
+      baseline?: PerformanceBaseline;
     };
 //#endregion
 
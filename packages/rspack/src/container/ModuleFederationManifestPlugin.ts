@@ -25,6 +25,7 @@ const MANIFEST_FILE_NAME = 'mf-manifest.json';
 const STATS_FILE_NAME = 'mf-stats.json';
 const LOCAL_BUILD_VERSION = 'local';
 const JSON_EXT = '.json';
+const TYPES_ZIP_NAME = '@mf-types.zip';
 
 function isPlainObject(value: unknown): value is Record<string, unknown> {
   return Boolean(value) && typeof value === 'object' && !Array.isArray(value);
@@ -130,13 +131,40 @@ type InternalManifestPluginOptions = {
   remoteAliasMap?: RemoteAliasMap;
   exposes?: ManifestExposeOption[];
   shared?: ManifestSharedOption[];
+  /**
+   * Emit the declarations of the exposes as a types archive.
+   * The declarations are generated like `tsc --isolatedDeclarations`.
+   */
+  types?: boolean | { zipName?: string };
+  /**
+   * Types archives of remotes to unpack before the build, keyed by the remote alias.
+   * Paths are relative to the context.
+   */
+  remoteTypes?: Record<string, string>;
+  /**
+   * The directory relative to the context to unpack the types of remotes to.
+   * @default "@mf-types"
+   */
+  typesFolder?: string;
+  /**
+   * Check the versions of shared modules against the `requiredVersion` of the host and remotes.
+   * Unsatisfiable singletons and duplicate provisions are reported as diagnostics and in the stats.
+   * Remotes whose entries are manifests on disk are checked, as well as `remoteManifests`.
+   */
+  sharedCheck?: boolean | { remoteManifests?: string[] };
 };
 
 export type ModuleFederationManifestPluginOptions =
   | boolean
   | Pick<
       InternalManifestPluginOptions,
-      'disableAssetsAnalyze' | 'filePath' | 'fileName'
+      | 'disableAssetsAnalyze'
+      | 'filePath'
+      | 'fileName'
+      | 'types'
+      | 'remoteTypes'
+      | 'typesFolder'
+      | 'sharedCheck'
     >;
 
 export function getFileName(
@@ -329,6 +357,10 @@ export class ModuleFederationManifestPlugin extends RspackBuiltinPlugin {
       remoteAliasMap,
       exposes,
       shared,
+      types,
+      remoteTypes,
+      typesFolder,
+      sharedCheck,
     } = opts;
     const { statsFileName, manifestFileName } = getFileName(opts);
 
@@ -348,6 +380,29 @@ export class ModuleFederationManifestPlugin extends RspackBuiltinPlugin {
         compiler,
         this.rawOpts,
       ),
+      types: types
+        ? {
+            zipName:
+              (typeof types === 'object' && types.zipName) || TYPES_ZIP_NAME,
+          }
+        : undefined,
+      remoteTypes: remoteTypes
+        ? Object.entries(remoteTypes).map(([alias, archive]) => ({
+            alias,
+            archive: resolve(compiler.context, archive),
+          }))
+        : undefined,
+      typesFolder,
+      sharedCheck: sharedCheck
+        ? {
+            remoteManifests:
+              typeof sharedCheck === 'object'
+                ? sharedCheck.remoteManifests?.map((manifest) =>
+                    resolve(compiler.context, manifest),
+                  )
+                : undefined,
+          }
+        : undefined,
     };
     return createBuiltinPlugin(this.name, rawOptions);
   }
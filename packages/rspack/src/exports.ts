@@ -99,15 +99,18 @@ export const util = { createHash, cleverMerge };
 export type {
   BannerPluginArgument,
   DefinePluginOptions,
+  DotenvPluginOptions,
   EntryOptions,
   ProgressPluginHandlerInfo,
   ProgressPluginOptions,
   ProvidePluginOptions,
+  TypeCheckPluginOptions,
 } from './builtin-plugin';
 export {
   BannerPlugin,
   CaseSensitivePlugin,
   DefinePlugin,
+  DotenvPlugin,
   DynamicEntryPlugin,
   EntryPlugin,
   ExternalsPlugin,
@@ -118,6 +121,7 @@ export {
   ProgressPlugin,
   ProvidePlugin,
   RuntimePlugin,
+  TypeCheckPlugin,
 } from './builtin-plugin';
 export { DllPlugin, type DllPluginOptions } from './lib/DllPlugin';
 export {
@@ -327,6 +331,7 @@ export type {
   CopyRspackPluginOptions,
   CssExtractRspackLoaderOptions,
   CssExtractRspackPluginOptions,
+  DuplicatePackageRspackPluginOptions,
   EvalDevToolModulePluginOptions,
   HtmlRspackPluginOptions,
   LightningCssMinimizerRspackPluginOptions,
@@ -341,6 +346,7 @@ export {
   ContextReplacementPlugin,
   CopyRspackPlugin,
   CssExtractRspackPlugin,
+  DuplicatePackageRspackPlugin,
   EvalDevToolModulePlugin,
   EvalSourceMapDevToolPlugin,
   HtmlRspackPlugin,
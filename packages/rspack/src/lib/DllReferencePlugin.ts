@@ -131,6 +131,17 @@ export interface DllReferencePluginOptionsContent {
      * Module ID.
      */
     id?: string | number;
+    /**
+     * The package the module was resolved from when the dll was built.
+     */
+    package?: {
+      name: string;
+      version: string;
+    };
+    /**
+     * The hash of the file content of the module when the dll was built.
+     */
+    hash?: string;
   };
 }
 
@@ -0,0 +1,66 @@
+const fs = require("fs");
+const path = require("path");
+const { createFsFromVolume, Volume } = require("memfs");
+const { TypeCheckPlugin } = require("@rspack/core");
+
+const results = [];
+
+/** @type {import('@rspack/test-tools').TCompilerCaseConfig} */
+module.exports = {
+	description:
+		"should report the type errors of a check done after the compilation by a new compilation",
+	options(context) {
+		const marker = path.join(context.getDist(), "first-done");
+		fs.rmSync(marker, { force: true });
+		return {
+			context: context.getSource("type-check"),
+			mode: "development",
+			entry: "./index.ts",
+			plugins: [
+				new TypeCheckPlugin({
+					command: process.execPath,
+					args: [path.join(context.getSource("type-check"), "checker.js"), marker],
+					buildInfoFile: false,
+					async: true
+				}),
+				compiler => {
+					compiler.hooks.done.tap("test", () => {
+						if (results.length === 1) {
+							fs.mkdirSync(context.getDist(), { recursive: true });
+							fs.writeFileSync(marker, "");
+						}
+					});
+				}
+			]
+		};
+	},
+	async compiler(context, compiler) {
+		compiler.outputFileSystem = createFsFromVolume(new Volume());
+	},
+	async build(context, compiler) {
+		await new Promise((resolve, reject) => {
+			const watching = compiler.watch({}, (err, stats) => {
+				if (err) return reject(err);
+				if (results.length === 2) {
+					watching.close(resolve);
+				}
+			});
+			compiler.hooks.done.tap(
+				{ name: "test", stage: -1 },
+				stats => {
+					results.push(stats.toJson({ all: false, errors: true, warnings: true }));
+				}
+			);
+		});
+	},
+	async check() {
+		expect(results).toHaveLength(2);
+		// the first compilation doesn't wait for the check
+		expect(results[0].errors).toHaveLength(0);
+		expect(results[0].warnings).toHaveLength(0);
+		// the result is reported as warnings by the compilation the check invalidates
+		expect(results[1].errors).toHaveLength(0);
+		expect(results[1].warnings).toHaveLength(1);
+		expect(results[1].warnings[0].message).toMatch(/TS2322/);
+	}
+};
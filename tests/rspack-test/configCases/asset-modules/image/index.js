@@ -0,0 +1,55 @@
+import url from "../_images/file.png";
+import png from "../_images/file.png?metadata";
+import smallPng from "../_images/file.png?w=64&metadata";
+import webp from "../_images/file.png?format=webp&metadata";
+import jpg from "../_images/file.jpg?metadata";
+import broken from "./broken.png?metadata";
+import resourceWebp from "../_images/file.png?resource&format=webp";
+import inlineWebp from "../_images/file.png?inline&format=webp";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should keep exporting the url without the metadata query", () => {
+	expect(url).toMatch(/^[\da-f]{16}\.png$/);
+});
+
+it("should export the metadata of images with the metadata query", () => {
+	expect(png.src).toMatch(/^[\da-f]{16}\.png\?metadata$/);
+	expect(png.width).toBe(150);
+	expect(png.height).toBe(150);
+	expect(png.blurhash).toMatch(/^[0-9A-Za-z#$%*+,-.:;=?@[\]^_{|}~]{28}$/);
+	expect(png.placeholder).toMatch(/^data:image\/png;base64,/);
+	expect(jpg.src).toMatch(/^[\da-f]{16}\.jpg\?metadata$/);
+	expect(jpg.width).toBe(1);
+	expect(jpg.height).toBe(1);
+});
+
+it("should resize images through the resource query", () => {
+	expect(smallPng.src).toMatch(/^[\da-f]{16}\.png\?w=64&metadata$/);
+	expect(smallPng.width).toBe(64);
+	expect(smallPng.height).toBe(64);
+});
+
+it("should convert images through the resource query", () => {
+	expect(webp.src).toMatch(/^[\da-f]{16}\.webp\?format=webp&metadata$/);
+	const content = fs.readFileSync(path.join(__dirname, webp.src.split("?")[0]));
+	expect(content.subarray(8, 12).toString()).toBe("WEBP");
+});
+
+it("should process images of asset/resource and asset/inline modules", () => {
+	expect(resourceWebp).toMatch(/^[\da-f]{16}\.webp\?resource&format=webp$/);
+	const content = fs.readFileSync(path.join(__dirname, resourceWebp.split("?")[0]));
+	expect(content.subarray(8, 12).toString()).toBe("WEBP");
+	expect(inlineWebp).toMatch(/^data:image\/webp;base64,/);
+});
+
+it("should recompress images losslessly", () => {
+	// the size of ../_images/file.png
+	const originalSize = 14910;
+	expect(fs.statSync(path.join(__dirname, url)).size).toBeLessThan(originalSize);
+});
+
+it("should keep the images that fail to be decoded", () => {
+	expect(broken).toEqual({ src: expect.stringMatching(/^[\da-f]{16}\.png\?metadata$/) });
+});
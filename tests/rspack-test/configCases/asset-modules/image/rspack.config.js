@@ -0,0 +1,36 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	module: {
+		rules: [
+			{
+				test: /\.(png|jpg)$/,
+				oneOf: [
+					{
+						resourceQuery: /inline/,
+						type: "asset/inline",
+						parser: {
+							image: {}
+						}
+					},
+					{
+						resourceQuery: /resource/,
+						type: "asset/resource",
+						parser: {
+							image: {}
+						}
+					},
+					{
+						type: "asset",
+						parser: {
+							dataUrlCondition: {
+								maxSize: 0
+							},
+							image: {}
+						}
+					}
+				]
+			}
+		]
+	}
+};
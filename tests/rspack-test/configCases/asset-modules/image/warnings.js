@@ -0,0 +1,3 @@
+module.exports = [
+	[/Unsupported image, only PNG, JPEG, GIF and WebP images can be processed, the original image is used instead/]
+];
@@ -0,0 +1,3 @@
+module.exports = [
+	[/Isolated declarations error/, /untyped\.ts/]
+];
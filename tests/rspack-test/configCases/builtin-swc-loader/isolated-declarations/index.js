@@ -0,0 +1,21 @@
+import Greeter, { greet } from "./src/lib";
+import { random } from "./src/untyped";
+
+const fs = require("fs");
+const path = require("path");
+
+it("should emit isolated declarations mirroring the source layout", () => {
+	expect(greet("rspack")).toBe("hello, rspack");
+	expect(new Greeter().name).toBe("greeter");
+	expect(typeof random).toBe("number");
+	const declaration = fs.readFileSync(
+		path.resolve(__dirname, "types/lib.d.ts"),
+		"utf-8"
+	);
+	expect(declaration).toContain("export declare const greeting: string;");
+	expect(declaration).toContain("The greeting of the library.");
+	expect(declaration).toContain(
+		"export declare function greet(name: string): string;"
+	);
+	expect(declaration).not.toContain("hello");
+});
@@ -0,0 +1,30 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				use: [
+					{
+						loader: "builtin:swc-loader",
+						options: {
+							jsc: {
+								parser: {
+									syntax: "typescript"
+								}
+							},
+							isolatedDeclarations: {
+								outDir: "types",
+								rootDir: "src"
+							}
+						}
+					}
+				],
+				type: "javascript/auto"
+			}
+		]
+	}
+};
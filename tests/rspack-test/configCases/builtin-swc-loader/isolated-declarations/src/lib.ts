@@ -0,0 +1,10 @@
+/** The greeting of the library. */
+export const greeting: string = "hello";
+
+export function greet(name: string): string {
+	return `${greeting}, ${name}`;
+}
+
+export default class Greeter {
+	name: string = "greeter";
+}
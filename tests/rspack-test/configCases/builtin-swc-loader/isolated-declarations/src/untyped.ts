@@ -0,0 +1 @@
+export const random = Math.random();
@@ -0,0 +1,20 @@
+import { Button, Card, PrimaryButton as Primary, icons, useToggle } from "my-ui";
+import { dep, value } from "my-lib";
+
+it("should import the declaring modules instead of the barrel", () => {
+	expect(Button()).toBe("button");
+	expect(Primary).toBe(Button);
+	expect(Card()).toBe("card");
+	expect(icons.close).toBe("close");
+	expect(useToggle(false)).toBe(true);
+	expect(globalThis.__MY_UI_BARREL_EXECUTED__).toBeUndefined();
+});
+
+it("should keep importing the barrel when the declaring module is not exported by the package", () => {
+	expect(value).toBe("value");
+	expect(globalThis.__MY_LIB_BARREL_EXECUTED__).toBe(true);
+});
+
+it("should keep importing the barrel when the declaring module resolves differently from the importer", () => {
+	expect(dep).toBe("nested dep");
+});
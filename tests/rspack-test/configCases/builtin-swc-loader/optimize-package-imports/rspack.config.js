@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	module: {
+		rules: [
+			{
+				test: /\.js$/,
+				loader: "builtin:swc-loader",
+				options: {
+					rspackExperiments: {
+						optimizePackageImports: ["my-ui", "my-lib"]
+					}
+				}
+			}
+		]
+	}
+};
@@ -0,0 +1,3 @@
+import { shared } from "./shared";
+
+globalThis.__MODULE_PRELOAD_EXECUTED__ = ["app", shared];
@@ -0,0 +1,53 @@
+const fs = require("fs");
+const path = require("path");
+const vm = require("vm");
+const { pathToFileURL } = require("url");
+
+const readHtml = file => fs.readFileSync(path.join(__dirname, file), "utf-8");
+
+it("should inject the statically imported chunks as modulepreload links", () => {
+	const htmlContent = readHtml("index.html");
+	expect(htmlContent).toContain('<link href="runtime.mjs" rel="modulepreload">');
+	expect(htmlContent).toContain('<link href="shared.mjs" rel="modulepreload">');
+	expect(htmlContent).toContain('<script src="app.mjs" type="module"></script>');
+	expect(htmlContent).not.toContain('<script src="runtime.mjs"');
+	expect(htmlContent).not.toContain('<script src="shared.mjs"');
+});
+
+it("should execute the preloaded chunks through the imports of the entry chunk", async () => {
+	const htmlContent = readHtml("index.html");
+	const [, entry] = htmlContent.match(/<script src="([^"]+)" type="module">/);
+	const preloaded = [...htmlContent.matchAll(/<link href="([^"]+)" rel="modulepreload">/g)].map(
+		([, href]) => href
+	);
+	const context = vm.createContext({});
+	const modules = new Map();
+	const load = file => {
+		let module = modules.get(file);
+		if (!module) {
+			module = new vm.SourceTextModule(fs.readFileSync(file, "utf-8"), {
+				identifier: pathToFileURL(file).href,
+				context
+			});
+			modules.set(file, module);
+		}
+		return module;
+	};
+	const module = load(path.join(__dirname, entry));
+	await module.link((specifier, referencingModule) =>
+		load(path.resolve(path.dirname(new URL(referencingModule.identifier).pathname), specifier))
+	);
+	await module.evaluate();
+	expect(context.__MODULE_PRELOAD_EXECUTED__).toEqual(["app", "shared"]);
+	for (const href of preloaded) {
+		expect(modules.get(path.join(__dirname, href)).status).toBe("evaluated");
+	}
+});
+
+it("should keep the script tags when the output is not esm", () => {
+	const htmlContent = readHtml("script/index.html");
+	expect(htmlContent).not.toContain('rel="modulepreload"');
+	expect(htmlContent).toContain('<script src="runtime.js" type="module"></script>');
+	expect(htmlContent).toContain('<script src="shared.js" type="module"></script>');
+	expect(htmlContent).toContain('<script src="app.js" type="module"></script>');
+});
@@ -0,0 +1,55 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+/**
+ * @param {boolean} module
+ * @returns {import("@rspack/core").Configuration}
+ */
+const web = module => {
+	const dir = module ? "" : "script/";
+	const extension = module ? "mjs" : "js";
+	return {
+		entry: {
+			app: "./app.js"
+		},
+		target: "web",
+		output: {
+			module,
+			filename: `${dir}[name].${extension}`,
+			chunkFilename: `${dir}[name].${extension}`
+		},
+		optimization: {
+			runtimeChunk: "single",
+			splitChunks: {
+				cacheGroups: {
+					shared: {
+						test: /shared/,
+						name: "shared",
+						chunks: "all",
+						enforce: true
+					}
+				}
+			}
+		},
+		plugins: [
+			new HtmlRspackPlugin({
+				filename: `${dir}index.html`,
+				scriptLoading: "module",
+				modulePreload: true
+			})
+		]
+	};
+};
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	web(true),
+	// the chunks of non-esm entries don't import each other
+	web(false),
+	{
+		entry: "./index.js",
+		target: "node",
+		output: {
+			filename: "test.js"
+		}
+	}
+];
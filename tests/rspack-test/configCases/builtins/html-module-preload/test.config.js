@@ -0,0 +1,6 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: function (i) {
+		return i === 2 ? ["test.js"] : [];
+	}
+};
@@ -0,0 +1,3 @@
+import(/* webpackChunkName: "settings", webpackPrefetch: true */ "./settings");
+import(/* webpackChunkName: "checkout", webpackPreload: true */ "./checkout");
+import(/* webpackChunkName: "about" */ "./about");
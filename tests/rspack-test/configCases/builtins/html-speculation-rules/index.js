@@ -0,0 +1,21 @@
+const fs = require("fs");
+const path = require("path");
+
+it("should inject speculation rules for the prefetched and preloaded chunks", () => {
+	const htmlContent = fs.readFileSync(
+		path.join(__dirname, "index.html"),
+		"utf-8"
+	);
+	const [, rules] = htmlContent.match(
+		/<script type="speculationrules">(.*?)<\/script>/
+	);
+	expect(JSON.parse(rules)).toEqual({
+		prefetch: [
+			{
+				source: "list",
+				urls: ["/settings", "/cart", "/checkout"],
+				eagerness: "moderate"
+			}
+		]
+	});
+});
@@ -0,0 +1,30 @@
+const { HtmlRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		entry: {
+			app: "./app.js"
+		},
+		target: "web",
+		plugins: [
+			new HtmlRspackPlugin({
+				speculationRules: {
+					urls: {
+						settings: "/settings",
+						checkout: ["/cart", "/checkout"],
+						about: "/about"
+					},
+					eagerness: "moderate"
+				}
+			})
+		]
+	},
+	{
+		entry: "./index.js",
+		target: "node",
+		output: {
+			filename: "test.js"
+		}
+	}
+];
@@ -0,0 +1,36 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should report unsatisfiable singletons in the stats", () => {
+	const stats = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "mf-stats.json"), "utf-8")
+	);
+	expect(stats.sharedConflicts).toEqual([
+		{
+			kind: "unsatisfiedSingleton",
+			name: "xreact",
+			shareScope: "default",
+			version: "2.0.0",
+			requiredVersion: "^1.0.0",
+			consumer: "container",
+			strictVersion: false,
+			providers: [
+				{
+					container: "container",
+					version: "1.2.0",
+					request: "node_modules/xreact/index.js"
+				},
+				{ container: "remote", version: "2.0.0" }
+			]
+		}
+	]);
+});
+
+it("should record the share scope and strict version of shared modules", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "mf-manifest.json"), "utf-8")
+	);
+	const xreact = manifest.shared.find(item => item.name === "xreact");
+	expect(xreact.shareScope).toBe("default");
+	expect(xreact.strictVersion).toBe(false);
+});
@@ -0,0 +1,5 @@
+import xreact from 'xreact';
+
+export function App() {
+	return xreact;
+}
@@ -0,0 +1,26 @@
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			filename: "container.js",
+			library: { type: "commonjs-module" },
+			exposes: {
+				"./App": "./module.js"
+			},
+			shared: {
+				xreact: {
+					singleton: true,
+					requiredVersion: "^1.0.0"
+				}
+			},
+			manifest: {
+				sharedCheck: {
+					remoteManifests: ["./remote-manifest.json"]
+				}
+			}
+		})
+	]
+};
@@ -0,0 +1,3 @@
+module.exports = [
+	[/Shared singleton module "xreact" .* resolves to version 2\.0\.0 provided by "remote", which doesn't satisfy the required version "\^1\.0\.0" of "container"/]
+];
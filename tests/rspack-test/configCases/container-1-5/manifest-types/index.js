@@ -0,0 +1,30 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+it("should record the types archive in the manifest", () => {
+	const manifest = JSON.parse(
+		fs.readFileSync(path.join(__dirname, "mf-manifest.json"), "utf-8")
+	);
+	expect(manifest.metaData.types).toEqual({
+		path: "compiled-types",
+		name: "@mf-types",
+		zip: "@mf-types.zip",
+		api: "@mf-types.d.ts"
+	});
+});
+
+it("should emit the remote keys of exposes", () => {
+	const api = fs.readFileSync(path.join(__dirname, "@mf-types.d.ts"), "utf-8");
+	expect(api).toContain(
+		"export type RemoteKeys = 'REMOTE_ALIAS_IDENTIFIER/Button';"
+	);
+});
+
+it("should emit declarations of exposes and their local imports", () => {
+	const archive = fs.readFileSync(path.join(__dirname, "@mf-types.zip"));
+	// entry names are stored uncompressed in the zip headers
+	const content = archive.toString("latin1");
+	expect(content).toContain("Button.d.ts");
+	expect(content).toContain("compiled-types/src/Button.d.ts");
+	expect(content).toContain("compiled-types/src/types.d.ts");
+});
@@ -0,0 +1,50 @@
+const path = require("path");
+const { ModuleFederationPlugin } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	plugins: [
+		new ModuleFederationPlugin({
+			name: "container",
+			filename: "container.js",
+			library: { type: "commonjs-module" },
+			exposes: {
+				"./Button": "./src/Button.ts"
+			},
+			manifest: {
+				types: true
+			}
+		}),
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", stats => {
+					// only imported by `import type`, so it is out of the module graph
+					expect(
+						stats.compilation.fileDependencies.has(
+							path.resolve(__dirname, "src/types.ts")
+						)
+					).toBe(true);
+				});
+			}
+		}
+	]
+};
@@ -0,0 +1,7 @@
+import type { ButtonProps } from "./types";
+
+export function Button(props: ButtonProps): string {
+	return `<button>${props.label}</button>`;
+}
+
+export default Button;
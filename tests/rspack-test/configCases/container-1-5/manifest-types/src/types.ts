@@ -0,0 +1,3 @@
+export interface ButtonProps {
+	label: string;
+}
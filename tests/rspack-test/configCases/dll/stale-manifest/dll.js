@@ -0,0 +1,2 @@
+require("pkg");
+require("./lib");
@@ -0,0 +1,7 @@
+import pkg from "pkg";
+import lib from "./lib";
+
+it("should still link the modules of a stale dll", () => {
+	expect(pkg).toBe("pkg");
+	expect(lib).toBe("lib");
+});
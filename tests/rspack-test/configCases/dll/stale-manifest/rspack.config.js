@@ -0,0 +1,50 @@
+const rspack = require("@rspack/core");
+const path = require("path");
+
+const dllManifest = path.resolve(
+	__dirname,
+	"../../../js/config/dll/stale-manifest/manifest.json"
+);
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	{
+		name: "create-dll",
+		entry: "./dll.js",
+		output: {
+			filename: "dll.js",
+			library: {
+				type: "commonjs2"
+			}
+		},
+		plugins: [
+			new rspack.DllPlugin({
+				path: dllManifest,
+				entryOnly: false
+			})
+		]
+	},
+	{
+		name: "use-dll",
+		dependencies: ["create-dll"],
+		entry: "./main.js",
+		plugins: [
+			function (compiler) {
+				compiler.hooks.beforeRun.tap("test", () => {
+					const manifest = require(dllManifest);
+					// pretend the dll was built from an older version of the package and
+					// a different content of the local module
+					manifest.content["./node_modules/pkg/index.js"].package.version =
+						"0.9.0";
+					manifest.content["./lib.js"].hash = "stale";
+					new rspack.DllReferencePlugin({
+						manifest,
+						context: __dirname,
+						sourceType: "commonjs2",
+						name: "./dll.js"
+					}).apply(compiler);
+				});
+			}
+		]
+	}
+];
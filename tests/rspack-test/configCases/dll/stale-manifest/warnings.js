@@ -0,0 +1,8 @@
+module.exports = [
+	[
+		/The manifest of dll-reference \.\/dll\.js is stale for "\.\/(node_modules\/pkg\/index\.js": it was built from pkg@0\.9\.0, but pkg@1\.0\.0 is resolved now|lib\.js": .*lib\.js has changed since it was built)/
+	],
+	[
+		/The manifest of dll-reference \.\/dll\.js is stale for "\.\/(node_modules\/pkg\/index\.js": it was built from pkg@0\.9\.0, but pkg@1\.0\.0 is resolved now|lib\.js": .*lib\.js has changed since it was built)/
+	]
+];
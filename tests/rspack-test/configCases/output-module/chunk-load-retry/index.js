@@ -0,0 +1,17 @@
+it("should retry import() from the output directory with the fallback public paths", async () => {
+	const urls = [];
+	globalThis.__retry_import__ = url => {
+		urls.push(url);
+		if (urls.length < 3) return Promise.reject(new Error("failed"));
+		return import(/* webpackIgnore: true */ url.split("?")[0]);
+	};
+
+	const module = await import(/* webpackChunkName: "the-chunk" */ "./chunk");
+	expect(module.default).toBe("ok");
+	// failed imports are cached by the module map, so the retry query is added without cacheBust
+	expect(urls).toEqual([
+		"./the-chunk.mjs",
+		"https://origin.test.cases/path/the-chunk.mjs?rspack-retry=1",
+		"./the-chunk.mjs?rspack-retry=2"
+	]);
+});
@@ -0,0 +1,23 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		module: true,
+		filename: "[name].mjs",
+		chunkFilename: "[name].mjs",
+		chunkFormat: "module",
+		chunkLoading: "import",
+		importFunctionName: "__retry_import__",
+		chunkLoadRetry: {
+			maxRetries: 2,
+			retryDelay: 0,
+			cacheBust: false,
+			fallbackPublicPaths: ["https://origin.test.cases/path/"]
+		}
+	},
+	performance: {
+		hints: false
+	},
+	optimization: {
+		minimize: false
+	}
+};
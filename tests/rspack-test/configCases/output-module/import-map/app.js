@@ -0,0 +1,5 @@
+import { value } from "./shared";
+
+export const load = () => import(/* webpackChunkName: "lazy" */ "./lazy").then(m => m.default + value);
+
+globalThis.__IMPORT_MAP_LOADED__ = load();
@@ -0,0 +1,96 @@
+const fs = require("fs");
+const path = require("path");
+const vm = require("vm");
+const { pathToFileURL } = require("url");
+
+const read = file => fs.readFileSync(path.resolve(__dirname, file), "utf-8");
+const findFile = (name, dir = "") =>
+	fs
+		.readdirSync(path.resolve(__dirname, dir))
+		.find(file => file.startsWith(`${name}.`) && file.endsWith(".mjs"));
+
+/**
+ * Loads an entry like a browser does, the chunks are resolved by the import map, and their
+ * addresses are resolved against the document next to the import map.
+ */
+const loadThroughImportMap = async (html, entry) => {
+	const { imports } = JSON.parse(read("importmap.json"));
+	const context = vm.createContext({});
+	const modules = new Map();
+	const load = file => {
+		let module = modules.get(file);
+		if (!module) {
+			module = new vm.SourceTextModule(read(file), {
+				identifier: pathToFileURL(file).href,
+				context,
+				initializeImportMeta(meta) {
+					meta.url = pathToFileURL(file).href;
+				},
+				async importModuleDynamically(specifier) {
+					const imported = resolve(specifier);
+					if (imported.status === "unlinked") {
+						await imported.link(resolve);
+					}
+					await imported.evaluate();
+					return imported;
+				}
+			});
+			modules.set(file, module);
+		}
+		return module;
+	};
+	const resolve = specifier => {
+		const address = imports[specifier];
+		if (!address) {
+			throw new Error(`${specifier} is not in the import map`);
+		}
+		return load(path.resolve(path.dirname(path.resolve(__dirname, html)), address));
+	};
+	const module = load(path.resolve(__dirname, entry));
+	await module.link(resolve);
+	await module.evaluate();
+	return context;
+};
+
+it("should emit an import map which resolves the chunk specifiers to the chunk files", () => {
+	const { imports } = JSON.parse(read("importmap.json"));
+	const specifiers = Object.keys(imports);
+	expect(specifiers.length).toBe(3);
+	for (const specifier of specifiers) {
+		expect(specifier).toMatch(/^@rspack-chunk\//);
+	}
+	// `publicPath: "auto"` creates relative urls, which have to start with `./` in import maps
+	expect(Object.values(imports).sort()).toEqual(
+		[findFile("app"), findFile("lazy"), findFile("runtime")].map(file => `./${file}`).sort()
+	);
+});
+
+it("should load the chunks through the import map", async () => {
+	const context = await loadThroughImportMap("index.html", findFile("app"));
+	expect(await context.__IMPORT_MAP_LOADED__).toBe(42);
+});
+
+it("should import chunks by their specifiers instead of their hashed filenames", () => {
+	const { imports } = JSON.parse(read("importmap.json"));
+	const app = read(findFile("app"));
+	const runtime = read(findFile("runtime"));
+	expect(app).toMatch(/from '@rspack-chunk\/[^']*runtime'/);
+	expect(app).not.toContain(findFile("runtime"));
+	expect(runtime).toContain('"@rspack-chunk/');
+	for (const file of Object.values(imports)) {
+		expect(runtime).not.toContain(file.slice(2));
+	}
+});
+
+it("should not change the importers when a leaf chunk changes", () => {
+	expect(findFile("lazy", "changed")).not.toBe(findFile("lazy"));
+	expect(findFile("app", "changed")).toBe(findFile("app"));
+});
+
+it("should inject the import map before the chunk scripts", () => {
+	const html = read("index.html");
+	const importMap = html.indexOf('<script type="importmap">');
+	expect(importMap).toBeGreaterThan(-1);
+	expect(html).toContain(read("importmap.json"));
+	expect(importMap).toBeLessThan(html.indexOf(findFile("app")));
+});
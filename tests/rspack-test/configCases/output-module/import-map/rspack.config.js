@@ -0,0 +1,42 @@
+const { DefinePlugin, HtmlRspackPlugin } = require("@rspack/core");
+
+/**
+ * @param {string} dir
+ * @param {number} lazyValue
+ * @returns {import("@rspack/core").Configuration}
+ */
+const web = (dir, lazyValue) => ({
+	entry: {
+		app: "./app.js"
+	},
+	target: "web",
+	output: {
+		module: true,
+		filename: `${dir}[name].[contenthash].mjs`,
+		chunkFilename: `${dir}[name].[contenthash].mjs`,
+		importMap: dir ? { filename: `${dir}importmap.json` } : true
+	},
+	optimization: {
+		chunkIds: "named",
+		runtimeChunk: "single",
+		realContentHash: true
+	},
+	plugins: [
+		new DefinePlugin({ LAZY_VALUE: lazyValue }),
+		new HtmlRspackPlugin({ filename: `${dir}index.html` })
+	]
+});
+
+/** @type {import("@rspack/core").Configuration[]} */
+module.exports = [
+	web("", 41),
+	// the same app with a changed leaf chunk
+	web("changed/", 42),
+	{
+		entry: "./index.js",
+		target: "node",
+		output: {
+			filename: "test.js"
+		}
+	}
+];
@@ -0,0 +1,8 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+	findBundle: function (i) {
+		// the esm output imports bare specifiers which are only resolved by the import map in browsers,
+		// the test loads it through the import map instead
+		return i === 2 ? ["test.js"] : [];
+	}
+};
@@ -0,0 +1,3 @@
+it("should warn when the baseline is missing", () => {
+	expect(typeof __filename).toBe("string");
+});
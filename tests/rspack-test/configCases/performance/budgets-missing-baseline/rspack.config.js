@@ -0,0 +1,10 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: false,
+		budgets: [{ name: "main-gzip", entry: "main", compression: "gzip" }],
+		baseline: {
+			path: "./baseline.json"
+		}
+	}
+};
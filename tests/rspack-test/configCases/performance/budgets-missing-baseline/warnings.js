@@ -0,0 +1,5 @@
+module.exports = [
+	[
+		/budget size growth: The baseline \.\/baseline\.json does not exist or can't be read[\s\S]*RSPACK_UPDATE_SIZE_BASELINE=true/
+	]
+];
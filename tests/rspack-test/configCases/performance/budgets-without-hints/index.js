@@ -0,0 +1,3 @@
+it("should report the budgets without performance hints", () => {
+	expect(typeof __filename).toBe("string");
+});
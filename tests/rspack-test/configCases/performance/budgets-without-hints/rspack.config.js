@@ -0,0 +1,7 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: false,
+		budgets: [{ entry: "main", maxSize: 100 }]
+	}
+};
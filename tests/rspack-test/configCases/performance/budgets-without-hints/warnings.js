@@ -0,0 +1,3 @@
+module.exports = [
+	[/budget size limit: The following budget\(s\) exceed their size limit[\s\S]*entry main \(/]
+];
@@ -0,0 +1,4 @@
+it("should load the async chunk", async () => {
+	const { default: value } = await import("./async");
+	expect(value).toBe(42);
+});
@@ -0,0 +1,17 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	performance: {
+		hints: "warning",
+		budgets: [
+			{ entry: "main", maxSize: 100 },
+			{ name: "main-gzip", entry: "main", compression: "gzip" },
+			{ name: "main-brotli", entry: "main", compression: "brotli" },
+			{ chunkType: "async", maxSize: 100000 },
+			{ entry: "missing" }
+		],
+		baseline: {
+			path: "./baseline.json",
+			maxGrowth: 10
+		}
+	}
+};
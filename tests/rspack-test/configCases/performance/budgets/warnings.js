@@ -0,0 +1,7 @@
+module.exports = [
+	[/budget size limit: The entrypoint "missing" of budget "entry missing" does not exist/],
+	[/budget size limit: The following budget\(s\) exceed their size limit[\s\S]*entry main \(/],
+	[
+		/budget size growth: The following budget\(s\) grew more than 10% over the baseline \.\/baseline\.json[\s\S]*main-gzip \(10\.000 bytes -> [\s\S]*main-brotli \(10\.000 bytes -> /
+	]
+];
@@ -0,0 +1,22 @@
+it("should expose expanded values through process.env", () => {
+	expect(process.env.PUBLIC_HOST).toBe("example.com");
+	expect(process.env.PUBLIC_API_URL).toBe("https://example.com/api/v1");
+	expect(process.env.PUBLIC_PORT).toBe("3000");
+	expect(process.env.PUBLIC_LITERAL).toBe("${PUBLIC_HOST}");
+	expect(process.env.PUBLIC_MODE).toBe("development");
+});
+
+it("should expose expanded values through import.meta.env", () => {
+	expect(import.meta.env.PUBLIC_API_URL).toBe("https://example.com/api/v1");
+	expect(import.meta.env.PUBLIC_PORT).toBe("3000");
+	expect(import.meta.env.PUBLIC_MODE).toBe("development");
+});
+
+it("should not expose variables without the prefix", () => {
+	expect(typeof process.env.SECRET).toBe("undefined");
+});
+
+it("should expand cyclic references to empty strings", () => {
+	expect(process.env.PUBLIC_CYCLE_A).toBe("a-b-");
+	expect(process.env.PUBLIC_CYCLE_B).toBe("b-a-");
+});
@@ -0,0 +1,7 @@
+const { DotenvPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	plugins: [new DotenvPlugin()]
+};
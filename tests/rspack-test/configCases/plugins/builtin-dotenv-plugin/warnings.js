@@ -0,0 +1,4 @@
+module.exports = [
+	[/Cyclic reference in env files: \$PUBLIC_CYCLE_A -> \$PUBLIC_CYCLE_B -> \$PUBLIC_CYCLE_A/],
+	[/Env variable "PUBLIC_API-VERSION" is not a valid identifier and is not exposed/]
+];
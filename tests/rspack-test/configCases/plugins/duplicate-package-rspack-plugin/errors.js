@@ -0,0 +1,17 @@
+module.exports = [
+	[
+		/Package "compatible" is bundled 2 times/,
+		/compatible@1\.1\.0 at \.\/node_modules\/b\/node_modules\/compatible \(/,
+		/imported through \.\/index\.js -> \.\/node_modules\/b\/index\.js -> \.\/node_modules\/b\/node_modules\/compatible\/index\.js/,
+		/compatible@1\.2\.0 at \.\/node_modules\/compatible \(/,
+		/imported through \.\/index\.js -> \.\/node_modules\/compatible\/index\.js/,
+		/the versions are compatible, aliasing "compatible" to \.\/node_modules\/compatible with `resolve\.alias` would dedupe them/
+	],
+	[
+		/Package "skew" is bundled 2 times/,
+		/skew@1\.0\.0 at \.\/node_modules\/a\/node_modules\/skew \(/,
+		/imported through \.\/index\.js -> \.\/node_modules\/a\/index\.js -> \.\/node_modules\/a\/node_modules\/skew\/index\.js/,
+		/skew@2\.0\.0 at \.\/node_modules\/b\/node_modules\/skew \(/,
+		/the versions are incompatible/
+	]
+];
@@ -0,0 +1,13 @@
+import a from "a";
+import b from "b";
+import compatible from "compatible";
+import allowed from "allowed";
+import single from "single";
+
+it("should still bundle every copy of a duplicated package", () => {
+	expect(a).toBe("skew@1");
+	expect(b).toBe("skew@2compatible@1.1allowed@1");
+	expect(compatible).toBe("compatible@1.2");
+	expect(allowed).toBe("allowed@2");
+	expect(single).toBe("single");
+});
@@ -0,0 +1,11 @@
+const { DuplicatePackageRspackPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	plugins: [
+		new DuplicatePackageRspackPlugin({
+			failOnError: true,
+			allowlist: ["allowed"]
+		})
+	]
+};
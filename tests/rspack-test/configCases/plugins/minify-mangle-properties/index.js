@@ -0,0 +1,15 @@
+const obj = {
+	_secret: 1,
+	_kept: 2,
+	get _value() {
+		return this._secret + 1;
+	}
+};
+
+it("should mangle properties matching the regex", () => {
+	const { _secret } = obj;
+	expect(_secret).toBe(1);
+	expect(obj._value).toBe(2);
+	expect(Object.keys(obj)).not.toContain("_secret");
+	expect(Object.keys(obj)).toContain("_kept");
+});
@@ -0,0 +1,3 @@
+module.exports = [
+	[/TS2322/, /Type 'number' is not assignable to type 'string'/]
+];
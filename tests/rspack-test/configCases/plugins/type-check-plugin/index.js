@@ -0,0 +1,5 @@
+import { greeting } from "./lib";
+
+it("should still emit the bundle with type errors", () => {
+	expect(greeting).toBe(42);
+});
@@ -0,0 +1,3 @@
+import type { Greeting } from "./types";
+
+export const greeting: Greeting = 42;
@@ -0,0 +1,44 @@
+const path = require("path");
+const { TypeCheckPlugin } = require("@rspack/core");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "production",
+	resolve: {
+		extensions: ["...", ".ts"]
+	},
+	module: {
+		rules: [
+			{
+				test: /\.ts$/,
+				loader: "builtin:swc-loader",
+				options: {
+					jsc: {
+						parser: {
+							syntax: "typescript"
+						}
+					}
+				},
+				type: "javascript/auto"
+			}
+		]
+	},
+	plugins: [
+		new TypeCheckPlugin({
+			command: "tsc",
+			buildInfoFile: false
+		}),
+		{
+			apply(compiler) {
+				compiler.hooks.done.tap("Test", stats => {
+					// only imported by `import type`, so it is out of the module graph
+					expect(
+						stats.compilation.fileDependencies.has(
+							path.resolve(__dirname, "types.ts")
+						)
+					).toBe(true);
+				});
+			}
+		}
+	]
+};
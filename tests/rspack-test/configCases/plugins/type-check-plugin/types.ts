@@ -0,0 +1 @@
+export type Greeting = string;
@@ -0,0 +1,26 @@
+import value from "lib";
+import util from "lib/util.js";
+import scoped from "./src/scoped/index.js";
+import exact, { util as exactUtil } from "./src/exact.js";
+import { util as queriedExactUtil } from "./src/exact.js?query#fragment";
+import sibling, { util as siblingUtil } from "./src/sibling.js";
+
+it("should resolve through top-level imports", () => {
+	expect(value).toBe("lib");
+	expect(util).toBe("util");
+});
+
+it("should resolve through scopes with a trailing slash", () => {
+	expect(scoped).toBe("scoped");
+});
+
+it("should resolve through scopes keyed on the issuer file", () => {
+	expect(exact).toBe("lib");
+	expect(exactUtil).toBe("exact-util");
+	expect(sibling).toBe("lib");
+	expect(siblingUtil).toBe("util");
+});
+
+it("should ignore the query and the fragment of the issuer", () => {
+	expect(queriedExactUtil).toBe("exact-util");
+});
@@ -0,0 +1,9 @@
+const path = require("path");
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	entry: "./index.js",
+	resolve: {
+		importMap: path.resolve(__dirname, "importmap.json")
+	}
+};
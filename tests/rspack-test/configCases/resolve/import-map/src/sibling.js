@@ -0,0 +1,2 @@
+export { default } from "lib";
+export { default as util } from "lib/util.js";
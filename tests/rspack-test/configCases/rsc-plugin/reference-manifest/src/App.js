@@ -0,0 +1,10 @@
+import { Client } from './Client';
+
+export const App = () => {
+  return (
+    <>
+      <h1>RSC App</h1>
+      <Client />
+    </>
+  );
+};
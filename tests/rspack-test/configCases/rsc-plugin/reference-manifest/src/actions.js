@@ -0,0 +1,9 @@
+'use server';
+
+export async function add() {}
+
+export async function del() {}
+
+export async function get() {}
+
+export async function update() {}
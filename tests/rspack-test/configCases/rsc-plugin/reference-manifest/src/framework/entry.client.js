@@ -0,0 +1,2 @@
+// In a real app this entry would consume the RSC payload and hydrate.
+// This file exists mainly to mirror the typical split of RSC/SSR/client entries.
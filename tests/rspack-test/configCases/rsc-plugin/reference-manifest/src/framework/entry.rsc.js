@@ -0,0 +1,34 @@
+import fs from 'node:fs';
+import path from 'node:path';
+import { renderToReadableStream } from 'react-server-dom-rspack/server';
+import { App } from '../App';
+
+export const renderRscStream = () => {
+  return renderToReadableStream(<App />);
+};
+
+const readManifest = (filename) =>
+  JSON.parse(fs.readFileSync(path.join(__dirname, filename), 'utf-8'));
+
+it('should emit the client reference manifest', () => {
+  const manifest = __rspack_rsc_manifest__;
+  const clientManifest = readManifest('client-reference-manifest.json');
+
+  expect(clientManifest.version).toBe(1);
+  expect(clientManifest.moduleLoading).toEqual(manifest.moduleLoading);
+  expect(clientManifest.clientManifest).toEqual(manifest.clientManifest);
+  expect(Object.keys(clientManifest.clientManifest)).toEqual([CLIENT_PATH]);
+  expect(clientManifest.serverConsumerModuleMap).toEqual(
+    manifest.serverConsumerModuleMap,
+  );
+  expect(clientManifest.entryJsFiles.main).toEqual(manifest.entryJsFiles);
+});
+
+it('should emit the server reference manifest', () => {
+  const manifest = __rspack_rsc_manifest__;
+  const serverManifest = readManifest('rsc/server-reference-manifest.json');
+
+  expect(serverManifest.version).toBe(1);
+  expect(serverManifest.serverManifest).toEqual(manifest.serverManifest);
+  expect(Object.keys(serverManifest.serverManifest)).toHaveLength(4);
+});
@@ -0,0 +1,6 @@
+/** @type {import("../../../..").TConfigCaseConfig} */
+module.exports = {
+  findBundle: function () {
+    return ['bundle0.js'];
+  },
+};
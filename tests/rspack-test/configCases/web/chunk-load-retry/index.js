@@ -0,0 +1,46 @@
+const nextScript = async () => {
+	// the retry is scheduled with a timeout
+	await new Promise(resolve => setTimeout(resolve, 10));
+	expect(document.head._children).toHaveLength(1);
+	return document.head._children[0];
+};
+
+it("should retry a failed chunk load with the fallback public paths", async () => {
+	const promise = import(/* webpackChunkName: "the-chunk" */ "./chunk");
+
+	let script = document.head._children[0];
+	expect(script.src).toBe("https://test.cases/path/the-chunk.js");
+	script.onerror({ type: "error", target: script });
+
+	script = await nextScript();
+	expect(script.src).toBe(
+		"https://origin.test.cases/path/the-chunk.js?rspack-retry=1"
+	);
+	script.onerror({ type: "error", target: script });
+
+	script = await nextScript();
+	expect(script.src).toBe("https://test.cases/path/the-chunk.js?rspack-retry=2");
+	__non_webpack_require__("./the-chunk.js");
+	script.onload();
+
+	const module = await promise;
+	expect(module).toEqual(nsObj({ default: "ok" }));
+});
+
+it("should reject when all retries failed", async () => {
+	const promise = import(/* webpackChunkName: "other-chunk" */ "./other");
+
+	let script = document.head._children[0];
+	for (let attempt = 0; attempt < 2; attempt++) {
+		script.onerror({ type: "error", target: script });
+		script = await nextScript();
+	}
+	script.onerror({ type: "error", target: script });
+
+	const err = await promise.catch(err => err);
+	expect(err).toBeInstanceOf(Error);
+	expect(err.name).toBe("ChunkLoadError");
+	expect(err.request).toBe(
+		"https://test.cases/path/other-chunk.js?rspack-retry=2"
+	);
+});
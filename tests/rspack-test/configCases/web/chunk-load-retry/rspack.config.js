@@ -0,0 +1,18 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	target: "web",
+	output: {
+		chunkFilename: "[name].js",
+		chunkLoadRetry: {
+			maxRetries: 2,
+			retryDelay: 0,
+			fallbackPublicPaths: ["https://origin.test.cases/path/"]
+		}
+	},
+	performance: {
+		hints: false
+	},
+	optimization: {
+		minimize: false
+	}
+};
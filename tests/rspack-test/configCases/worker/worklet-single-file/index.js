@@ -0,0 +1,30 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const readWorklet = url =>
+	fs.readFileSync(path.resolve(__dirname, path.basename(new URL(url).pathname)), "utf-8");
+
+it("should bundle an audio worklet into a single file", async () => {
+	const context = new AudioContext();
+	const url = await context.audioWorklet.addModule(
+		new URL(/* webpackChunkName: "processor" */ "./processor.js", import.meta.url),
+		{ credentials: "omit" }
+	);
+	expect(url).toMatch(/processor\.js$/);
+
+	const content = readWorklet(url);
+	expect(content).not.toContain("importScripts");
+	expect(content).toContain("data * gain");
+	expect(fs.existsSync(path.resolve(__dirname, "lazy.js"))).toBe(false);
+});
+
+it("should bundle a paint worklet into a single file", async () => {
+	const url = await CSS.paintWorklet.addModule(
+		new URL(/* webpackChunkName: "painter" */ "./painter.js", import.meta.url)
+	);
+	expect(url).toMatch(/painter\.js$/);
+
+	const content = readWorklet(url);
+	expect(content).toContain("registerPaint");
+	expect(content).toContain("0.5");
+});
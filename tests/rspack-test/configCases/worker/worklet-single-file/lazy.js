@@ -0,0 +1,3 @@
+export function process(data, gain) {
+	return data * gain;
+}
@@ -0,0 +1,9 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	},
+	target: "web",
+	node: false
+};
@@ -0,0 +1 @@
+export const gain = 0.5;
@@ -0,0 +1,16 @@
+module.exports = {
+	moduleScope(scope) {
+		const addModule = url => Promise.resolve(url.toString());
+		scope.AudioContext = class AudioContext {
+			constructor() {
+				this.audioWorklet = { addModule };
+			}
+		};
+		scope.CSS = {
+			paintWorklet: { addModule }
+		};
+	},
+	findBundle: function () {
+		return ["main.js"];
+	}
+};
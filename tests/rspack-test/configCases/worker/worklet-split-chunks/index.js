@@ -0,0 +1,32 @@
+const fs = __non_webpack_require__("fs");
+const path = __non_webpack_require__("path");
+
+const readWorklet = url =>
+	fs.readFileSync(path.resolve(__dirname, path.basename(new URL(url).pathname)), "utf-8");
+
+it("should keep the modules of worklets in the worklet files", async () => {
+	const context = new AudioContext();
+	const processor = readWorklet(
+		await context.audioWorklet.addModule(
+			new URL(/* webpackChunkName: "processor" */ "./processor.js", import.meta.url)
+		)
+	);
+	const painter = readWorklet(
+		await CSS.paintWorklet.addModule(
+			new URL(/* webpackChunkName: "painter" */ "./painter.js", import.meta.url)
+		)
+	);
+
+	expect(processor).not.toContain("importScripts");
+	expect(processor).toContain("data * gain");
+	expect(processor).toContain("0.5");
+	expect(painter).not.toContain("importScripts");
+	expect(painter).toContain("registerPaint");
+	expect(painter).toContain("0.5");
+	expect(fs.readdirSync(__dirname).filter(file => file.endsWith(".js")).sort()).toEqual([
+		"main.js",
+		"painter.js",
+		"processor.js",
+		"runtime.js"
+	]);
+});
@@ -0,0 +1,7 @@
+import { gain } from "./shared";
+
+registerPaint("painter", class {
+	paint() {
+		return gain;
+	}
+});
@@ -0,0 +1,6 @@
+import { gain } from "./shared";
+
+onmessage = async event => {
+	const { process } = await import(/* webpackChunkName: "lazy" */ "./lazy");
+	postMessage(process(event.data, gain));
+};
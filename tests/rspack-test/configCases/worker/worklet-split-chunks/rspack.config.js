@@ -0,0 +1,16 @@
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	output: {
+		filename: "[name].js",
+		chunkFilename: "[name].js"
+	},
+	target: "web",
+	node: false,
+	optimization: {
+		runtimeChunk: "single",
+		splitChunks: {
+			chunks: "all",
+			minSize: 0
+		}
+	}
+};
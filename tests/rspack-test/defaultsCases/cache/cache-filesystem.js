@@ -18,10 +18,12 @@ module.exports = {
 			+       "managedPaths": Array [
 			+         /[\\\\/]node_modules[\\\\/][^.]/,
 			+       ],
+			+       "mode": "timestamp",
 			+       "unmanagedPaths": Array [],
 			+     },
 			+     "storage": Object {
 			+       "directory": "<cwd>/node_modules/.cache/rspack",
+			+       "remote": undefined,
 			+       "type": "filesystem",
 			+     },
 			+     "type": "persistent",
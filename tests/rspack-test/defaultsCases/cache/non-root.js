@@ -24,10 +24,12 @@ module.exports = {
 			+       "managedPaths": Array [
 			+         /[\\\\/]node_modules[\\\\/][^.]/,
 			+       ],
+			+       "mode": "timestamp",
 			+       "unmanagedPaths": Array [],
 			+     },
 			+     "storage": Object {
 			+       "directory": "<cwd>/fixtures/node_modules/.cache/rspack",
+			+       "remote": undefined,
 			+       "type": "filesystem",
 			+     },
 			+     "type": "persistent",
@@ -24,6 +24,7 @@ module.exports = {
 			    buildHttp: undefined,
 			    deferImport: false,
 			    futureDefaults: false,
+			    strictRegex: false,
 			    useInputFileSystem: false,
 			  },
 			  externals: undefined,
@@ -351,6 +352,7 @@ module.exports = {
 			    },
 			    chunkFilename: [name].js,
 			    chunkFormat: array-push,
+			    chunkLoadRetry: undefined,
 			    chunkLoadTimeout: 120000,
 			    chunkLoading: jsonp,
 			    chunkLoadingGlobal: rspackChunk_rspack_tests,
@@ -399,6 +401,7 @@ module.exports = {
 			    hotUpdateMainFilename: [runtime].[fullhash].hot-update.json,
 			    iife: true,
 			    importFunctionName: import,
+			    importMap: undefined,
 			    importMetaName: import.meta,
 			    library: undefined,
 			    module: false,
@@ -0,0 +1,16 @@
+import fs from 'fs'
+import path from 'path'
+import { button } from './components/button'
+
+it('should preserve modules with css side files', () => {
+	expect(button()).toBe('button')
+
+	const css = fs.readFileSync(path.resolve(__dirname, 'components/button.css'), 'utf-8')
+	expect(css).toContain('.button')
+
+	const style = fs.readFileSync(path.resolve(__dirname, 'components/button.css.mjs'), 'utf-8')
+	expect(style).toContain('import "./button.css";')
+
+	const component = fs.readFileSync(path.resolve(__dirname, 'components/button.mjs'), 'utf-8')
+	expect(component).toContain('./button.css.mjs')
+})
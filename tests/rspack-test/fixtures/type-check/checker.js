@@ -0,0 +1,13 @@
+// A fake checker which reports a type error once the marker file passed as the first
+// argument is written, after the first compilation is done
+const fs = require("fs");
+
+const marker = process.argv[2];
+const timer = setInterval(() => {
+	if (!fs.existsSync(marker)) return;
+	clearInterval(timer);
+	console.log(
+		"index.ts(1,14): error TS2322: Type 'number' is not assignable to type 'string'."
+	);
+	process.exitCode = 2;
+}, 50);
@@ -0,0 +1,5 @@
+import value from "container/value";
+
+export const getValue = () => value;
+
+module.hot.accept("container/value");
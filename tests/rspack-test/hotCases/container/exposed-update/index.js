@@ -0,0 +1,6 @@
+it("should apply the updates of the exposed modules in the hosts", async () => {
+	const { getValue } = await import("./app");
+	expect(getValue()).toBe(1);
+	await NEXT_HMR();
+	expect(getValue()).toBe(2);
+});
@@ -0,0 +1,21 @@
+// eslint-disable-next-line node/no-unpublished-require
+const { ModuleFederationPluginV1 } = require("@rspack/core").container;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	devtool: false,
+	plugins: [
+		new ModuleFederationPluginV1({
+			name: "container",
+			filename: "container.js",
+			library: { type: "commonjs-module" },
+			exposes: {
+				"./value": "./value"
+			},
+			remotes: {
+				container: "./container.js"
+			}
+		})
+	]
+};
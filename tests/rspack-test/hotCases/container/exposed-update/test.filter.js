@@ -0,0 +1,6 @@
+// the hot update globals of the container and the host would conflict in one web page
+module.exports = function (config) {
+	if (config.target !== "async-node") {
+		return false;
+	}
+};
@@ -0,0 +1,9 @@
+import value from "shared";
+
+it("should propagate the updates of a shared singleton to its consumers", async () => {
+	expect(value).toBe(1);
+	await NEXT_HMR();
+	expect(value).toBe(2);
+});
+
+module.hot.accept("shared");
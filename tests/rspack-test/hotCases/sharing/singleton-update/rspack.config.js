@@ -0,0 +1,21 @@
+// eslint-disable-next-line node/no-unpublished-require
+const { SharePlugin } = require("@rspack/core").sharing;
+
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	mode: "development",
+	devtool: false,
+	plugins: [
+		new SharePlugin({
+			shared: {
+				shared: {
+					eager: true,
+					import: "./shared",
+					singleton: true,
+					version: "1.0.0",
+					requiredVersion: "^1.0.0"
+				}
+			}
+		})
+	]
+};
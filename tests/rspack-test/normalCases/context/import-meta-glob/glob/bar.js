@@ -0,0 +1,2 @@
+export const name = "bar";
+export default "bar";
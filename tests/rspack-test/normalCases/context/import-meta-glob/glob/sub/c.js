@@ -0,0 +1,2 @@
+export const name = "c";
+export default "c";
@@ -0,0 +1,68 @@
+it("should expand a glob into lazy loaders", async () => {
+	const modules = import.meta.glob("./glob/*.js");
+	expect(Object.keys(modules)).toEqual([
+		"./glob/a.js",
+		"./glob/b.js",
+		"./glob/bar.js"
+	]);
+	expect(typeof modules["./glob/a.js"]).toBe("function");
+	const a = await modules["./glob/a.js"]();
+	expect(a.default).toBe("a");
+	expect(a.name).toBe("a");
+});
+
+it("should support negative patterns and eager loading", () => {
+	const modules = import.meta.glob(["./glob/**/*.js", "!**/bar.js"], {
+		eager: true
+	});
+	expect(Object.keys(modules)).toEqual([
+		"./glob/a.js",
+		"./glob/b.js",
+		"./glob/sub/c.js"
+	]);
+	expect(modules["./glob/sub/c.js"].default).toBe("c");
+});
+
+it("should pick the named import", async () => {
+	const lazy = import.meta.glob("./glob/{a,b}.js", { import: "default" });
+	expect(await lazy["./glob/b.js"]()).toBe("b");
+
+	const eager = import.meta.glob("./glob/sub/*.js", {
+		import: "name",
+		eager: true
+	});
+	expect(eager).toEqual({ "./glob/sub/c.js": "c" });
+});
+
+it("should support import.meta.globEager", () => {
+	const modules = import.meta.globEager("./glob/a.js");
+	expect(modules["./glob/a.js"].name).toBe("a");
+});
+
+it("should create keys relative to the importer and exclude the importer", async () => {
+	const { modules } = await import("./nested/importer");
+	expect(modules).toEqual({ "../glob/a.js": "a", "./d.js": "d" });
+	expect(Object.keys(import.meta.glob("./*.js"))).toEqual([]);
+});
+
+it("should load each base directory on its own", () => {
+	const modules = import.meta.glob(["./glob/*.js", "./glob/sub/*.js"], {
+		import: "default",
+		eager: true
+	});
+	expect(modules).toEqual({
+		"./glob/a.js": "a",
+		"./glob/b.js": "b",
+		"./glob/bar.js": "bar",
+		"./glob/sub/c.js": "c"
+	});
+});
+
+it("should append the query option to the requests", () => {
+	const modules = import.meta.glob("./query/*.js", {
+		query: { lang: "en", raw: true },
+		import: "default",
+		eager: true
+	});
+	expect(modules).toEqual({ "./query/q.js": "?lang=en&raw=true" });
+});
@@ -0,0 +1,4 @@
+export const modules = import.meta.glob(["./*.js", "../glob/a.js"], {
+	import: "name",
+	eager: true
+});
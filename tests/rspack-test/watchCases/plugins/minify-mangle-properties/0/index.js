@@ -0,0 +1,15 @@
+import { obj } from "./module";
+
+it("should keep the mangled names between builds", () => {
+	const [mangled] = Object.keys(obj);
+	expect(mangled).not.toBe("_secret");
+	expect(obj[mangled]).toBe(1);
+	switch (WATCH_STEP) {
+		case "0":
+			STATE.mangled = mangled;
+			break;
+		case "1":
+			expect(mangled).toBe(STATE.mangled);
+			break;
+	}
+});
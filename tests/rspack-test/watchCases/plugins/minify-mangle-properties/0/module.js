@@ -0,0 +1 @@
+export const obj = { _secret: 1 };
@@ -0,0 +1,14 @@
+const { rspack } = require("@rspack/core");
+/** @type {import("@rspack/core").Configuration} */
+module.exports = {
+	optimization: {
+		minimize: true
+	},
+	plugins: [
+		new rspack.SwcJsMinimizerRspackPlugin({
+			mangleProperties: {
+				regex: /^_[a-z]/
+			}
+		})
+	]
+};